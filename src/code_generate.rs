@@ -1,4 +1,5 @@
 use std::cmp::min;
+use std::fs::{self, write};
 use std::path::{PathBuf};
 use std::sync::mpsc::channel;
 use std::sync::{Arc, Mutex};
@@ -7,14 +8,24 @@ use threadpool::ThreadPool;
 
 use crate::file_cache::FileCache;
 use crate::run_options::show_times::ShowTimes;
-use crate::utils::logger::DEFAULT_LOG_OPTIONS;
+use crate::run_options::show_output::ShowOutputs;
+use crate::steps::sementic::literal_retention::retention_to_string;
+use crate::steps::sementic::optimizer::optimizations_to_string;
+use crate::steps::sementic::reachability::{analyse_reachability, dead_symbols_to_string, remove_dead_functions, unused_function_warnings, ProgramPage, Reachability};
+use crate::steps::code_generator::cpp_generator::generate_cpp;
+use crate::steps::code_generator::cpp_compiler::{compiler_arguments, compiler_faults, find_cpp_compiler, run_cpp_compiler};
+use crate::steps::code_generator::cpp_names::page_file;
+use crate::steps::code_generator::cpp_runtime::{SOUL_RUNTIME_FILE, SOUL_RUNTIME_HEADER};
+use crate::steps::step_interfaces::i_parser::abstract_syntax_tree::soul_type::type_kind::SoulPagePath;
+use crate::utils::logger::{default_log_options, DEFAULT_LOG_OPTIONS};
 use crate::steps::step_interfaces::i_parser::header::ExternalHeaders;
 use crate::steps::step_interfaces::i_sementic::soul_fault::SoulFault;
 use crate::steps::step_interfaces::i_sementic::scope_vistitor::ScopeVisitor;
 use crate::steps::step_interfaces::i_parser::parser_response::ParserResponse;
+use crate::steps::step_interfaces::i_parser::abstract_syntax_tree::pretty_format::PrettyFormat;
 use crate::steps::step_interfaces::i_sementic::sementic_response::SementicResponse;
 use crate::{run_options::run_options::RunOptions, utils::{logger::Logger, time_logs::TimeLogs}};
-use crate::steps::step_interfaces::i_sementic::ast_visitor::{AstAnalyser, ExternalHeaderAnalyser, NameResolutionAnalyser};
+use crate::steps::step_interfaces::i_sementic::ast_visitor::{AstAnalyser, BorrowChecker, ControlFlowAnalyser, ExternalHeaderAnalyser, LambdaCaptureAnalyser, LiteralEvaluator, LiteralRetentionAnalyser, NameResolutionAnalyser, Optimizer, RulesetAnalyser, TraitAnalyser, TypeCheckingAnalyser, TypeResolutionAnalyser};
 
 /// Runs semantic analysis and code generation preparation for all parsed source files.
 ///
@@ -24,9 +35,25 @@ use crate::steps::step_interfaces::i_sementic::ast_visitor::{AstAnalyser, Extern
 /// 3. Performs semantic analysis on the parsed AST using multiple analysers:
 ///    - [`NameResolutionAnalyser`]
 ///    - [`ExternalHeaderAnalyser`]
+///    - [`TypeResolutionAnalyser`]
+///    - [`TypeCheckingAnalyser`]
+///    - [`ControlFlowAnalyser`]
+///    - [`TraitAnalyser`]
+///    - [`RulesetAnalyser`]
+///    - [`LiteralEvaluator`]
+///    - [`LiteralRetentionAnalyser`]
+///    - [`LambdaCaptureAnalyser`]
+///    - [`BorrowChecker`]
+///    - [`Optimizer`]
 ///    - [`ScopeVisitor`]
-/// 4. Aggregates semantic faults and warnings from all files.
-/// 5. Records timing information for analysis if `ShowTimes::SHOW_CODE_GENERATOR` is enabled.
+/// 4. Runs whole program reachability from `main` (see [`analyse_reachability`]), warns about unused
+///    private functions and removes functions that can not be reached.
+/// 5. If no file has an error, converts every used page to c++ (see [`generate_cpp`]) and writes
+///    the header and source of each page to `{output_dir}/cpp` (formatted if `-prettyCppCode`).
+///    If the main page has a `main` function the c++ is compiled and linked into an executable
+///    (see [`build_executable`]), compiler errors are turned into faults of the soul files.
+/// 6. Aggregates semantic faults and warnings from all files.
+/// 7. Records timing information for analysis if `ShowTimes::SHOW_CODE_GENERATOR` is enabled.
 ///
 /// # Parameters
 /// - `run_options`: Shared configuration for the current compilation run, including 
@@ -106,17 +133,168 @@ fn generate_all_codes(
 
     drop(sender);
 
+    let mut responses = vec![];
     for result in reciever {
         
         match result {
-            Ok(response) => errors.push((response.path, response.faults)),
+            Ok(response) => responses.push(response),
             Err(err) => panic!("build interupted code generation failed, error: {}", err),
         }
     }
 
+    responses.sort_by(|a, b| a.path.cmp(&b.path));
+    if !responses.iter().any(|response| response.has_error) {
+        let reachability = eliminate_dead_code(&mut responses, run_options)?;
+        let cpp_sources = write_cpp_code(&responses, reachability.as_ref(), run_options, time_logs)?;
+
+        if reachability.is_some() {
+            build_executable(&mut responses, &cpp_sources, run_options, logger, time_logs)?;
+        }
+    }
+
+    for response in responses {
+        errors.push((response.path, response.faults));
+    }
+
     Ok(errors)
 }
 
+/// whole program reachability from 'main', warns for unused private functions and removes unreachable functions
+fn eliminate_dead_code(responses: &mut [SementicResponse], run_options: &Arc<RunOptions>) -> Result<Option<Reachability>, String> {
+    let pages = responses.iter()
+        .map(|response| ProgramPage{path: SoulPagePath::from_path(&response.path), tree: &response.tree, scope: &response.scopes})
+        .collect::<Vec<_>>();
+
+    let reachability = match analyse_reachability(&pages, &SoulPagePath::from_path(&run_options.file_path)) {
+        Some(reachability) => reachability,
+        None => return Ok(None),
+    };
+
+    for response in responses.iter_mut() {
+        let page = SoulPagePath::from_path(&response.path);
+        response.faults.extend(unused_function_warnings(&reachability, &page));
+        remove_dead_functions(&mut response.tree, &reachability, &page);
+    }
+
+    if run_options.show_outputs.contains(ShowOutputs::SHOW_SEMENTIC_ANALYSER) {
+        let print_path = format!("{}/steps", run_options.output_dir.to_string_lossy());
+
+        fs::create_dir_all(&print_path)
+            .map_err(|err| err.to_string())?;
+        write(format!("{}/deadSymbols.soulc", print_path), dead_symbols_to_string(&reachability))
+            .map_err(|err| err.to_string())?;
+    }
+
+    Ok(Some(reachability))
+}
+
+/// writes c++ header and source of every used page and the soul runtime to '{output_dir}/cpp', returns the written sources
+fn write_cpp_code(
+    responses: &[SementicResponse], 
+    reachability: Option<&Reachability>,
+    run_options: &Arc<RunOptions>, 
+    time_logs: &Arc<Mutex<TimeLogs>>,
+) -> Result<Vec<PathBuf>, String> {
+    let cpp_dir = run_options.output_dir.join("cpp");
+    let main_page = SoulPagePath::from_path(&run_options.file_path);
+
+    fs::create_dir_all(&cpp_dir)
+        .map_err(|err| err.to_string())?;
+    write(cpp_dir.join(SOUL_RUNTIME_FILE), SOUL_RUNTIME_HEADER)
+        .map_err(|err| err.to_string())?;
+
+    let mut cpp_sources = vec![];
+    for response in responses {
+        let page = SoulPagePath::from_path(&response.path);
+        if reachability.is_some_and(|reachability| reachability.unused_pages.contains(&page)) {
+            continue
+        }
+
+        // pretty c++ is for reading so only compiled c++ points back to the soul code
+        let line_directives_file = (!run_options.pretty_cpp_code).then_some(response.path.as_path());
+
+        let start = Instant::now();
+        let cpp = generate_cpp(&response.tree, &response.scopes, &page, page == main_page, run_options.pretty_cpp_code, line_directives_file);
+
+        if run_options.show_times.contains(ShowTimes::SHOW_CODE_GENERATOR) {
+            time_logs
+                .lock().unwrap()
+                .push(&response.path.to_string_lossy().to_string(), "cppGenerator", start.elapsed());
+        }
+
+        let header_path = cpp_dir.join(page_file(&page, "h"));
+        if let Some(parent) = header_path.parent() {
+            fs::create_dir_all(parent)
+                .map_err(|err| err.to_string())?;
+        }
+
+        write(&header_path, &cpp.header)
+            .map_err(|err| err.to_string())?;
+        let source_path = cpp_dir.join(page_file(&page, "cpp"));
+        write(&source_path, &cpp.source)
+            .map_err(|err| err.to_string())?;
+        cpp_sources.push(source_path);
+
+        if run_options.show_outputs.contains(ShowOutputs::SHOW_CPP_CONVERTION) {
+            let print_path = format!("{}/steps/{}", run_options.output_dir.to_string_lossy(), response.path.to_string_lossy());
+
+            fs::create_dir_all(&print_path)
+                .map_err(|err| err.to_string())?;
+            write(format!("{}/cppConvertion.soulc", print_path), format!("{}\n{}", cpp.header, cpp.source))
+                .map_err(|err| err.to_string())?;
+        }
+    }
+
+    Ok(cpp_sources)
+}
+
+/// compiles and links cpp_sources into '{output_dir}/{outputName}' with a c++ compiler (see '--cppCompiler'),
+/// compiler errors in soul code are added to the faults of the response of that file
+fn build_executable(
+    responses: &mut [SementicResponse], 
+    cpp_sources: &[PathBuf],
+    run_options: &Arc<RunOptions>, 
+    logger: &Arc<Logger>, 
+    time_logs: &Arc<Mutex<TimeLogs>>,
+) -> Result<(), String> {
+    let compiler = find_cpp_compiler(run_options.cpp_compiler.as_deref())?;
+
+    let binary = run_options.output_dir.join(run_options.output_name.as_ref().unwrap_or(&run_options.project_name));
+    let include_paths = std::iter::once(run_options.output_dir.join("cpp"))
+        .chain(run_options.include_paths.iter().cloned())
+        .collect::<Vec<_>>();
+    let arguments = compiler_arguments(cpp_sources, &include_paths, &run_options.library_paths, run_options.optimize_level, &binary);
+
+    let start = Instant::now();
+    let output = run_cpp_compiler(&compiler, &arguments)?;
+
+    if run_options.show_times.contains(ShowTimes::SHOW_CODE_GENERATOR) {
+        time_logs
+            .lock().unwrap()
+            .push(&binary.to_string_lossy().to_string(), "cppCompiler", start.elapsed());
+    }
+
+    let source_files = responses.iter().map(|response| response.path.clone()).collect::<Vec<_>>();
+    let faults = compiler_faults(&output.stderr, &source_files);
+
+    if !output.success && faults.soul_faults.is_empty() {
+        return Err(format!("c++ compiler '{}' failed:\n{}", compiler.to_string_lossy(), output.stderr))
+    }
+
+    for error in &faults.other_errors {
+        logger.error(error, &default_log_options());
+    }
+
+    for (file, fault) in faults.soul_faults {
+        if let Some(response) = responses.iter_mut().find(|response| response.path == file) {
+            response.faults.push(fault);
+        }
+    }
+
+    Ok(())
+}
+
+
 fn sementic_analyse(
     parser: ParserResponse, 
     run_options: &Arc<RunOptions>, 
@@ -136,6 +314,39 @@ fn sementic_analyse(
     let mut analyser = ExternalHeaderAnalyser::new(analyser, SHOULD_RESET_SCOPE);
     analyser.analyse_ast(&mut tree);
 
+    let mut analyser = TypeResolutionAnalyser::new(analyser, SHOULD_RESET_SCOPE);
+    analyser.analyse_ast(&mut tree);
+
+    let mut analyser = TypeCheckingAnalyser::new(analyser, SHOULD_RESET_SCOPE);
+    analyser.analyse_ast(&mut tree);
+
+    let mut analyser = ControlFlowAnalyser::new(analyser, SHOULD_RESET_SCOPE);
+    analyser.analyse_ast(&mut tree);
+
+    let mut analyser = TraitAnalyser::new(analyser, SHOULD_RESET_SCOPE);
+    analyser.analyse_ast(&mut tree);
+
+    let mut analyser = RulesetAnalyser::new(analyser, SHOULD_RESET_SCOPE);
+    analyser.analyse_ast(&mut tree);
+
+    let mut analyser = LiteralEvaluator::new(analyser, SHOULD_RESET_SCOPE);
+    analyser.analyse_ast(&mut tree);
+
+    let mut analyser = LiteralRetentionAnalyser::new(analyser, SHOULD_RESET_SCOPE);
+    let retained_uses = analyser.analyse_retention(&mut tree);
+
+    let mut analyser = LambdaCaptureAnalyser::new(analyser, SHOULD_RESET_SCOPE);
+    analyser.analyse_ast(&mut tree);
+
+    let mut analyser = BorrowChecker::new(analyser, SHOULD_RESET_SCOPE);
+    analyser.analyse_ast(&mut tree);
+
+    let show_sementic = run_options.show_outputs.contains(ShowOutputs::SHOW_SEMENTIC_ANALYSER);
+    let unoptimized_tree = show_sementic.then(|| tree.to_pretty_string());
+
+    let mut analyser = Optimizer::new(analyser, SHOULD_RESET_SCOPE);
+    let optimizations = analyser.optimize(&mut tree);
+
     let (scopes, faults, has_error) = analyser.consume_to_tuple();
 
     if run_options.show_times.contains(ShowTimes::SHOW_CODE_GENERATOR) {
@@ -144,6 +355,23 @@ fn sementic_analyse(
             .push(&file_path.to_string_lossy().to_string(), "semeticAnalyser", start.elapsed());
     }
 
+    if show_sementic {
+        let print_path = format!("{}/steps/{}", run_options.output_dir.to_string_lossy(), file_path.to_string_lossy());
+        let file_path = format!("{}/literalRetention.soulc", print_path);
+
+        fs::create_dir_all(&print_path)
+            .map_err(|err| err.to_string())?;
+        write(file_path, retention_to_string(&retained_uses))
+            .map_err(|err| err.to_string())?;
+
+        write(format!("{}/optimizerBeforeAST.soulc", print_path), unoptimized_tree.unwrap_or_default())
+            .map_err(|err| err.to_string())?;
+        write(format!("{}/optimizerAfterAST.soulc", print_path), tree.to_pretty_string())
+            .map_err(|err| err.to_string())?;
+        write(format!("{}/optimizations.soulc", print_path), optimizations_to_string(&optimizations))
+            .map_err(|err| err.to_string())?;
+    }
+
     Ok(SementicResponse{tree, scopes, faults, has_error, path: file_path})
 }
 
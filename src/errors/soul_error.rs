@@ -12,6 +12,7 @@ pub enum SoulErrorKind {
     InternalError,
 
     ArgError, // error with program args
+    CppCompilerError, // error of the c++ compiler that builds the executable
     ReaderError, // e.g. could not read line
 
     UnterminatedStringLiteral, // e.g., string not closed
@@ -1,3 +1,4 @@
 pub mod run_options;
 pub mod show_output;
-pub mod show_times;
\ No newline at end of file
+pub mod show_times;
+pub mod optimize_level;
\ No newline at end of file
@@ -0,0 +1,39 @@
+use std::str::FromStr;
+
+/// optimization level of the c++ compiler ('-O0', '-O1', '-O2', '-O3' or '-Os')
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OptimizeLevel {
+    None,
+    Basic,
+    Full,
+    Aggressive,
+    Size,
+}
+
+impl FromStr for OptimizeLevel {
+    type Err = String;
+
+    fn from_str(text: &str) -> Result<Self, String> {
+        match text {
+            "O0" => Ok(Self::None),
+            "O1" => Ok(Self::Basic),
+            "O2" => Ok(Self::Full),
+            "O3" => Ok(Self::Aggressive),
+            "Os" => Ok(Self::Size),
+            _ => Err(format!("optimize level: '{}' is not a valid option (options: O0, O1, O2, O3, Os)", text)),
+        }
+    }
+}
+
+impl OptimizeLevel {
+    /// flag of c++ compiler (e.g. '-O2')
+    pub fn as_flag(&self) -> &'static str {
+        match self {
+            Self::None => "-O0",
+            Self::Basic => "-O1",
+            Self::Full => "-O2",
+            Self::Aggressive => "-O3",
+            Self::Size => "-Os",
+        }
+    }
+}
@@ -9,9 +9,11 @@ use once_cell::sync::Lazy;
 use std::collections::HashMap;
 
 use crate::errors::soul_error::{new_soul_error, SoulErrorKind, Result};
+use crate::steps::code_generator::name_mangling::{demangle, demangle_text};
 use crate::utils::logger::{LogLevel, LogMode};
 
 use super::show_times::ShowTimes;
+use super::optimize_level::OptimizeLevel;
 use super::show_output::ShowOutputs;
 
 pub struct RunOptions {
@@ -32,6 +34,14 @@ pub struct RunOptions {
     pub log_colored: bool,
 
     pub max_thread_count: Option<usize>,
+
+    /// c++ compiler to build the executable with, if None 'g++' or 'clang++' is used
+    pub cpp_compiler: Option<PathBuf>,
+    pub optimize_level: OptimizeLevel,
+    pub include_paths: Vec<PathBuf>,
+    pub library_paths: Vec<PathBuf>,
+    /// name of the executable in the output folder, if None the project name is used
+    pub output_name: Option<String>,
 } 
 
 type ArgFunc = Box<dyn Fn(&String, &mut RunOptions) -> std::result::Result<(), String> + Send + Sync + 'static>;
@@ -59,7 +69,7 @@ static OPTIONS: Lazy<HashMap<&'static str, ArgFunc>> = Lazy::new(|| {
             Box::new(|arg: &String, options: &mut RunOptions| {
                 let input = get_input(arg)?;
                 options.tab_char_len = input.parse()
-                    .map_err(|err: ParseIntError| format!("input of argument '--tabCharLen' could not be parsed into u32 interger parserError:\n{}", err.to_string()))?;
+                    .map_err(|err: ParseIntError| format!("input of argument '--tabCharLen' could not be parsed into u32 interger parserError:\n{}", err))?;
                 
                 const MAX_TAB_LEN: u32 = 128;
                 if options.tab_char_len > MAX_TAB_LEN {
@@ -138,14 +148,54 @@ static OPTIONS: Lazy<HashMap<&'static str, ArgFunc>> = Lazy::new(|| {
             Box::new(|arg: &String, options: &mut RunOptions| {
                 let input = get_input(arg)?;
                 options.max_thread_count = Some(input.parse()
-                    .map_err(|err: ParseIntError| format!("input of argument '--maxThreads' could not be parsed into usize interger parserError:\n{}", err.to_string()))?);
+                    .map_err(|err: ParseIntError| format!("input of argument '--maxThreads' could not be parsed into usize interger parserError:\n{}", err))?);
+                Ok(())
+            }) as ArgFunc
+        ),
+        (
+            "--cppCompiler",
+            Box::new(|arg: &String, options: &mut RunOptions| {
+                let input = get_input(arg)?;
+                options.cpp_compiler = Some(input.into());
+                Ok(())
+            }) as ArgFunc
+        ),
+        (
+            "--optimize",
+            Box::new(|arg: &String, options: &mut RunOptions| {
+                let input = get_input(arg)?;
+                options.optimize_level = input.parse()?;
+                Ok(())
+            }) as ArgFunc
+        ),
+        (
+            "--includePaths",
+            Box::new(|arg: &String, options: &mut RunOptions| {
+                let input = get_input(arg)?;
+                options.include_paths.extend(input.split('+').map(PathBuf::from));
+                Ok(())
+            }) as ArgFunc
+        ),
+        (
+            "--libraryPaths",
+            Box::new(|arg: &String, options: &mut RunOptions| {
+                let input = get_input(arg)?;
+                options.library_paths.extend(input.split('+').map(PathBuf::from));
+                Ok(())
+            }) as ArgFunc
+        ),
+        (
+            "--outputName",
+            Box::new(|arg: &String, options: &mut RunOptions| {
+                let input = get_input(arg)?;
+                options.output_name = Some(input.to_string());
                 Ok(())
             }) as ArgFunc
         ),
     ])
 });
 
-const ALLOWED_COMMANDS: &[&str] = &["build", "help"];
+const ALLOWED_COMMANDS: &[&str] = &["build", "help", "demangle"];
 
 impl RunOptions {
     pub fn new(_args: Args) -> result::Result<Self, String> {
@@ -172,6 +222,12 @@ impl RunOptions {
                 .expect("current dir name is not UTF8 valid")
                 .to_string(),
             max_thread_count: None,
+
+            cpp_compiler: None,
+            optimize_level: OptimizeLevel::None,
+            include_paths: vec![],
+            library_paths: vec![],
+            output_name: None,
         };
 
         let mut args = _args.collect::<Vec<_>>();
@@ -193,6 +249,12 @@ impl RunOptions {
             std::process::exit(0);
         }
 
+        if run_command == "demangle" {
+            let is_valid = print_demangled(&args[2..]);
+
+            std::process::exit(if is_valid {0} else {1});
+        }
+
         options.command = std::mem::take(&mut args[1]);
 
         let mut errors = Vec::new();
@@ -239,7 +301,7 @@ impl RunOptions {
 
     fn get_sub_files(&self) -> Result<SubFileTree> {
         let sub_tree = SubFileTree::from_bin_file(Path::new(&self.sub_tree_path))
-            .map_err(|msg| new_soul_error(SoulErrorKind::InternalError, None, format!("!!internal error!! while trying to get subfilesTree\n{}", msg.to_string())))?;
+            .map_err(|msg| new_soul_error(SoulErrorKind::InternalError, None, format!("!!internal error!! while trying to get subfilesTree\n{}", msg)))?;
         
         Ok(sub_tree)
     }
@@ -272,6 +334,30 @@ fn pocess_arg(arg: &String, options: &mut RunOptions, file_path_set: &mut bool,
     }
 }
 
+/// returns false if one of the names could not be demangled
+fn print_demangled(names: &[String]) -> bool {
+
+    if names.is_empty() {
+        for line in std::io::stdin().lines().map_while(result::Result::ok) {
+            println!("{}", demangle_text(&line));
+        }
+        return true
+    }
+
+    let mut is_valid = true;
+    for name in names {
+        match demangle(name) {
+            Ok(demangled) => println!("{}", demangled),
+            Err(msg) => {
+                is_valid = false;
+                eprintln!("{}", msg);
+            },
+        }
+    }
+
+    is_valid
+}
+
 fn print_help_list() {
     const HELP_ARGS_LIST: &str = 
 "
@@ -285,6 +371,8 @@ have fun :).
     Commands:
         build           info: Compile the selected file
         help            info: prints this list you are reading
+        demangle        info: prints the readable form of mangled names (e.g. 'soul demangle _SN4mainEF4mainPE'),
+                        without names the text of stdin is printed with every mangled name in it demangled
     
     Options:
         to call flag you do '-flag'
@@ -322,6 +410,21 @@ have fun :).
         
         --maxThreads    info: the max amout of threads allowed to be used in compiler
                         args: (Default)<max amount available>, <any positive interger>
+
+        --cppCompiler   info: the c++ compiler that builds the executable from the c++ output
+                        args: (Default)<g++ or clang++>, <any path>
+
+        --optimize      info: the optimization level of the c++ compiler
+                        args: (Default)O0, O1, O2, O3, Os
+
+        --includePaths  info: extra include paths for the c++ compiler
+                        args(chainable): (Default)<empty>, <any path>
+
+        --libraryPaths  info: extra library paths for the c++ compiler
+                        args(chainable): (Default)<empty>, <any path>
+
+        --outputName    info: the name of the executable in the output folder
+                        args: (Default)<project name>, <any name>
 ";
 
     println!("{}", HELP_ARGS_LIST);
@@ -330,7 +433,7 @@ have fun :).
     std::io::stdout().flush().expect("could not flush");
 }
 
-fn get_input<'a>(arg: &'a String) -> result::Result<&'a str, String> {
+fn get_input(arg: &String) -> result::Result<&str, String> {
     if !arg.contains("=") {
         Err(format!("arg: '{}' should have an input (add '=<input>')", arg))
     } 
@@ -0,0 +1,134 @@
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use crate::errors::soul_error::{new_soul_error, SoulErrorKind, SoulSpan};
+use crate::run_options::optimize_level::OptimizeLevel;
+use crate::steps::code_generator::name_mangling::demangle_text;
+use crate::steps::step_interfaces::i_sementic::soul_fault::SoulFault;
+
+/// c++ compilers that are tried (in order) if no compiler is given (see '--cppCompiler')
+pub const CPP_COMPILERS: &[&str] = &["g++", "clang++"];
+
+/// c++ standard that generated code needs (designated initializers, lambda templates)
+const CPP_STANDARD: &str = "-std=c++20";
+/// return paths are checked by the sementic analyser, c++ does not know that a match is exhaustive
+const NO_RETURN_WARNING: &str = "-Wno-return-type";
+
+/// output of a c++ compiler run
+pub struct CompilerOutput {
+    pub success: bool,
+    pub stderr: String,
+}
+
+/// compiler diagnostics split in the ones that point to soul code (via '#line') and the rest
+#[derive(Debug, Default)]
+pub struct CompilerFaults {
+    pub soul_faults: Vec<(PathBuf, SoulFault)>,
+    pub other_errors: Vec<String>,
+}
+
+/// configured compiler or first of CPP_COMPILERS that can be run
+pub fn find_cpp_compiler(configured: Option<&Path>) -> Result<PathBuf, String> {
+
+    if let Some(compiler) = configured {
+        return match can_run(compiler) {
+            true => Ok(compiler.to_path_buf()),
+            false => Err(format!("c++ compiler '{}' could not be run, check the path of '--cppCompiler'", compiler.to_string_lossy())),
+        }
+    }
+
+    CPP_COMPILERS.iter()
+        .map(PathBuf::from)
+        .find(|compiler| can_run(compiler))
+        .ok_or_else(|| format!(
+            "no c++ compiler found (tried: {}), install one of them or give the path of a compiler with '--cppCompiler=<path>'",
+            CPP_COMPILERS.iter().map(|compiler| format!("'{}'", compiler)).collect::<Vec<_>>().join(", "),
+        ))
+}
+
+fn can_run(compiler: &Path) -> bool {
+    Command::new(compiler)
+        .arg("--version")
+        .output()
+        .is_ok_and(|output| output.status.success())
+}
+
+/// arguments of the c++ compiler to compile and link sources into binary
+pub fn compiler_arguments(
+    sources: &[PathBuf],
+    include_paths: &[PathBuf],
+    library_paths: &[PathBuf],
+    optimize_level: OptimizeLevel,
+    binary: &Path,
+) -> Vec<String> {
+    let mut arguments = vec![CPP_STANDARD.to_string(), NO_RETURN_WARNING.to_string(), optimize_level.as_flag().to_string()];
+
+    arguments.extend(include_paths.iter().map(|path| format!("-I{}", path.to_string_lossy())));
+    arguments.extend(sources.iter().map(|path| path.to_string_lossy().to_string()));
+    arguments.extend(library_paths.iter().map(|path| format!("-L{}", path.to_string_lossy())));
+    arguments.push("-o".into());
+    arguments.push(binary.to_string_lossy().to_string());
+    arguments
+}
+
+pub fn run_cpp_compiler(compiler: &Path, arguments: &[String]) -> Result<CompilerOutput, String> {
+    let output = Command::new(compiler)
+        .args(arguments)
+        .output()
+        .map_err(|err| format!("could not run c++ compiler '{}': {}", compiler.to_string_lossy(), err))?;
+
+    Ok(CompilerOutput{success: output.status.success(), stderr: String::from_utf8_lossy(&output.stderr).to_string()})
+}
+
+/// turns diagnostics of compiler (e.g. 'main.soul:12:5: error: ...') in source_files into soul faults (with demangled names)
+pub fn compiler_faults(stderr: &str, source_files: &[PathBuf]) -> CompilerFaults {
+    let mut faults = CompilerFaults::default();
+
+    for line in stderr.lines() {
+        let (location, msg, is_error) = match (line.split_once(": error: "), line.split_once(": warning: ")) {
+            (Some((location, msg)), _) => (location, msg, true),
+            (None, Some((location, msg))) => (location, msg, false),
+            (None, None) => continue,
+        };
+
+        let source = parse_location(location)
+            .and_then(|(file, line_number)| {
+                let file = source_files.iter().find(|source| same_file(source, file))?;
+                Some((file, line_number))
+            });
+
+        let (file, line_number) = match source {
+            Some(source) => source,
+            None => {
+                if is_error {
+                    faults.other_errors.push(demangle_text(line));
+                }
+                continue
+            },
+        };
+
+        let error = new_soul_error(SoulErrorKind::CppCompilerError, Some(SoulSpan::new(line_number, 0, 0)), format!("c++ compiler: {}", demangle_text(msg)));
+        let fault = match is_error {
+            true => SoulFault::new_error(error),
+            false => SoulFault::new_warning(error),
+        };
+        faults.soul_faults.push((file.clone(), fault));
+    }
+
+    faults
+}
+
+/// 'file:line:column' or 'file:line' into file and line
+fn parse_location(location: &str) -> Option<(&str, usize)> {
+    let mut parts = location.rsplitn(3, ':');
+    let last = parts.next()?;
+    let middle = parts.next()?;
+
+    match (parts.next(), middle.parse::<usize>()) {
+        (Some(file), Ok(line_number)) if last.parse::<usize>().is_ok() => Some((file, line_number)),
+        _ => Some((middle, last.parse().ok()?)),
+    }
+}
+
+fn same_file(source: &Path, file: &str) -> bool {
+    source.to_string_lossy().replace('\\', "/") == file.replace('\\', "/")
+}
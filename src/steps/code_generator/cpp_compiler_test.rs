@@ -0,0 +1,58 @@
+use std::path::{Path, PathBuf};
+use crate::{run_options::optimize_level::OptimizeLevel, steps::{code_generator::cpp_compiler::{compiler_arguments, compiler_faults, find_cpp_compiler}, sementic::test_helpers::{error_messages, warning_messages}}};
+
+const STDERR: &str = "\
+src/main.soul: In function 'void soul_program::src::main::main()':
+src/main.soul:3:7: error: '_SN3src4mainEF14undefinedThingPE' was not declared in this scope
+src/std/math.soul:12: warning: unused variable 'x' [-Wunused-variable]
+output/cpp/soul_runtime.h:40:5: error: no match for 'operator<<'
+/usr/bin/ld: undefined reference to 'soul_program::src::main::_SN3src4mainEF6helperPisE(long)'
+collect2: error: ld returned 1 exit status
+";
+
+#[test]
+fn test_compiler_faults() {
+    let sources = vec![PathBuf::from("src/main.soul"), PathBuf::from("src/std/math.soul")];
+    let faults = compiler_faults(STDERR, &sources);
+
+    let files = faults.soul_faults.iter().map(|(file, _)| file.clone()).collect::<Vec<_>>();
+    assert_eq!(files, sources);
+
+    let main_faults = [faults.soul_faults[0].1.clone()];
+    let errors = error_messages(&main_faults);
+    assert_eq!(errors, vec!["at 3:0| c++ compiler: 'src.main::undefinedThing()' was not declared in this scope"]);
+
+    let math_faults = [faults.soul_faults[1].1.clone()];
+    let warnings = warning_messages(&math_faults);
+    assert_eq!(warnings, vec!["at 12:0| c++ compiler: unused variable 'x' [-Wunused-variable]"]);
+
+    assert_eq!(faults.other_errors, vec![
+        "output/cpp/soul_runtime.h:40:5: error: no match for 'operator<<'",
+        "collect2: error: ld returned 1 exit status",
+    ]);
+}
+
+#[test]
+fn test_compiler_arguments() {
+    let arguments = compiler_arguments(
+        &[PathBuf::from("out/cpp/main.cpp"), PathBuf::from("out/cpp/std/math.cpp")],
+        &[PathBuf::from("out/cpp"), PathBuf::from("/opt/include")],
+        &[PathBuf::from("/opt/lib")],
+        OptimizeLevel::Full,
+        Path::new("out/app"),
+    );
+
+    assert_eq!(arguments, vec![
+        "-std=c++20", "-Wno-return-type", "-O2",
+        "-Iout/cpp", "-I/opt/include",
+        "out/cpp/main.cpp", "out/cpp/std/math.cpp",
+        "-L/opt/lib",
+        "-o", "out/app",
+    ]);
+}
+
+#[test]
+fn test_missing_compiler() {
+    let err = find_cpp_compiler(Some(Path::new("/no/such/compiler++"))).unwrap_err();
+    assert!(err.contains("c++ compiler '/no/such/compiler++' could not be run"), "{}", err);
+}
@@ -0,0 +1,1243 @@
+use std::{collections::{BTreeSet, HashMap, HashSet}, path::Path};
+use itertools::Itertools;
+use crate::{errors::soul_error::SoulSpan, steps::{code_generator::{cpp_names::{escape_name, generics_to_cpp, inner_type_to_cpp, is_unresolved, literal_to_cpp, literal_type_to_cpp, page_file, page_namespace, parameters_to_cpp, return_type_to_cpp, type_to_cpp}, cpp_runtime::SOUL_RUNTIME_FILE, cpp_writer::CppWriter, name_mangling::{function_symbols, mangle_function, mangle_global}}, sementic::reachability::MAIN_FUNCTION, step_interfaces::{i_code_generator::CodeGeneratorResponse, i_parser::{abstract_syntax_tree::{abstract_syntax_tree::AbstractSyntacTree, enum_like::{Enum, EnumVariantKind, Union, UnionVariantKind}, expression::{BinaryOperatorKind, CaseDoKind, CaseSwitch, ElseKind, Expression, ExpressionGroup, ExpressionKind, If, IfCaseKind, Match, ReturnKind, UnaryOperatorKind, UnwrapVariable}, function::{Function, FunctionCall, FunctionSignature, Lambda, LambdaBody}, generic::{GenericKind, GenericParameter}, literal::Literal, object::{Class, ClassChild, Field, Struct, Visibility}, soul_type::{soul_type::{Modifier, SoulType, TypeGenericKind}, type_kind::{SoulPagePath, TypeKind}}, spanned::Spanned, statement::{Block, Statement, StatementKind}}, scope_builder::{ProgramMemmory, ScopeId, ScopeKind}}, i_sementic::scope_vistitor::ScopeVisitor}}};
+
+/// name of 'this' parameter in free methodes ('this' is a c++ keyword)
+const THIS_PARAMETER: &str = "this_";
+
+/// turns an analysed page into a c++ header and source (see 'i_code_generator'),
+/// if source_file is given statments get a '#line' of source_file so c++ compiler errors point to the soul code
+pub fn generate_cpp(tree: &AbstractSyntacTree, scope: &ScopeVisitor, page: &SoulPagePath, is_main_page: bool, pretty: bool, source_file: Option<&Path>) -> CodeGeneratorResponse {
+    let mut generator = CppGenerator{
+        scope,
+        page: page.clone(),
+        pretty,
+        source_file: source_file.map(|file| file.to_string_lossy().replace('\\', "/")),
+        includes: BTreeSet::new(),
+        free_methodes: HashSet::new(),
+        class_methodes: HashSet::new(),
+        current_scope: tree.root.scope_id,
+        this_name: None,
+        in_value_block: false,
+        temp_count: 0,
+        function_symbols: function_symbols(tree, page),
+        external_page: None,
+    };
+
+    generator.generate(tree, is_main_page)
+}
+
+struct CppGenerator<'a> {
+    scope: &'a ScopeVisitor,
+    page: SoulPagePath,
+    pretty: bool,
+    /// soul file of page for '#line' directives
+    source_file: Option<String>,
+    /// pages that are used by this page
+    includes: BTreeSet<SoulPagePath>,
+    /// methodes that are written as free functions (called as 'name(callee, args)')
+    free_methodes: HashSet<String>,
+    /// methodes that are written in a class (called as 'callee.name(args)')
+    class_methodes: HashSet<String>,
+    /// scope of the block that is written
+    current_scope: ScopeId,
+    /// how 'this' is written, None outside of methodes
+    this_name: Option<String>,
+    /// true in a block that is written as lambda for its value ('fall' returns the value)
+    in_value_block: bool,
+    /// counter for names of helper variables (e.g. value of match)
+    temp_count: usize,
+    /// mangled name of every function of page by the scope of its block (see 'name_mangling')
+    function_symbols: HashMap<ScopeId, String>,
+    /// page of the 'ExternalExpression' that is written
+    external_page: Option<SoulPagePath>,
+}
+
+/// parts of the header in the order they are written
+struct HeaderParts {
+    forward_declarations: CppWriter,
+    type_defs: CppWriter,
+    prototypes: CppWriter,
+    types: CppWriter,
+    generic_functions: CppWriter,
+    globals: CppWriter,
+}
+
+impl<'a> CppGenerator<'a> {
+
+    fn generate(&mut self, tree: &AbstractSyntacTree, is_main_page: bool) -> CodeGeneratorResponse {
+        self.collect_methodes(&tree.root);
+
+        let mut header = HeaderParts{
+            forward_declarations: CppWriter::new(self.pretty),
+            type_defs: CppWriter::new(self.pretty),
+            prototypes: CppWriter::new(self.pretty),
+            types: CppWriter::new(self.pretty),
+            generic_functions: CppWriter::new(self.pretty),
+            globals: CppWriter::new(self.pretty),
+        };
+        let mut definitions = CppWriter::new(self.pretty);
+
+        self.write_program_memmory(&mut header.globals, &self.scope.global_literals);
+        self.write_type_defs(&mut header.type_defs);
+
+        let mut main_symbol = None;
+        for statment in &tree.root.statments {
+
+            match &statment.node {
+                StatementKind::Expression(_) |
+                StatementKind::Assignment(_) |
+                StatementKind::CloseBlock => (),
+
+                StatementKind::Variable(variable) => {
+                    let declaration = self.variable_declaration(&variable.name.0);
+                    header.globals.line(format!("inline {}", declaration));
+                },
+                StatementKind::Function(function) => {
+                    if function.signature.name.0 == MAIN_FUNCTION && function.signature.callee.is_none() {
+                        let returns_int = function.signature.return_type.as_ref().is_some_and(|ty| ty.wrappers.is_empty() && ty.base.is_integer());
+                        main_symbol = Some((self.function_name(function), returns_int));
+                    }
+                    self.write_global_function(function, &mut header, &mut definitions);
+                },
+                StatementKind::UseBlock(use_block) => for statment in &use_block.block.statments {
+                    if let StatementKind::Function(function) = &statment.node {
+                        self.write_global_function(function, &mut header, &mut definitions);
+                    }
+                },
+                StatementKind::Struct(struct_decl) => {
+                    header.forward_declarations.line(format!("{}struct {};", template_line(&struct_decl.generics), escape_name(&struct_decl.name.0)));
+                    self.write_struct(&mut header.types, struct_decl);
+                },
+                StatementKind::Class(class) => {
+                    header.forward_declarations.line(format!("{}class {};", template_line(&class.generics), escape_name(&class.name.0)));
+                    self.write_class(&mut header.types, class);
+                },
+                StatementKind::Enum(enum_decl) => {
+                    match enum_decl.ty {
+                        Some(_) => header.forward_declarations.line(format!("struct {};", escape_name(&enum_decl.name.0))),
+                        None => header.forward_declarations.line(format!("enum class {} : int64_t;", escape_name(&enum_decl.name.0))),
+                    }
+                    self.write_enum(&mut header.types, enum_decl);
+                },
+                StatementKind::Union(union_decl) => {
+                    header.forward_declarations.line(format!("{}struct {};", template_line(&union_decl.generics), escape_name(&union_decl.name.0)));
+                    self.write_union(&mut header.types, union_decl);
+                },
+                StatementKind::TypeEnum(type_enum) => header.type_defs.line(format!(
+                    "using {} = ::std::variant<{}>;",
+                    escape_name(&type_enum.name.0),
+                    type_enum.body.types.iter().map(inner_type_to_cpp).join(", "),
+                )),
+                StatementKind::Trait(trait_decl) => {
+                    header.types.blank_line();
+                    header.types.pretty_line(format!("// trait {} (traits are checked by the sementic analyser)", trait_decl.signature.name.0));
+                },
+            }
+        }
+
+        let namespace = page_namespace(&self.page);
+
+        let mut header_file = CppWriter::new(self.pretty);
+        header_file.line("#pragma once");
+        header_file.line(format!("#include \"{}\"", SOUL_RUNTIME_FILE));
+        for include in self.includes.iter().filter(|include| **include != self.page) {
+            header_file.line(format!("#include \"{}\"", page_file(include, "h").to_string_lossy()));
+        }
+        header_file.blank_line();
+        header_file.open(format!("namespace {}", namespace));
+        for part in [header.forward_declarations, header.type_defs, header.prototypes, header.types, header.generic_functions, header.globals] {
+            if !part.is_empty() {
+                header_file.blank_line();
+                header_file.append(part);
+            }
+        }
+        header_file.close("");
+
+        let mut source_file = CppWriter::new(self.pretty);
+        source_file.line(format!("#include \"{}\"", page_file(&self.page, "h").to_string_lossy()));
+        source_file.blank_line();
+        source_file.open(format!("namespace {}", namespace));
+        source_file.append(definitions);
+        source_file.close("");
+
+        if is_main_page && let Some((main_symbol, returns_int)) = main_symbol {
+            source_file.blank_line();
+            source_file.open("int main()");
+            match returns_int {
+                // 'main() int' gives the exit status of the program
+                true => source_file.line(format!("return static_cast<int>(::{}::{}());", namespace, main_symbol)),
+                false => {
+                    source_file.line(format!("::{}::{}();", namespace, main_symbol));
+                    source_file.line("return 0;");
+                },
+            }
+            source_file.close("");
+        }
+
+        CodeGeneratorResponse{path: self.page.clone(), header: header_file.into_string(), source: source_file.into_string()}
+    }
+
+    /// names of free methodes and class methodes (to know how a methode is called)
+    fn collect_methodes(&mut self, root: &Block) {
+
+        for statment in &root.statments {
+            match &statment.node {
+                StatementKind::Function(function) if function.signature.callee.is_some() => {
+                    self.free_methodes.insert(function.signature.name.0.clone());
+                },
+                StatementKind::UseBlock(use_block) => for statment in &use_block.block.statments {
+                    if let StatementKind::Function(function) = &statment.node {
+                        self.free_methodes.insert(function.signature.name.0.clone());
+                    }
+                },
+                StatementKind::Class(class) => for function in class_functions(class) {
+                    self.class_methodes.insert(function.signature.name.0.clone());
+                },
+                _ => (),
+            }
+        }
+    }
+
+    fn write_program_memmory(&self, writer: &mut CppWriter, memmory: &ProgramMemmory) {
+
+        for (literal, id) in memmory.store.iter().sorted_by_key(|(_, id)| id.0) {
+            writer.line(format!(
+                "inline const {} {} = {};",
+                literal_type_to_cpp(&literal.get_literal_type()),
+                ProgramMemmory::to_program_memory_name(id).0,
+                literal_to_cpp(literal),
+            ));
+        }
+    }
+
+    fn write_type_defs(&self, writer: &mut CppWriter) {
+        let global = &self.scope.get_scopes()[ScopeVisitor::GLOBAL_SCOPE_INDEX.0];
+
+        for (_, kinds) in global.symbols.iter().sorted_by(|a, b| a.0.cmp(b.0)) {
+            for kind in kinds {
+                if let ScopeKind::TypeDef{new_type, of_type} | ScopeKind::UseTypeDef{new_type, of_type} = &kind.node {
+                    writer.line(format!("using {} = {};", inner_type_to_cpp(new_type), inner_type_to_cpp(of_type)));
+                }
+            }
+        }
+    }
+
+    fn write_global_function(&mut self, function: &Function, header: &mut HeaderParts, definitions: &mut CppWriter) {
+        let declaration = self.function_declaration(function);
+
+        if has_template(&function.signature.generics) {
+            header.generic_functions.blank_line();
+            self.write_function(&mut header.generic_functions, function, declaration);
+            return
+        }
+
+        header.prototypes.line(format!("{};", declaration));
+        definitions.blank_line();
+        self.write_function(definitions, function, declaration);
+    }
+
+    /// '<template>R name(params)' of a global function or free methode
+    fn function_declaration(&self, function: &Function) -> String {
+        let signature = &function.signature;
+
+        let mut parameters = vec![];
+        if let Some(this) = signature.callee.as_ref().and_then(|callee| callee.node.this.as_ref()) {
+            parameters.push(format!("{} {}", type_to_cpp(this), THIS_PARAMETER));
+        }
+        if !signature.parameters.is_empty() {
+            parameters.push(parameters_to_cpp(&signature.parameters));
+        }
+
+        format!(
+            "{}{} {}({})",
+            template_line(&signature.generics),
+            return_type_to_cpp(signature.return_type.as_ref()),
+            self.function_name(function),
+            parameters.join(", "),
+        )
+    }
+
+    fn write_function(&mut self, writer: &mut CppWriter, function: &Function, declaration: String) {
+        let is_methode = function.signature.callee.as_ref().is_some_and(|callee| callee.node.this.is_some());
+        let this_name = is_methode.then(|| THIS_PARAMETER.to_string());
+
+        writer.open(declaration);
+        self.write_body(writer, &function.block, this_name);
+        writer.close("");
+    }
+
+    fn write_body(&mut self, writer: &mut CppWriter, block: &Block, this_name: Option<String>) {
+        let parent_this = std::mem::replace(&mut self.this_name, this_name);
+        let parent_value_block = std::mem::replace(&mut self.in_value_block, false);
+
+        self.write_statments(writer, block, false);
+
+        self.in_value_block = parent_value_block;
+        self.this_name = parent_this;
+    }
+
+    fn write_struct(&mut self, writer: &mut CppWriter, struct_decl: &Struct) {
+        writer.blank_line();
+        writer.open(format!("{}struct {}", template_line(&struct_decl.generics), escape_name(&struct_decl.name.0)));
+
+        let parent_scope = self.set_scope(struct_decl.scope_id);
+        for field in &struct_decl.fields {
+            self.write_field(writer, &field.node);
+        }
+        self.current_scope = parent_scope;
+
+        writer.close(";");
+    }
+
+    fn write_class(&mut self, writer: &mut CppWriter, class: &Class) {
+        writer.blank_line();
+        writer.open(format!("{}class {}", template_line(&class.generics), escape_name(&class.name.0)));
+        writer.line("public:");
+
+        let parent_scope = self.set_scope(class.scope_id);
+        let mut is_public = true;
+
+        for child in &class.children {
+            if let ClassChild::Field(field) = child {
+                let field_is_public = field.node.vis.get_visibility() == Visibility::Public;
+                if field_is_public != is_public {
+                    is_public = field_is_public;
+                    writer.line(if is_public {"public:"} else {"private:"});
+                }
+
+                self.write_field(writer, &field.node);
+            }
+        }
+
+        if !is_public {
+            writer.line("public:");
+        }
+
+        for function in class_functions(class) {
+            let signature = &function.signature;
+            let is_static = signature.callee.as_ref().is_none_or(|callee| callee.node.this.is_none());
+
+            writer.blank_line();
+            writer.open(format!(
+                "{}{}{} {}({})",
+                template_line(&signature.generics),
+                if is_static {"static "} else {""},
+                return_type_to_cpp(signature.return_type.as_ref()),
+                self.function_name(function),
+                parameters_to_cpp(&signature.parameters),
+            ));
+            self.write_body(writer, &function.block, (!is_static).then(|| "(*this)".to_string()));
+            writer.close("");
+        }
+
+        self.current_scope = parent_scope;
+        writer.close(";");
+    }
+
+    fn write_field(&mut self, writer: &mut CppWriter, field: &Field) {
+        match &field.default_value {
+            Some(value) => {
+                let value = self.expression(value);
+                writer.line(format!("{} {} = {};", inner_type_to_cpp(&field.ty), escape_name(&field.name.0), value));
+            },
+            None => writer.line(format!("{} {}{{}};", inner_type_to_cpp(&field.ty), escape_name(&field.name.0))),
+        }
+    }
+
+    fn write_enum(&mut self, writer: &mut CppWriter, enum_decl: &Enum) {
+        writer.blank_line();
+        let name = escape_name(&enum_decl.name.0);
+
+        let ty = match &enum_decl.ty {
+            Some(ty) => ty,
+            None => {
+                writer.open(format!("enum class {} : int64_t", name));
+                if let EnumVariantKind::Int(variants) = &enum_decl.variants {
+                    for variant in variants {
+                        writer.line(format!("{} = {},", escape_name(&variant.node.name.0), variant.node.value));
+                    }
+                }
+                writer.close(";");
+                return
+            },
+        };
+
+        // enum of other type is a struct with a constant per variant
+        writer.open(format!("struct {}", name));
+        for (variant, value) in enum_decl.values() {
+            let value = match value {
+                Some(literal) => literal_to_cpp(&literal),
+                None => "{}".into(),
+            };
+            writer.line(format!("inline static const {} {} = {};", inner_type_to_cpp(ty), escape_name(&variant.0), value));
+        }
+        writer.close(";");
+    }
+
+    fn write_union(&mut self, writer: &mut CppWriter, union_decl: &Union) {
+        writer.blank_line();
+        writer.open(format!("{}struct {}", template_line(&union_decl.generics), escape_name(&union_decl.name.0)));
+
+        for variant in &union_decl.variants {
+            let fields = match &variant.node.field {
+                UnionVariantKind::Tuple(types) => types.iter()
+                    .enumerate()
+                    .map(|(index, ty)| format!("{} _{};", inner_type_to_cpp(ty), index))
+                    .join(" "),
+                UnionVariantKind::NamedTuple(types) => types.iter()
+                    .sorted_by(|a, b| a.0.0.cmp(&b.0.0))
+                    .map(|(name, ty)| format!("{} {};", inner_type_to_cpp(ty), escape_name(&name.0)))
+                    .join(" "),
+            };
+
+            match fields.is_empty() {
+                true => writer.line(format!("struct {} {{}};", escape_name(&variant.node.name.0))),
+                false => writer.line(format!("struct {} {{ {} }};", escape_name(&variant.node.name.0), fields)),
+            }
+        }
+
+        writer.line(format!("::std::variant<{}> value;", union_decl.variants.iter().map(|variant| escape_name(&variant.node.name.0)).join(", ")));
+        writer.close(";");
+    }
+
+    fn set_scope(&mut self, scope_id: ScopeId) -> ScopeId {
+        std::mem::replace(&mut self.current_scope, scope_id)
+    }
+
+    /// writes statments of block, if as_value the last expression is returned (block is the body of a lambda)
+    fn write_statments(&mut self, writer: &mut CppWriter, block: &Block, as_value: bool) {
+        let parent_scope = self.set_scope(block.scope_id);
+
+        let last_index = block.statments.iter().rposition(|statment| !matches!(statment.node, StatementKind::CloseBlock));
+        for (index, statment) in block.statments.iter().enumerate() {
+            self.write_statment(writer, statment, as_value && Some(index) == last_index);
+        }
+
+        self.current_scope = parent_scope;
+    }
+
+    fn write_statment(&mut self, writer: &mut CppWriter, statment: &Statement, as_value: bool) {
+        if let Some(source_file) = &self.source_file && !matches!(statment.node, StatementKind::CloseBlock) {
+            writer.line(format!("#line {} \"{}\"", statment.span.line_number, source_file));
+        }
+
+        match &statment.node {
+            StatementKind::CloseBlock |
+            StatementKind::Trait(_) => (),
+
+            StatementKind::Expression(expression) => self.write_expression_statment(writer, expression, as_value),
+            StatementKind::Variable(variable) => {
+                let declaration = self.variable_declaration(&variable.name.0);
+                writer.line(declaration);
+            },
+            StatementKind::Assignment(assignment) => {
+                let variable = self.expression(&assignment.variable);
+                let value = self.expression(&assignment.value);
+                writer.line(format!("{} = {};", variable, value));
+            },
+            StatementKind::Function(function) => self.write_local_function(writer, function),
+            StatementKind::UseBlock(use_block) => for statment in &use_block.block.statments {
+                if let StatementKind::Function(function) = &statment.node {
+                    self.write_local_function(writer, function);
+                }
+            },
+            StatementKind::Struct(struct_decl) => self.write_struct(writer, struct_decl),
+            StatementKind::Class(class) => self.write_class(writer, class),
+            StatementKind::Enum(enum_decl) => self.write_enum(writer, enum_decl),
+            StatementKind::Union(union_decl) => self.write_union(writer, union_decl),
+            StatementKind::TypeEnum(type_enum) => writer.line(format!(
+                "using {} = ::std::variant<{}>;",
+                escape_name(&type_enum.name.0),
+                type_enum.body.types.iter().map(inner_type_to_cpp).join(", "),
+            )),
+        }
+    }
+
+    /// function in function is a lambda (e.g. 'auto inner = [&](int64_t a) -> int64_t {...};')
+    fn write_local_function(&mut self, writer: &mut CppWriter, function: &Function) {
+        let signature = &function.signature;
+
+        let mut body = writer.new_block();
+        self.write_body(&mut body, &function.block, self.this_name.clone());
+
+        writer.line(format!(
+            "auto {} = [&]{}({}) -> {} {};",
+            self.function_name(function),
+            lambda_template(&signature.generics),
+            parameters_to_cpp(&signature.parameters),
+            return_type_to_cpp(signature.return_type.as_ref()),
+            body.into_inline_block(),
+        ));
+    }
+
+    /// 'T name = value;' of variable in current scope
+    fn variable_declaration(&mut self, name: &str) -> String {
+        let variable = self.scope.get_scopes()[self.current_scope.0].get(name)
+            .and_then(|kinds| kinds.iter().find_map(|kind| match &kind.node {
+                ScopeKind::Variable(variable) => Some(variable),
+                _ => None,
+            }));
+
+        let variable = match variable {
+            Some(variable) => variable,
+            None => return format!("auto {}{{}};", escape_name(name)),
+        };
+        let cpp_name = self.variable_name(name);
+
+        let ty = match is_unresolved(&variable.ty) {
+            true if variable.ty.modifier == Modifier::Default => "auto".to_string(),
+            true => "const auto".to_string(),
+            false => type_to_cpp(&variable.ty),
+        };
+
+        match &variable.initialize_value {
+            Some(value) if value.node != ExpressionKind::Empty => {
+                let value = self.value_expression(value);
+                format!("{} {} = {};", ty, cpp_name, value)
+            },
+            _ => format!("{} {}{{}};", ty, cpp_name),
+        }
+    }
+
+    fn write_expression_statment(&mut self, writer: &mut CppWriter, expression: &Expression, as_value: bool) {
+
+        match &expression.node {
+            ExpressionKind::Empty => (),
+            ExpressionKind::If(if_decl) => self.write_if(writer, if_decl, as_value),
+            ExpressionKind::Match(match_decl) => self.write_match(writer, match_decl, as_value),
+            ExpressionKind::Block(block) => {
+                writer.open("");
+                self.write_statments(writer, block, as_value);
+                writer.close("");
+            },
+            ExpressionKind::For(for_decl) => {
+                let parent_scope = self.set_scope(for_decl.block.scope_id);
+                let header = self.for_header(&for_decl.element, &for_decl.collection);
+                self.current_scope = parent_scope;
+
+                writer.open(header);
+                self.write_statments(writer, &for_decl.block, false);
+                writer.close("");
+            },
+            ExpressionKind::While(while_decl) => {
+                let condition = match &while_decl.condition {
+                    Some(condition) => self.expression(condition),
+                    None => "true".into(),
+                };
+
+                writer.open(format!("while ({})", condition));
+                self.write_statments(writer, &while_decl.block, false);
+                writer.close("");
+            },
+            ExpressionKind::ReturnLike(return_like) => {
+                let value = return_like.value.as_ref().map(|value| self.value_expression(value));
+
+                let line = match (&return_like.kind, value) {
+                    (ReturnKind::Break, _) => "break;".to_string(),
+                    (ReturnKind::Fall, _) if !self.in_value_block => "break;".to_string(),
+                    (_, Some(value)) => format!("return {};", value),
+                    (_, None) => "return;".to_string(),
+                };
+                writer.line(line);
+            },
+            _ => {
+                let value = self.expression(expression);
+                match as_value {
+                    true => writer.line(format!("return {};", value)),
+                    false => writer.line(format!("{};", value)),
+                }
+            },
+        }
+    }
+
+    fn for_header(&mut self, element: &Option<Box<Expression>>, collection: &Expression) -> String {
+        let element = match element.as_deref() {
+            Some(element) => self.expression(element),
+            None => self.temp_name("element"),
+        };
+
+        match &collection.node {
+            ExpressionKind::Binary(binary) if binary.operator.node == BinaryOperatorKind::Range => {
+                let begin = self.expression(&binary.left);
+                let end = self.expression(&binary.right);
+                format!("for (int64_t {0} = {1}; {0} < {2}; {0}++)", element, begin, end)
+            },
+            ExpressionKind::Literal(Literal::Int(_) | Literal::Uint(_)) => {
+                let end = self.expression(collection);
+                format!("for (int64_t {0} = 0; {0} < {1}; {0}++)", element, end)
+            },
+            _ => {
+                let collection = self.expression(collection);
+                format!("for (auto&& {} : {})", element, collection)
+            },
+        }
+    }
+
+    fn write_if(&mut self, writer: &mut CppWriter, if_decl: &If, as_value: bool) {
+        let condition = self.expression(&if_decl.condition);
+        writer.open(format!("if ({})", condition));
+        self.write_statments(writer, &if_decl.block, as_value);
+
+        let mut branches = if_decl.else_branchs.iter().collect::<Vec<_>>();
+        while !branches.is_empty() {
+            let branch = branches.remove(0);
+
+            match &branch.node {
+                ElseKind::Else(block) => {
+                    writer.reopen("else");
+                    self.write_statments(writer, &block.node, as_value);
+                },
+                ElseKind::ElseIf(else_if) => {
+                    let condition = self.expression(&else_if.node.condition);
+                    writer.reopen(format!("else if ({})", condition));
+                    self.write_statments(writer, &else_if.node.block, as_value);
+
+                    // else branches of an 'else if' follow it
+                    for (index, nested) in else_if.node.else_branchs.iter().enumerate() {
+                        branches.insert(index, nested);
+                    }
+                },
+            }
+        }
+
+        writer.close("");
+    }
+
+    /// match is written as 'if' chain on a copy of the condition
+    fn write_match(&mut self, writer: &mut CppWriter, match_decl: &Match, as_value: bool) {
+        let parent_scope = self.set_scope(match_decl.scope_id);
+
+        let value_name = self.temp_name("match");
+        let condition = self.expression(&match_decl.condition);
+
+        writer.open("");
+        writer.line(format!("auto&& {} = {};", value_name, condition));
+
+        let mut is_first = true;
+        let mut has_else = false;
+        for case in &match_decl.cases {
+            let case_scope = self.set_scope(case.scope_id);
+            let (condition, bindings) = self.case_condition(case, &value_name);
+
+            let line = match (&condition, is_first) {
+                (None, true) => "if (true)".to_string(),
+                (None, false) => "else".to_string(),
+                (Some(condition), true) => format!("if ({})", condition),
+                (Some(condition), false) => format!("else if ({})", condition),
+            };
+
+            match is_first {
+                true => writer.open(line),
+                false => writer.reopen(line),
+            }
+            is_first = false;
+
+            for binding in bindings {
+                writer.line(binding);
+            }
+
+            match &case.do_fn {
+                CaseDoKind::Block(block) => self.write_statments(writer, &block.node, as_value),
+                CaseDoKind::Expression(expression) => self.write_expression_statment(writer, expression, as_value),
+            }
+
+            self.current_scope = case_scope;
+            if condition.is_none() {
+                has_else = true;
+                break
+            }
+        }
+
+        if !is_first {
+            writer.close("");
+        }
+        if as_value && !has_else {
+            writer.line("throw ::std::logic_error(\"match has no case for value\");");
+        }
+
+        writer.close("");
+        self.current_scope = parent_scope;
+    }
+
+    /// condition of case (None for '_') and the variables that the case binds
+    fn case_condition(&mut self, case: &CaseSwitch, value_name: &str) -> (Option<String>, Vec<String>) {
+
+        let (variant, fields) = match &case.if_kind {
+            IfCaseKind::Expression(Spanned{node: ExpressionKind::Variable(variable), ..}) if variable.name.0 == "_" => return (None, vec![]),
+            IfCaseKind::Expression(Spanned{node: ExpressionKind::Default, ..}) => return (None, vec![]),
+            IfCaseKind::Expression(Spanned{node: ExpressionKind::Variable(variable), ..}) if self.find_union_of_variant(&variable.name.0).is_some() => {
+                (&variable.name.0, vec![])
+            },
+            IfCaseKind::Expression(pattern) => {
+                let pattern = self.expression(pattern);
+                return (Some(format!("{} == {}", value_name, pattern)), vec![])
+            },
+            IfCaseKind::Variant{name, params} => (&name.0, params.values.iter().enumerate().map(|(index, value)| (format!("_{}", index), value)).collect::<Vec<_>>()),
+            IfCaseKind::NamedVariant{name, params} => (&name.0, params.values.iter().sorted_by(|a, b| a.0.0.cmp(&b.0.0)).map(|(field, value)| (escape_name(&field.0), value)).collect::<Vec<_>>()),
+        };
+
+        let variant_type = format!("::std::remove_cvref_t<decltype({})>::{}", value_name, escape_name(variant));
+        let get_variant = format!("::std::get<{}>({}.value)", variant_type, value_name);
+
+        let mut conditions = vec![format!("::std::holds_alternative<{}>({}.value)", variant_type, value_name)];
+        let mut bindings = vec![];
+        for (field, value) in fields {
+            match &value.node {
+                ExpressionKind::Variable(variable) if variable.name.0 == "_" => (),
+                ExpressionKind::Variable(variable) => bindings.push(format!("auto&& {} = {}.{};", escape_name(&variable.name.0), get_variant, field)),
+                _ => {
+                    let value = self.expression(value);
+                    conditions.push(format!("{}.{} == {}", get_variant, field, value));
+                },
+            }
+        }
+
+        (Some(conditions.join(" && ")), bindings)
+    }
+
+    /// expression that can be a statment (e.g. 'if') is written as lambda that is called directly
+    fn value_expression(&mut self, expression: &Expression) -> String {
+
+        match &expression.node {
+            ExpressionKind::If(_) |
+            ExpressionKind::Match(_) |
+            ExpressionKind::Block(_) => {
+                let parent_value_block = std::mem::replace(&mut self.in_value_block, true);
+
+                let mut body = CppWriter::new(self.pretty).new_block();
+                match &expression.node {
+                    ExpressionKind::If(if_decl) => self.write_if(&mut body, if_decl, true),
+                    ExpressionKind::Match(match_decl) => self.write_match(&mut body, match_decl, true),
+                    ExpressionKind::Block(block) => self.write_statments(&mut body, block, true),
+                    _ => unreachable!(),
+                }
+
+                self.in_value_block = parent_value_block;
+                format!("[&]() {}()", body.into_inline_block())
+            },
+            _ => self.expression(expression),
+        }
+    }
+
+    fn expression(&mut self, expression: &Expression) -> String {
+
+        match &expression.node {
+            ExpressionKind::Empty => String::new(),
+            ExpressionKind::Default => "{}".into(),
+            ExpressionKind::Literal(literal) => literal_to_cpp(literal),
+
+            ExpressionKind::Variable(variable) => {
+                let name = &variable.name.0;
+                if let Some(page) = self.external_page.take() {
+                    return mangle_global(&page, name)
+                }
+
+                if name == "this" {
+                    return self.this_name.clone().unwrap_or_else(|| "(*this)".into())
+                }
+
+                if !self.is_variable(name) && let Some(union_decl) = self.find_union_of_variant(name) {
+                    return format!("{0}{{{0}::{1}{{}}}}", escape_name(&union_decl.name.0), escape_name(name))
+                }
+
+                // function as value (e.g. 'apply(double)')
+                if !self.is_variable(name) && self.is_function(name) {
+                    return self.callee_symbol(name, Receiver::Free, None, expression.span)
+                }
+
+                self.variable_name(name)
+            },
+            ExpressionKind::Index(index) => {
+                let collection = self.expression(&index.collection);
+                let index = self.expression(&index.index);
+                format!("{}[{}]", collection, index)
+            },
+            ExpressionKind::Lambda(lambda) => self.lambda(lambda),
+            ExpressionKind::FunctionCall(function_call) => self.function_call(function_call, expression.span),
+            ExpressionKind::StructConstructor(struct_constructor) => {
+                let ty = inner_type_to_cpp(&struct_constructor.calle);
+                let field_order = self.field_order(&struct_constructor.calle);
+
+                let mut values = struct_constructor.arguments.values.iter()
+                    .map(|(name, value)| (name.0.clone(), value))
+                    .collect::<Vec<_>>();
+                values.sort_by_key(|(name, _)| field_order.iter().position(|field| field == name).unwrap_or(usize::MAX));
+
+                let values = values.into_iter()
+                    .map(|(name, value)| format!(".{} = {}", escape_name(&name), self.value_expression(value)))
+                    .join(", ");
+                format!("{}{{{}}}", ty, values)
+            },
+            ExpressionKind::AccessField(access_field) => {
+                let object = self.expression(&access_field.object);
+                format!("{}.{}", object, escape_name(&access_field.field.name.0))
+            },
+            ExpressionKind::StaticField(static_field) => {
+                let field = &static_field.field.name.0;
+
+                match (&static_field.object.base, field.as_str()) {
+                    (base, "MAX") if base.is_numeric() => format!("::std::numeric_limits<{}>::max()", inner_type_to_cpp(&static_field.object)),
+                    (base, "MIN") if base.is_numeric() => format!("::std::numeric_limits<{}>::lowest()", inner_type_to_cpp(&static_field.object)),
+                    _ => format!("{}::{}", inner_type_to_cpp(&static_field.object), escape_name(field)),
+                }
+            },
+            ExpressionKind::StaticMethod(static_method) => {
+                let arguments = self.arguments(&static_method.arguments.values);
+                let generics = self.generics(&static_method.generics);
+                let symbol = self.callee_symbol(&static_method.name.0, Receiver::Static, Some(static_method.arguments.values.len()), expression.span);
+                format!("{}{}({})", static_function_name(&static_method.callee.node, &symbol), generics, arguments)
+            },
+            ExpressionKind::UnwrapVariable(unwrap_variable) => match unwrap_variable {
+                UnwrapVariable::Variable(variable) => escape_name(&variable.name.0),
+                UnwrapVariable::MultiVariable{vars, initializer, ..} => {
+                    let initializer = initializer.as_ref().map(|initializer| self.value_expression(initializer)).unwrap_or_default();
+                    format!("auto [{}] = {}", vars.iter().map(|var| escape_name(&var.name.0)).join(", "), initializer)
+                },
+            },
+            ExpressionKind::ExternalExpression(external) => {
+                self.includes.insert(external.path.clone());
+                self.external_page = Some(external.path.clone());
+                let inner = self.expression(&external.expr);
+                self.external_page = None;
+                format!("::{}::{}", page_namespace(&external.path), inner)
+            },
+            ExpressionKind::Unary(unary) => {
+                let inner = self.expression(&unary.expression);
+
+                match &unary.operator.node {
+                    UnaryOperatorKind::Invalid => inner,
+                    UnaryOperatorKind::Neg => format!("(-{})", inner),
+                    UnaryOperatorKind::Not => format!("(!{})", inner),
+                    UnaryOperatorKind::Increment{before_var: true} => format!("++{}", inner),
+                    UnaryOperatorKind::Increment{before_var: false} => format!("{}++", inner),
+                    UnaryOperatorKind::Decrement{before_var: true} => format!("--{}", inner),
+                    UnaryOperatorKind::Decrement{before_var: false} => format!("{}--", inner),
+                }
+            },
+            ExpressionKind::Binary(binary) => {
+                let left = self.expression(&binary.left);
+                let right = self.expression(&binary.right);
+                binary_to_cpp(&binary.operator.node, left, right)
+            },
+            ExpressionKind::Ternary(ternary) => {
+                let condition = self.expression(&ternary.condition);
+                let if_branch = self.value_expression(&ternary.if_branch);
+                let else_branch = self.value_expression(&ternary.else_branch);
+                format!("({} ? {} : {})", condition, if_branch, else_branch)
+            },
+            ExpressionKind::If(_) |
+            ExpressionKind::Match(_) |
+            ExpressionKind::Block(_) => self.value_expression(expression),
+            ExpressionKind::For(_) |
+            ExpressionKind::While(_) |
+            ExpressionKind::ReturnLike(_) => {
+                let mut body = CppWriter::new(self.pretty).new_block();
+                self.write_expression_statment(&mut body, expression, false);
+                format!("[&]() {}()", body.into_inline_block())
+            },
+            // soul references are c++ references so taking or reading a reference is the variable itself
+            ExpressionKind::Deref(inner) |
+            ExpressionKind::MutRef(inner) |
+            ExpressionKind::ConstRef(inner) |
+            ExpressionKind::Copy(inner) => self.expression(inner),
+            ExpressionKind::ExpressionGroup(expression_group) => match expression_group {
+                ExpressionGroup::Tuple(tuple) => format!("::std::make_tuple({})", self.arguments(&tuple.values)),
+                ExpressionGroup::NamedTuple(named_tuple) => {
+                    let values = named_tuple.values.iter()
+                        .sorted_by(|a, b| a.0.0.cmp(&b.0.0))
+                        .map(|(_, value)| self.value_expression(value))
+                        .join(", ");
+                    format!("::std::make_tuple({})", values)
+                },
+                ExpressionGroup::Array(array) => {
+                    let values = self.arguments(&array.values);
+                    match &array.element_type {
+                        Some(element_type) => format!("::std::vector<{}>{{{}}}", inner_type_to_cpp(element_type), values),
+                        None => format!("::std::vector{{{}}}", values),
+                    }
+                },
+                ExpressionGroup::ArrayFiller(array_filler) => {
+                    let parent_scope = self.set_scope(array_filler.scope_id);
+                    let amount = self.expression(&array_filler.amount);
+                    let index = array_filler.index.as_ref().map(|index| escape_name(&index.name.0)).unwrap_or_else(|| self.temp_name("index"));
+                    let fill = self.value_expression(&array_filler.fill_expr);
+                    self.current_scope = parent_scope;
+
+                    format!("::soul::Fill({}, [&]([[maybe_unused]] int64_t {}) {{ return {}; }})", amount, index, fill)
+                },
+            },
+        }
+    }
+
+    fn lambda(&mut self, lambda: &Lambda) -> String {
+        let parent_scope = self.set_scope(lambda.scope_id);
+        let parameters = parameters_to_cpp(&lambda.signature.params);
+        let return_type = lambda.signature.return_type.as_deref()
+            .map(|ty| format!(" -> {}", inner_type_to_cpp(ty)))
+            .unwrap_or_default();
+
+        let body = match &lambda.body {
+            LambdaBody::Block(block) => {
+                let mut body = CppWriter::new(self.pretty).new_block();
+                self.write_body(&mut body, block, self.this_name.clone());
+                body.into_inline_block()
+            },
+            LambdaBody::Expression(expression) => format!("{{ return {}; }}", self.value_expression(expression)),
+        };
+
+        self.current_scope = parent_scope;
+        format!("[&]({}){} {}", parameters, return_type, body)
+    }
+
+    fn function_call(&mut self, function_call: &FunctionCall, span: SoulSpan) -> String {
+        let name = &function_call.name.0;
+        let external_page = self.external_page.take();
+        let generics = self.generics(&function_call.generics);
+        let arguments = self.arguments(&function_call.arguments.values);
+        let argument_count = function_call.arguments.values.len();
+
+        let callee = match &function_call.callee {
+            Some(callee) => callee,
+            None => {
+                // soul std functions are in the runtime (e.g. 'std::fmt::Arg')
+                if let Some(std_name) = name.strip_prefix("std::") {
+                    return format!("::soul::{}{}({})", std_name, generics, arguments)
+                }
+
+                if let Some(page) = external_page {
+                    return format!("{}{}({})", self.external_symbol(&page, name, argument_count), generics, arguments)
+                }
+
+                if !self.is_function(name) && let Some(union_decl) = self.find_union_of_variant(name) {
+                    return format!("{0}{{{0}::{1}{{{2}}}}}", escape_name(&union_decl.name.0), escape_name(name), arguments)
+                }
+
+                // variable that holds a lambda
+                if self.is_variable(name) {
+                    return format!("{}{}({})", self.variable_name(name), generics, arguments)
+                }
+
+                let symbol = self.callee_symbol(name, Receiver::Free, Some(argument_count), span);
+                return format!("{}{}({})", symbol, generics, arguments)
+            },
+        };
+
+        // 'u8.maxValue()' calls static methode of type
+        if let ExpressionKind::Variable(variable) = &callee.node && let Some(ty) = self.type_of_name(&variable.name.0) {
+            let symbol = self.callee_symbol(name, Receiver::Static, Some(argument_count), span);
+            return format!("{}{}({})", static_function_name(&ty, &symbol), generics, arguments)
+        }
+
+        let callee = self.expression(callee);
+        let symbol = self.callee_symbol(name, Receiver::This, Some(argument_count), span);
+        if self.free_methodes.contains(name) && !self.class_methodes.contains(name) {
+            return match arguments.is_empty() {
+                true => format!("{}{}({})", symbol, generics, callee),
+                false => format!("{}{}({}, {})", symbol, generics, callee, arguments),
+            }
+        }
+
+        format!("{}.{}{}({})", callee, symbol, generics, arguments)
+    }
+
+    /// mangled name of function of page (or escaped soul name for functions that are not in the tree)
+    fn function_name(&self, function: &Function) -> String {
+        self.function_symbols.get(&function.block.scope_id)
+            .cloned()
+            .unwrap_or_else(|| escape_name(&function.signature.name.0))
+    }
+
+    /// mangled name of the overload that the sementic analyser selected for call,
+    /// calls that are not resolved (e.g. in generic functions) take the overload with the same amount of parameters
+    fn callee_symbol(&self, name: &str, receiver: Receiver, argument_count: Option<usize>, span: SoulSpan) -> String {
+        let resolved = self.scope.resolved_calls.get(span, name);
+        let scopes = self.scope.get_scopes();
+
+        let mut declarations = vec![];
+        for scope in scopes {
+            let functions = scope.get(name).into_iter()
+                .flatten()
+                .filter_map(|kind| match &kind.node {
+                    ScopeKind::Functions(functions) => Some(functions),
+                    _ => None,
+                })
+                .flatten();
+
+            for function in functions {
+                let signature = &function.node.signature;
+                let is_match = match resolved {
+                    Some(resolved) => signature == resolved,
+                    None => receiver.matches(signature) && argument_count.is_none_or(|count| signature.parameters.len() == count),
+                };
+
+                if is_match {
+                    declarations.push((scope.self_index, &function.node));
+                }
+            }
+        }
+
+        // same signature can be declared in multiple functions, the one of the closest scope is called
+        let mut visible = vec![];
+        let mut scope_id = Some(self.current_scope);
+        while let Some(id) = scope_id {
+            visible.push(id);
+            scope_id = scopes.get(id.0).and_then(|scope| scope.parent_index);
+        }
+
+        declarations.iter()
+            .min_by_key(|(scope_id, _)| visible.iter().position(|id| id == scope_id).unwrap_or(usize::MAX))
+            .map(|(_, function)| self.function_name(function))
+            .unwrap_or_else(|| escape_name(name))
+    }
+
+    /// mangled name of function in header of other page (external calls are not type checked so the overload is picked by amount of parameters)
+    fn external_symbol(&self, page: &SoulPagePath, name: &str, argument_count: usize) -> String {
+        self.scope.external_headers.get(page)
+            .and_then(|header| header.scope.get(name))
+            .into_iter()
+            .flatten()
+            .filter_map(|kind| match kind {
+                ScopeKind::Functions(functions) => Some(functions),
+                _ => None,
+            })
+            .flatten()
+            .find(|function| function.node.signature.callee.is_none() && function.node.signature.parameters.len() == argument_count)
+            .map(|function| mangle_function(page, &[], &function.node.signature))
+            .unwrap_or_else(|| escape_name(name))
+    }
+
+    fn arguments(&mut self, arguments: &[Expression]) -> String {
+        arguments.iter()
+            .map(|argument| self.value_expression(argument))
+            .join(", ")
+    }
+
+    fn generics(&self, generics: &[TypeGenericKind]) -> String {
+        match generics.is_empty() {
+            true => String::new(),
+            false => format!("<{}>", generics_to_cpp(generics)),
+        }
+    }
+
+    fn temp_name(&mut self, name: &str) -> String {
+        self.temp_count += 1;
+        format!("__{}{}", name, self.temp_count)
+    }
+
+    fn lookup(&self, name: &str) -> Option<&'a Vec<Spanned<ScopeKind>>> {
+        self.lookup_scope(name).map(|(_, kinds)| kinds)
+    }
+
+    /// symbols of name and the scope they are in
+    fn lookup_scope(&self, name: &str) -> Option<(ScopeId, &'a Vec<Spanned<ScopeKind>>)> {
+        let scopes = self.scope.get_scopes();
+
+        let mut scope_id = Some(self.current_scope);
+        while let Some(id) = scope_id {
+            let scope = scopes.get(id.0)?;
+            if let Some(kinds) = scope.get(name) {
+                return Some((id, kinds))
+            }
+
+            scope_id = scope.parent_index;
+        }
+
+        None
+    }
+
+    /// variables of page are mangled (see 'name_mangling'), local variables keep their name
+    fn variable_name(&self, name: &str) -> String {
+        match self.lookup_scope(name) {
+            Some((scope_id, kinds)) if scope_id == ScopeVisitor::GLOBAL_SCOPE_INDEX && kinds.iter().any(|kind| matches!(kind.node, ScopeKind::Variable(_))) => mangle_global(&self.page, name),
+            _ => escape_name(name),
+        }
+    }
+
+    fn is_variable(&self, name: &str) -> bool {
+        self.lookup(name).is_some_and(|kinds| kinds.iter().any(|kind| matches!(kind.node, ScopeKind::Variable(_))))
+    }
+
+    fn is_function(&self, name: &str) -> bool {
+        self.lookup(name).is_some_and(|kinds| kinds.iter().any(|kind| matches!(kind.node, ScopeKind::Functions(_))))
+    }
+
+    /// type that name refers to if name is not a variable (e.g. 'u8' in 'u8.maxValue()')
+    fn type_of_name(&self, name: &str) -> Option<SoulType> {
+        if self.is_variable(name) {
+            return None
+        }
+
+        if let Some(primitive) = TypeKind::from_internal_name(name) {
+            return Some(SoulType::from_type_kind(primitive))
+        }
+
+        let kinds = self.lookup(name)?;
+        kinds.iter().find_map(|kind| match &kind.node {
+            ScopeKind::Class(class) => Some(SoulType::from_type_kind(TypeKind::Class(class.name.clone()))),
+            ScopeKind::Struct(struct_decl) => Some(SoulType::from_type_kind(TypeKind::Struct(struct_decl.name.clone()))),
+            ScopeKind::Enum(enum_decl) => Some(SoulType::from_type_kind(TypeKind::Enum(enum_decl.name.clone()))),
+            ScopeKind::Union(union_decl) => Some(SoulType::from_type_kind(TypeKind::Union(union_decl.name.clone()))),
+            ScopeKind::Type(ty) => Some(ty.clone()),
+            _ => None,
+        })
+    }
+
+    /// non generic union that has variant (generic unions need the type of the value)
+    fn find_union_of_variant(&self, variant: &str) -> Option<&'a Union> {
+        let scopes = self.scope.get_scopes();
+
+        let mut scope_id = Some(self.current_scope);
+        while let Some(id) = scope_id {
+            let scope = scopes.get(id.0)?;
+
+            let found = scope.symbols.values()
+                .flatten()
+                .find_map(|kind| match &kind.node {
+                    ScopeKind::Union(union_decl) if union_decl.generics.is_empty() && union_decl.variants.iter().any(|el| el.node.name.0 == variant) => Some(union_decl),
+                    _ => None,
+                });
+            if found.is_some() {
+                return found
+            }
+
+            scope_id = scope.parent_index;
+        }
+
+        None
+    }
+
+    /// names of fields in order of declaration (designated initializers have to be in that order)
+    fn field_order(&self, ty: &SoulType) -> Vec<String> {
+        let name = match ty.base.try_get_name() {
+            Some(name) => name,
+            None => return vec![],
+        };
+
+        let kinds = match self.lookup(name) {
+            Some(kinds) => kinds,
+            None => return vec![],
+        };
+
+        kinds.iter()
+            .find_map(|kind| match &kind.node {
+                ScopeKind::Struct(struct_decl) => Some(struct_decl.fields.iter().map(|field| field.node.name.0.clone()).collect()),
+                ScopeKind::Class(class) => Some(class.children.iter().filter_map(|child| match child {
+                    ClassChild::Field(field) => Some(field.node.name.0.clone()),
+                    _ => None,
+                }).collect()),
+                _ => None,
+            })
+            .unwrap_or_default()
+    }
+}
+
+/// methodes of class and of the 'use' blocks in class
+fn class_functions(class: &Class) -> Vec<&Function> {
+    let mut functions = vec![];
+
+    for child in &class.children {
+        match child {
+            ClassChild::Field(_) => (),
+            ClassChild::Methode(methode) => functions.push(&methode.node),
+            ClassChild::ImplBlock(impl_block) => for statment in &impl_block.node.block.statments {
+                if let StatementKind::Function(function) = &statment.node {
+                    functions.push(function);
+                }
+            },
+        }
+    }
+
+    functions
+}
+
+/// kind of callee of a call
+#[derive(Debug, Clone, Copy)]
+enum Receiver {
+    /// 'name()'
+    Free,
+    /// 'value.name()'
+    This,
+    /// 'Type.name()'
+    Static,
+}
+
+impl Receiver {
+    fn matches(&self, signature: &FunctionSignature) -> bool {
+        match (self, &signature.callee) {
+            (Receiver::Free, None) => true,
+            (Receiver::This, Some(callee)) => callee.node.this.is_some(),
+            (Receiver::Static, Some(callee)) => callee.node.this.is_none(),
+            _ => false,
+        }
+    }
+}
+
+/// static methodes of classes are members of the class (e.g. 'Counter::symbol'), static methodes of other types are global
+fn static_function_name(ty: &SoulType, symbol: &str) -> String {
+    match &ty.base {
+        TypeKind::Class(class) => format!("{}::{}", escape_name(&class.0), symbol),
+        _ => symbol.to_string(),
+    }
+}
+
+fn template_parameters(generics: &[GenericParameter]) -> Vec<String> {
+    generics.iter()
+        .filter_map(|generic| match &generic.kind {
+            GenericKind::Type{impl_type: Some(ty), ..} => Some(format!("{} {}", inner_type_to_cpp(ty), escape_name(&generic.name.0))),
+            GenericKind::Type{impl_type: None, ..} => Some(format!("typename {}", escape_name(&generic.name.0))),
+            GenericKind::Lifetime => None,
+        })
+        .collect()
+}
+
+fn has_template(generics: &[GenericParameter]) -> bool {
+    !template_parameters(generics).is_empty()
+}
+
+/// 'template<typename T> ' or '' if there are no type generics
+fn template_line(generics: &[GenericParameter]) -> String {
+    let parameters = template_parameters(generics);
+    match parameters.is_empty() {
+        true => String::new(),
+        false => format!("template<{}> ", parameters.join(", ")),
+    }
+}
+
+fn lambda_template(generics: &[GenericParameter]) -> String {
+    let parameters = template_parameters(generics);
+    match parameters.is_empty() {
+        true => String::new(),
+        false => format!("<{}>", parameters.join(", ")),
+    }
+}
+
+fn binary_to_cpp(operator: &BinaryOperatorKind, left: String, right: String) -> String {
+    let symbool = match operator {
+        BinaryOperatorKind::Invalid => return left,
+        BinaryOperatorKind::Pow => return format!("::std::pow({}, {})", left, right),
+        BinaryOperatorKind::Root => return format!("::std::pow({}, 1.0 / {})", left, right),
+        BinaryOperatorKind::Log => return format!("(::std::log({}) / ::std::log({}))", left, right),
+        BinaryOperatorKind::Range => return format!("::soul::Range({}, {})", left, right),
+        BinaryOperatorKind::TypeOf => return format!("::std::holds_alternative<{}>({})", right, left),
+
+        BinaryOperatorKind::Add => "+",
+        BinaryOperatorKind::Sub => "-",
+        BinaryOperatorKind::Mul => "*",
+        BinaryOperatorKind::Div => "/",
+        BinaryOperatorKind::Mod => "%",
+        BinaryOperatorKind::BitAnd => "&",
+        BinaryOperatorKind::BitOr => "|",
+        BinaryOperatorKind::BitXor => "^",
+        BinaryOperatorKind::LogAnd => "&&",
+        BinaryOperatorKind::LogOr => "||",
+        BinaryOperatorKind::Eq => "==",
+        BinaryOperatorKind::NotEq => "!=",
+        BinaryOperatorKind::Lt => "<",
+        BinaryOperatorKind::Gt => ">",
+        BinaryOperatorKind::Le => "<=",
+        BinaryOperatorKind::Ge => ">=",
+    };
+
+    format!("({} {} {})", left, symbool, right)
+}
@@ -0,0 +1,297 @@
+use std::{fs, process::Command};
+use crate::{run_options::optimize_level::OptimizeLevel, steps::{code_generator::{cpp_compiler::{compiler_arguments, find_cpp_compiler, run_cpp_compiler}, cpp_generator::generate_cpp, cpp_names::page_file, cpp_runtime::{SOUL_RUNTIME_FILE, SOUL_RUNTIME_HEADER}, name_mangling::demangle_text}, sementic::test_helpers::{error_messages, optimize_source}, step_interfaces::{i_code_generator::CodeGeneratorResponse, i_parser::abstract_syntax_tree::soul_type::type_kind::SoulPagePath}}};
+
+const PAGE: &str = "testProject.main";
+
+fn generate(source: &str, pretty: bool) -> CodeGeneratorResponse {
+    let (tree, scope, _, faults) = optimize_source(source);
+    assert!(error_messages(&faults).is_empty(), "{:?}", error_messages(&faults));
+
+    generate_cpp(&tree, &scope, &SoulPagePath(PAGE.into()), true, pretty, None)
+}
+
+/// compiles source with the c++ compiler and returns stdout of binary (None if there is no c++ compiler)
+fn compile_and_run(source: &str, name: &str) -> Option<String> {
+    let compiler = find_cpp_compiler(None).ok()?;
+    let cpp = generate(source, true);
+
+    let dir = std::env::temp_dir().join(format!("soul_cpp_generator_test_{}", name));
+    let page = SoulPagePath(PAGE.into());
+    let cpp_file = dir.join(page_file(&page, "cpp"));
+    fs::create_dir_all(cpp_file.parent().unwrap()).unwrap();
+    fs::write(dir.join(SOUL_RUNTIME_FILE), SOUL_RUNTIME_HEADER).unwrap();
+    fs::write(dir.join(page_file(&page, "h")), &cpp.header).unwrap();
+    fs::write(&cpp_file, &cpp.source).unwrap();
+
+    let binary = dir.join("test_binary");
+    let arguments = compiler_arguments(&[cpp_file], std::slice::from_ref(&dir), &[], OptimizeLevel::None, &binary);
+    let output = run_cpp_compiler(&compiler, &arguments).unwrap();
+    assert!(output.success, "{}\n{}", output.stderr, cpp.source);
+
+    let run = Command::new(&binary).output().unwrap();
+    fs::remove_dir_all(&dir).unwrap();
+    Some(String::from_utf8_lossy(&run.stdout).to_string())
+}
+
+fn assert_contains(code: &str, expected: &[&str]) {
+    for line in expected {
+        assert!(code.contains(line), "expected '{}' in:\n{}", line, code);
+    }
+}
+
+#[test]
+fn test_functions_and_control_flow() {
+    let source = "
+u8 maxValue() u8 {
+    return 255
+}
+
+int triple(this) int {
+    return this * 3
+}
+
+main() int {
+    mut sum := 0
+    for i in 0..10 {
+        sum += i
+    }
+    while sum > 3 {
+        sum -= 1
+    }
+    t := sum.triple()
+    m := u8.maxValue()
+    inner(int a) int {
+        return a + 1
+    }
+    r := inner(t)
+    x := if sum > 2 { 1 } else { 2 }
+    Println(f\"num {sum}\")
+    return 7
+}
+";
+    let cpp = generate(source, true);
+
+    assert_contains(&cpp.header, &[
+        "#pragma once",
+        "#include \"soul_runtime.h\"",
+        "namespace soul_program::testProject::main {",
+        "uint8_t _SN11testProject4mainESua8maxValuePE();",
+        "int64_t _SN11testProject4mainEMis6triplePE(int64_t this_);",
+        "int64_t _SN11testProject4mainEF4mainPE();",
+    ]);
+    assert_contains(&cpp.source, &[
+        "#include \"testProject/main.h\"",
+        "    return (this_ * 3);",
+        "    int64_t sum = 0;",
+        "    for (int64_t i = 0; i < 10; i++) {\n        sum = (sum + i);\n    }",
+        "    while ((sum > 3)) {",
+        "const int64_t t = _SN11testProject4mainEMis6triplePE(sum);",
+        "const auto m = _SN11testProject4mainESua8maxValuePE();",
+        "    auto _SN11testProject4mainELF4mainPEF5innerPisE = [&](const int64_t a) -> int64_t {\n        return (a + 1);\n    };",
+        "const int64_t r = _SN11testProject4mainELF4mainPEF5innerPisE(t);",
+        "[&]() {\n        if ((sum > 2)) {\n            return 1;\n        } else {\n            return 2;\n        }\n    }();",
+        "Println(::soul::fmt::FormatArgs(::std::string(\"num \"), ::soul::fmt::Arg(sum, false), ::std::string(\"\")));",
+        "int main() {\n    return static_cast<int>(::soul_program::testProject::main::_SN11testProject4mainEF4mainPE());\n}",
+    ]);
+}
+
+#[test]
+fn test_objects() {
+    let source = "
+struct Point {
+    int x
+    int y = 2
+}
+
+class Counter {
+    int count
+
+    Increment(this&) {
+        this.count += 1
+    }
+}
+
+enum Color {
+    Red,
+    Green,
+}
+
+union Shape {
+    Circle(f32),
+    Rect(f32, f32),
+    Empty,
+}
+
+area(Shape shape) f32 {
+    match shape {
+        Circle(_) => {
+            return 3.0
+        },
+        Rect(_, _) => {
+            return 6.0
+        },
+        Empty => {
+            return 0.0
+        },
+    }
+}
+
+main() {
+    p := Point{x: 1}
+    mut counter := Counter{count: p.x}
+    counter.Increment()
+    c := Color.Green
+    a := area(Rect(2.0, 3.0))
+}
+";
+    let cpp = generate(source, true);
+
+    assert_contains(&cpp.header, &[
+        "struct Point;\nclass Counter;\nenum class Color : int64_t;\nstruct Shape;",
+        "struct Point {\n    int64_t x{};\n    int64_t y = 2;\n};",
+        "class Counter {\n    public:\n    int64_t count{};\n\n    void _SN11testProject4mainEMR7Counter9IncrementPE() {\n        (*this).count = ((*this).count + 1);\n    }\n};",
+        "enum class Color : int64_t {\n    Red = 0,\n    Green = 1,\n};",
+        "    struct Rect { float _0; float _1; };\n    struct Empty {};\n    ::std::variant<Circle, Rect, Empty> value;",
+    ]);
+    assert_contains(&cpp.source, &[
+        "const Point p = Point{.x = 1};",
+        "counter._SN11testProject4mainEMR7Counter9IncrementPE();",
+        "Color::Green",
+        "_SN11testProject4mainEF4areaP5ShapeE(Shape{Shape::Rect{2.0, 3.0}})",
+        "auto&& __match1 = shape;",
+        "if (::std::holds_alternative<::std::remove_cvref_t<decltype(__match1)>::Circle>(__match1.value)) {",
+        "} else if (::std::holds_alternative<::std::remove_cvref_t<decltype(__match1)>::Empty>(__match1.value)) {",
+    ]);
+}
+
+#[test]
+fn test_program_memmory() {
+    let source = "
+main() {
+    arr := [1, 2, 3]
+    first := arr[0]
+}
+";
+    let cpp = generate(source, true);
+
+    assert_contains(&cpp.header, &["inline const ::std::vector<int64_t> __soul_mem_0 = ::std::vector<int64_t>{1, 2, 3};"]);
+    assert_contains(&cpp.source, &["const ::std::vector<int64_t> arr = __soul_mem_0;"]);
+}
+
+#[test]
+fn test_not_pretty() {
+    let source = "
+main() {
+    mut sum := 0
+    if sum > 2 {
+        sum = 1
+    }
+}
+";
+    let cpp = generate(source, false);
+
+    assert_contains(&cpp.source, &["void _SN11testProject4mainEF4mainPE() {\nint64_t sum = 0;\nif ((sum > 2)) {\nsum = 1;\n}\n}\n"]);
+    assert!(!cpp.source.contains("    ") && !cpp.source.contains("\n\n"), "{}", cpp.source);
+}
+
+#[test]
+fn test_mut_ref_parameter() {
+    let source = "
+bump(int& p) {
+    *p += 1
+}
+
+main() {
+    mut n := 1
+    bump(&n)
+    bump(&n)
+    u8 small = 200
+    Println(f\"n {n} {small}\")
+}
+";
+    let cpp = generate(source, true);
+    assert_contains(&cpp.source, &["void _SN11testProject4mainEF4bumpPRisE(int64_t& p) {"]);
+
+    if let Some(stdout) = compile_and_run(source, "mut_ref_parameter") {
+        assert_eq!(stdout, "n 3 200\n");
+    }
+}
+
+#[test]
+fn test_overloads() {
+    let source = "
+add(int a, int b) int {
+    return a + b
+}
+
+add(f32 a, f32 b) f32 {
+    return a + b
+}
+
+main() {
+    i := add(1, 2)
+    f := add(1.5, 2.0)
+    Println(f\"{i} {f}\")
+}
+";
+    let cpp = generate(source, true);
+    assert_contains(&cpp.source, &[
+        "_SN11testProject4mainEF3addPisisE(1, 2)",
+        "_SN11testProject4mainEF3addPfcfcE(1.5, 2.0)",
+    ]);
+
+    // every symbol in the c++ code is a soul name that 'soul demangle' can read
+    assert_contains(&demangle_text(&cpp.header), &[
+        "int64_t testProject.main::add(int, int)(const int64_t a, const int64_t b);",
+        "float testProject.main::add(f32, f32)(const float a, const float b);",
+        "void testProject.main::main()();",
+    ]);
+
+    if let Some(stdout) = compile_and_run(source, "overloads") {
+        assert_eq!(stdout, "3 3.5\n");
+    }
+}
+
+#[test]
+fn test_page_symbols() {
+    let source = "
+mut count := 0
+
+bump(int amount) {
+    count += amount
+}
+
+main() {
+    mut count := 1
+    count += 1
+    bump(count)
+}
+";
+    let cpp = generate(source, true);
+    assert_contains(&cpp.header, &["inline int64_t _SN11testProject4mainEG5count = 0;"]);
+    assert_contains(&cpp.source, &[
+        "_SN11testProject4mainEG5count = (_SN11testProject4mainEG5count + amount);",
+        "int64_t count = 1;\n    count = (count + 1);",
+        "_SN11testProject4mainEF4bumpPisE(count);",
+    ]);
+}
+
+#[test]
+fn test_generic_struct_constructor() {
+    let source = "
+struct Box<T> {
+    T value
+}
+
+main() {
+    b := Box{value: 1}
+    Println(b.value)
+}
+";
+    let cpp = generate(source, true);
+    assert_contains(&cpp.source, &["const Box<int64_t> b = Box<int64_t>{.value = 1};"]);
+
+    if let Some(stdout) = compile_and_run(source, "generic_struct_constructor") {
+        assert_eq!(stdout, "1\n");
+    }
+}
@@ -0,0 +1,229 @@
+use std::path::PathBuf;
+use itertools::Itertools;
+use crate::steps::step_interfaces::i_parser::abstract_syntax_tree::{expression::ExpressionKind, function::Parameter, literal::{Literal, LiteralType}, soul_type::{soul_type::{Modifier, SoulType, TypeGenericKind, TypeWrapper}, type_kind::{SoulPagePath, TypeKind, TypeSize, UnionKind}}, spanned::Spanned};
+
+/// c++ keywords that are valid soul names, these get a '_' suffix
+const CPP_KEYWORDS: &[&str] = &[
+    "alignas", "alignof", "and", "asm", "auto", "bitand", "bitor", "bool", "case", "catch", "char", "class", "compl", "concept",
+    "const_cast", "consteval", "constexpr", "constinit", "continue", "decltype", "default", "delete", "do", "double", "dynamic_cast",
+    "explicit", "export", "extern", "float", "friend", "goto", "inline", "int", "long", "mutable", "namespace", "new", "noexcept", "not",
+    "nullptr", "operator", "or", "private", "protected", "public", "register", "reinterpret_cast", "requires", "short", "signed",
+    "sizeof", "static", "static_assert", "static_cast", "switch", "template", "this", "thread_local", "throw", "try", "typedef",
+    "typeid", "typename", "union", "unsigned", "virtual", "void", "volatile", "wchar_t", "xor",
+];
+
+/// soul name as valid c++ name (e.g. 'double' becomes 'double_')
+pub fn escape_name(name: &str) -> String {
+    if CPP_KEYWORDS.contains(&name) {
+        return format!("{}_", name)
+    }
+
+    name.into()
+}
+
+/// namespace that all pages are in (so page names can not clash with 'main' or the runtime)
+pub const PROGRAM_NAMESPACE: &str = "soul_program";
+
+/// namespace of page (e.g. 'project.std.math' becomes 'soul_program::project::std::math')
+pub fn page_namespace(path: &SoulPagePath) -> String {
+    std::iter::once(PROGRAM_NAMESPACE.to_string())
+        .chain(path.0.split('.').map(escape_name))
+        .join("::")
+}
+
+/// file of page relative to the c++ output folder (e.g. 'project.std.math' becomes 'project/std/math.h')
+pub fn page_file(path: &SoulPagePath, extension: &str) -> PathBuf {
+    let mut file = path.to_path_buf(false);
+    file.set_extension(extension);
+    file
+}
+
+/// c++ type with modifier (e.g. 'const int[]' becomes 'const ::std::vector<int64_t>'),
+/// the modifier of a reference is the binding so the wrapper decides constness (e.g. 'int&' stays 'int64_t&')
+pub fn type_to_cpp(ty: &SoulType) -> String {
+    if ty.modifier == Modifier::Default {
+        return inner_type_to_cpp(ty)
+    }
+
+    match ty.wrappers.last() {
+        Some(TypeWrapper::MutRef(_) | TypeWrapper::ConstRef(_)) => inner_type_to_cpp(ty),
+        Some(TypeWrapper::Pointer | TypeWrapper::ConstPointer) => format!("{} const", inner_type_to_cpp(ty)),
+        _ => format!("const {}", inner_type_to_cpp(ty)),
+    }
+}
+
+/// c++ type without modifier (for types in other types e.g. 'int' in 'int[]')
+pub fn inner_type_to_cpp(ty: &SoulType) -> String {
+    let mut cpp = base_to_cpp(&ty.base);
+    if !ty.generics.is_empty() {
+        cpp = format!("{}<{}>", cpp, generics_to_cpp(&ty.generics));
+    }
+
+    for wrapper in &ty.wrappers {
+        cpp = match wrapper {
+            TypeWrapper::Invalid => cpp,
+            TypeWrapper::Array => format!("::std::vector<{}>", cpp),
+            TypeWrapper::StackArray(size) => format!("::std::array<{}, {}>", cpp, size),
+            TypeWrapper::StackArrayGeneric(size) => format!("::std::array<{}, {}>", cpp, inner_type_to_cpp(size)),
+            TypeWrapper::ConstRef(_) => format!("const {}&", cpp),
+            TypeWrapper::MutRef(_) => format!("{}&", cpp),
+            TypeWrapper::Pointer => format!("{}*", cpp),
+            TypeWrapper::ConstPointer => format!("const {}*", cpp),
+        };
+    }
+
+    cpp
+}
+
+/// true if ty is not known after type resolution so the c++ type has to be inferred ('auto')
+pub fn is_unresolved(ty: &SoulType) -> bool {
+    ty.wrappers.is_empty() && matches!(ty.base, TypeKind::None | TypeKind::Unknown(_))
+}
+
+pub fn generics_to_cpp(generics: &[TypeGenericKind]) -> String {
+    generics.iter()
+        .filter_map(|generic| match generic {
+            TypeGenericKind::Type(ty) => Some(inner_type_to_cpp(ty)),
+            TypeGenericKind::Expression(expression) => match &expression.node {
+                ExpressionKind::Literal(literal) => Some(literal_to_cpp(literal)),
+                _ => None,
+            },
+            TypeGenericKind::Lifetime(_) => None,
+        })
+        .join(", ")
+}
+
+pub fn parameters_to_cpp(parameters: &[Spanned<Parameter>]) -> String {
+    parameters.iter()
+        .map(|parameter| format!("{} {}", type_to_cpp(&parameter.node.ty), escape_name(&parameter.node.name.0)))
+        .join(", ")
+}
+
+pub fn return_type_to_cpp(return_type: Option<&SoulType>) -> String {
+    match return_type {
+        Some(ty) => inner_type_to_cpp(ty),
+        None => "void".into(),
+    }
+}
+
+fn base_to_cpp(base: &TypeKind) -> String {
+
+    match base {
+        TypeKind::None => "void".into(),
+        TypeKind::UntypedInt |
+        TypeKind::SystemInt => "int64_t".into(),
+        TypeKind::Int(size) => format!("int{}_t", size_bits(size)),
+        TypeKind::UntypedUint |
+        TypeKind::SystemUint => "uint64_t".into(),
+        TypeKind::Uint(size) => format!("uint{}_t", size_bits(size)),
+        TypeKind::UntypedFloat => "double".into(),
+        TypeKind::Float(TypeSize::Bit64) => "double".into(),
+        TypeKind::Float(_) => "float".into(),
+        TypeKind::Char(TypeSize::Bit8) => "char".into(),
+        TypeKind::Char(TypeSize::Bit16) => "char16_t".into(),
+        TypeKind::Char(_) => "char32_t".into(),
+        TypeKind::Bool => "bool".into(),
+        TypeKind::Str => "::std::string".into(),
+
+        TypeKind::Unknown(name) |
+        TypeKind::Custom(name) |
+        TypeKind::Struct(name) |
+        TypeKind::Class(name) |
+        TypeKind::Trait(name) |
+        TypeKind::Enum(name) |
+        TypeKind::Union(name) |
+        TypeKind::TypeEnum(name, _) |
+        TypeKind::Generic(name) => escape_name(&name.0),
+        TypeKind::LifeTime(_) => "void".into(),
+
+        TypeKind::Tuple(types) => format!("::std::tuple<{}>", types.iter().map(inner_type_to_cpp).join(", ")),
+        TypeKind::NamedTuple(types) => format!(
+            "::std::tuple<{}>",
+            types.iter().sorted_by(|a, b| a.0.0.cmp(&b.0.0)).map(|(_, ty)| inner_type_to_cpp(ty)).join(", "),
+        ),
+        TypeKind::Function(signature) => format!(
+            "::std::function<{}({})>",
+            return_type_to_cpp(signature.return_type.as_ref()),
+            signature.parameters.iter().map(|parameter| type_to_cpp(&parameter.node.ty)).join(", "),
+        ),
+        TypeKind::Lambda(signature) => format!(
+            "::std::function<{}({})>",
+            return_type_to_cpp(signature.return_type.as_deref()),
+            signature.params.iter().map(|parameter| type_to_cpp(&parameter.node.ty)).join(", "),
+        ),
+
+        TypeKind::UnionVariant(union_type) => match &union_type.union {
+            UnionKind::Union(name) => format!("{}::{}", escape_name(&name.0), escape_name(&union_type.variant.0)),
+            UnionKind::External(external) => format!("::{}::{}::{}", page_namespace(&external.path), escape_name(&external.name.0), escape_name(&union_type.variant.0)),
+        },
+        TypeKind::ExternalType(external) => format!("::{}::{}", page_namespace(&external.node.path), escape_name(&external.node.name.0)),
+        TypeKind::ExternalPath(external) => format!("::{}", page_namespace(&external.node.path)),
+    }
+}
+
+fn size_bits(size: &TypeSize) -> u8 {
+    match size {
+        TypeSize::Bit8 => 8,
+        TypeSize::Bit16 => 16,
+        TypeSize::Bit32 => 32,
+        TypeSize::Bit64 => 64,
+    }
+}
+
+pub fn literal_type_to_cpp(ty: &LiteralType) -> String {
+    match ty {
+        LiteralType::Int => "int64_t".into(),
+        LiteralType::Uint => "uint64_t".into(),
+        LiteralType::Float => "double".into(),
+        LiteralType::Bool => "bool".into(),
+        LiteralType::Char => "char".into(),
+        LiteralType::Str => "::std::string".into(),
+        LiteralType::Array(element) => format!("::std::vector<{}>", literal_type_to_cpp(element)),
+        LiteralType::Tuple(types) => format!("::std::tuple<{}>", types.iter().map(literal_type_to_cpp).join(", ")),
+        LiteralType::NamedTuple(types) => format!("::std::tuple<{}>", types.values().map(literal_type_to_cpp).join(", ")),
+        LiteralType::ProgramMemmory(inner) => literal_type_to_cpp(inner),
+    }
+}
+
+pub fn literal_to_cpp(literal: &Literal) -> String {
+    match literal {
+        Literal::Int(value) => match i32::try_from(*value) {
+            Ok(_) => value.to_string(),
+            Err(_) => format!("{}LL", value),
+        },
+        Literal::Uint(value) => match i32::try_from(*value) {
+            Ok(_) => value.to_string(),
+            Err(_) => format!("{}ULL", value),
+        },
+        Literal::Float(value) => {
+            let text = value.as_f64().to_string();
+            match text.contains(['.', 'e', 'i', 'N']) {
+                true => text,
+                false => format!("{}.0", text),
+            }
+        },
+        Literal::Bool(value) => value.to_string(),
+        Literal::Char(char) => match char.is_ascii() {
+            true => format!("'{}'", escape_char(*char)),
+            false => format!("U'\\U{:08x}'", *char as u32),
+        },
+        Literal::Str(text) => format!("::std::string(\"{}\")", text.chars().map(escape_char).collect::<String>()),
+        Literal::Array{ty, values} => format!("::std::vector<{}>{{{}}}", literal_type_to_cpp(ty), values.iter().map(literal_to_cpp).join(", ")),
+        Literal::Tuple{values} => format!("::std::make_tuple({})", values.iter().map(literal_to_cpp).join(", ")),
+        Literal::NamedTuple{values, ..} => format!("::std::make_tuple({})", values.values().map(literal_to_cpp).join(", ")),
+        Literal::ProgramMemmory(name, _) => escape_name(&name.0),
+    }
+}
+
+fn escape_char(char: char) -> String {
+    match char {
+        '\n' => "\\n".into(),
+        '\t' => "\\t".into(),
+        '\r' => "\\r".into(),
+        '\0' => "\\0".into(),
+        '\\' => "\\\\".into(),
+        '\'' => "\\'".into(),
+        '"' => "\\\"".into(),
+        _ => char.to_string(),
+    }
+}
@@ -0,0 +1,123 @@
+/// file name of SOUL_RUNTIME_HEADER in the c++ output folder
+pub const SOUL_RUNTIME_FILE: &str = "soul_runtime.h";
+
+/// builtins that generated pages use (e.g. 'Println', f-strings and ranges)
+pub const SOUL_RUNTIME_HEADER: &str = r#"#pragma once
+#include <array>
+#include <cmath>
+#include <cstdint>
+#include <functional>
+#include <iostream>
+#include <limits>
+#include <sstream>
+#include <string>
+#include <tuple>
+#include <type_traits>
+#include <utility>
+#include <variant>
+#include <vector>
+
+namespace soul {
+
+template<typename T>
+struct IsVector : ::std::false_type {};
+template<typename T>
+struct IsVector<::std::vector<T>> : ::std::true_type {};
+
+template<typename T>
+struct IsArray : ::std::false_type {};
+template<typename T, ::std::size_t N>
+struct IsArray<::std::array<T, N>> : ::std::true_type {};
+
+template<typename T>
+struct IsTuple : ::std::false_type {};
+template<typename... T>
+struct IsTuple<::std::tuple<T...>> : ::std::true_type {};
+
+template<typename T>
+::std::string ToString(const T& value) {
+    if constexpr (::std::is_same_v<T, ::std::string>) {
+        return value;
+    }
+    else if constexpr (::std::is_same_v<T, bool>) {
+        return value ? "true" : "false";
+    }
+    else if constexpr (::std::is_same_v<T, char>) {
+        return ::std::string(1, value);
+    }
+    else if constexpr (::std::is_convertible_v<T, const char*>) {
+        return ::std::string(value);
+    }
+    else if constexpr (::std::is_arithmetic_v<T>) {
+        // '+' promotes int8_t/uint8_t so they are written as number and not as char
+        ::std::ostringstream stream;
+        stream << +value;
+        return stream.str();
+    }
+    else if constexpr (::std::is_enum_v<T>) {
+        return ::std::to_string(static_cast<int64_t>(value));
+    }
+    else if constexpr (IsVector<T>::value || IsArray<T>::value) {
+        ::std::string text = "[";
+        for (::std::size_t i = 0; i < value.size(); i++) {
+            text += (i == 0 ? "" : ", ") + ToString(value[i]);
+        }
+        return text + "]";
+    }
+    else if constexpr (IsTuple<T>::value) {
+        ::std::string text = "(";
+        ::std::apply([&](const auto&... elements) {
+            ::std::size_t i = 0;
+            ((text += (i++ == 0 ? "" : ", ") + ToString(elements)), ...);
+        }, value);
+        return text + ")";
+    }
+    else {
+        return "<object>";
+    }
+}
+
+namespace fmt {
+
+template<typename T>
+::std::string Arg(const T& value, bool) {
+    return ::soul::ToString(value);
+}
+
+template<typename... Parts>
+::std::string FormatArgs(const Parts&... parts) {
+    return (::std::string() + ... + ::soul::ToString(parts));
+}
+
+}
+
+inline ::std::vector<int64_t> Range(int64_t begin, int64_t end) {
+    ::std::vector<int64_t> range;
+    for (int64_t i = begin; i < end; i++) {
+        range.push_back(i);
+    }
+    return range;
+}
+
+template<typename Filler>
+auto Fill(int64_t amount, Filler filler) {
+    ::std::vector<decltype(filler(int64_t{0}))> array;
+    for (int64_t i = 0; i < amount; i++) {
+        array.push_back(filler(i));
+    }
+    return array;
+}
+
+}
+
+template<typename... Args>
+void Print(const Args&... args) {
+    ((::std::cout << ::soul::ToString(args)), ...);
+}
+
+template<typename... Args>
+void Println(const Args&... args) {
+    Print(args...);
+    ::std::cout << '\n';
+}
+"#;
@@ -0,0 +1,82 @@
+/// builds c++ text line by line, indentation and blank lines are only written if pretty (see '-prettyCppCode')
+pub struct CppWriter {
+    buffer: String,
+    indent: usize,
+    pretty: bool,
+}
+
+impl CppWriter {
+    const INDENT: &str = "    ";
+
+    pub fn new(pretty: bool) -> Self {
+        Self{buffer: String::new(), indent: 0, pretty}
+    }
+
+    /// writer for a block that is inserted in a line (e.g. body of lambda), see 'into_inline_block'
+    pub fn new_block(&self) -> Self {
+        Self{buffer: String::new(), indent: 1, pretty: self.pretty}
+    }
+
+    /// '{\n<lines>}', the lines get the indentation of the line they are written in
+    pub fn into_inline_block(self) -> String {
+        format!("{{\n{}}}", self.buffer)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.buffer.is_empty()
+    }
+
+    /// writes line with indentation, every line of a multi line text is indented (e.g. inline block)
+    pub fn line<S: AsRef<str>>(&mut self, line: S) {
+        for text in line.as_ref().split('\n') {
+            if self.pretty {
+                self.buffer.push_str(&Self::INDENT.repeat(self.indent));
+            }
+
+            self.buffer.push_str(text);
+            self.buffer.push('\n');
+        }
+    }
+
+    /// writes 'line {' and indents the lines after it
+    pub fn open<S: AsRef<str>>(&mut self, line: S) {
+        match line.as_ref().is_empty() {
+            true => self.line("{"),
+            false => self.line(format!("{} {{", line.as_ref())),
+        }
+        self.indent += 1;
+    }
+
+    /// writes '}suffix' (e.g. '};' for a struct)
+    pub fn close(&mut self, suffix: &str) {
+        self.indent = self.indent.saturating_sub(1);
+        self.line(format!("}}{}", suffix));
+    }
+
+    /// closes and opens a block on the same line (e.g. '} else {')
+    pub fn reopen<S: AsRef<str>>(&mut self, line: S) {
+        self.indent = self.indent.saturating_sub(1);
+        self.open(format!("}} {}", line.as_ref()));
+    }
+
+    /// line that is only written if pretty (e.g. comments)
+    pub fn pretty_line<S: AsRef<str>>(&mut self, line: S) {
+        if self.pretty {
+            self.line(line);
+        }
+    }
+
+    pub fn blank_line(&mut self) {
+        if self.pretty && !self.buffer.is_empty() && !self.buffer.ends_with("{\n") && !self.buffer.ends_with("\n\n") {
+            self.buffer.push('\n');
+        }
+    }
+
+    pub fn append(&mut self, other: CppWriter) {
+        self.buffer.push_str(&other.buffer);
+    }
+
+    pub fn into_string(self) -> String {
+        self.buffer
+    }
+}
@@ -0,0 +1,13 @@
+pub mod cpp_names;
+pub mod cpp_writer;
+pub mod cpp_runtime;
+pub mod cpp_generator;
+pub mod cpp_compiler;
+pub mod name_mangling;
+
+#[cfg(test)]
+mod cpp_generator_test;
+#[cfg(test)]
+mod cpp_compiler_test;
+#[cfg(test)]
+mod name_mangling_test;
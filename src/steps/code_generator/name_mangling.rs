@@ -0,0 +1,575 @@
+//! Deterministic symbol names for soul functions and globals.
+//!
+//! A mangled name only contains `[A-Za-z0-9_]` so every backend can use it as a symbol, and it only
+//! depends on the declaration (page, enclosing functions, receiver, name and parameter types) so the
+//! same program always gets the same names. Return types are not part of a name because overloading
+//! by return type is not allowed. The c++ backend names every function and page variable this way, so
+//! `soul demangle` can read the symbols and compiler errors of a build.
+//!
+//! # Scheme
+//! ```text
+//! symbol     := "_S" page { "L" function } ( function | "G" ident )
+//! page       := "N" { ident } "E"                  (segments of SoulPagePath e.g. 'main.std' -> N4main3stdE)
+//! function   := receiver ident "P" { type } "E"    (modifier of a parameter is its binding so it is not part of a name)
+//! receiver   := "F"                                 free function
+//!             | "M" type                            methode with 'this' of type (e.g. 'int triple(this)')
+//!             | "S" type                            static methode of type (e.g. 'u8 maxValue()')
+//! ident      := <length> <name>                     (e.g. '7maxValue')
+//!             | "h" <length> "_" <hex of utf8>      (name with chars outside [A-Za-z0-9_])
+//!
+//! type       := [ "K" | "J" ] { wrapper } base [ "I" { generic } "E" ]
+//!                                                   (K = const, J = literal, wrappers outermost first)
+//! wrapper    := "A"                                 array 'T[]'
+//!             | "B" <number> "_"                    stack array 'T[3]'
+//!             | "G" type                            stack array with generic size 'T[N]'
+//!             | "C" | "R"                           const ref 'T@', mut ref 'T&' (lifetimes are not part of a name)
+//!             | "Q" | "U"                           pointer 'T*', const pointer 'T const*'
+//! generic    := type | "e" ident                    (expressions are written as text e.g. '3')
+//! base       := primitive | ident                   (named types e.g. struct, class, generic)
+//!             | "O" page ident                      type of other page
+//!             | "V" ident ident                     union variant ('Union::Variant')
+//!             | "W" page ident ident                union variant of other page
+//!             | "T" { type } "E"                    tuple
+//!             | "D" { ident type } "E"              named tuple (fields sorted by name)
+//!             | "Y" { type } "E" type               function (parameters and return type)
+//!             | "X" ( "m" | "c" | "o" ) { type } "E" type   lambda (MutFn, ConstFn, OnceFn)
+//! primitive  := "v" | "b" | "s"                     none, bool, str
+//!             | ( "i" | "u" | "f" | "c" ) size      int, uint, float, char
+//! size       := "a" | "b" | "c" | "d"               8, 16, 32, 64 bits
+//!             | "s" | "u"                           system size ('int'), untyped
+//! ```
+//!
+//! e.g. `u8 maxValue()` in page `main` is `_SN4mainESua8maxValuePE` and `parent() { child(int a) }`
+//! gives `child` the name `_SN4mainELF6parentPEF5childPisE` (see [`demangle`] for the readable form).
+
+use std::collections::HashMap;
+use itertools::Itertools;
+use crate::steps::step_interfaces::i_parser::{scope_builder::ScopeId, abstract_syntax_tree::{abstract_syntax_tree::AbstractSyntacTree, function::{FunctionSignature, LambdaMode}, object::ClassChild, pretty_format::ToString, soul_type::{soul_type::{Modifier, SoulType, TypeGenericKind, TypeWrapper}, type_kind::{SoulPagePath, TypeKind, TypeSize, UnionKind}}, statement::{Block, StatementKind}}};
+
+/// prefix of every mangled name
+pub const MANGLE_PREFIX: &str = "_S";
+
+pub fn mangle_function(page: &SoulPagePath, parents: &[&FunctionSignature], signature: &FunctionSignature) -> String {
+    let mut mangled = format!("{}{}", MANGLE_PREFIX, mangle_page(page));
+
+    for parent in parents {
+        mangled.push('L');
+        mangled.push_str(&mangle_signature(parent));
+    }
+
+    mangled.push_str(&mangle_signature(signature));
+    mangled
+}
+
+/// name of global variable or type of page
+pub fn mangle_global(page: &SoulPagePath, name: &str) -> String {
+    format!("{}{}G{}", MANGLE_PREFIX, mangle_page(page), mangle_ident(name))
+}
+
+/// mangled names of all functions in tree (free functions, methodes and nested functions) in order of declaration
+pub fn mangle_tree(tree: &AbstractSyntacTree, page: &SoulPagePath) -> Vec<String> {
+    let mut names = vec![];
+    mangle_block(&tree.root, page, &mut vec![], &mut names);
+    names.into_iter().map(|(_, name)| name).collect()
+}
+
+/// mangled name of every function in tree by the scope of its block (a scope belongs to exactly one function)
+pub fn function_symbols(tree: &AbstractSyntacTree, page: &SoulPagePath) -> HashMap<ScopeId, String> {
+    let mut names = vec![];
+    mangle_block(&tree.root, page, &mut vec![], &mut names);
+    names.into_iter().collect()
+}
+
+fn mangle_block<'a>(block: &'a Block, page: &SoulPagePath, parents: &mut Vec<&'a FunctionSignature>, names: &mut Vec<(ScopeId, String)>) {
+
+    for statment in &block.statments {
+        let functions = match &statment.node {
+            StatementKind::Function(function) => vec![function],
+            StatementKind::UseBlock(use_block) => use_block.block.statments.iter()
+                .filter_map(|statment| match &statment.node {
+                    StatementKind::Function(function) => Some(function),
+                    _ => None,
+                })
+                .collect(),
+            StatementKind::Class(class) => class.children.iter()
+                .flat_map(|child| match child {
+                    ClassChild::Methode(methode) => vec![&methode.node],
+                    ClassChild::ImplBlock(impl_block) => impl_block.node.block.statments.iter()
+                        .filter_map(|statment| match &statment.node {
+                            StatementKind::Function(function) => Some(function),
+                            _ => None,
+                        })
+                        .collect(),
+                    ClassChild::Field(_) => vec![],
+                })
+                .collect(),
+            _ => vec![],
+        };
+
+        for function in functions {
+            names.push((function.block.scope_id, mangle_function(page, parents, &function.signature)));
+
+            parents.push(&function.signature);
+            mangle_block(&function.block, page, parents, names);
+            parents.pop();
+        }
+    }
+}
+
+fn mangle_signature(signature: &FunctionSignature) -> String {
+    let receiver = match &signature.callee {
+        None => "F".to_string(),
+        Some(callee) => match &callee.node.this {
+            Some(this) => format!("M{}", mangle_type(this)),
+            None => format!("S{}", mangle_type(&callee.node.extention_type)),
+        },
+    };
+
+    format!(
+        "{}{}P{}E",
+        receiver,
+        mangle_ident(&signature.name.0),
+        signature.parameters.iter().map(|parameter| mangle_parameter(&parameter.node.ty)).join(""),
+    )
+}
+
+/// 'add(int a)' and 'add(mut int a)' can not overload each other, the modifier only decides if the parameter can be changed
+/// (constness of a reference or pointer is part of its wrapper)
+fn mangle_parameter(ty: &SoulType) -> String {
+    match ty.modifier {
+        Modifier::Default => mangle_type(ty),
+        _ => mangle_type(&SoulType{modifier: Modifier::Default, ..ty.clone()}),
+    }
+}
+
+fn mangle_page(page: &SoulPagePath) -> String {
+    format!("N{}E", page.0.split('.').map(mangle_ident).join(""))
+}
+
+fn mangle_ident(name: &str) -> String {
+    match name.chars().all(|char| char.is_ascii_alphanumeric() || char == '_') {
+        true => format!("{}{}", name.len(), name),
+        false => {
+            let hex = name.bytes().map(|byte| format!("{:02x}", byte)).join("");
+            format!("h{}_{}", hex.len(), hex)
+        },
+    }
+}
+
+pub fn mangle_type(ty: &SoulType) -> String {
+    let mut mangled = match ty.modifier {
+        Modifier::Default => String::new(),
+        Modifier::Const => "K".into(),
+        Modifier::Literal => "J".into(),
+    };
+
+    for wrapper in ty.wrappers.iter().rev() {
+        match wrapper {
+            TypeWrapper::Invalid => (),
+            TypeWrapper::Array => mangled.push('A'),
+            TypeWrapper::StackArray(size) => mangled.push_str(&format!("B{}_", size)),
+            TypeWrapper::StackArrayGeneric(size) => mangled.push_str(&format!("G{}", mangle_type(size))),
+            TypeWrapper::ConstRef(_) => mangled.push('C'),
+            TypeWrapper::MutRef(_) => mangled.push('R'),
+            TypeWrapper::Pointer => mangled.push('Q'),
+            TypeWrapper::ConstPointer => mangled.push('U'),
+        }
+    }
+
+    mangled.push_str(&mangle_base(&ty.base));
+
+    let generics = ty.generics.iter()
+        .filter_map(|generic| match generic {
+            TypeGenericKind::Type(ty) => Some(mangle_type(ty)),
+            TypeGenericKind::Expression(expression) => Some(format!("e{}", mangle_ident(&expression.node.to_string()))),
+            TypeGenericKind::Lifetime(_) => None,
+        })
+        .join("");
+
+    if !generics.is_empty() {
+        mangled.push_str(&format!("I{}E", generics));
+    }
+
+    mangled
+}
+
+fn mangle_base(base: &TypeKind) -> String {
+
+    match base {
+        TypeKind::None => "v".into(),
+        TypeKind::Bool => "b".into(),
+        TypeKind::Str => "s".into(),
+        TypeKind::SystemInt => "is".into(),
+        TypeKind::UntypedInt => "iu".into(),
+        TypeKind::Int(size) => format!("i{}", mangle_size(size)),
+        TypeKind::SystemUint => "us".into(),
+        TypeKind::UntypedUint => "uu".into(),
+        TypeKind::Uint(size) => format!("u{}", mangle_size(size)),
+        TypeKind::UntypedFloat => "fu".into(),
+        TypeKind::Float(size) => format!("f{}", mangle_size(size)),
+        TypeKind::Char(size) => format!("c{}", mangle_size(size)),
+
+        TypeKind::Unknown(name) |
+        TypeKind::Custom(name) |
+        TypeKind::Struct(name) |
+        TypeKind::Class(name) |
+        TypeKind::Trait(name) |
+        TypeKind::Enum(name) |
+        TypeKind::Union(name) |
+        TypeKind::TypeEnum(name, _) |
+        TypeKind::Generic(name) |
+        TypeKind::LifeTime(name) => mangle_ident(&name.0),
+
+        TypeKind::ExternalType(external) => format!("O{}{}", mangle_page(&external.node.path), mangle_ident(&external.node.name.0)),
+        TypeKind::ExternalPath(external) => format!("O{}{}", mangle_page(&external.node.path), mangle_ident(&external.node.name.0)),
+        TypeKind::UnionVariant(union_type) => match &union_type.union {
+            UnionKind::Union(name) => format!("V{}{}", mangle_ident(&name.0), mangle_ident(&union_type.variant.0)),
+            UnionKind::External(external) => format!("W{}{}{}", mangle_page(&external.path), mangle_ident(&external.name.0), mangle_ident(&union_type.variant.0)),
+        },
+
+        TypeKind::Tuple(types) => format!("T{}E", types.iter().map(mangle_type).join("")),
+        TypeKind::NamedTuple(types) => format!(
+            "D{}E",
+            types.iter()
+                .sorted_by(|a, b| a.0.0.cmp(&b.0.0))
+                .map(|(name, ty)| format!("{}{}", mangle_ident(&name.0), mangle_type(ty)))
+                .join(""),
+        ),
+        TypeKind::Function(signature) => format!(
+            "Y{}E{}",
+            signature.parameters.iter().map(|parameter| mangle_type(&parameter.node.ty)).join(""),
+            signature.return_type.as_ref().map(mangle_type).unwrap_or_else(|| "v".into()),
+        ),
+        TypeKind::Lambda(signature) => format!(
+            "X{}{}E{}",
+            match signature.mode {
+                LambdaMode::Mut => 'm',
+                LambdaMode::Const => 'c',
+                LambdaMode::Consume => 'o',
+            },
+            signature.params.iter().map(|parameter| mangle_type(&parameter.node.ty)).join(""),
+            signature.return_type.as_deref().map(mangle_type).unwrap_or_else(|| "v".into()),
+        ),
+    }
+}
+
+fn mangle_size(size: &TypeSize) -> char {
+    match size {
+        TypeSize::Bit8 => 'a',
+        TypeSize::Bit16 => 'b',
+        TypeSize::Bit32 => 'c',
+        TypeSize::Bit64 => 'd',
+    }
+}
+
+/// readable form of mangled name (e.g. '_SN4mainESua8maxValuePE' becomes 'main::u8.maxValue()')
+pub fn demangle(symbol: &str) -> Result<String, String> {
+    let body = symbol.strip_prefix(MANGLE_PREFIX)
+        .ok_or_else(|| format!("'{}' is not a mangled soul name (should start with '{}')", symbol, MANGLE_PREFIX))?;
+
+    let mut demangler = Demangler{text: body.as_bytes(), position: 0};
+    let demangled = demangler.symbol()
+        .map_err(|msg| format!("invalid mangled name '{}' at {}: {}", symbol, demangler.position + MANGLE_PREFIX.len(), msg))?;
+
+    match demangler.is_done() {
+        true => Ok(demangled),
+        false => Err(format!("invalid mangled name '{}' at {}: unexpected '{}'", symbol, demangler.position + MANGLE_PREFIX.len(), &body[demangler.position..])),
+    }
+}
+
+/// demangles every mangled name in text (e.g. in errors of a c++ compiler or in a c++ mangled symbol), invalid names are kept
+pub fn demangle_text(text: &str) -> String {
+    let mut result = String::new();
+    let mut rest = text;
+
+    while let Some(index) = rest.find(MANGLE_PREFIX) {
+        result.push_str(&rest[..index]);
+        rest = &rest[index + MANGLE_PREFIX.len()..];
+
+        let mut demangler = Demangler{text: rest.as_bytes(), position: 0};
+        match demangler.symbol() {
+            Ok(demangled) => {
+                result.push_str(&demangled);
+                rest = &rest[demangler.position..];
+            },
+            Err(_) => result.push_str(MANGLE_PREFIX),
+        }
+    }
+
+    result.push_str(rest);
+    result
+}
+
+struct Demangler<'a> {
+    text: &'a [u8],
+    position: usize,
+}
+
+type DemangleResult = Result<String, String>;
+
+impl<'a> Demangler<'a> {
+
+    fn is_done(&self) -> bool {
+        self.position >= self.text.len()
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.text.get(self.position).copied()
+    }
+
+    fn next(&mut self) -> Result<u8, String> {
+        let byte = self.peek().ok_or("unexpected end")?;
+        self.position += 1;
+        Ok(byte)
+    }
+
+    fn expect(&mut self, expected: u8) -> Result<(), String> {
+        match self.next()? {
+            byte if byte == expected => Ok(()),
+            byte => Err(format!("expected '{}' but found '{}'", expected as char, byte as char)),
+        }
+    }
+
+    fn eat(&mut self, expected: u8) -> bool {
+        let is_expected = self.peek() == Some(expected);
+        if is_expected {
+            self.position += 1;
+        }
+        is_expected
+    }
+
+    fn symbol(&mut self) -> DemangleResult {
+        let mut parts = vec![self.page()?];
+
+        while self.eat(b'L') {
+            parts.push(self.function()?);
+        }
+
+        match self.eat(b'G') {
+            true => parts.push(self.ident()?),
+            false => parts.push(self.function()?),
+        }
+
+        Ok(parts.join("::"))
+    }
+
+    fn page(&mut self) -> DemangleResult {
+        self.expect(b'N')?;
+
+        let mut segments = vec![];
+        while !self.eat(b'E') {
+            segments.push(self.ident()?);
+        }
+
+        Ok(segments.join("."))
+    }
+
+    fn function(&mut self) -> DemangleResult {
+        let receiver = match self.next()? {
+            b'F' => String::new(),
+            b'M' => format!("({}).", self.ty()?),
+            b'S' => format!("{}.", self.ty()?),
+            byte => return Err(format!("expected function ('F', 'M' or 'S') but found '{}'", byte as char)),
+        };
+
+        let name = self.ident()?;
+        self.expect(b'P')?;
+        let parameters = self.types_until_end()?;
+
+        Ok(format!("{}{}({})", receiver, name, parameters.join(", ")))
+    }
+
+    fn ident(&mut self) -> DemangleResult {
+        let is_hex = self.eat(b'h');
+
+        let start = self.position;
+        while self.peek().is_some_and(|byte| byte.is_ascii_digit()) {
+            self.position += 1;
+        }
+
+        let length = std::str::from_utf8(&self.text[start..self.position]).unwrap_or_default()
+            .parse::<usize>()
+            .map_err(|_| "expected length of name".to_string())?;
+
+        if is_hex {
+            self.expect(b'_')?;
+        }
+
+        let end = self.position + length;
+        let name = self.text.get(self.position..end)
+            .ok_or("name is longer then mangled name")?;
+        self.position = end;
+
+        let name = std::str::from_utf8(name).map_err(|err| err.to_string())?;
+        if !is_hex {
+            return Ok(name.to_string())
+        }
+
+        let bytes = (0..name.len()).step_by(2)
+            .map(|index| name.get(index..index + 2).and_then(|byte| u8::from_str_radix(byte, 16).ok()))
+            .collect::<Option<Vec<_>>>()
+            .ok_or("invalid hex name")?;
+        String::from_utf8(bytes).map_err(|err| err.to_string())
+    }
+
+    fn types_until_end(&mut self) -> Result<Vec<String>, String> {
+        let mut types = vec![];
+        while !self.eat(b'E') {
+            types.push(self.ty()?);
+        }
+        Ok(types)
+    }
+
+    fn ty(&mut self) -> DemangleResult {
+        let modifier = match self.peek() {
+            Some(b'K') => {self.position += 1; format!("{} ", Modifier::Const.to_str())},
+            Some(b'J') => {self.position += 1; format!("{} ", Modifier::Literal.to_str())},
+            _ => String::new(),
+        };
+
+        let mut wrappers = vec![];
+        loop {
+            let wrapper = match self.peek() {
+                Some(b'A') => TypeWrapper::Array.to_string(),
+                Some(b'C') => TypeWrapper::ConstRef(None).to_string(),
+                Some(b'R') => TypeWrapper::MutRef(None).to_string(),
+                Some(b'Q') => TypeWrapper::Pointer.to_string(),
+                Some(b'U') => TypeWrapper::ConstPointer.to_string(),
+                Some(b'B') => {
+                    self.position += 1;
+                    let start = self.position;
+                    while self.peek().is_some_and(|byte| byte.is_ascii_digit()) {
+                        self.position += 1;
+                    }
+                    let size = std::str::from_utf8(&self.text[start..self.position]).unwrap_or_default()
+                        .parse::<u32>()
+                        .map_err(|_| "expected size of stack array".to_string())?;
+                    self.expect(b'_')?;
+                    wrappers.push(TypeWrapper::StackArray(size).to_string());
+                    continue
+                },
+                Some(b'G') => {
+                    self.position += 1;
+                    let size = self.ty()?;
+                    wrappers.push(format!("[{}]", size));
+                    continue
+                },
+                _ => break,
+            };
+
+            self.position += 1;
+            wrappers.push(wrapper);
+        }
+
+        let base = self.base()?;
+
+        let generics = match self.eat(b'I') {
+            true => {
+                let mut generics = vec![];
+                while !self.eat(b'E') {
+                    match self.eat(b'e') {
+                        true => generics.push(self.ident()?),
+                        false => generics.push(self.ty()?),
+                    }
+                }
+                format!("<{}>", generics.join(","))
+            },
+            false => String::new(),
+        };
+
+        // wrappers are mangled outermost first but written innermost first (e.g. 'int[]&')
+        Ok(format!("{}{}{}{}", modifier, base, generics, wrappers.iter().rev().join("")))
+    }
+
+    fn base(&mut self) -> DemangleResult {
+        let byte = self.peek().ok_or("unexpected end")?;
+        if byte.is_ascii_digit() || byte == b'h' {
+            return self.ident()
+        }
+
+        self.position += 1;
+        let primitive = match byte {
+            b'v' => TypeKind::None,
+            b'b' => TypeKind::Bool,
+            b's' => TypeKind::Str,
+            b'i' | b'u' | b'f' | b'c' => self.sized_primitive(byte)?,
+
+            b'O' => {
+                let page = self.page()?;
+                return Ok(format!("{}.{}", page, self.ident()?))
+            },
+            b'V' => {
+                let union = self.ident()?;
+                return Ok(format!("{}::{}", union, self.ident()?))
+            },
+            b'W' => {
+                let page = self.page()?;
+                let union = self.ident()?;
+                return Ok(format!("{}.{}::{}", page, union, self.ident()?))
+            },
+            b'T' => return Ok(format!("({})", self.types_until_end()?.join(", "))),
+            b'D' => {
+                let mut fields = vec![];
+                while !self.eat(b'E') {
+                    let name = self.ident()?;
+                    fields.push(format!("{}: {}", name, self.ty()?));
+                }
+                return Ok(format!("({})", fields.join(", ")))
+            },
+            b'Y' => return self.function_type("fn"),
+            b'X' => {
+                let mode = match self.next()? {
+                    b'm' => LambdaMode::Mut,
+                    b'c' => LambdaMode::Const,
+                    b'o' => LambdaMode::Consume,
+                    byte => return Err(format!("expected lambda mode ('m', 'c' or 'o') but found '{}'", byte as char)),
+                };
+                return self.function_type(mode.get_lambda_name())
+            },
+            _ => return Err(format!("expected type but found '{}'", byte as char)),
+        };
+
+        Ok(primitive.get_variant().to_string())
+    }
+
+    fn sized_primitive(&mut self, kind: u8) -> Result<TypeKind, String> {
+        let size = self.next()?;
+
+        let type_size = match size {
+            b'a' => Some(TypeSize::Bit8),
+            b'b' => Some(TypeSize::Bit16),
+            b'c' => Some(TypeSize::Bit32),
+            b'd' => Some(TypeSize::Bit64),
+            b's' | b'u' => None,
+            _ => return Err(format!("expected size of primitive but found '{}'", size as char)),
+        };
+
+        let primitive = match (kind, type_size, size) {
+            (b'i', Some(size), _) => TypeKind::Int(size),
+            (b'i', None, b's') => TypeKind::SystemInt,
+            (b'i', None, _) => TypeKind::UntypedInt,
+            (b'u', Some(size), _) => TypeKind::Uint(size),
+            (b'u', None, b's') => TypeKind::SystemUint,
+            (b'u', None, _) => TypeKind::UntypedUint,
+            (b'f', Some(size), _) => TypeKind::Float(size),
+            (b'f', None, b'u') => TypeKind::UntypedFloat,
+            (b'c', Some(size), _) => TypeKind::Char(size),
+            _ => return Err(format!("'{}{}' is not a primitive", kind as char, size as char)),
+        };
+
+        Ok(primitive)
+    }
+
+    /// 'fn<int,str>bool' (same form as lambda types e.g. 'MutFn<int>')
+    fn function_type(&mut self, name: &str) -> DemangleResult {
+        let parameters = self.types_until_end()?;
+        let return_type = self.ty()?;
+
+        let return_type = match return_type == TypeKind::None.get_variant() {
+            true => String::new(),
+            false => return_type,
+        };
+
+        Ok(format!("{}<{}>{}", name, parameters.join(","), return_type))
+    }
+}
@@ -0,0 +1,171 @@
+use std::collections::HashMap;
+use crate::steps::{code_generator::name_mangling::{demangle, demangle_text, mangle_global, mangle_tree, mangle_type}, sementic::test_helpers::{error_messages, optimize_source}, step_interfaces::i_parser::abstract_syntax_tree::{expression::Ident, soul_type::{soul_type::{Modifier, SoulType, TypeWrapper}, type_kind::{SoulPagePath, TypeKind, TypeSize}}}};
+
+const PAGE: &str = "testProject.main";
+
+fn mangle_source(source: &str, page: &str) -> Vec<String> {
+    let (tree, _, _, faults) = optimize_source(source);
+    assert!(error_messages(&faults).is_empty(), "{:?}", error_messages(&faults));
+
+    mangle_tree(&tree, &SoulPagePath(page.into()))
+}
+
+fn demangle_all(names: &[String]) -> Vec<String> {
+    names.iter().map(|name| demangle(name).unwrap()).collect()
+}
+
+fn assert_unique(names: &[String]) {
+    for (i, name) in names.iter().enumerate() {
+        assert!(!names[i+1..].contains(name), "'{}' is not unique in {:?}", name, names);
+    }
+}
+
+#[test]
+fn test_overloads_and_receivers() {
+    let source = "
+add(int a, int b) int {
+    return a + b
+}
+
+add(f32 a, f32 b) f32 {
+    return a + b
+}
+
+u8 maxValue() u8 {
+    return 255
+}
+
+int maxValue(this) int {
+    return this
+}
+
+maxValue() int {
+    return 1
+}
+
+main() {
+    a := add(1, 2)
+}
+";
+    let names = mangle_source(source, PAGE);
+    assert_unique(&names);
+
+    // exact names so backends get the same symbols on every run and every version of the compiler
+    assert_eq!(names, vec![
+        "_SN11testProject4mainEF3addPisisE",
+        "_SN11testProject4mainEF3addPfcfcE",
+        "_SN11testProject4mainESua8maxValuePE",
+        "_SN11testProject4mainEMis8maxValuePE",
+        "_SN11testProject4mainEF8maxValuePE",
+        "_SN11testProject4mainEF4mainPE",
+    ]);
+
+    assert_eq!(demangle_all(&names), vec![
+        "testProject.main::add(int, int)",
+        "testProject.main::add(f32, f32)",
+        "testProject.main::u8.maxValue()",
+        "testProject.main::(int).maxValue()",
+        "testProject.main::maxValue()",
+        "testProject.main::main()",
+    ]);
+}
+
+#[test]
+fn test_nested_functions() {
+    let source = "
+child() int {
+    return 1
+}
+
+parent(int a) int {
+    child(int a) int {
+        inner() int {
+            return 1
+        }
+        return a + inner()
+    }
+    return child(a)
+}
+
+main() {
+    a := parent(1)
+}
+";
+    let names = mangle_source(source, PAGE);
+    assert_unique(&names);
+
+    assert_eq!(demangle_all(&names), vec![
+        "testProject.main::child()",
+        "testProject.main::parent(int)",
+        "testProject.main::parent(int)::child(int)",
+        "testProject.main::parent(int)::child(int)::inner()",
+        "testProject.main::main()",
+    ]);
+    assert_eq!(names[3], "_SN11testProject4mainELF6parentPisELF5childPisEF5innerPE");
+}
+
+#[test]
+fn test_pages() {
+    let source = "
+helper() {
+}
+
+main() {
+    helper()
+}
+";
+    let main = mangle_source(source, "testProject.main");
+    let other = mangle_source(source, "testProject.std.main");
+
+    assert_ne!(main[0], other[0]);
+    assert_eq!(demangle(&other[0]).unwrap(), "testProject.std.main::helper()");
+
+    let page = SoulPagePath("testProject.main".into());
+    assert_ne!(mangle_global(&page, "helper"), main[0]);
+    assert_eq!(demangle(&mangle_global(&page, "helper")).unwrap(), "testProject.main::helper");
+}
+
+#[test]
+fn test_mangle_type() {
+    let mut array = SoulType::from_type_kind(TypeKind::Char(TypeSize::Bit8));
+    array.modifier = Modifier::Const;
+    array.wrappers = vec![TypeWrapper::StackArray(3), TypeWrapper::MutRef(None)];
+    assert_eq!(mangle_type(&array), "KRB3_ca");
+
+    let fields = [("z", TypeKind::Bool), ("a", TypeKind::Str), ("m", TypeKind::UntypedFloat)];
+    let named_tuple = SoulType::from_type_kind(TypeKind::NamedTuple(
+        fields.into_iter().map(|(name, ty)| (Ident::new(name), SoulType::from_type_kind(ty))).collect::<HashMap<_, _>>()
+    ));
+
+    // fields of named tuple are in a HashMap so ordering has to be fixed by mangling
+    for _ in 0..16 {
+        assert_eq!(mangle_type(&named_tuple), "D1as1mfu1zbE");
+    }
+
+    let page = SoulPagePath("testProject.main".into());
+    let symbol = format!("_SN4mainEF4testP{}{}E", mangle_type(&array), mangle_type(&named_tuple));
+    assert_eq!(demangle(&symbol).unwrap(), "main::test(const char[3]&, (a: str, m: untypedFloat, z: bool))");
+
+    let unicode = mangle_global(&page, "größe");
+    assert!(unicode.chars().all(|char| char.is_ascii_alphanumeric() || char == '_'), "{}", unicode);
+    assert_eq!(demangle(&unicode).unwrap(), "testProject.main::größe");
+}
+
+#[test]
+fn test_invalid_demangle() {
+    assert!(demangle("main").unwrap_err().contains("is not a mangled soul name"));
+    assert!(demangle("_SN4mainEF4mainX").unwrap_err().contains("expected 'P'"));
+    assert!(demangle("_SN4mainEF9mainPE").unwrap_err().contains("name is longer then mangled name"));
+    assert!(demangle("_SN4mainEF4mainPzE").unwrap_err().contains("expected type but found 'z'"));
+    assert!(demangle("_SN4mainEF4mainPE4").unwrap_err().contains("unexpected '4'"));
+}
+
+#[test]
+fn test_demangle_text() {
+    let text = "undefined reference to '_SN4mainESua8maxValuePE' in _SN4mainEF4mainPE, _Sbroken";
+    assert_eq!(demangle_text(text), "undefined reference to 'main::u8.maxValue()' in main::main(), _Sbroken");
+
+    // soul name in symbol of c++ compiler
+    let symbol = "_ZN12soul_program4main18_SN4mainEF4mainPEEv";
+    assert_eq!(demangle_text(symbol), "_ZN12soul_program4main18main::main()Ev");
+}
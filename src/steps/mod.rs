@@ -1,5 +1,6 @@
 pub mod parser;
 pub mod sementic;
+pub mod code_generator;
 pub mod tokenizer;
 pub mod source_reader;
 pub mod step_interfaces;
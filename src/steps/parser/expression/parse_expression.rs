@@ -520,8 +520,9 @@ fn add_ref(
                 "trying to ref without expression to ref (e.g. '@'/'&' should be '@obj'/'&obj')"
             ))?;
 
-        if let ExpressionKind::Literal(literal) = expression.node {
-            expression = turn_into_program_memory(literal, scopes, expression.span);            
+        // only a reference to a literal needs the literal in program memory
+        if let ExpressionKind::Literal(literal) = &expression.node && !matches!(ref_kind.node, RefKind::Copy) {
+            expression = turn_into_program_memory(literal.clone(), scopes, expression.span);            
         }
 
         let span = expression.span.combine(&ref_kind.span);
@@ -529,6 +530,7 @@ fn add_ref(
             RefKind::Deref => Expression::new(ExpressionKind::Deref(Box::new(expression)), span),
             RefKind::MutRef => Expression::new(ExpressionKind::MutRef(Box::new(expression)), span),
             RefKind::ConstRef => Expression::new(ExpressionKind::ConstRef(Box::new(expression)), span),
+            RefKind::Copy => Expression::new(ExpressionKind::Copy(Box::new(expression)), span),
         };
 
         stacks.expressions.push(any_ref);
@@ -697,6 +699,8 @@ pub enum RefKind {
     ConstRef,
     MutRef,
     Deref,
+    /// 'copy expr'
+    Copy,
 }
 
 impl ExpressionStacks {
@@ -711,6 +715,7 @@ impl RefKind {
             val if val == SOUL_NAMES.get_name(NamesTypeWrapper::MutRef) => Some(Self::MutRef),
             val if val == SOUL_NAMES.get_name(NamesTypeWrapper::Pointer) => Some(Self::Deref),
             val if val == SOUL_NAMES.get_name(NamesTypeWrapper::ConstRef) => Some(Self::ConstRef),
+            val if val == SOUL_NAMES.get_name(NamesOtherKeyWords::CopyData) => Some(Self::Copy),
             _ => None,
         }
     }
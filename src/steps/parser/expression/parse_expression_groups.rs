@@ -184,7 +184,11 @@ fn parse_named_group(stream: &mut TokenStream, scopes: &mut ScopeBuilder) -> Res
 
             let expression = get_expression(stream, scopes, &[",", "\n", GROUP_END_TOKEN])?;
             if let ExpressionKind::Variable(_) = &expression.node {
-                values.insert(name, expression);
+                // shorthand 'Foo{field}' for 'Foo{field: field}'
+                if let Some(duplicate) = values.insert(name, expression) {
+                    return Err(err_duplicate_field(&stream[name_i].text, stream[name_i].span, &duplicate));
+                }
+
                 match end_named_loop(stream, group_i, GROUP_END_TOKEN)? {
                     BREAK => break,
                     CONTINUE => continue,
@@ -208,11 +212,7 @@ fn parse_named_group(stream: &mut TokenStream, scopes: &mut ScopeBuilder) -> Res
 
         let expression = get_expression(stream, scopes, &[",", "\n", GROUP_END_TOKEN, ")"])?;
         if let Some(duplicate) = values.insert(name, expression) {
-            return Err(new_soul_error(
-                SoulErrorKind::InvalidName, 
-                stream.current_span_some(), 
-                format!("in NamedTuple fieldName: '{}' already exists at{}:{};", stream[name_i].text, duplicate.span.line_number, duplicate.span.line_offset),
-            ))
+            return Err(err_duplicate_field(&stream[name_i].text, stream[name_i].span, &duplicate));
         }
 
         if stream.next_if("\n").is_none() {
@@ -410,6 +410,14 @@ fn err_out_of_bounds(group_i: usize, stream: &TokenStream) -> SoulError {
     new_soul_error(SoulErrorKind::UnexpectedEnd, Some(stream[group_i].span.combine(&stream.current_span())), "unexpeced end while parsing expression group")
 }
 
+fn err_duplicate_field(name: &str, span: SoulSpan, duplicate: &Expression) -> SoulError {
+    new_soul_error(
+        SoulErrorKind::InvalidName, 
+        Some(span), 
+        format!("in NamedTuple fieldName: '{}' already exists at {}:{}", name, duplicate.span.line_number, duplicate.span.line_offset),
+    )
+}
+
 
 
 
@@ -55,6 +55,7 @@ fn inner_from_stream(stream: &mut TokenStream, scopes: &mut ScopeBuilder) -> Res
 
         SoulType::from_type_kind(TypeKind::Unknown(stream.current_text().into()))
     };
+    collection_type.modifier = modifier;
 
     if stream.next().is_none() {
         return Err(err_out_of_bounds(stream))
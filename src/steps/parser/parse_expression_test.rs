@@ -1139,3 +1139,24 @@ fn test_ref_and_deref_expression() {
 
 
 
+
+#[test]
+fn test_duplicate_constructor_fields() {
+    let mut scope = empty_scope();
+
+    let mut stream = stream_from_strs(&["Type", "{", "field", ":", "1", ",", "field", ":", "2", "}", "\n"]);
+    let result = get_expression(&mut stream, &mut scope, &["\n"]);
+    assert!(result.is_err());
+    assert!(
+        result.as_ref().unwrap_err().get_last_kind() == SoulErrorKind::InvalidName,
+        "{}", result.unwrap_err().to_err_message().join("\n")
+    );
+
+    let mut stream = stream_from_strs(&["Type", "{", "field", ":", "1", ",", "field", "}", "\n"]);
+    let result = get_expression(&mut stream, &mut scope, &["\n"]);
+    assert!(result.is_err());
+    assert!(
+        result.as_ref().unwrap_err().get_last_kind() == SoulErrorKind::InvalidName,
+        "{}", result.unwrap_err().to_err_message().join("\n")
+    );
+}
@@ -185,11 +185,12 @@ pub fn get_enum(stream: &mut TokenStream, scopes: &mut ScopeBuilder) -> Result<S
             .map_err(|msg| new_soul_error(SoulErrorKind::InvalidName, stream.current_span_some(), msg))?;
 
         let name = stream.current_text().into();
+        let name_span = stream.current_span();
 
         if !stream.peek_is("=") {
             
             match &mut variants {
-                EnumVariantKind::Int(enum_variants) => enum_variants.push(EnumVariant{name, value: current_number}),
+                EnumVariantKind::Int(enum_variants) => enum_variants.push(Spanned::new(EnumVariant{name, value: current_number}, name_span)),
                 EnumVariantKind::Expression(enum_variants) => enum_variants.push(EnumVariant{name, value: Expression::new(ExpressionKind::Default, stream.current_span())}),
             }
 
@@ -229,7 +230,7 @@ pub fn get_enum(stream: &mut TokenStream, scopes: &mut ScopeBuilder) -> Result<S
                 }
 
                 current_number = num;
-                enum_variants.push(EnumVariant{name, value: num});
+                enum_variants.push(Spanned::new(EnumVariant{name, value: num}, name_span));
             },
             EnumVariantKind::Expression(enum_variants) => {
                 let expression = get_expression(stream, scopes, &[",", "\n", "}"])?;
@@ -265,7 +266,7 @@ pub fn get_enum(stream: &mut TokenStream, scopes: &mut ScopeBuilder) -> Result<S
 
     let scope_id = scopes.current_id();
     scopes.pop_scope(stream.current_span())?;
-    let enum_decl = Enum{name, variants, scope_id};
+    let enum_decl = Enum{name, ty: enum_type, variants, scope_id};
     let span = stream[enum_i].span.combine(&stream.current_span());
 
     scopes.insert(enum_decl.name.0.clone(), ScopeKind::Enum(enum_decl.clone()), span)?;
@@ -4,7 +4,7 @@ use crate::steps::parser::statment::parse_block::get_use_block;
 use crate::steps::parser::statment::parse_function::get_function;
 use crate::steps::parser::statment::parse_variable::get_variable;
 use crate::steps::step_interfaces::i_parser::scope_builder::ScopeKind;
-use crate::soul_names::{NamesOtherKeyWords, ASSIGN_SYMBOOLS, SOUL_NAMES};
+use crate::soul_names::{NamesOtherKeyWords, ASSIGN_SYMBOOLS, OPERATOR_ASSIGN_SYMBOOLS, SOUL_NAMES};
 use crate::steps::parser::statment::parse_enum_like::{get_enum, get_union};
 use crate::steps::parser::statment::parse_generics_decl::{get_type_enum_body};
 use crate::steps::step_interfaces::i_parser::parser_response::FromTokenStream;
@@ -15,7 +15,7 @@ use crate::steps::parser::statment::statment_type::{get_statement_type, Statemen
 use crate::steps::step_interfaces::i_parser::abstract_syntax_tree::enum_like::TypeEnum;
 use crate::steps::parser::expression::parse_expression::{get_expression, get_expression_statment};
 use crate::steps::step_interfaces::i_parser::abstract_syntax_tree::soul_type::soul_type::{SoulType};
-use crate::steps::step_interfaces::i_parser::abstract_syntax_tree::expression::{Expression, ExpressionKind, Ident};
+use crate::steps::step_interfaces::i_parser::abstract_syntax_tree::expression::{Binary, BinaryOperatorKind, Expression, ExpressionKind, Ident};
 use crate::steps::step_interfaces::i_parser::abstract_syntax_tree::statement::{Assignment, Block, StatementKind, STATMENT_END_TOKENS};
 use crate::steps::step_interfaces::i_parser::abstract_syntax_tree::soul_type::type_kind::{ExternalPath, ExternalType, SoulPagePath, TypeKind};
 use crate::steps::step_interfaces::{i_parser::{abstract_syntax_tree::{abstract_syntax_tree::BlockBuilder, statement::Statement}, scope_builder::ScopeBuilder}, i_tokenizer::TokenStream};
@@ -388,11 +388,21 @@ fn get_if(stream: &mut TokenStream, scopes: &mut ScopeBuilder) -> Result<Express
 fn get_assignment(stream: &mut TokenStream, scopes: &mut ScopeBuilder) -> Result<Spanned<Assignment>> {
     let assign_i = stream.current_index();
     let variable = get_expression(stream, scopes, ASSIGN_SYMBOOLS)?;
+
+    // 'a += b' is stored as 'a = a + b'
+    let operator = OPERATOR_ASSIGN_SYMBOOLS.contains(&stream.current_text().as_str())
+        .then(|| Spanned::new(BinaryOperatorKind::from_str(stream.current_text().trim_end_matches('=')), stream.current_span()));
+
     if stream.next().is_none() {
         return Err(err_out_of_bounds(stream))
     }
 
-    let value = get_expression(stream, scopes, STATMENT_END_TOKENS)?;
+    let mut value = get_expression(stream, scopes, STATMENT_END_TOKENS)?;
+    if let Some(operator) = operator {
+        let span = variable.span.combine(&value.span);
+        value = Spanned::new(ExpressionKind::Binary(Binary::new(variable.clone(), operator, value)), span);
+    }
+
     let span = stream[assign_i].span.combine(&stream.current_span());
     Ok(Spanned::new(Assignment{variable, value}, span))
 }
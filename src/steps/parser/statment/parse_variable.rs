@@ -13,7 +13,9 @@ pub fn get_variable(stream: &mut TokenStream, scopes: &mut ScopeBuilder) -> Resu
     let begin_i = stream.current_index();
     let possible_type = match SoulType::try_from_stream(stream, scopes)? {
         Some(ty) => {
-            if stream.current_text() == "=" || stream.current_text() == ":=" {
+            // 'const b' without type, 'b' is the name and not the type
+            let is_name = STATMENT_END_TOKENS.iter().any(|sym| sym == stream.current_text());
+            if stream.current_text() == "=" || stream.current_text() == ":=" || is_name {
                 stream.go_to_index(begin_i);
                 None
             } 
@@ -69,7 +71,10 @@ pub fn get_variable(stream: &mut TokenStream, scopes: &mut ScopeBuilder) -> Resu
         }
 
         let ty = if is_type_invered {
-            SoulType::none()
+            // type is inferred from first assignment
+            let mut ty = SoulType::none();
+            ty.modifier = modifier;
+            ty
         }
         else {
             possible_type.unwrap()
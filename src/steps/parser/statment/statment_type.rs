@@ -123,6 +123,10 @@ fn inner_get_statment_type(stream: &mut TokenStream) -> Result<StatementType> {
                         return Ok(StatementType::FunctionCall)
                     }
                 }
+                // uninitialized variable (e.g 'const b' or 'int e')
+                else if modifier != Modifier::Default || consecutive_parts > 1 {
+                    return Ok(StatementType::Variable)
+                }
                 else {
                     return Ok(StatementType::Expression)
                 }
@@ -0,0 +1,908 @@
+use crate::{errors::soul_error::{new_soul_error, pass_soul_error, SoulError, SoulErrorKind, SoulSpan}, steps::{sementic::{generics::{collect_trait_impls, implements_trait, TraitImpls}, type_checking::{find_callee_functions, find_functions, is_builtin_type, STRICT_BORROW, STRICT_BORROW_MUT, STRICT_UNSTRICT}, type_resolution::{AUTO_COPY_TRAIT, STRICT_TYPE}}, step_interfaces::{i_parser::{abstract_syntax_tree::{abstract_syntax_tree::AbstractSyntacTree, expression::{CaseDoKind, ElseKind, Expression, ExpressionGroup, ExpressionKind, Ident, If, ReturnKind}, function::{Capture, CaptureKind, FunctionCall, FunctionSignature, LambdaBody}, object::ClassChild, soul_type::{soul_type::{Modifier, SoulType, TypeWrapper}, type_kind::TypeKind}, statement::{Block, Statement, StatementKind}}, scope_builder::ScopeKind}, i_sementic::{ast_visitor::{AstAnalyser, BorrowChecker}, scope_vistitor::ScopeVisitor}}}};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum BorrowKind {
+    /// normal reference, only its lifetime is checked
+    Lifetime,
+    /// 'Strict(source)' holds the value of source until 'UnStrict()'
+    StrictSource,
+    /// 'strict.Borrow()', allowed alongside other shared borrows
+    StrictShared,
+    /// 'strict.BorrowMut()', strict can not be used while this borrow lives
+    StrictMut,
+}
+
+/// reference to variable 'referent' created at 'span'
+#[derive(Debug, Clone, PartialEq)]
+struct Borrow {
+    referent: String,
+    depth: usize,
+    span: SoulSpan,
+    kind: BorrowKind,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum DeathKind {
+    OutOfScope,
+    Consumed,
+    /// moved into 'Strict(..)'
+    Strict,
+}
+
+/// why a variable can not be used anymore
+#[derive(Debug, Clone, PartialEq)]
+struct Death {
+    kind: DeathKind,
+    referent: String,
+    /// None if variable itself is consumed
+    borrow_span: Option<SoulSpan>,
+    /// name of the function or methode that consumed referent
+    consumer: Option<String>,
+    span: SoulSpan,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct VariableState {
+    name: String,
+    depth: usize,
+    borrows: Vec<Borrow>,
+    death: Option<Death>,
+}
+
+struct Frame {
+    start: usize,
+    end_span: SoulSpan,
+}
+
+#[derive(Default)]
+struct BorrowContext {
+    variables: Vec<VariableState>,
+    frames: Vec<Frame>,
+    /// depth of the frame with the parameters of the current function
+    function_depth: Option<usize>,
+    impls: TraitImpls,
+}
+
+impl BorrowContext {
+
+    fn get(&self, name: &str) -> Option<&VariableState> {
+        self.variables.iter().rev().find(|variable| variable.name == name)
+    }
+
+    fn get_mut(&mut self, name: &str) -> Option<&mut VariableState> {
+        self.variables.iter_mut().rev().find(|variable| variable.name == name)
+    }
+
+    fn declare(&mut self, name: String, borrows: Vec<Borrow>) {
+        let depth = self.frames.len().saturating_sub(1);
+        self.variables.push(VariableState{name, depth, borrows, death: None});
+    }
+
+    fn push_frame(&mut self, end_span: SoulSpan) {
+        self.frames.push(Frame{start: self.variables.len(), end_span});
+    }
+
+    /// removes variables of frame, references to these variables become dangling
+    fn pop_frame(&mut self) {
+        let frame = self.frames.pop().expect("frame should be pushed");
+        let depth = self.frames.len();
+
+        let dying = self.variables.split_off(frame.start);
+        for variable in &mut self.variables {
+            kill_borrows(variable, |borrow| borrow.kind != BorrowKind::StrictSource && borrow.depth == depth && dying.iter().any(|dead| dead.name == borrow.referent), DeathKind::OutOfScope, None, frame.end_span);
+        }
+    }
+
+    /// variable is consumed, variable and references to it can not be used anymore
+    fn consume(&mut self, name: &str, kind: DeathKind, consumer: Option<&str>, span: SoulSpan) {
+
+        let depth = match self.get_mut(name) {
+            Some(variable) => {
+                variable.death = Some(Death{kind, referent: name.into(), borrow_span: None, consumer: consumer.map(|name| name.to_string()), span});
+                // value (and the references it holds) moved to the consumer
+                variable.borrows.clear();
+                variable.depth
+            },
+            None => return,
+        };
+
+        for variable in &mut self.variables {
+            kill_borrows(variable, |borrow| borrow.kind != BorrowKind::StrictSource && borrow.depth == depth && borrow.referent == name, kind, consumer, span);
+        }
+    }
+
+    /// 'strict.Borrow()' and 'strict.BorrowMut()' borrows that are still held by a living variable
+    fn strict_borrows(&self, name: &str, depth: usize) -> Vec<Borrow> {
+        self.variables.iter()
+            .filter(|variable| variable.death.is_none())
+            .flat_map(|variable| &variable.borrows)
+            .filter(|borrow| borrow.referent == name && borrow.depth == depth && matches!(borrow.kind, BorrowKind::StrictShared | BorrowKind::StrictMut))
+            .cloned()
+            .collect()
+    }
+
+    /// state after one of two branches has run
+    fn merge(&mut self, other: Vec<VariableState>) {
+
+        for (variable, other) in self.variables.iter_mut().zip(other) {
+
+            if variable.death.is_none() {
+                variable.death = other.death;
+            }
+
+            for borrow in other.borrows {
+                if !variable.borrows.contains(&borrow) {
+                    variable.borrows.push(borrow);
+                }
+            }
+        }
+    }
+}
+
+impl AstAnalyser for BorrowChecker {
+
+    fn analyse_ast(&mut self, tree: &mut AbstractSyntacTree) {
+        let mut context = BorrowContext::default();
+        collect_trait_impls(&tree.root, &mut context.impls);
+        self.analyse_block(&tree.root, &mut context);
+    }
+}
+
+impl BorrowChecker {
+
+    fn analyse_block(&mut self, block: &Block, context: &mut BorrowContext) {
+        let parent_id = self.get_scope().current_id();
+        self.get_scope_mut().set_current(block.scope_id);
+
+        let end_span = block.statments.last().map(|statment| statment.span).unwrap_or_default();
+        context.push_frame(end_span);
+
+        for statment in &block.statments {
+            self.analyse_statment(statment, context);
+        }
+
+        context.pop_frame();
+
+        self.get_scope_mut()
+            .set_current(parent_id)
+            .expect("scope_id should be valid");
+    }
+
+    fn analyse_statment(&mut self, statment: &Statement, context: &mut BorrowContext) {
+        let span = statment.span;
+
+        let parent_id = self.get_scope().current_id();
+        if let Some(id) = statment.node.get_scope_id() && self.get_scope_mut().set_current(id).is_none() {
+            panic!("could not get scope_id({}) at line: {}:{}", id.0, span.line_number, span.line_offset)
+        }
+
+        match &statment.node {
+            StatementKind::Enum(_) |
+            StatementKind::Trait(_) |
+            StatementKind::Union(_) |
+            StatementKind::Struct(_) |
+            StatementKind::TypeEnum(_) |
+            StatementKind::CloseBlock => (),
+
+            StatementKind::Expression(expression) => {
+                self.analyse_expression(expression, context);
+            },
+            StatementKind::Variable(variable_name) => {
+                let value = self.get_scope()
+                    .flat_lookup(&variable_name.name.0)
+                    .and_then(|kinds| kinds.iter().find_map(|kind| match &kind.node {
+                        ScopeKind::Variable(variable) => variable.initialize_value.clone(),
+                        _ => None,
+                    }));
+
+                let borrows = match value {
+                    Some(value) => self.analyse_expression(&value, context),
+                    None => vec![],
+                };
+
+                context.declare(variable_name.name.0.clone(), borrows);
+            },
+            StatementKind::Assignment(assignment) => {
+                let borrows = self.analyse_expression(&assignment.value, context);
+
+                let is_writable = root_variable(&assignment.variable).is_none_or(|name| self.check_strict_write(name, assignment.variable.span, context));
+
+                match &assignment.variable.node {
+                    _ if !is_writable => (),
+                    ExpressionKind::Variable(variable_name) => {
+
+                        if let Some(variable) = context.get_mut(&variable_name.name.0) {
+                            variable.borrows = borrows;
+                            variable.death = None;
+                        }
+                    },
+                    _ => {
+                        self.analyse_expression(&assignment.variable, context);
+
+                        // e.g. 'object.field = &value', object now also holds the reference
+                        let root = root_variable(&assignment.variable).map(|name| name.to_string());
+                        if let Some(variable) = root.and_then(|name| context.get_mut(&name)) {
+                            variable.borrows.extend(borrows);
+                        }
+                    },
+                }
+            },
+            StatementKind::Function(function) => self.analyse_function(&function.signature, &function.block, span, context),
+            StatementKind::UseBlock(use_block) => self.analyse_block(&use_block.block, context),
+            StatementKind::Class(class) => {
+
+                for child in &class.children {
+                    match child {
+                        ClassChild::Field(_) => (),
+                        ClassChild::Methode(methode) => {
+                            let parent_id = self.get_scope().current_id();
+                            self.get_scope_mut().set_current(methode.node.block.scope_id);
+
+                            self.analyse_function(&methode.node.signature, &methode.node.block, methode.span, context);
+
+                            self.get_scope_mut()
+                                .set_current(parent_id)
+                                .expect("scope_id should be valid");
+                        },
+                        ClassChild::ImplBlock(impl_block) => self.analyse_block(&impl_block.node.block, context),
+                    }
+                }
+            },
+        }
+
+        self.get_scope_mut()
+            .set_current(parent_id)
+            .expect("scope_id should be valid");
+    }
+
+    /// functions can not use local variables of outer functions so they get their own context
+    fn analyse_function(&mut self, signature: &FunctionSignature, block: &Block, span: SoulSpan, outer: &BorrowContext) {
+        let mut context = BorrowContext{impls: outer.impls.clone(), ..Default::default()};
+
+        context.push_frame(span);
+        context.function_depth = Some(0);
+
+        if signature.callee.as_ref().is_some_and(|callee| callee.node.this.is_some()) {
+            context.declare("this".into(), vec![]);
+        }
+
+        for parameter in &signature.parameters {
+            context.declare(parameter.node.name.0.clone(), vec![]);
+        }
+
+        self.analyse_block(block, &mut context);
+        context.pop_frame();
+    }
+
+    /// checks expression and returns the references that the value of the expression holds
+    fn analyse_expression(&mut self, expression: &Expression, context: &mut BorrowContext) -> Vec<Borrow> {
+        let span = expression.span;
+
+        let parent_id = self.get_scope().current_id();
+        if let Some(id) = expression.node.get_scope_id() && self.get_scope_mut().set_current(id).is_none() {
+            panic!("could not get scope_id({}) at line: {}:{}", id.0, span.line_number, span.line_offset)
+        }
+
+        let borrows = self.analyse_expression_kind(expression, context);
+
+        self.get_scope_mut()
+            .set_current(parent_id)
+            .expect("scope_id should be valid");
+
+        borrows
+    }
+
+    fn analyse_expression_kind(&mut self, expression: &Expression, context: &mut BorrowContext) -> Vec<Borrow> {
+        let span = expression.span;
+
+        match &expression.node {
+            ExpressionKind::Empty |
+            ExpressionKind::Default |
+            ExpressionKind::Literal(_) |
+            ExpressionKind::StaticField(_) |
+            ExpressionKind::UnwrapVariable(_) |
+            ExpressionKind::ExternalExpression(_) => vec![],
+
+            ExpressionKind::Variable(variable_name) => self.use_variable(&variable_name.name.0, span, context),
+            ExpressionKind::MutRef(inner) |
+            ExpressionKind::ConstRef(inner) => {
+                let borrows = self.analyse_expression(inner, context);
+
+                match root_variable(inner).and_then(|name| context.get(name)) {
+                    // '&*ref' borrows what ref borrows
+                    Some(_) if matches!(inner.node, ExpressionKind::Deref(_)) => borrows,
+                    Some(variable) => vec![Borrow{referent: variable.name.clone(), depth: variable.depth, span, kind: BorrowKind::Lifetime}],
+                    None => borrows,
+                }
+            },
+            ExpressionKind::Deref(inner) => {
+                self.analyse_expression(inner, context);
+                vec![]
+            },
+            // copy holds the same references as the value it copies
+            ExpressionKind::Copy(inner) => self.analyse_expression(inner, context),
+            ExpressionKind::AccessField(access_field) => self.analyse_expression(&access_field.object, context),
+            ExpressionKind::Index(index) => {
+                self.analyse_expression(&index.index, context);
+                self.analyse_expression(&index.collection, context)
+            },
+            ExpressionKind::FunctionCall(function_call) => {
+
+                if let Some(borrows) = self.analyse_strict_call(function_call, context) {
+                    return borrows
+                }
+
+                let callee_borrows = match &function_call.callee {
+                    Some(callee) => self.analyse_expression(callee, context),
+                    None => vec![],
+                };
+
+                let mut argument_borrows = vec![];
+                for argument in &function_call.arguments.values {
+                    argument_borrows.extend(self.analyse_expression(argument, context));
+                }
+
+                let callee_type = function_call.callee.as_ref()
+                    .and_then(|callee| root_variable(callee))
+                    .and_then(|name| self.get_variable_type(name));
+
+                let methodes = call_signatures(self.get_scope(), function_call, callee_type.as_ref());
+                self.consume_arguments(function_call, &methodes, context);
+
+                let callee = match &function_call.callee {
+                    Some(callee) => callee,
+                    None => {
+                        // calling a lambda that is stored in a variable
+                        if context.get(&function_call.name.0).is_some() {
+                            self.use_variable(&function_call.name.0, span, context);
+                        }
+                        return vec![]
+                    },
+                };
+
+                if methodes.is_empty() {
+                    return vec![]
+                }
+
+                if let ExpressionKind::Variable(variable_name) = &callee.node && methodes.iter().all(is_consume_methode) {
+                    let ty = self.get_variable_type(&variable_name.name.0);
+
+                    if ty.is_some_and(|ty| !is_auto_copy(self.get_scope(), &context.impls, &ty)) {
+                        context.consume(&variable_name.name.0, DeathKind::Consumed, Some(&function_call.name.0), callee.span);
+                    }
+                    return vec![]
+                }
+
+                // returned reference can point to 'this' or to any of the arguments
+                let returns_reference = methodes.iter().all(|methode| methode.return_type.as_ref().is_some_and(is_reference));
+                if !returns_reference {
+                    return vec![]
+                }
+
+                let mut borrows = argument_borrows;
+                match root_variable(callee).and_then(|name| context.get(name)) {
+                    Some(variable) if !is_reference_variable(variable) && methodes.iter().all(|methode| !is_consume_methode(methode)) => {
+                        borrows.push(Borrow{referent: variable.name.clone(), depth: variable.depth, span: callee.span, kind: BorrowKind::Lifetime});
+                    },
+                    _ => borrows.extend(callee_borrows),
+                }
+                borrows
+            },
+            ExpressionKind::StaticMethod(static_method) => {
+                for argument in &static_method.arguments.values {
+                    self.analyse_expression(argument, context);
+                }
+                vec![]
+            },
+            ExpressionKind::StructConstructor(struct_constructor) => {
+                let mut borrows = vec![];
+                for value in struct_constructor.arguments.values.values() {
+                    borrows.extend(self.analyse_expression(value, context));
+                }
+                borrows
+            },
+            ExpressionKind::Unary(unary) => {
+                self.analyse_expression(&unary.expression, context);
+                vec![]
+            },
+            ExpressionKind::Binary(binary) => {
+                self.analyse_expression(&binary.left, context);
+                self.analyse_expression(&binary.right, context);
+                vec![]
+            },
+            ExpressionKind::Ternary(ternary) => {
+                self.analyse_expression(&ternary.condition, context);
+                let mut borrows = self.analyse_expression(&ternary.if_branch, context);
+                borrows.extend(self.analyse_expression(&ternary.else_branch, context));
+                borrows
+            },
+            ExpressionKind::Lambda(lambda) => {
+                context.push_frame(span);
+                match &lambda.body {
+                    LambdaBody::Block(block) => self.analyse_block(block, context),
+                    LambdaBody::Expression(body) => {
+                        self.analyse_expression(body, context);
+                    },
+                }
+                context.pop_frame();
+                capture_borrows(&lambda.captures, context)
+            },
+            ExpressionKind::If(if_decl) => {
+                self.analyse_if(if_decl, context);
+                vec![]
+            },
+            ExpressionKind::Match(match_decl) => {
+                self.analyse_expression(&match_decl.condition, context);
+
+                let before = context.variables.clone();
+                let mut merged: Option<Vec<VariableState>> = None;
+                for case in &match_decl.cases {
+                    let parent_id = self.get_scope().current_id();
+                    self.get_scope_mut().set_current(case.scope_id);
+
+                    context.variables = before.clone();
+                    context.push_frame(span);
+                    match &case.do_fn {
+                        CaseDoKind::Block(block) => self.analyse_block(&block.node, context),
+                        CaseDoKind::Expression(do_expression) => {
+                            self.analyse_expression(do_expression, context);
+                        },
+                    }
+                    context.pop_frame();
+
+                    if let Some(merged) = merged.take() {
+                        let branch = std::mem::replace(&mut context.variables, merged);
+                        context.merge(branch);
+                    }
+                    merged = Some(std::mem::take(&mut context.variables));
+
+                    self.get_scope_mut()
+                        .set_current(parent_id)
+                        .expect("scope_id should be valid");
+                }
+
+                context.variables = merged.unwrap_or(before);
+                vec![]
+            },
+            ExpressionKind::For(for_decl) => {
+                self.analyse_expression(&for_decl.collection, context);
+
+                self.analyse_loop(context, |this, context| {
+                    context.push_frame(span);
+                    if let Some(element) = &for_decl.element {
+                        declare_pattern(element, context);
+                    }
+                    this.analyse_block(&for_decl.block, context);
+                    context.pop_frame();
+                });
+                vec![]
+            },
+            ExpressionKind::While(while_decl) => {
+                self.analyse_loop(context, |this, context| {
+                    if let Some(condition) = &while_decl.condition {
+                        this.analyse_expression(condition, context);
+                    }
+                    this.analyse_block(&while_decl.block, context);
+                });
+                vec![]
+            },
+            ExpressionKind::Block(block) => {
+                self.analyse_block(block, context);
+                vec![]
+            },
+            ExpressionKind::ReturnLike(return_like) => {
+                let borrows = match &return_like.value {
+                    Some(value) => self.analyse_expression(value, context),
+                    None => vec![],
+                };
+
+                if return_like.kind == ReturnKind::Return {
+                    self.check_returned_borrows(&borrows, span, context);
+                }
+                borrows
+            },
+            ExpressionKind::ExpressionGroup(expression_group) => {
+                let values = match expression_group {
+                    ExpressionGroup::Tuple(tuple) => tuple.values.iter().collect::<Vec<_>>(),
+                    ExpressionGroup::Array(array) => array.values.iter().collect(),
+                    ExpressionGroup::NamedTuple(named_tuple) => named_tuple.values.values().collect(),
+                    ExpressionGroup::ArrayFiller(array_filler) => vec![array_filler.amount.as_ref(), array_filler.fill_expr.as_ref()],
+                };
+
+                let mut borrows = vec![];
+                for value in values {
+                    borrows.extend(self.analyse_expression(value, context));
+                }
+                borrows
+            },
+        }
+    }
+
+    /// runs iteration twice so values consumed in the first iteration are reported when the second uses them,
+    /// faults that the first iteration already reported are not reported again
+    fn analyse_loop(&mut self, context: &mut BorrowContext, mut iteration: impl FnMut(&mut Self, &mut BorrowContext)) {
+        let first_start = self.get_faults().len();
+        iteration(self, context);
+
+        let second_start = self.get_faults().len();
+        iteration(self, context);
+
+        let first = self.get_faults()[first_start..second_start].iter()
+            .map(|fault| fault.msg.to_err_message())
+            .collect::<Vec<_>>();
+        let second = self.get_faults_mut().split_off(second_start);
+        self.get_faults_mut().extend(second.into_iter().filter(|fault| !first.contains(&fault.msg.to_err_message())));
+    }
+
+    fn analyse_if(&mut self, if_decl: &If, context: &mut BorrowContext) {
+        self.analyse_expression(&if_decl.condition, context);
+
+        let before = context.variables.clone();
+        self.analyse_block(&if_decl.block, context);
+
+        let mut has_else = false;
+        for branch in &if_decl.else_branchs {
+            let after = std::mem::replace(&mut context.variables, before.clone());
+
+            match &branch.node {
+                ElseKind::Else(block) => {
+                    has_else = true;
+                    self.analyse_block(&block.node, context);
+                },
+                ElseKind::ElseIf(else_if) => self.analyse_if(&else_if.node, context),
+            }
+
+            let branch = std::mem::replace(&mut context.variables, after);
+            context.merge(branch);
+        }
+
+        if !has_else {
+            context.merge(before);
+        }
+    }
+
+    /// reads variable, reports error if variable is consumed, references a variable that is dead or is mutably borrowed by 'BorrowMut()'
+    fn use_variable(&mut self, name: &str, span: SoulSpan, context: &mut BorrowContext) -> Vec<Borrow> {
+        let borrows = self.use_living_variable(name, span, context);
+
+        let mut_borrow = context.get(name)
+            .and_then(|variable| context.strict_borrows(name, variable.depth).into_iter().find(|borrow| borrow.kind == BorrowKind::StrictMut));
+
+        if let Some(mut_borrow) = mut_borrow {
+            let error = new_soul_error(SoulErrorKind::InvalidInContext, Some(span), format!("'{}' is used while it is mutably borrowed", name));
+            let error = pass_soul_error(SoulErrorKind::InvalidInContext, Some(mut_borrow.span), format!("'{}' is borrowed as mutable here", name), error);
+            self.add_error(error);
+        }
+
+        borrows
+    }
+
+    /// reads variable, reports error if variable is consumed or references a variable that is dead
+    fn use_living_variable(&mut self, name: &str, span: SoulSpan, context: &mut BorrowContext) -> Vec<Borrow> {
+
+        let variable = match context.get_mut(name) {
+            Some(variable) => variable,
+            None => return vec![],
+        };
+
+        // only report first use after death
+        if let Some(death) = variable.death.take() {
+            self.add_error(death_error(name, &death, span));
+        }
+
+        variable.borrows.clone()
+    }
+
+    /// 'Strict(source)', 'strict.Borrow()', 'strict.BorrowMut()' and 'strict.UnStrict()', None if call is not one of these
+    fn analyse_strict_call(&mut self, function_call: &FunctionCall, context: &mut BorrowContext) -> Option<Vec<Borrow>> {
+        let name = function_call.name.0.as_str();
+
+        let callee = match &function_call.callee {
+            Some(callee) => callee,
+            None if name == STRICT_TYPE && self.get_scope().lookup_kind(name, |kind| matches!(kind, ScopeKind::Functions(_))).is_none() => return Some(self.analyse_strict_source(function_call, context)),
+            None => return None,
+        };
+
+        let strict = match &callee.node {
+            ExpressionKind::Variable(variable_name) if self.get_variable_type(&variable_name.name.0).is_some_and(|ty| is_builtin_type(&ty, STRICT_TYPE)) => &variable_name.name.0,
+            _ => return None,
+        };
+
+        let kind = match name {
+            STRICT_BORROW => BorrowKind::StrictShared,
+            STRICT_BORROW_MUT => BorrowKind::StrictMut,
+            STRICT_UNSTRICT => {
+                self.analyse_unstrict(strict, callee.span, context);
+                return Some(vec![])
+            },
+            _ => return None,
+        };
+
+        self.use_living_variable(strict, callee.span, context);
+        let depth = context.get(strict)?.depth;
+
+        let conflict = context.strict_borrows(strict, depth)
+            .into_iter()
+            .find(|borrow| kind == BorrowKind::StrictMut || borrow.kind == BorrowKind::StrictMut);
+
+        if let Some(conflict) = conflict {
+            let error = new_soul_error(
+                SoulErrorKind::InvalidInContext,
+                Some(callee.span),
+                format!("can not borrow '{}' as {} because it is also borrowed as {}", strict, borrow_kind_name(kind), borrow_kind_name(conflict.kind)),
+            );
+            let error = pass_soul_error(SoulErrorKind::InvalidInContext, Some(conflict.span), format!("'{}' is borrowed as {} here", strict, borrow_kind_name(conflict.kind)), error);
+            self.add_error(error);
+        }
+
+        Some(vec![Borrow{referent: strict.into(), depth, span: callee.span, kind}])
+    }
+
+    /// 'Strict(source)' moves source into the strict value, source can not be used until 'UnStrict()'
+    fn analyse_strict_source(&mut self, function_call: &FunctionCall, context: &mut BorrowContext) -> Vec<Borrow> {
+        for argument in &function_call.arguments.values {
+            self.analyse_expression(argument, context);
+        }
+
+        let source = match function_call.arguments.values.first().map(|argument| &argument.node) {
+            Some(ExpressionKind::Variable(variable_name)) => &variable_name.name.0,
+            _ => return vec![],
+        };
+
+        let depth = match context.get(source) {
+            Some(variable) => variable.depth,
+            None => return vec![],
+        };
+
+        let span = function_call.arguments.values[0].span;
+        context.consume(source, DeathKind::Strict, None, span);
+        vec![Borrow{referent: source.clone(), depth, span, kind: BorrowKind::StrictSource}]
+    }
+
+    /// 'strict.UnStrict()' consumes strict and gives the value back to its source
+    fn analyse_unstrict(&mut self, strict: &str, span: SoulSpan, context: &mut BorrowContext) {
+        let sources = self.use_living_variable(strict, span, context);
+
+        if let Some(variable) = context.get(strict) && let Some(borrow) = context.strict_borrows(strict, variable.depth).first() {
+            let error = new_soul_error(SoulErrorKind::InvalidInContext, Some(span), format!("can not call '{}()' on '{}' while it is borrowed", STRICT_UNSTRICT, strict));
+            let error = pass_soul_error(SoulErrorKind::InvalidInContext, Some(borrow.span), format!("'{}' is borrowed as {} here", strict, borrow_kind_name(borrow.kind)), error);
+            self.add_error(error);
+        }
+
+        context.consume(strict, DeathKind::Consumed, Some(STRICT_UNSTRICT), span);
+
+        for source in sources.iter().filter(|borrow| borrow.kind == BorrowKind::StrictSource) {
+            let variable = context.variables.iter_mut()
+                .rev()
+                .find(|variable| variable.name == source.referent && variable.depth == source.depth);
+
+            if let Some(variable) = variable {
+                variable.death = None;
+            }
+        }
+    }
+
+    /// writing to a 'Strict' value while it is borrowed is not allowed, returns false if error is reported
+    fn check_strict_write(&mut self, name: &str, span: SoulSpan, context: &BorrowContext) -> bool {
+
+        let borrow = match context.get(name).and_then(|variable| context.strict_borrows(name, variable.depth).into_iter().next()) {
+            Some(borrow) => borrow,
+            None => return true,
+        };
+
+        let error = new_soul_error(SoulErrorKind::InvalidInContext, Some(span), format!("can not assign to '{}' while it is borrowed", name));
+        let error = pass_soul_error(SoulErrorKind::InvalidInContext, Some(borrow.span), format!("'{}' is borrowed as {} here", name, borrow_kind_name(borrow.kind)), error);
+        self.add_error(error);
+        false
+    }
+
+    fn check_returned_borrows(&mut self, borrows: &[Borrow], span: SoulSpan, context: &BorrowContext) {
+
+        let function_depth = match context.function_depth {
+            Some(depth) => depth,
+            None => return,
+        };
+
+        // the value of a 'Strict' source is moved into the strict value so it does not dangle
+        for borrow in borrows.iter().filter(|borrow| borrow.depth >= function_depth && borrow.kind != BorrowKind::StrictSource) {
+            let is_reference = context.variables.iter()
+                .find(|variable| variable.name == borrow.referent && variable.depth == borrow.depth)
+                .is_some_and(|variable| self.get_variable_type(&variable.name).is_some_and(|ty| is_reference(&ty)));
+
+            if is_reference {
+                continue
+            }
+
+            let error = new_soul_error(SoulErrorKind::InvalidInContext, Some(span), format!("returns reference to local variable '{}'", borrow.referent));
+            let error = pass_soul_error(SoulErrorKind::InvalidInContext, Some(span), format!("'{}' dies at end of function", borrow.referent), error);
+            let error = pass_soul_error(SoulErrorKind::InvalidInContext, Some(borrow.span), format!("'{}' is borrowed here", borrow.referent), error);
+            self.add_error(error);
+        }
+    }
+
+    /// variables passed to a consuming parameter (e.g. 'mut int[] list') are moved into the call
+    fn consume_arguments(&mut self, function_call: &FunctionCall, signatures: &[FunctionSignature], context: &mut BorrowContext) {
+
+        for (index, argument) in function_call.arguments.values.iter().enumerate() {
+
+            let name = match &argument.node {
+                ExpressionKind::Variable(variable_name) => &variable_name.name.0,
+                _ => continue,
+            };
+
+            if !consumes_argument(signatures, index) {
+                continue
+            }
+
+            if self.get_variable_type(name).is_some_and(|ty| !is_auto_copy(self.get_scope(), &context.impls, &ty)) {
+                context.consume(name, DeathKind::Consumed, Some(&function_call.name.0), argument.span);
+            }
+        }
+    }
+
+    fn get_variable_type(&self, name: &str) -> Option<SoulType> {
+
+        match &self.get_scope().lookup_kind(name, |kind| matches!(kind, ScopeKind::Variable(_)))?.node {
+            ScopeKind::Variable(variable) if !variable.ty.is_none_type() => Some(variable.ty.clone()),
+            _ => None,
+        }
+    }
+}
+
+/// marks variable as dead if it holds a reference that matches should_kill
+fn kill_borrows<F>(variable: &mut VariableState, should_kill: F, kind: DeathKind, consumer: Option<&str>, span: SoulSpan)
+where
+    F: Fn(&Borrow) -> bool
+{
+    let killed = match variable.borrows.iter().position(&should_kill) {
+        Some(index) => variable.borrows.remove(index),
+        None => return,
+    };
+
+    variable.borrows.retain(|borrow| !should_kill(borrow));
+    if variable.death.is_none() {
+        variable.death = Some(Death{kind, referent: killed.referent, borrow_span: Some(killed.span), consumer: consumer.map(|name| name.to_string()), span});
+    }
+}
+
+fn death_error(name: &str, death: &Death, span: SoulSpan) -> SoulError {
+
+    let consumed_msg = match &death.consumer {
+        Some(consumer) => format!("'{}' is consumed by '{}' here", death.referent, consumer),
+        None => format!("'{}' is consumed here", death.referent),
+    };
+
+    let (death_msg, use_msg) = match (death.kind, death.borrow_span) {
+        (DeathKind::Consumed, None) => (
+            consumed_msg,
+            format!("'{}' is used after being consumed", name),
+        ),
+        (DeathKind::Consumed, Some(_)) => (
+            consumed_msg,
+            format!("'{}' is used after the variable it references ('{}') is consumed", name, death.referent),
+        ),
+        (DeathKind::Strict, None) => (
+            format!("'{}' is moved into '{}' here", name, STRICT_TYPE),
+            format!("'{}' is used while it is moved into '{}', call '{}()' first", name, STRICT_TYPE, STRICT_UNSTRICT),
+        ),
+        (DeathKind::Strict, Some(_)) => (
+            format!("'{}' is moved into '{}' here", death.referent, STRICT_TYPE),
+            format!("'{}' is used after the variable it references ('{}') is moved into '{}'", name, death.referent, STRICT_TYPE),
+        ),
+        (DeathKind::OutOfScope, _) => (
+            format!("'{}' goes out of scope here", death.referent),
+            format!("'{}' is used after the variable it references ('{}') went out of scope", name, death.referent),
+        ),
+    };
+
+    let error = new_soul_error(SoulErrorKind::InvalidInContext, Some(span), use_msg);
+    let error = pass_soul_error(SoulErrorKind::InvalidInContext, Some(death.span), death_msg, error);
+    match death.borrow_span {
+        Some(borrow_span) => pass_soul_error(SoulErrorKind::InvalidInContext, Some(borrow_span), format!("'{}' is borrowed here", death.referent), error),
+        None => error,
+    }
+}
+
+/// for loop element (variable or tuple of variables)
+fn declare_pattern(pattern: &Expression, context: &mut BorrowContext) {
+
+    match &pattern.node {
+        ExpressionKind::Variable(variable_name) => context.declare(variable_name.name.0.clone(), vec![]),
+        ExpressionKind::ExpressionGroup(ExpressionGroup::Tuple(tuple)) => for element in &tuple.values {
+            declare_pattern(element, context);
+        },
+        _ => (),
+    }
+}
+
+/// variable at the root of a place expression (e.g. 'object' in 'object.field[0]')
+fn root_variable(expression: &Expression) -> Option<&str> {
+
+    match &expression.node {
+        ExpressionKind::Variable(variable_name) => Some(&variable_name.name.0),
+        ExpressionKind::AccessField(access_field) => root_variable(&access_field.object),
+        ExpressionKind::Index(index) => root_variable(&index.collection),
+        ExpressionKind::Deref(inner) => root_variable(inner),
+        _ => None,
+    }
+}
+
+fn is_reference(ty: &SoulType) -> bool {
+    matches!(ty.wrappers.last(), Some(TypeWrapper::MutRef(_) | TypeWrapper::ConstRef(_)))
+}
+
+fn is_reference_variable(variable: &VariableState) -> bool {
+    variable.borrows.iter().any(|borrow| borrow.kind != BorrowKind::StrictSource)
+}
+
+fn borrow_kind_name(kind: BorrowKind) -> &'static str {
+    match kind {
+        BorrowKind::StrictMut => "mutable",
+        _ => "immutable",
+    }
+}
+
+/// references that a lambda holds to the variables it captures, variables captured by value are consumed
+fn capture_borrows(captures: &[Capture], context: &mut BorrowContext) -> Vec<Borrow> {
+    let mut borrows = vec![];
+
+    for capture in captures {
+        let name = &capture.variable.name.0;
+
+        match capture.kind {
+            CaptureKind::Consume => context.consume(name, DeathKind::Consumed, None, capture.variable.span),
+            CaptureKind::ConstRef |
+            CaptureKind::MutRef => if let Some(variable) = context.get(name) {
+                borrows.push(Borrow{referent: variable.name.clone(), depth: variable.depth, span: capture.variable.span, kind: BorrowKind::Lifetime});
+            },
+        }
+    }
+    borrows
+}
+
+/// methode that takes 'this' by value (e.g. 'consume(this)')
+pub fn is_consume_methode(methode: &FunctionSignature) -> bool {
+    methode.callee.as_ref()
+        .and_then(|callee| callee.node.this.as_ref())
+        .is_some_and(|this| !is_reference(this))
+}
+
+/// signatures that function_call can resolve to, methodes of callee_type if the call has a callee
+pub fn call_signatures(scope: &ScopeVisitor, function_call: &FunctionCall, callee_type: Option<&SoulType>) -> Vec<FunctionSignature> {
+    let arity = function_call.arguments.values.len();
+
+    let signatures = match (&function_call.callee, callee_type) {
+        (Some(_), Some(ty)) => find_callee_functions(scope, &function_call.name.0, ty, true),
+        (Some(_), None) => return vec![],
+        (None, _) => find_functions(scope, &function_call.name.0)
+            .into_iter()
+            .filter(|signature| signature.callee.is_none())
+            .collect(),
+    };
+
+    signatures.into_iter()
+        .filter(|signature| signature.parameters.len() == arity)
+        .collect()
+}
+
+/// true if every signature takes argument index by value
+pub fn consumes_argument(signatures: &[FunctionSignature], index: usize) -> bool {
+    !signatures.is_empty() && signatures.iter().all(|signature| is_consuming_parameter(&signature.parameters[index].node.ty))
+}
+
+/// parameter that takes its argument by value (e.g. 'mut int[] list')
+pub fn is_consuming_parameter(ty: &SoulType) -> bool {
+    !is_reference(ty) && ty.modifier == Modifier::Default
+}
+
+/// types that are copied instead of consumed, primitives and types that implement 'AutoCopy'
+pub fn is_auto_copy(scope: &ScopeVisitor, impls: &TraitImpls, ty: &SoulType) -> bool {
+
+    if !ty.wrappers.is_empty() {
+        return false
+    }
+
+    ty.base.is_numeric() ||
+    matches!(ty.base, TypeKind::Bool | TypeKind::Char(_) | TypeKind::Enum(_)) ||
+    implements_trait(scope, impls, ty, &Ident::new(AUTO_COPY_TRAIT))
+}
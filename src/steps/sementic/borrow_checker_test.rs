@@ -0,0 +1,276 @@
+use crate::steps::sementic::test_helpers::{assert_single_error, check_borrows, error_messages, Stage};
+
+#[test]
+fn test_valid_references() {
+    let source = "
+main() {
+    int foo = 1
+
+    {
+        int@ constRef = @foo
+        int& mutRef = &foo
+        Println(*constRef)
+        Println(*mutRef)
+    }
+
+    int& outer = &foo
+    {
+        int bar = 2
+        outer = &bar
+    }
+    outer = &foo
+    Println(*outer)
+}
+";
+    let faults = check_borrows(source);
+    assert!(error_messages(&faults).is_empty(), "{:?}", error_messages(&faults));
+}
+
+#[test]
+fn test_reference_outlives_referent() {
+    let source = "
+main() {
+    int foo = 1
+    int& outer = &foo
+    {
+        int bar = 2
+        outer = &bar
+    }
+    Println(*outer)
+}
+";
+    assert_single_error(source, Stage::BorrowChecking, &[
+        "at 7:16| 'bar' is borrowed here",
+        "at 8:5| 'bar' goes out of scope here",
+        "at 9:13| 'outer' is used after the variable it references ('bar') went out of scope",
+    ]);
+}
+
+#[test]
+fn test_return_local_reference() {
+    let source = "
+foo(int param) int@ {
+    return @param
+}
+";
+    assert_single_error(source, Stage::BorrowChecking, &["'param' is borrowed here", "returns reference to local variable 'param'"]);
+
+    let source = "
+foo() int& {
+    int local = 1
+    int& ref = &local
+    return ref
+}
+";
+    assert_single_error(source, Stage::BorrowChecking, &["returns reference to local variable 'local'"]);
+
+    let source = "
+foo(int& param) int& {
+    return param
+}
+";
+    let faults = check_borrows(source);
+    assert!(error_messages(&faults).is_empty(), "{:?}", error_messages(&faults));
+}
+
+#[test]
+fn test_use_after_consume() {
+    let source = "
+int[] consumeToEl(mut this, int a) int[] {
+    this = [0]
+    this[0] = a
+    return this
+}
+
+main() {
+    arr := [1, 2, 3]
+    newArr := arr.consumeToEl(1)
+    el := arr[1]
+}
+";
+    assert_single_error(source, Stage::BorrowChecking, &[
+        "at 10:14| 'arr' is consumed by 'consumeToEl' here",
+        "at 11:10| 'arr' is used after being consumed",
+    ]);
+}
+
+#[test]
+fn test_valid_strict() {
+    let source = "
+main() {
+    int foo = 1
+    int& mutRef = &foo
+
+    bar := Strict(foo)
+    {
+        Mut<int> ref = bar.BorrowMut()
+        *ref += 1
+    }
+    {
+        int@ first = bar.Borrow()
+        int@ second = bar.Borrow()
+        Println(bar)
+    }
+    Println(bar)
+
+    foo = bar.UnStrict()
+    int@ constRef = @foo
+    Println(*constRef)
+}
+";
+    let faults = check_borrows(source);
+    assert!(error_messages(&faults).is_empty(), "{:?}", error_messages(&faults));
+}
+
+#[test]
+fn test_strict_source_is_consumed() {
+    let source = "
+main() {
+    int foo = 1
+    Strict<int> bar = Strict(foo)
+    Println(foo)
+}
+";
+    assert_single_error(source, Stage::BorrowChecking, &[
+        "at 4:29| 'foo' is moved into 'Strict' here",
+        "at 5:12| 'foo' is used while it is moved into 'Strict', call 'UnStrict()' first",
+    ]);
+
+    let source = "
+main() {
+    int foo = 1
+    int& mutRef = &foo
+    Strict<int> bar = Strict(foo)
+    Println(*mutRef)
+}
+";
+    assert_single_error(source, Stage::BorrowChecking, &["'mutRef' is used after the variable it references ('foo') is moved into 'Strict'"]);
+}
+
+#[test]
+fn test_strict_aliasing() {
+    let source = "
+main() {
+    Strict<int> bar = Strict(1)
+    Mut<int> ref = bar.BorrowMut()
+    Println(bar)
+}
+";
+    assert_single_error(source, Stage::BorrowChecking, &[
+        "at 4:19| 'bar' is borrowed as mutable here",
+        "at 5:12| 'bar' is used while it is mutably borrowed",
+    ]);
+
+    let source = "
+main() {
+    Strict<int> bar = Strict(1)
+    int@ shared = bar.Borrow()
+    Mut<int> ref = bar.BorrowMut()
+}
+";
+    assert_single_error(source, Stage::BorrowChecking, &[
+        "at 4:18| 'bar' is borrowed as immutable here",
+        "at 5:19| can not borrow 'bar' as mutable because it is also borrowed as immutable",
+    ]);
+}
+
+#[test]
+fn test_unstrict_while_borrowed() {
+    let source = "
+main() {
+    int foo = 1
+    Strict<int> bar = Strict(foo)
+    int@ shared = bar.Borrow()
+    foo = bar.UnStrict()
+}
+";
+    assert_single_error(source, Stage::BorrowChecking, &["can not call 'UnStrict()' on 'bar' while it is borrowed"]);
+}
+
+#[test]
+fn test_consuming_parameter() {
+    let source = "
+sum(mut int[] list) int {
+    return list[0]
+}
+
+show(int[] list) {
+}
+
+main() {
+    arr := [1, 2, 3]
+    show(arr)
+    total := sum(arr)
+    Println(arr)
+}
+";
+    assert_single_error(source, Stage::BorrowChecking, &[
+        "at 12:17| 'arr' is consumed by 'sum' here",
+        "at 13:12| 'arr' is used after being consumed",
+    ]);
+}
+
+#[test]
+fn test_copy_and_auto_copy() {
+    let source = "
+struct Point {
+    int x
+    int y
+}
+
+use Point impl AutoCopy {}
+
+sum(mut int[] list) int {
+    return list[0]
+}
+
+move(mut Point point) int {
+    return point.x
+}
+
+main() {
+    arr := [1, 2, 3]
+    total := sum(copy arr)
+    Println(arr)
+
+    point := Point{x: 1, y: 2}
+    x := move(point)
+    Println(point)
+}
+";
+    let faults = check_borrows(source);
+    assert!(error_messages(&faults).is_empty(), "{:?}", error_messages(&faults));
+}
+
+#[test]
+fn test_consume_in_loop() {
+    let source = "
+sum(mut int[] list) int {
+    return list[0]
+}
+
+main() {
+    arr := [1, 2, 3]
+    mut i := 0
+    while i < 3 {
+        total := sum(arr)
+        i++
+    }
+
+    other := [1, 2, 3]
+    for j in 0..3 {
+        total := sum(other)
+    }
+
+    for j in 0..3 {
+        fresh := [1, 2, 3]
+        total := sum(fresh)
+    }
+}
+";
+    let faults = check_borrows(source);
+    let errors = error_messages(&faults);
+    assert_eq!(errors.len(), 2, "{:?}", errors);
+    assert!(errors[0].contains("at 10:21| 'arr' is consumed by 'sum' here") && errors[0].contains("at 10:21| 'arr' is used after being consumed"), "{}", errors[0]);
+    assert!(errors[1].contains("at 16:21| 'other' is consumed by 'sum' here") && errors[1].contains("at 16:21| 'other' is used after being consumed"), "{}", errors[1]);
+}
@@ -0,0 +1,603 @@
+use std::collections::{BTreeMap, BTreeSet};
+use crate::{errors::soul_error::{new_soul_error, SoulErrorKind, SoulSpan}, steps::step_interfaces::{i_parser::{abstract_syntax_tree::{abstract_syntax_tree::AbstractSyntacTree, expression::{CaseDoKind, ElseKind, Expression, ExpressionGroup, ExpressionKind, If, ReturnKind}, function::{FunctionSignature, LambdaBody}, literal::Literal, object::ClassChild, soul_type::soul_type::Modifier, statement::{Block, Statement, StatementKind}}, scope_builder::ScopeKind}, i_sementic::ast_visitor::{AstAnalyser, ControlFlowAnalyser}}};
+
+/// how control leaves a statement or expression
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Flow {
+    /// continues with the next statement
+    Continues,
+    /// leaves the current block-valued expression ('fall')
+    Falls,
+    /// leaves the current loop ('break')
+    Breaks,
+    /// leaves the function ('return') or never finishes (e.g. 'while {}' without 'break')
+    Returns,
+}
+
+impl Flow {
+    /// flow of two parts where other runs after self
+    fn then(self, other: Flow) -> Flow {
+        match self {
+            Flow::Continues => other,
+            _ => self,
+        }
+    }
+
+    /// flow of branches where only one of them runs (e.g. if/else), the branch that leaves the least code wins
+    fn join(self, other: Flow) -> Flow {
+        match (self, other) {
+            (Flow::Continues, _) | (_, Flow::Continues) => Flow::Continues,
+            (Flow::Falls, _) | (_, Flow::Falls) => Flow::Falls,
+            (Flow::Breaks, _) | (_, Flow::Breaks) => Flow::Breaks,
+            (Flow::Returns, Flow::Returns) => Flow::Returns,
+        }
+    }
+}
+
+/// variable by index of the scope it is declared in and its name
+type VariableId = (usize, String);
+
+/// definite assignment state of the variables declared in the current function
+#[derive(Debug, Clone, Default)]
+struct Assignments {
+    /// variables declared without value that are not assigned on every path to the current code
+    unassigned: BTreeSet<VariableId>,
+    /// const variables declared in the current function
+    consts: BTreeSet<VariableId>,
+    /// const variables that are assigned on some path to the current code, with the span of that assignment
+    const_assigned: BTreeMap<VariableId, SoulSpan>,
+}
+
+impl Assignments {
+    /// state after branches where only one of them runs (e.g. if/else)
+    fn join(mut self, other: Assignments) -> Assignments {
+        self.unassigned.extend(other.unassigned);
+        self.consts.extend(other.consts);
+
+        for (id, span) in other.const_assigned {
+            self.const_assigned.entry(id).or_insert(span);
+        }
+        self
+    }
+}
+
+#[derive(Default)]
+struct FlowContext {
+    /// per loop, true if loop contains a 'break'
+    loops: Vec<bool>,
+    /// amount of block-valued expressions the current code is in
+    block_value_depth: usize,
+    assignments: Assignments,
+}
+
+impl AstAnalyser for ControlFlowAnalyser {
+
+    fn analyse_ast(&mut self, tree: &mut AbstractSyntacTree) {
+        let mut context = FlowContext::default();
+        self.analyse_block(&tree.root, &mut context);
+    }
+}
+
+impl ControlFlowAnalyser {
+
+    fn analyse_block(&mut self, block: &Block, context: &mut FlowContext) -> Flow {
+        let parent_id = self.get_scope().current_id();
+        self.get_scope_mut().set_current(block.scope_id);
+
+        let mut flow = Flow::Continues;
+        for statment in &block.statments {
+
+            if matches!(statment.node, StatementKind::CloseBlock) {
+                continue
+            }
+
+            if flow != Flow::Continues {
+                self.add_warning(new_soul_error(SoulErrorKind::InvalidInContext, Some(statment.span), "unreachable code"));
+                break
+            }
+
+            flow = self.analyse_statment(statment, context);
+        }
+
+        self.get_scope_mut()
+            .set_current(parent_id)
+            .expect("scope_id should be valid");
+
+        flow
+    }
+
+    fn analyse_statment(&mut self, statment: &Statement, context: &mut FlowContext) -> Flow {
+        let span = statment.span;
+
+        let parent_id = self.get_scope().current_id();
+        if let Some(id) = statment.node.get_scope_id() && self.get_scope_mut().set_current(id).is_none() {
+            panic!("could not get scope_id({}) at line: {}:{}", id.0, span.line_number, span.line_offset)
+        }
+
+        let flow = match &statment.node {
+            StatementKind::Trait(_) |
+            StatementKind::Union(_) |
+            StatementKind::Struct(_) |
+            StatementKind::TypeEnum(_) |
+            StatementKind::CloseBlock => Flow::Continues,
+
+            StatementKind::Expression(expression) => self.analyse_expression(expression, false, context),
+            StatementKind::Variable(variable_name) => {
+                let variable = self.get_scope()
+                    .flat_lookup(&variable_name.name.0)
+                    .and_then(|kinds| kinds.iter().find_map(|kind| match &kind.node {
+                        ScopeKind::Variable(variable) => Some(variable.clone()),
+                        _ => None,
+                    }));
+
+                let variable = match variable {
+                    Some(variable) => variable,
+                    None => return Flow::Continues,
+                };
+
+                let flow = match &variable.initialize_value {
+                    Some(value) => self.analyse_expression(value, true, context),
+                    None => Flow::Continues,
+                };
+
+                self.declare_variable(&variable_name.name.0, &variable.ty.modifier, variable.initialize_value.is_some(), variable_name.span, context);
+                flow
+            },
+            StatementKind::Assignment(assignment) => {
+                let flow = match &assignment.variable.node {
+                    // only assigned, not used
+                    ExpressionKind::Variable(_) => Flow::Continues,
+                    _ => self.analyse_expression(&assignment.variable, true, context),
+                };
+                let flow = flow.then(self.analyse_expression(&assignment.value, true, context));
+
+                if let ExpressionKind::Variable(variable_name) = &assignment.variable.node {
+                    self.assign_variable(&variable_name.name.0, variable_name.span, context);
+                }
+                flow
+            },
+            StatementKind::Function(function) => {
+                self.analyse_function_block(&function.signature, &function.block, span);
+                Flow::Continues
+            },
+            StatementKind::UseBlock(use_block) => {
+                self.analyse_block(&use_block.block, context);
+                Flow::Continues
+            },
+            StatementKind::Class(class) => {
+
+                for child in &class.children {
+                    match child {
+                        ClassChild::Field(_) => (),
+                        ClassChild::Methode(methode) => {
+                            let parent_id = self.get_scope().current_id();
+                            self.get_scope_mut().set_current(methode.node.block.scope_id);
+
+                            self.analyse_function_block(&methode.node.signature, &methode.node.block, methode.span);
+
+                            self.get_scope_mut()
+                                .set_current(parent_id)
+                                .expect("scope_id should be valid");
+                        },
+                        ClassChild::ImplBlock(impl_block) => {
+                            self.analyse_block(&impl_block.node.block, context);
+                        },
+                    }
+                }
+                Flow::Continues
+            },
+            StatementKind::Enum(_) => Flow::Continues,
+        };
+
+        self.get_scope_mut()
+            .set_current(parent_id)
+            .expect("scope_id should be valid");
+
+        flow
+    }
+
+    /// body of function has its own loops and block-valued expressions
+    fn analyse_function_block(&mut self, signature: &FunctionSignature, block: &Block, span: SoulSpan) {
+        let mut context = FlowContext::default();
+        let flow = self.analyse_block(block, &mut context);
+
+        let has_return_type = signature.return_type.as_ref().is_some_and(|ty| !ty.is_none_type());
+        if has_return_type && flow != Flow::Returns {
+            self.add_missing_return(&signature.name.0, span);
+        }
+    }
+
+    /// is_value is true if the value of the expression is used (e.g. 'x := if true {fall 1} else {fall 2}')
+    fn analyse_expression(&mut self, expression: &Expression, is_value: bool, context: &mut FlowContext) -> Flow {
+        let span = expression.span;
+
+        let parent_id = self.get_scope().current_id();
+        if let Some(id) = expression.node.get_scope_id() && self.get_scope_mut().set_current(id).is_none() {
+            panic!("could not get scope_id({}) at line: {}:{}", id.0, span.line_number, span.line_offset)
+        }
+
+        if is_value {
+            context.block_value_depth += 1;
+        }
+
+        let flow = self.analyse_expression_kind(expression, context);
+
+        if is_value {
+            context.block_value_depth -= 1;
+        }
+
+        self.get_scope_mut()
+            .set_current(parent_id)
+            .expect("scope_id should be valid");
+
+        flow
+    }
+
+    fn analyse_expression_kind(&mut self, expression: &Expression, context: &mut FlowContext) -> Flow {
+        let span = expression.span;
+
+        match &expression.node {
+            ExpressionKind::Variable(variable_name) => {
+                self.use_variable(&variable_name.name.0, span, context);
+                Flow::Continues
+            },
+
+            ExpressionKind::Empty |
+            ExpressionKind::Default |
+            ExpressionKind::Literal(_) |
+            ExpressionKind::StaticField(_) |
+            ExpressionKind::UnwrapVariable(_) |
+            ExpressionKind::ExternalExpression(_) => Flow::Continues,
+
+            ExpressionKind::Index(index) => {
+                self.analyse_expression(&index.collection, true, context)
+                    .then(self.analyse_expression(&index.index, true, context))
+            },
+            ExpressionKind::Lambda(lambda) => {
+                // lambda can use variables of the function it is declared in, but its assignments are not part of that function
+                let mut lambda_context = FlowContext{assignments: context.assignments.clone(), ..Default::default()};
+
+                match &lambda.body {
+                    LambdaBody::Block(block) => {
+                        let flow = self.analyse_block(block, &mut lambda_context);
+
+                        let has_return_type = lambda.signature.return_type.as_ref().is_some_and(|ty| !ty.is_none_type());
+                        if has_return_type && flow != Flow::Returns {
+                            self.add_missing_return("lambda", span);
+                        }
+                    },
+                    LambdaBody::Expression(body) => {
+                        self.analyse_expression(body, true, &mut lambda_context);
+                    },
+                }
+                Flow::Continues
+            },
+            ExpressionKind::FunctionCall(function_call) => {
+                let mut flow = match &function_call.callee {
+                    Some(callee) => self.analyse_expression(callee, true, context),
+                    None => Flow::Continues,
+                };
+
+                for argument in &function_call.arguments.values {
+                    flow = flow.then(self.analyse_expression(argument, true, context));
+                }
+                flow
+            },
+            ExpressionKind::StaticMethod(static_method) => {
+                let mut flow = Flow::Continues;
+                for argument in &static_method.arguments.values {
+                    flow = flow.then(self.analyse_expression(argument, true, context));
+                }
+                flow
+            },
+            ExpressionKind::StructConstructor(struct_constructor) => {
+                let mut flow = Flow::Continues;
+                for value in struct_constructor.arguments.values.values() {
+                    flow = flow.then(self.analyse_expression(value, true, context));
+                }
+                flow
+            },
+            ExpressionKind::AccessField(access_field) => self.analyse_expression(&access_field.object, true, context),
+            ExpressionKind::Unary(unary) => self.analyse_expression(&unary.expression, true, context),
+            ExpressionKind::Binary(binary) => {
+                self.analyse_expression(&binary.left, true, context)
+                    .then(self.analyse_expression(&binary.right, true, context))
+            },
+            ExpressionKind::Ternary(ternary) => {
+                let condition = self.analyse_expression(&ternary.condition, true, context);
+                let entry = context.assignments.clone();
+
+                let if_branch = self.analyse_expression(&ternary.if_branch, true, context);
+                let if_assignments = std::mem::replace(&mut context.assignments, entry.clone());
+                let else_branch = self.analyse_expression(&ternary.else_branch, true, context);
+
+                let branches = [(if_branch, if_assignments), (else_branch, std::mem::take(&mut context.assignments))];
+                context.assignments = join_branches(branches, entry);
+
+                condition.then(if_branch.join(else_branch))
+            },
+            ExpressionKind::Deref(inner) |
+            ExpressionKind::MutRef(inner) |
+            ExpressionKind::ConstRef(inner) |
+            ExpressionKind::Copy(inner) => self.analyse_expression(inner, true, context),
+
+            ExpressionKind::If(if_decl) => self.analyse_if(if_decl, context),
+            ExpressionKind::Match(match_decl) => {
+                let condition = self.analyse_expression(&match_decl.condition, true, context);
+                let entry = context.assignments.clone();
+
+                let mut arms: Option<Flow> = None;
+                let mut branches = vec![];
+                for case in &match_decl.cases {
+                    let parent_id = self.get_scope().current_id();
+                    self.get_scope_mut().set_current(case.scope_id);
+
+                    let flow = match &case.do_fn {
+                        CaseDoKind::Block(block) => self.analyse_block(&block.node, context),
+                        CaseDoKind::Expression(do_expression) => self.analyse_expression(do_expression, false, context),
+                    };
+
+                    self.get_scope_mut()
+                        .set_current(parent_id)
+                        .expect("scope_id should be valid");
+
+                    branches.push((flow, std::mem::replace(&mut context.assignments, entry.clone())));
+                    arms = Some(arms.map_or(flow, |arms| arms.join(flow)));
+                }
+
+                // match has to match every value (see type checking), so one of the cases always runs
+                context.assignments = join_branches(branches, entry);
+
+                condition.then(absorb_fall(arms.unwrap_or(Flow::Continues)))
+            },
+            ExpressionKind::For(for_decl) => {
+                let collection = self.analyse_expression(&for_decl.collection, true, context);
+                let entry = context.assignments.clone();
+
+                context.loops.push(false);
+                self.analyse_block(&for_decl.block, context);
+                context.loops.pop();
+
+                self.end_loop_assignments(entry, context);
+
+                collection
+            },
+            ExpressionKind::While(while_decl) => {
+                let condition = match &while_decl.condition {
+                    Some(condition) => self.analyse_expression(condition, true, context),
+                    None => Flow::Continues,
+                };
+
+                let entry = context.assignments.clone();
+
+                context.loops.push(false);
+                self.analyse_block(&while_decl.block, context);
+                let has_break = context.loops.pop().expect("loop should be pushed");
+
+                self.end_loop_assignments(entry, context);
+
+                // 'while {}' and 'while true {}' without break never finish
+                let is_endless = match &while_decl.condition {
+                    Some(condition) => condition.node == ExpressionKind::Literal(Literal::Bool(true)),
+                    None => true,
+                };
+
+                if is_endless && !has_break {
+                    Flow::Returns
+                }
+                else {
+                    condition
+                }
+            },
+            ExpressionKind::Block(block) => self.analyse_block(block, context),
+            ExpressionKind::ReturnLike(return_like) => {
+
+                if let Some(value) = &return_like.value {
+                    self.analyse_expression(value, true, context);
+                }
+
+                match return_like.kind {
+                    ReturnKind::Return => Flow::Returns,
+                    ReturnKind::Break => {
+
+                        match context.loops.last_mut() {
+                            Some(has_break) => *has_break = true,
+                            None => self.add_error(new_soul_error(SoulErrorKind::InvalidInContext, Some(span), "'break' can only be used inside of 'while' or 'for' loop")),
+                        }
+                        Flow::Breaks
+                    },
+                    ReturnKind::Fall => {
+
+                        if context.block_value_depth == 0 {
+                            self.add_error(new_soul_error(SoulErrorKind::InvalidInContext, Some(span), "'fall' can only be used inside of block-valued expression (e.g. 'x := if true {fall 1} else {fall 2}')"));
+                        }
+                        Flow::Falls
+                    },
+                }
+            },
+            ExpressionKind::ExpressionGroup(expression_group) => {
+                let values = match expression_group {
+                    ExpressionGroup::Tuple(tuple) => tuple.values.iter().collect::<Vec<_>>(),
+                    ExpressionGroup::Array(array) => array.values.iter().collect(),
+                    ExpressionGroup::NamedTuple(named_tuple) => named_tuple.values.values().collect(),
+                    ExpressionGroup::ArrayFiller(array_filler) => vec![array_filler.amount.as_ref(), array_filler.fill_expr.as_ref()],
+                };
+
+                let mut flow = Flow::Continues;
+                for value in values {
+                    flow = flow.then(self.analyse_expression(value, true, context));
+                }
+                flow
+            },
+        }
+    }
+
+    fn analyse_if(&mut self, if_decl: &If, context: &mut FlowContext) -> Flow {
+        let condition = self.analyse_expression(&if_decl.condition, true, context);
+        let entry = context.assignments.clone();
+
+        let mut flow = self.analyse_block(&if_decl.block, context);
+        let mut branches = vec![(flow, std::mem::replace(&mut context.assignments, entry.clone()))];
+
+        let mut has_else = false;
+        for branch in &if_decl.else_branchs {
+
+            let branch_flow = match &branch.node {
+                ElseKind::Else(block) => {
+                    has_else = true;
+                    self.analyse_block(&block.node, context)
+                },
+                ElseKind::ElseIf(else_if) => {
+                    let else_if = &else_if.node;
+                    let condition = self.analyse_expression(&else_if.condition, true, context);
+                    condition.then(self.analyse_block(&else_if.block, context))
+                },
+            };
+
+            branches.push((branch_flow, std::mem::replace(&mut context.assignments, entry.clone())));
+            flow = flow.join(branch_flow);
+        }
+
+        if !has_else {
+            // no branch runs if every condition is false
+            branches.push((Flow::Continues, entry.clone()));
+            flow = Flow::Continues;
+        }
+        context.assignments = join_branches(branches, entry);
+
+        condition.then(absorb_fall(flow))
+    }
+
+    fn declare_variable(&mut self, name: &str, modifier: &Modifier, is_initialized: bool, span: SoulSpan, context: &mut FlowContext) {
+        let id = (self.get_scope().current_id().0, name.to_string());
+
+        if !is_initialized && *modifier == Modifier::Literal {
+            self.add_error(new_soul_error(
+                SoulErrorKind::InvalidInContext,
+                Some(span),
+                format!("Literal variable '{}' has to be assigned when it is declared", name),
+            ));
+            return
+        }
+
+        if *modifier == Modifier::Const {
+            context.assignments.consts.insert(id.clone());
+
+            if is_initialized {
+                context.assignments.const_assigned.insert(id.clone(), span);
+            }
+        }
+
+        if !is_initialized {
+            context.assignments.unassigned.insert(id);
+        }
+    }
+
+    fn assign_variable(&mut self, name: &str, span: SoulSpan, context: &mut FlowContext) {
+        let id = match self.variable_id(name) {
+            Some(id) => id,
+            None => return,
+        };
+
+        if context.assignments.consts.contains(&id) {
+
+            match context.assignments.const_assigned.get(&id) {
+                Some(assigned) => self.add_error(new_soul_error(
+                    SoulErrorKind::InvalidInContext,
+                    Some(span),
+                    format!("const variable '{}' is already assigned at {}:{}, const variables can only be assigned once", name, assigned.line_number, assigned.line_offset),
+                )),
+                None => {
+                    context.assignments.const_assigned.insert(id.clone(), span);
+                },
+            }
+        }
+
+        context.assignments.unassigned.remove(&id);
+    }
+
+    fn use_variable(&mut self, name: &str, span: SoulSpan, context: &mut FlowContext) {
+        let id = match self.variable_id(name) {
+            Some(id) => id,
+            None => return,
+        };
+
+        // only reported once per variable
+        if context.assignments.unassigned.remove(&id) {
+            self.add_error(new_soul_error(
+                SoulErrorKind::InvalidInContext,
+                Some(span),
+                format!("variable '{}' is used before it is assigned", name),
+            ));
+        }
+    }
+
+    /// body of loop can run zero times so assignments in it are not definite, and a const assigned in it would be assigned every iteration
+    fn end_loop_assignments(&mut self, entry: Assignments, context: &mut FlowContext) {
+        let body = std::mem::replace(&mut context.assignments, entry);
+
+        for (id, span) in body.const_assigned {
+
+            if context.assignments.const_assigned.contains_key(&id) {
+                continue
+            }
+
+            if context.assignments.consts.contains(&id) {
+                self.add_error(new_soul_error(
+                    SoulErrorKind::InvalidInContext,
+                    Some(span),
+                    format!("const variable '{}' is assigned inside of a loop, const variables can only be assigned once", id.1),
+                ));
+            }
+            context.assignments.const_assigned.insert(id, span);
+        }
+    }
+
+    /// variable name refers to from the current scope
+    fn variable_id(&self, name: &str) -> Option<VariableId> {
+        let scopes = self.get_scope().get_scopes();
+
+        let mut current_index = Some(self.get_scope().current_id());
+        while let Some(index) = current_index {
+
+            let is_variable = scopes[index.0].get(name)
+                .is_some_and(|kinds| kinds.iter().any(|kind| matches!(kind.node, ScopeKind::Variable(_))));
+
+            if is_variable {
+                return Some((index.0, name.to_string()))
+            }
+
+            current_index = scopes[index.0].parent_index;
+        }
+
+        None
+    }
+
+    fn add_missing_return(&mut self, name: &str, span: SoulSpan) {
+        self.add_error(new_soul_error(
+            SoulErrorKind::InvalidInContext,
+            Some(span),
+            format!("'{}' does not return a value in all code paths", name),
+        ));
+    }
+}
+
+/// assignment state after branches, branches that return do not reach the code after them
+fn join_branches<I>(branches: I, entry: Assignments) -> Assignments
+where
+    I: IntoIterator<Item = (Flow, Assignments)>
+{
+    branches.into_iter()
+        .filter(|(flow, _)| *flow != Flow::Returns)
+        .map(|(_, assignments)| assignments)
+        .reduce(Assignments::join)
+        .unwrap_or(entry)
+}
+
+/// 'fall' in a branch only leaves the if or match, so code after it is still reachable ('break' also leaves the loop around it)
+fn absorb_fall(flow: Flow) -> Flow {
+    match flow {
+        Flow::Falls => Flow::Continues,
+        flow => flow,
+    }
+}
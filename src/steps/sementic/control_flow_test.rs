@@ -0,0 +1,227 @@
+use crate::steps::sementic::test_helpers::{assert_single_error, check_control_flow, error_messages, warning_messages, Stage};
+
+#[test]
+fn test_valid_control_flow() {
+    let source = "
+sign(int a) int {
+    if a < 0 {
+        return -1
+    }
+    else if a == 0 {
+        return 0
+    }
+    else {
+        return 1
+    }
+}
+
+forever() int {
+    while {
+    }
+}
+
+first() int {
+    while true {
+        return 1
+    }
+}
+
+main() {
+    mut i := 0
+    while true {
+        if i == 20 {
+            break
+        }
+        i += 1
+    }
+
+    const num := match 1 == 2 {
+        true => fall 1,
+        false => fall 2,
+    }
+
+    value := if true {
+        fall 1
+    }
+    else {
+        fall 2
+    }
+}
+";
+    let faults = check_control_flow(source);
+    assert!(error_messages(&faults).is_empty(), "{:?}", error_messages(&faults));
+    assert!(warning_messages(&faults).is_empty(), "{:?}", warning_messages(&faults));
+}
+
+#[test]
+fn test_missing_return() {
+    let source = "
+sign(int a) int {
+    if a < 0 {
+        return -1
+    }
+}
+";
+    assert_single_error(source, Stage::ControlFlow, &["'sign' does not return a value in all code paths"]);
+
+    let source = "
+loop(int a) int {
+    while {
+        if a == 1 {
+            break
+        }
+    }
+}
+";
+    assert_single_error(source, Stage::ControlFlow, &["'loop' does not return a value in all code paths"]);
+
+    let source = "
+loop(int a) int {
+    while true {
+        if a == 1 {
+            break
+        }
+    }
+}
+";
+    assert_single_error(source, Stage::ControlFlow, &["'loop' does not return a value in all code paths"]);
+}
+
+#[test]
+fn test_unreachable_code() {
+    let source = "
+foo() int {
+    return 1
+    a := 2
+}
+";
+    let faults = check_control_flow(source);
+    let warnings = warning_messages(&faults);
+
+    assert!(error_messages(&faults).is_empty(), "{:?}", error_messages(&faults));
+    assert_eq!(warnings.len(), 1, "{:?}", warnings);
+    assert!(warnings[0].contains("unreachable code"), "{}", warnings[0]);
+}
+
+#[test]
+fn test_unreachable_after_break() {
+    let source = "
+main() {
+    mut i := 0
+    while i < 10 {
+        if i > 5 {
+            break
+        }
+        else {
+            break
+        }
+        i += 1
+    }
+
+    while i < 20 {
+        x := if i > 15 {
+            break
+        }
+        else {
+            fall 1
+        }
+        i += x
+    }
+}
+";
+    let faults = check_control_flow(source);
+    let warnings = warning_messages(&faults);
+
+    // 'break' leaves the loop but 'fall' only leaves the if
+    assert!(error_messages(&faults).is_empty(), "{:?}", error_messages(&faults));
+    assert_eq!(warnings.len(), 1, "{:?}", warnings);
+    assert!(warnings[0].contains("at 11:") && warnings[0].contains("unreachable code"), "{}", warnings[0]);
+}
+
+#[test]
+fn test_break_outside_loop() {
+    assert_single_error("main() {\n\tif true {\n\t\tbreak\n\t}\n}\n", Stage::ControlFlow, &["'break' can only be used inside of 'while' or 'for' loop"]);
+}
+
+#[test]
+fn test_fall_outside_block_value() {
+    assert_single_error("main() {\n\tif true {\n\t\tfall 1\n\t}\n}\n", Stage::ControlFlow, &["'fall' can only be used inside of block-valued expression"]);
+}
+
+#[test]
+fn test_definite_assignment() {
+    let source = "
+main() {
+    mut a
+    if true {
+        a = 1
+    }
+    else {
+        a = 2
+    }
+    b := a
+
+    mut c
+    if true {
+        c = 1
+    }
+    d := c
+
+    int e
+    if false {
+        return
+    }
+    else {
+        e = 1
+    }
+    f := e
+
+    mut g
+    h := g
+    g = 1
+}
+";
+    let faults = check_control_flow(source);
+    let errors = error_messages(&faults);
+
+    assert_eq!(errors.len(), 2, "{:?}", errors);
+    assert!(errors.iter().any(|err| err.contains("at 16:9") && err.contains("variable 'c' is used before it is assigned")), "{:?}", errors);
+    assert!(errors.iter().any(|err| err.contains("at 28:9") && err.contains("variable 'g' is used before it is assigned")), "{:?}", errors);
+}
+
+#[test]
+fn test_const_assignment() {
+    let source = "
+main() {
+    const a = 1
+    a = 2
+
+    const b
+    if true {
+        b = 1
+    }
+    else {
+        b = 2
+    }
+
+    const c
+    while true {
+        c = 1
+    }
+
+    mut d = 1
+    d = 2
+}
+";
+    let faults = check_control_flow(source);
+    let errors = error_messages(&faults);
+
+    assert_eq!(errors.len(), 2, "{:?}", errors);
+    assert!(errors.iter().any(|err| err.contains("at 4:4") && err.contains("const variable 'a' is already assigned at 3:10, const variables can only be assigned once")), "{:?}", errors);
+    assert!(errors.iter().any(|err| err.contains("at 16:8") && err.contains("const variable 'c' is assigned inside of a loop, const variables can only be assigned once")), "{:?}", errors);
+}
+
+#[test]
+fn test_uninitialized_literal() {
+    assert_single_error("main() {\n\tLiteral a\n}\n", Stage::ControlFlow, &["Literal variable 'a' has to be assigned when it is declared"]);
+}
@@ -0,0 +1,190 @@
+use std::collections::{HashMap, HashSet};
+use crate::{errors::soul_error::SoulSpan, steps::step_interfaces::i_parser::abstract_syntax_tree::{enum_like::UnionVariantKind, expression::{CaseDoKind, CaseSwitch, Expression, ExpressionKind, IfCaseKind}, literal::Literal}};
+
+/// type of a match condition that has a finite amount of values
+pub enum MatchSubject {
+    Bool,
+    /// C-style enum with its variant names
+    Enum{name: String, variants: Vec<String>},
+    /// Rust-style enum with its variants (e.g 'Option<T>' with 'Some(T)' and 'None')
+    Union{name: String, variants: Vec<(String, UnionVariantKind)>},
+}
+
+pub enum CaseFault {
+    /// case can never be reached because earlier cases already match all its values
+    Unreachable{span: SoulSpan, msg: String},
+    /// case does not fit the variant (e.g 'Some(a, b)' for 'Some(T)')
+    WrongVariant{span: SoulSpan, msg: String},
+}
+
+pub struct MatchReport {
+    /// variants that no case matches (empty if match is exhaustive)
+    pub missing: Vec<String>,
+    pub faults: Vec<CaseFault>,
+}
+
+/// what values of the subject a case matches
+enum Coverage {
+    All,
+    Variant(String),
+    /// case of variant with a reported fault (e.g 'Rect(w)' for 'Rect(int, int)'), counts as matching it so it is not also missing
+    Reported(String),
+    /// only some values of variant (e.g 'Some(1)'), or a value that can not be checked
+    Partial,
+}
+
+/// checks if cases match every value of subject, reports unreachable cases and variants with the wrong amount of fields
+pub fn check_cases(subject: &MatchSubject, cases: &[CaseSwitch]) -> MatchReport {
+    let mut faults = vec![];
+    let mut covered: HashMap<String, SoulSpan> = HashMap::new();
+    let mut reported: HashSet<String> = HashSet::new();
+    let mut wildcard: Option<SoulSpan> = None;
+
+    for case in cases {
+        let span = case_span(case);
+
+        if let Some(wildcard) = wildcard {
+            faults.push(CaseFault::Unreachable{span, msg: format!("unreachable case, all values are already matched by case at {}:{}", wildcard.line_number, wildcard.line_offset)});
+            continue
+        }
+
+        match case_coverage(subject, &case.if_kind, span, &mut faults) {
+            Coverage::All => wildcard = Some(span),
+            Coverage::Variant(variant) => match covered.get(&variant) {
+                Some(other) => faults.push(CaseFault::Unreachable{span, msg: format!("unreachable case, '{}' is already matched by case at {}:{}", variant, other.line_number, other.line_offset)}),
+                None => {
+                    covered.insert(variant, span);
+                },
+            },
+            Coverage::Reported(variant) => {
+                reported.insert(variant);
+            },
+            Coverage::Partial => (),
+        }
+    }
+
+    let missing = match wildcard {
+        Some(_) => vec![],
+        None => variant_names(subject).into_iter()
+            .filter(|variant| !covered.contains_key(variant) && !reported.contains(variant))
+            .collect(),
+    };
+
+    MatchReport{missing, faults}
+}
+
+pub fn subject_name(subject: &MatchSubject) -> &str {
+    match subject {
+        MatchSubject::Bool => "bool",
+        MatchSubject::Enum{name, ..} |
+        MatchSubject::Union{name, ..} => name,
+    }
+}
+
+fn variant_names(subject: &MatchSubject) -> Vec<String> {
+    match subject {
+        MatchSubject::Bool => vec!["true".into(), "false".into()],
+        MatchSubject::Enum{variants, ..} => variants.clone(),
+        MatchSubject::Union{variants, ..} => variants.iter().map(|(name, _)| name.clone()).collect(),
+    }
+}
+
+fn case_coverage(subject: &MatchSubject, if_kind: &IfCaseKind, span: SoulSpan, faults: &mut Vec<CaseFault>) -> Coverage {
+
+    match (subject, if_kind) {
+        (MatchSubject::Bool, IfCaseKind::Expression(Expression{node: ExpressionKind::Literal(Literal::Bool(value)), ..})) => Coverage::Variant(value.to_string()),
+        (MatchSubject::Enum{variants, ..}, IfCaseKind::Expression(Expression{node: ExpressionKind::StaticField(static_field), ..})) if variants.contains(&static_field.field.name.0) => Coverage::Variant(static_field.field.name.0.clone()),
+        (_, IfCaseKind::Expression(_)) => Coverage::Partial,
+
+        (_, IfCaseKind::Variant{name, params}) => {
+            let variant = variant_names(subject).into_iter().find(|variant| variant == &name.0);
+
+            let variant = match variant {
+                Some(variant) => variant,
+                // 'value =>' binds the whole value
+                None if params.values.is_empty() => return Coverage::All,
+                None => {
+                    faults.push(CaseFault::WrongVariant{span, msg: format!("variant '{}' not found in '{}'", name.0, subject_name(subject))});
+                    return Coverage::Partial
+                },
+            };
+
+            let field = match subject {
+                MatchSubject::Union{variants, ..} => variants.iter().find(|(name, _)| name == &variant).map(|(_, field)| field),
+                _ => None,
+            };
+
+            match field {
+                Some(UnionVariantKind::Tuple(types)) if types.is_empty() && !params.values.is_empty() => {
+                    faults.push(CaseFault::WrongVariant{span, msg: format!("variant '{}' does not have fields", variant)});
+                    Coverage::Reported(variant)
+                },
+                Some(UnionVariantKind::Tuple(types)) if types.len() != params.values.len() => {
+                    faults.push(CaseFault::WrongVariant{span, msg: format!(
+                        "variant '{}' has {} field{} but case has {}",
+                        variant,
+                        types.len(),
+                        if types.len() == 1 { "" } else { "s" },
+                        params.values.len(),
+                    )});
+                    Coverage::Reported(variant)
+                },
+                Some(UnionVariantKind::NamedTuple(_)) if !params.values.is_empty() => {
+                    faults.push(CaseFault::WrongVariant{span, msg: format!("variant '{}' has named fields, use '{}{{..}}'", variant, variant)});
+                    Coverage::Reported(variant)
+                },
+                None if !params.values.is_empty() => {
+                    faults.push(CaseFault::WrongVariant{span, msg: format!("variant '{}' does not have fields", variant)});
+                    Coverage::Reported(variant)
+                },
+                _ if params.values.iter().all(is_binding) => Coverage::Variant(variant),
+                _ => Coverage::Partial,
+            }
+        },
+        (_, IfCaseKind::NamedVariant{name, params}) => {
+            let field = match subject {
+                MatchSubject::Union{variants, ..} => variants.iter().find(|(variant, _)| variant == &name.0).map(|(_, field)| field),
+                _ => None,
+            };
+
+            match field {
+                Some(UnionVariantKind::NamedTuple(fields)) => {
+                    let mut unknown = params.values.keys().filter(|field| !fields.contains_key(*field)).map(|field| field.0.clone()).collect::<Vec<_>>();
+                    unknown.sort();
+
+                    if !unknown.is_empty() {
+                        faults.push(CaseFault::WrongVariant{span, msg: format!("variant '{}' does not have field{} '{}'", name.0, if unknown.len() == 1 { "" } else { "s" }, unknown.join("', '"))});
+                        Coverage::Reported(name.0.clone())
+                    }
+                    else if params.values.values().all(is_binding) {
+                        Coverage::Variant(name.0.clone())
+                    }
+                    else {
+                        Coverage::Partial
+                    }
+                },
+                Some(UnionVariantKind::Tuple(_)) => {
+                    faults.push(CaseFault::WrongVariant{span, msg: format!("variant '{}' does not have named fields, use '{}(..)'", name.0, name.0)});
+                    Coverage::Reported(name.0.clone())
+                },
+                None => {
+                    faults.push(CaseFault::WrongVariant{span, msg: format!("variant '{}' not found in '{}'", name.0, subject_name(subject))});
+                    Coverage::Partial
+                },
+            }
+        },
+    }
+}
+
+/// field pattern that matches every value (e.g 'num' in 'Some(num)')
+fn is_binding(pattern: &Expression) -> bool {
+    matches!(&pattern.node, ExpressionKind::Variable(_))
+}
+
+fn case_span(case: &CaseSwitch) -> SoulSpan {
+    match (&case.if_kind, &case.do_fn) {
+        (IfCaseKind::Expression(expression), _) => expression.span,
+        (_, CaseDoKind::Block(block)) => block.span,
+        (_, CaseDoKind::Expression(expression)) => expression.span,
+    }
+}
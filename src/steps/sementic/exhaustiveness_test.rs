@@ -0,0 +1,146 @@
+use crate::steps::sementic::test_helpers::{check_types, error_messages, warning_messages};
+
+const TYPES: &str = "
+enum Color {
+    Red,
+    Green,
+    Blue,
+}
+
+union Option<T> {
+    Some(T),
+    None,
+}
+
+union Result<K, E> {
+    Ok(K),
+    Err(E),
+}
+";
+
+#[test]
+fn test_exhaustive_matches() {
+    let source = format!("{}
+matches(bool flag, Color color, Option<int> option, Result<int, str> result) {{
+    match flag {{
+        true => Println(1),
+        false => Println(2),
+    }}
+    match color {{
+        Color.Red => Println(1),
+        _ => Println(2),
+    }}
+    match option {{
+        Some(num) => Println(1),
+        None => (),
+    }}
+    match result {{
+        Ok(1) => Println(1),
+        Ok(value) => Println(2),
+        Err(msg) => Println(3),
+    }}
+}}
+", TYPES);
+
+    let (_, faults) = check_types(&source);
+    assert!(error_messages(&faults).is_empty(), "{:?}", error_messages(&faults));
+    assert!(warning_messages(&faults).is_empty(), "{:?}", warning_messages(&faults));
+}
+
+#[test]
+fn test_missing_variants() {
+    let source = format!("{}
+matches(bool flag, Color color, Option<int> option) {{
+    match flag {{
+        true => Println(1),
+    }}
+    match color {{
+        Color.Red => Println(1),
+        Color.Blue => Println(2),
+    }}
+    match option {{
+        Some(1) => Println(1),
+    }}
+}}
+", TYPES);
+
+    let (_, faults) = check_types(&source);
+    let errors = error_messages(&faults);
+    assert_eq!(errors.len(), 3, "{:?}", errors);
+    assert!(errors[0].contains("match on 'bool' is not exhaustive, missing 'false'"), "{}", errors[0]);
+    assert!(errors[1].contains("match on 'Color' is not exhaustive, missing 'Green'"), "{}", errors[1]);
+    assert!(errors[2].contains("match on 'Option' is not exhaustive, missing 'Some', 'None'"), "{}", errors[2]);
+}
+
+#[test]
+fn test_unreachable_cases_and_arity() {
+    let source = format!("{}
+matches(Color color, Option<int> option) {{
+    match color {{
+        Color.Red => Println(1),
+        Color.Red => Println(2),
+        other => Println(3),
+        Color.Blue => Println(4),
+    }}
+    match option {{
+        Some(a, b) => Println(1),
+        None(value) => Println(2),
+        Any(value) => Println(3),
+        _ => (),
+    }}
+}}
+", TYPES);
+
+    let (_, faults) = check_types(&source);
+    let warnings = warning_messages(&faults);
+    assert_eq!(warnings.len(), 2, "{:?}", warnings);
+    assert!(warnings[0].contains("unreachable case, 'Red' is already matched by case at 20:8"), "{}", warnings[0]);
+    assert!(warnings[1].contains("unreachable case, all values are already matched by case at"), "{}", warnings[1]);
+
+    let errors = error_messages(&faults);
+    assert_eq!(errors.len(), 3, "{:?}", errors);
+    assert!(errors[0].contains("variant 'Some' has 1 field but case has 2"), "{}", errors[0]);
+    assert!(errors[1].contains("variant 'None' does not have fields"), "{}", errors[1]);
+    assert!(errors[2].contains("variant 'Any' not found in 'Option'"), "{}", errors[2]);
+}
+
+#[test]
+fn test_wrong_arity_covers_variant() {
+    let source = "
+union Shape {
+    Circle(int),
+    Rect(int, int),
+}
+
+matches(Shape shape) {
+    match shape {
+        Circle(radius) => Println(1),
+        Rect(width) => Println(2),
+    }
+}
+";
+
+    let (_, faults) = check_types(source);
+    assert!(warning_messages(&faults).is_empty(), "{:?}", warning_messages(&faults));
+
+    let errors = error_messages(&faults);
+    assert_eq!(errors.len(), 1, "{:?}", errors);
+    assert!(errors[0].contains("variant 'Rect' has 2 fields but case has 1"), "{}", errors[0]);
+}
+
+#[test]
+fn test_missing_variant_of_inferred_union() {
+    let source = format!("{}
+main() {{
+    value := Some(1)
+    match value {{
+        Some(num) => Println(1),
+    }}
+}}
+", TYPES);
+
+    let (_, faults) = check_types(&source);
+    let errors = error_messages(&faults);
+    assert_eq!(errors.len(), 1, "{:?}", errors);
+    assert!(errors[0].contains("match on 'Option' is not exhaustive, missing 'None'"), "{}", errors[0]);
+}
@@ -1,5 +1,372 @@
-use crate::{steps::step_interfaces::{i_parser::{abstract_syntax_tree::{abstract_syntax_tree::AbstractSyntacTree}}, i_sementic::{ast_visitor::{AstAnalyser, ExternalHeaderAnalyser}}}};
+use std::collections::HashSet;
+use crate::{errors::soul_error::{new_soul_error, SoulError, SoulErrorKind, SoulSpan}, steps::{sementic::type_resolution::PATH_SEPERATOR, step_interfaces::{i_parser::{abstract_syntax_tree::{abstract_syntax_tree::AbstractSyntacTree, enum_like::EnumVariantKind, expression::{CaseDoKind, ElseKind, Expression, ExpressionGroup, ExpressionKind, ExternalExpression, Ident, If, IfCaseKind, UnwrapVariable}, function::LambdaBody, object::{Class, ClassChild}, soul_type::{soul_type::SoulType, type_kind::{ExternalPath, SoulPagePath, TypeKind}}, spanned::Spanned, statement::{Block, Statement, StatementKind}}, header::{starts_with_capital, ExternalHeaders, Header}, scope_builder::ScopeKind}, i_sementic::{ast_visitor::{AstAnalyser, ExternalHeaderAnalyser}, scope_vistitor::{Scope, ScopeVisitor}}}}};
 
 impl AstAnalyser for ExternalHeaderAnalyser {
-    fn analyse_ast(&mut self, _tree: &mut AbstractSyntacTree) {}
-}
\ No newline at end of file
+
+    fn analyse_ast(&mut self, tree: &mut AbstractSyntacTree) {
+        self.analyse_block(&mut tree.root);
+        self.analyse_scopes();
+    }
+}
+
+/// resolves the page part of a path (e.g 'this::std::test' in 'this::std::test::Sum') to its page path
+pub fn resolve_page_path(scope: &ScopeVisitor, segments: &[&str]) -> SoulPagePath {
+    let first = segments.first().copied().unwrap_or("");
+
+    let mut path = if first == "this" {
+        scope.project_name.clone()
+    }
+    else {
+
+        match scope.lookup_kind(first, |kind| matches!(kind, ScopeKind::Type(_))) {
+            Some(Spanned{node: ScopeKind::Type(SoulType{base: TypeKind::ExternalPath(external), ..}), span:_}) => external_path_page(&external.node).0,
+            _ => first.to_string(),
+        }
+    };
+
+    for segment in segments.iter().skip(1) {
+        path.push('.');
+        path.push_str(segment);
+    }
+
+    SoulPagePath(path)
+}
+
+/// looks up 'name' in the header of page, fails if page is unknown or name is not exported
+pub fn find_external_symbol<'a>(headers: &'a ExternalHeaders, path: &SoulPagePath, name: &str, span: SoulSpan) -> Result<&'a Vec<ScopeKind>, SoulError> {
+    let Header{scope} = headers.get(path)
+        .ok_or_else(|| new_soul_error(SoulErrorKind::InvalidPath, Some(span), format!("page: '{}' not found", path.0)))?;
+
+    if !starts_with_capital(name) {
+        return Err(new_soul_error(
+            SoulErrorKind::InvalidInContext,
+            Some(span),
+            format!("'{}' is not exported by page: '{}', only names that start with a capital letter can be used outside of their page", name, path.0),
+        ))
+    }
+
+    scope.get(name)
+        .ok_or_else(|| new_soul_error(SoulErrorKind::NotFoundInScope, Some(span), format!("'{}' not found in page: '{}'", name, path.0)))
+}
+
+fn external_path_page(external: &ExternalPath) -> SoulPagePath {
+    if external.path.0.is_empty() {
+        SoulPagePath(external.name.0.clone())
+    }
+    else {
+        SoulPagePath(external.to_string())
+    }
+}
+
+impl ExternalHeaderAnalyser {
+
+    fn analyse_statment(&mut self, statment: &mut Statement) {
+
+        let parent_id = self.get_scope().current_id();
+        if let Some(id) = statment.node.get_scope_id() && self.get_scope_mut().set_current(id).is_none() {
+            panic!("could not get scope_id({}) at line: {}:{}", id.0, statment.span.line_number, statment.span.line_offset)
+        }
+
+        match &mut statment.node {
+            StatementKind::Trait(_) => (),
+            StatementKind::Union(_) => (),
+            StatementKind::CloseBlock => (),
+            StatementKind::TypeEnum(_) => (),
+            StatementKind::Variable(_) => (),
+
+            StatementKind::Enum(enum_) => if let EnumVariantKind::Expression(variants) = &mut enum_.variants {
+
+                for variant in variants {
+                    self.analyse_expression(&mut variant.value);
+                }
+            },
+            StatementKind::Struct(struct_) => for field in &mut struct_.fields {
+
+                if let Some(value) = &mut field.node.default_value {
+                    self.analyse_expression(value);
+                }
+            },
+            StatementKind::Assignment(assignment) => {
+                self.analyse_expression(&mut assignment.variable);
+                self.analyse_expression(&mut assignment.value);
+            },
+            StatementKind::Class(class) => self.analyse_class(class, statment.span),
+            StatementKind::Function(function) => self.analyse_block(&mut function.block),
+            StatementKind::Expression(expression) => self.analyse_expression(expression),
+            StatementKind::UseBlock(use_block) => self.analyse_block(&mut use_block.block),
+        }
+
+        self.get_scope_mut()
+            .set_current(parent_id)
+            .expect("scope_id should be valid");
+    }
+
+    fn analyse_expression(&mut self, expression: &mut Expression) {
+
+        let parent_id = self.get_scope().current_id();
+        if let Some(id) = expression.node.get_scope_id() && self.get_scope_mut().set_current(id).is_none() {
+            panic!("could not get scope_id({}) at line: {}:{}", id.0, expression.span.line_number, expression.span.line_offset)
+        }
+
+        let span = expression.span;
+        let mut external = None;
+
+        match &mut expression.node {
+            ExpressionKind::Empty => (),
+            ExpressionKind::Default => (),
+            ExpressionKind::Literal(_) => (),
+            ExpressionKind::Variable(_) => (),
+            ExpressionKind::StaticField(_) => (),
+
+            ExpressionKind::ExternalExpression(ExternalExpression{path, expr}) => {
+
+                let name = match &mut expr.node {
+                    ExpressionKind::Variable(variable) => Some(variable.name.0.clone()),
+                    ExpressionKind::FunctionCall(function_call) => {
+
+                        for argument in &mut function_call.arguments.values {
+                            self.analyse_expression(argument);
+                        }
+                        Some(function_call.name.0.clone())
+                    },
+                    _ => None,
+                };
+
+                if let Some(name) = name {
+                    self.check_external_symbol(path, &name, span);
+                }
+            },
+            ExpressionKind::FunctionCall(function_call) => {
+
+                if let Some(callee) = &mut function_call.callee {
+                    self.analyse_expression(callee);
+                }
+
+                for argument in &mut function_call.arguments.values {
+                    self.analyse_expression(argument);
+                }
+
+                if function_call.callee.is_none() && let Some((path, name)) = self.try_get_external_call(&function_call.name.0) {
+                    self.check_external_symbol(&path, &name, span);
+
+                    let mut call = function_call.clone();
+                    call.name = Ident::new(name);
+                    external = Some(ExternalExpression{path, expr: Box::new(Expression::new(ExpressionKind::FunctionCall(call), span))});
+                }
+            },
+            ExpressionKind::Index(index) => {
+                self.analyse_expression(&mut index.collection);
+                self.analyse_expression(&mut index.index);
+            },
+            ExpressionKind::Lambda(lambda) => match &mut lambda.body {
+                LambdaBody::Block(block) => self.analyse_block(block),
+                LambdaBody::Expression(body) => self.analyse_expression(body),
+            },
+            ExpressionKind::StructConstructor(struct_constructor) => for value in struct_constructor.arguments.values.values_mut() {
+                self.analyse_expression(value);
+            },
+            ExpressionKind::AccessField(access_field) => self.analyse_expression(&mut access_field.object),
+            ExpressionKind::StaticMethod(static_method) => for argument in &mut static_method.arguments.values {
+                self.analyse_expression(argument);
+            },
+            ExpressionKind::UnwrapVariable(unwrap_variable) => if let UnwrapVariable::MultiVariable{initializer: Some(initializer), ..} = unwrap_variable {
+                self.analyse_expression(initializer);
+            },
+            ExpressionKind::Unary(unary) => self.analyse_expression(&mut unary.expression),
+            ExpressionKind::Binary(binary) => {
+                self.analyse_expression(&mut binary.left);
+                self.analyse_expression(&mut binary.right);
+            },
+            ExpressionKind::If(if_decl) => self.analyse_if(if_decl),
+            ExpressionKind::For(for_decl) => {
+                self.analyse_expression(&mut for_decl.collection);
+                self.analyse_block(&mut for_decl.block);
+            },
+            ExpressionKind::While(while_decl) => {
+                if let Some(condition) = &mut while_decl.condition {
+                    self.analyse_expression(condition);
+                }
+                self.analyse_block(&mut while_decl.block);
+            },
+            ExpressionKind::Match(match_decl) => {
+                self.analyse_expression(&mut match_decl.condition);
+
+                for case in &mut match_decl.cases {
+
+                    if let IfCaseKind::Expression(pattern) = &mut case.if_kind {
+                        self.analyse_expression(pattern);
+                    }
+
+                    match &mut case.do_fn {
+                        CaseDoKind::Block(block) => self.analyse_block(&mut block.node),
+                        CaseDoKind::Expression(value) => self.analyse_expression(value),
+                    }
+                }
+            },
+            ExpressionKind::Ternary(ternary) => {
+                self.analyse_expression(&mut ternary.condition);
+                self.analyse_expression(&mut ternary.if_branch);
+                self.analyse_expression(&mut ternary.else_branch);
+            },
+            ExpressionKind::Deref(inner) |
+            ExpressionKind::MutRef(inner) |
+            ExpressionKind::ConstRef(inner) |
+            ExpressionKind::Copy(inner) => self.analyse_expression(inner),
+            ExpressionKind::Block(block) => self.analyse_block(block),
+            ExpressionKind::ReturnLike(return_like) => if let Some(value) = &mut return_like.value {
+                self.analyse_expression(value);
+            },
+            ExpressionKind::ExpressionGroup(group) => match group {
+                ExpressionGroup::Tuple(tuple) => for value in &mut tuple.values {
+                    self.analyse_expression(value);
+                },
+                ExpressionGroup::Array(array) => for value in &mut array.values {
+                    self.analyse_expression(value);
+                },
+                ExpressionGroup::NamedTuple(named_tuple) => for value in named_tuple.values.values_mut() {
+                    self.analyse_expression(value);
+                },
+                ExpressionGroup::ArrayFiller(array_filler) => {
+                    self.analyse_expression(&mut array_filler.amount);
+                    self.analyse_expression(&mut array_filler.fill_expr);
+                },
+            },
+        }
+
+        if let Some(external) = external {
+            expression.node = ExpressionKind::ExternalExpression(external);
+        }
+
+        self.get_scope_mut()
+            .set_current(parent_id)
+            .expect("scope_id should be valid");
+    }
+
+    /// splits 'this::std::test::Sum' into page path and name, if the path before the name is a page or folder
+    fn try_get_external_call(&self, name: &str) -> Option<(SoulPagePath, String)> {
+        let mut segments = name.split(PATH_SEPERATOR).collect::<Vec<_>>();
+        if segments.len() < 2 {
+            return None
+        }
+
+        let name = segments.pop()?.to_string();
+        let scope = self.get_scope();
+        let path = resolve_page_path(scope, &segments);
+
+        let is_external = segments[0] == "this" ||
+            scope.external_headers.contains_path(&path) ||
+            matches!(
+                scope.lookup_kind(segments[0], |kind| matches!(kind, ScopeKind::Type(_))),
+                Some(Spanned{node: ScopeKind::Type(SoulType{base: TypeKind::ExternalPath(_), ..}), span:_})
+            );
+
+        is_external.then_some((path, name))
+    }
+
+    fn check_external_symbol(&mut self, path: &SoulPagePath, name: &str, span: SoulSpan) {
+        if let Err(err) = find_external_symbol(&self.get_scope().external_headers, path, name, span) {
+            self.add_error(err);
+        }
+    }
+
+    /// true if page exists, a missing page is reported once per span (e.g. 'use path.to.[thing, stuff]' adds 2 symbols of the same page)
+    fn check_external_page(&mut self, page: &SoulPagePath, span: SoulSpan, missing_pages: &mut HashSet<(SoulPagePath, SoulSpan)>) -> bool {
+
+        if self.get_scope().external_headers.contains_path(page) {
+            return true
+        }
+
+        if missing_pages.insert((page.clone(), span)) {
+            self.add_error(new_soul_error(SoulErrorKind::InvalidPath, Some(span), format!("page: '{}' not found", page.0)));
+        }
+        false
+    }
+
+    fn analyse_if(&mut self, if_decl: &mut If) {
+        self.analyse_expression(&mut if_decl.condition);
+        self.analyse_block(&mut if_decl.block);
+
+        for branch in &mut if_decl.else_branchs {
+
+            match &mut branch.node {
+                ElseKind::Else(block) => self.analyse_block(&mut block.node),
+                ElseKind::ElseIf(else_if) => self.analyse_if(&mut else_if.node),
+            }
+        }
+    }
+
+    fn analyse_class(&mut self, class: &mut Class, span: SoulSpan) {
+
+        for child in &mut class.children {
+
+            let parent_id = self.get_scope().current_id();
+            if let Some(id) = child.get_scope_id() && self.get_scope_mut().set_current(id).is_none() {
+                panic!("could not get scope_id({}) at line: {}:{}", id.0, span.line_number, span.line_offset)
+            }
+
+            match child {
+                ClassChild::Field(field) => if let Some(value) = &mut field.node.default_value {
+                    self.analyse_expression(value);
+                },
+                ClassChild::Methode(methode) => self.analyse_block(&mut methode.node.block),
+                ClassChild::ImplBlock(impl_block) => self.analyse_block(&mut impl_block.node.block),
+            }
+
+            self.get_scope_mut()
+                .set_current(parent_id)
+                .expect("scope_id should be valid");
+        }
+    }
+
+    fn analyse_block(&mut self, block: &mut Block) {
+
+        for statment in &mut block.statments {
+            self.analyse_statment(statment);
+        }
+    }
+
+    fn analyse_scopes(&mut self) {
+
+        let parent_id = self.get_scope().current_id();
+        let mut missing_pages = HashSet::new();
+        for index in 0..self.get_scope().get_scopes().len() {
+
+            let mut scope = self.get_scope().get_scopes()[index].clone();
+            self.get_scope_mut().set_current(scope.self_index);
+
+            self.analyse_scope(&mut scope, &mut missing_pages);
+            self.get_scope_mut().get_scopes_mut()[index] = scope;
+        }
+
+        self.get_scope_mut()
+            .set_current(parent_id)
+            .expect("scope_id should be valid");
+    }
+
+    fn analyse_scope(&mut self, scope: &mut Scope, missing_pages: &mut HashSet<(SoulPagePath, SoulSpan)>) {
+
+        for symbools in scope.symbols.values_mut() {
+
+            for symbool in symbools {
+
+                let page = match &symbool.node {
+                    ScopeKind::Type(SoulType{base: TypeKind::ExternalType(external), ..}) => Some(external.node.path.clone()),
+                    ScopeKind::Type(SoulType{base: TypeKind::ExternalPath(external), ..}) => Some(external_path_page(&external.node)),
+                    _ => None,
+                };
+
+                if let Some(page) = page && !self.check_external_page(&page, symbool.span, missing_pages) {
+                    continue
+                }
+
+                match &mut symbool.node {
+                    ScopeKind::Type(SoulType{base: TypeKind::ExternalType(external), ..}) => self.check_external_symbol(&external.node.path, &external.node.name.0, symbool.span),
+                    ScopeKind::Variable(variable) => if let Some(value) = &mut variable.initialize_value {
+                        self.analyse_expression(value);
+                    },
+                    _ => (),
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,65 @@
+use std::collections::HashMap;
+use crate::steps::{sementic::test_helpers::{check_types_with_headers, error_messages, parse_header}, step_interfaces::i_parser::{abstract_syntax_tree::soul_type::type_kind::SoulPagePath, header::ExternalHeaders}};
+
+fn test_headers() -> ExternalHeaders {
+    let page = "
+Sum(int a, int b) int {
+    return a + b
+}
+
+secret(int a) int {
+    return a
+}
+
+struct Point {
+    int x
+}
+";
+
+    let mut headers = HashMap::new();
+    headers.insert(SoulPagePath("testProject.std.test".into()), parse_header(page));
+    ExternalHeaders::from_headers(headers)
+}
+
+#[test]
+fn test_resolve_external_references() {
+    let source = "
+use this.std.test.[Sum, Point]
+use this.std.test.[this]
+
+main() {
+    sum := this::std::test::Sum(1, 2)
+    this::std::test::Point point = this::std::test::Point(1)
+    test::Point other = point
+}
+";
+
+    let (_, faults) = check_types_with_headers(source, test_headers());
+    assert!(error_messages(&faults).is_empty(), "{:?}", error_messages(&faults));
+}
+
+#[test]
+fn test_unknown_external_references() {
+    let source = "
+use this.std.tests.[Sum, Point, this]
+use this.std.test.[Missing]
+use this.other.[this]
+
+main() {
+    sum := this::std::test::secret(1)
+    this::std::test::Vector vector = 1
+}
+";
+
+    let (_, faults) = check_types_with_headers(source, test_headers());
+    let mut errors = error_messages(&faults);
+    errors.sort();
+    assert_eq!(errors.len(), 5, "{:?}", errors);
+
+    // page of a 'use' is reported once not per name
+    assert_eq!(errors.iter().filter(|err| err.contains("at 2:") && err.contains("page: 'testProject.std.tests' not found")).count(), 1, "{:?}", errors);
+    assert!(errors.iter().any(|err| err.contains("at 3:") && err.contains("'Missing' not found in page: 'testProject.std.test'")), "{:?}", errors);
+    assert!(errors.iter().any(|err| err.contains("at 4:") && err.contains("page: 'testProject.other' not found")), "{:?}", errors);
+    assert!(errors.iter().any(|err| err.contains("at 7:") && err.contains("'secret' is not exported by page: 'testProject.std.test'")), "{:?}", errors);
+    assert!(errors.iter().any(|err| err.contains("at 8:") && err.contains("'Vector' not found in page: 'testProject.std.test'")), "{:?}", errors);
+}
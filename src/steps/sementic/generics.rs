@@ -0,0 +1,523 @@
+use itertools::Itertools;
+use std::collections::{BTreeMap, BTreeSet};
+use crate::{errors::soul_error::SoulSpan, steps::{sementic::{type_checking::{default_untyped, is_assignable, substitute_generics, type_name}, type_resolution::type_with_generics}, step_interfaces::{i_parser::{abstract_syntax_tree::{enum_like::{Union, UnionVariantKind}, expression::{Expression, ExpressionKind, Ident, UnaryOperatorKind}, function::{Function, FunctionSignature, Parameter}, generic::{GenericKind, GenericParameter, TypeConstraint}, literal::Literal, object::{Class, ClassChild, Struct}, soul_type::{soul_type::{Modifier, SoulType, TypeGenericKind, TypeWrapper}, type_kind::TypeKind}, spanned::Spanned, statement::{Block, StatementKind}}, scope_builder::ScopeKind}, i_sementic::{generic_instances::{GenericBindings, GenericInstanceKind}, scope_vistitor::ScopeVisitor}}}};
+
+/// (implementor, trait) of every implementation (e.g 'class Num impl Add' or 'use int impl Debug')
+pub type TraitImpls = Vec<(SoulType, Ident)>;
+
+/// collects every trait implementation of classes, impl blocks and use blocks in block
+pub fn collect_trait_impls(block: &Block, impls: &mut TraitImpls) {
+
+    for statment in &block.statments {
+
+        match &statment.node {
+            StatementKind::Class(class) => {
+                let this_type = type_with_generics(TypeKind::Class(class.name.clone()), &class.generics);
+
+                let impl_traits = class.children.iter()
+                    .filter_map(|child| match child {
+                        ClassChild::ImplBlock(impl_block) => impl_block.node.impl_trait.as_ref(),
+                        _ => None,
+                    });
+
+                for trait_type in class.implements.iter().chain(impl_traits) {
+
+                    if let TypeKind::Trait(name) = &trait_type.base {
+                        impls.push((this_type.clone(), name.clone()));
+                    }
+                }
+            },
+            StatementKind::UseBlock(use_block) => {
+
+                if let Some(TypeKind::Trait(name)) = use_block.impl_trait.as_ref().map(|ty| &ty.base) {
+                    impls.push((use_block.ty.clone(), name.clone()));
+                }
+                collect_trait_impls(&use_block.block, impls);
+            },
+            StatementKind::Function(function) => collect_trait_impls(&function.block, impls),
+            _ => (),
+        }
+    }
+}
+
+/// true if ty implements trait_name directly or through a trait that has trait_name as supertrait
+pub fn implements_trait(scope: &ScopeVisitor, impls: &TraitImpls, ty: &SoulType, trait_name: &Ident) -> bool {
+    implemented_traits(scope, impls, ty).contains(trait_name)
+}
+
+/// every trait ty implements including supertraits, a type enum implements the traits that all its members implement
+pub fn implemented_traits(scope: &ScopeVisitor, impls: &TraitImpls, ty: &SoulType) -> BTreeSet<Ident> {
+    let mut traits = impls.iter()
+        .filter(|(implementor, _)| implementor.base == ty.base && implementor.wrappers == ty.wrappers)
+        .map(|(_, name)| name.clone())
+        .collect::<Vec<_>>();
+
+    if ty.wrappers.is_empty() && let TypeKind::TypeEnum(_, members) = &ty.base {
+        traits.extend(common_traits(scope, impls, members));
+    }
+
+    let mut index = 0;
+    while index < traits.len() {
+
+        if let Some(ScopeKind::Trait(trait_)) = scope.lookup_kind(&traits[index].0, |kind| matches!(kind, ScopeKind::Trait(_))).map(|symbol| &symbol.node) {
+
+            for super_trait in &trait_.signature.implements {
+
+                if let TypeKind::Trait(name) = &super_trait.base && !traits.contains(name) {
+                    traits.push(name.clone());
+                }
+            }
+        }
+
+        index += 1;
+    }
+
+    traits.into_iter().collect()
+}
+
+/// intersection of the traits of members (e.g. the interface of 'type Number impl[int, f32]')
+pub fn common_traits(scope: &ScopeVisitor, impls: &TraitImpls, members: &[SoulType]) -> BTreeSet<Ident> {
+    members.iter()
+        .map(|member| implemented_traits(scope, impls, member))
+        .reduce(|common, traits| common.intersection(&traits).cloned().collect())
+        .unwrap_or_default()
+}
+
+/// name and generics of a generic declaration
+pub fn declaration_generics(declaration: &GenericInstanceKind) -> (&Ident, &[GenericParameter]) {
+    match declaration {
+        GenericInstanceKind::Function(function) => (&function.signature.name, &function.signature.generics),
+        GenericInstanceKind::Struct(struct_) => (&struct_.name, &struct_.generics),
+        GenericInstanceKind::Class(class) => (&class.name, &class.generics),
+        GenericInstanceKind::Union(union) => (&union.name, &union.generics),
+    }
+}
+
+/// binds explicit generics (e.g 'int' in 'sumGeneric<int>(1, 2)') in order of declaration
+pub fn bind_explicit(name: &str, generics: &[GenericParameter], explicit: &[TypeGenericKind]) -> Result<GenericBindings, String> {
+    let explicit = explicit.iter()
+        .filter(|generic| !matches!(generic, TypeGenericKind::Lifetime(_)))
+        .collect::<Vec<_>>();
+
+    let amount = instantiable(generics).count();
+    if explicit.len() > amount {
+        return Err(format!("'{}' has {} generic{} but got {}", name, amount, if amount == 1 { "" } else { "s" }, explicit.len()))
+    }
+
+    Ok(
+        instantiable(generics)
+            .zip(explicit)
+            .map(|(generic, binding)| (generic.name.clone(), binding.clone()))
+            .collect()
+    )
+}
+
+/// infers unbound generics from the types of the arguments, array_lengths has the amount of elements of array literal arguments
+pub fn infer_from_arguments(name: &str, generics: &[GenericParameter], parameters: &[Spanned<Parameter>], argument_types: &[Option<SoulType>], array_lengths: &[Option<usize>], span: SoulSpan, bindings: &mut GenericBindings) -> Vec<String> {
+    let mut faults = vec![];
+
+    for (index, (parameter, argument_type)) in parameters.iter().zip(argument_types).enumerate() {
+
+        let argument_type = match argument_type {
+            Some(ty) => ty,
+            None => continue,
+        };
+
+        let array_length = array_lengths.get(index).copied().flatten();
+        if let Some(fault) = infer_array_size(generics, &parameter.node, argument_type, array_length, name, span, bindings) {
+            faults.push(fault);
+        }
+
+        infer_type(generics, &parameter.node.ty, argument_type, bindings);
+    }
+
+    faults
+}
+
+/// binds generics that are not given or infered to their default, returns the generics that have no binding
+pub fn bind_defaults(generics: &[GenericParameter], bindings: &mut GenericBindings) -> Vec<Ident> {
+    let mut missing = vec![];
+
+    for generic in instantiable(generics) {
+
+        if bindings.contains_key(&generic.name) {
+            continue
+        }
+
+        match &generic.kind {
+            GenericKind::Type{default: Some(default), ..} => {
+                let mut default = default.clone();
+                substitute(&mut default, bindings);
+                bindings.insert(generic.name.clone(), TypeGenericKind::Type(default));
+            },
+            _ => missing.push(generic.name.clone()),
+        }
+    }
+
+    missing
+}
+
+/// checks bindings against the constraints of generics, returns a message for every violated constraint
+pub fn check_constraints(scope: &ScopeVisitor, impls: &TraitImpls, name: &str, generics: &[GenericParameter], bindings: &GenericBindings) -> Vec<String> {
+    let mut faults = vec![];
+
+    for generic in instantiable(generics) {
+
+        let (binding, impl_type) = match (bindings.get(&generic.name), &generic.kind) {
+            (Some(binding), GenericKind::Type{impl_type, ..}) => (binding, impl_type),
+            _ => continue,
+        };
+
+        match (impl_type, binding) {
+            (Some(value_type), TypeGenericKind::Expression(value)) => {
+
+                if let Some(literal) = literal_value(value) && !is_valid_value(value_type, &literal) {
+                    faults.push(format!("generic '{}' of '{}' has to be a value of type '{}' but is '{}'", generic.name, name, type_name(value_type), literal.value_to_string()));
+                }
+            },
+            (Some(value_type), TypeGenericKind::Type(ty)) => {
+                faults.push(format!("generic '{}' of '{}' has to be a value of type '{}' but got type '{}'", generic.name, name, type_name(value_type), type_name(ty)));
+            },
+            (None, TypeGenericKind::Expression(value)) => {
+                faults.push(format!("generic '{}' of '{}' has to be a type but got value '{}'", generic.name, name, binding_to_string(&TypeGenericKind::Expression(value.clone()))));
+            },
+            (None, TypeGenericKind::Type(ty)) if is_concrete(ty) => for constraint in &generic.constraint {
+
+                if let Some(fault) = check_constraint(scope, impls, constraint, ty, &generic.name, name) {
+                    faults.push(fault);
+                }
+            },
+            _ => (),
+        }
+    }
+
+    faults
+}
+
+/// false if type still depends on a generic (e.g 'T[]' in a call from another generic function) or could not be resolved
+pub fn is_concrete(ty: &SoulType) -> bool {
+
+    let is_concrete_base = match &ty.base {
+        TypeKind::Generic(_) | TypeKind::Unknown(_) | TypeKind::Custom(_) |
+        TypeKind::ExternalType(_) | TypeKind::ExternalPath(_) | TypeKind::LifeTime(_) => false,
+        TypeKind::Tuple(types) => types.iter().all(is_concrete),
+        TypeKind::NamedTuple(types) => types.values().all(is_concrete),
+        _ => true,
+    };
+
+    is_concrete_base &&
+    ty.generics.iter().all(is_concrete_binding) &&
+    ty.wrappers.iter().all(|wrapper| !matches!(wrapper, TypeWrapper::StackArrayGeneric(_)))
+}
+
+pub fn is_concrete_binding(binding: &TypeGenericKind) -> bool {
+    match binding {
+        TypeGenericKind::Type(ty) => is_concrete(ty),
+        TypeGenericKind::Expression(value) => literal_value(value).is_some(),
+        TypeGenericKind::Lifetime(_) => true,
+    }
+}
+
+/// name of instance (e.g 'sumGeneric<int>' or 'sumStackArray<5>')
+pub fn instance_name(name: &str, generics: &[GenericParameter], bindings: &GenericBindings) -> String {
+    let arguments = instantiable(generics)
+        .map(|generic| match bindings.get(&generic.name) {
+            Some(binding) => binding_to_string(binding),
+            None => generic.name.0.clone(),
+        })
+        .join(",");
+
+    format!("{}<{}>", name, arguments)
+}
+
+fn binding_to_string(binding: &TypeGenericKind) -> String {
+    match binding {
+        TypeGenericKind::Type(ty) => type_name(ty),
+        TypeGenericKind::Expression(value) if let Some(literal) = literal_value(value) => literal.value_to_string(),
+        _ => binding.to_string(),
+    }
+}
+
+/// replaces generic types and array sizes (e.g 'int[N]' -> 'int[5]') by their binding
+pub fn substitute(ty: &mut SoulType, bindings: &GenericBindings) {
+    let types = bindings.iter()
+        .filter_map(|(name, binding)| match binding {
+            TypeGenericKind::Type(ty) => Some((name.clone(), ty.clone())),
+            _ => None,
+        })
+        .collect::<BTreeMap<_, _>>();
+
+    substitute_generics(ty, &types);
+    substitute_array_sizes(ty, bindings);
+}
+
+pub fn substitute_signature(signature: &mut FunctionSignature, bindings: &GenericBindings) {
+    signature.generics.clear();
+
+    for parameter in &mut signature.parameters {
+        substitute(&mut parameter.node.ty, bindings);
+    }
+
+    if let Some(return_type) = &mut signature.return_type {
+        substitute(return_type, bindings);
+    }
+
+    if let Some(callee) = &mut signature.callee {
+        substitute(&mut callee.node.extention_type, bindings);
+
+        if let Some(this) = &mut callee.node.this {
+            substitute(this, bindings);
+        }
+    }
+}
+
+/// monomorphized copy of declaration
+pub fn instantiate(declaration: &GenericInstanceKind, bindings: &GenericBindings) -> GenericInstanceKind {
+    match declaration {
+        GenericInstanceKind::Function(function) => GenericInstanceKind::Function(Box::new(instantiate_function(function, bindings))),
+        GenericInstanceKind::Struct(struct_) => GenericInstanceKind::Struct(instantiate_struct(struct_, bindings)),
+        GenericInstanceKind::Class(class) => GenericInstanceKind::Class(instantiate_class(class, bindings)),
+        GenericInstanceKind::Union(union) => GenericInstanceKind::Union(instantiate_union(union, bindings)),
+    }
+}
+
+fn instantiate_function(function: &Function, bindings: &GenericBindings) -> Function {
+    let mut function = function.clone();
+    substitute_signature(&mut function.signature, bindings);
+    function
+}
+
+fn instantiate_struct(struct_: &Struct, bindings: &GenericBindings) -> Struct {
+    let mut struct_ = struct_.clone();
+    struct_.generics.clear();
+
+    for field in &mut struct_.fields {
+        substitute(&mut field.node.ty, bindings);
+    }
+
+    struct_
+}
+
+fn instantiate_class(class: &Class, bindings: &GenericBindings) -> Class {
+    let mut class = class.clone();
+    class.generics.clear();
+
+    for implement in &mut class.implements {
+        substitute(implement, bindings);
+    }
+
+    for child in &mut class.children {
+
+        match child {
+            ClassChild::Field(field) => substitute(&mut field.node.ty, bindings),
+            ClassChild::Methode(methode) => substitute_signature(&mut methode.node.signature, bindings),
+            ClassChild::ImplBlock(impl_block) => {
+
+                for statment in &mut impl_block.node.block.statments {
+
+                    if let StatementKind::Function(function) = &mut statment.node {
+                        substitute_signature(&mut function.signature, bindings);
+                    }
+                }
+            },
+        }
+    }
+
+    class
+}
+
+fn instantiate_union(union: &Union, bindings: &GenericBindings) -> Union {
+    let mut union = union.clone();
+    union.generics.clear();
+
+    for variant in &mut union.variants {
+
+        match &mut variant.node.field {
+            UnionVariantKind::Tuple(types) => for ty in types {
+                substitute(ty, bindings);
+            },
+            UnionVariantKind::NamedTuple(types) => for ty in types.values_mut() {
+                substitute(ty, bindings);
+            },
+        }
+    }
+
+    union
+}
+
+/// generics that take a type or value (lifetimes are not instantiated)
+fn instantiable(generics: &[GenericParameter]) -> impl Iterator<Item = &GenericParameter> {
+    generics.iter().filter(|generic| matches!(generic.kind, GenericKind::Type{..}))
+}
+
+fn is_generic_of(generics: &[GenericParameter], name: &Ident) -> bool {
+    instantiable(generics).any(|generic| &generic.name == name)
+}
+
+/// binds generic of parameter type to the matching part of argument type (e.g 'T' of 'T[]' with 'int[]' is 'int')
+fn infer_type(generics: &[GenericParameter], parameter: &SoulType, argument: &SoulType, bindings: &mut GenericBindings) {
+
+    match (&parameter.base, &argument.base) {
+        (TypeKind::Generic(name), _) if is_generic_of(generics, name) => {
+
+            if bindings.contains_key(name) || argument.wrappers.len() < parameter.wrappers.len() {
+                return
+            }
+
+            let mut binding = default_untyped(argument.clone()).with_mod(Modifier::Default);
+            binding.wrappers.truncate(argument.wrappers.len() - parameter.wrappers.len());
+
+            if is_concrete(&binding) {
+                bindings.insert(name.clone(), TypeGenericKind::Type(binding));
+            }
+        },
+        (TypeKind::Tuple(parameters), TypeKind::Tuple(arguments)) => for (parameter, argument) in parameters.iter().zip(arguments) {
+            infer_type(generics, parameter, argument, bindings);
+        },
+        (parameter_base, argument_base) if parameter_base == argument_base => for pair in parameter.generics.iter().zip(&argument.generics) {
+
+            if let (TypeGenericKind::Type(parameter), TypeGenericKind::Type(argument)) = pair {
+                infer_type(generics, parameter, argument, bindings);
+            }
+        },
+        _ => (),
+    }
+}
+
+/// binds 'N' of parameter 'int[N] arr' to the size of the argument, reports a size that does not match an explicit 'N'
+fn infer_array_size(generics: &[GenericParameter], parameter: &Parameter, argument: &SoulType, array_length: Option<usize>, name: &str, span: SoulSpan, bindings: &mut GenericBindings) -> Option<String> {
+
+    let size_name = match parameter.ty.wrappers.last() {
+        Some(TypeWrapper::StackArrayGeneric(size)) => match &size.base {
+            TypeKind::Generic(size_name) if is_generic_of(generics, size_name) => size_name,
+            _ => return None,
+        },
+        _ => return None,
+    };
+
+    let length = match argument.wrappers.last() {
+        Some(TypeWrapper::StackArray(length)) => *length as usize,
+        Some(TypeWrapper::Array) => array_length?,
+        _ => return None,
+    };
+
+    match bindings.get(size_name) {
+        None => {
+            let value = Expression::new(ExpressionKind::Literal(Literal::Uint(length as u64)), span);
+            bindings.insert(size_name.clone(), TypeGenericKind::Expression(value));
+            None
+        },
+        Some(TypeGenericKind::Expression(value)) => match literal_size(value) {
+            Some(size) if size as usize != length => Some(format!(
+                "argument '{}' of '{}' has {} element{} but '{}' is {}",
+                parameter.name,
+                name,
+                length,
+                if length == 1 { "" } else { "s" },
+                size_name,
+                size,
+            )),
+            _ => None,
+        },
+        Some(_) => None,
+    }
+}
+
+fn substitute_array_sizes(ty: &mut SoulType, bindings: &GenericBindings) {
+
+    for wrapper in &mut ty.wrappers {
+
+        let size = match wrapper {
+            TypeWrapper::StackArrayGeneric(size) => match &size.base {
+                TypeKind::Generic(name) => bindings.get(name),
+                _ => None,
+            },
+            _ => None,
+        };
+
+        if let Some(TypeGenericKind::Expression(value)) = size && let Some(size) = literal_size(value) {
+            *wrapper = TypeWrapper::StackArray(size);
+        }
+    }
+
+    for generic in &mut ty.generics {
+        if let TypeGenericKind::Type(generic) = generic {
+            substitute_array_sizes(generic, bindings);
+        }
+    }
+
+    if let TypeKind::Tuple(types) = &mut ty.base {
+        for ty in types {
+            substitute_array_sizes(ty, bindings);
+        }
+    }
+}
+
+/// size of array given by a literal value generic (e.g '5' in 'sumStackArray<5>')
+fn literal_size(value: &Expression) -> Option<u32> {
+    match literal_value(value)? {
+        Literal::Int(size) => u32::try_from(size).ok(),
+        Literal::Uint(size) => u32::try_from(size).ok(),
+        _ => None,
+    }
+}
+
+/// value of a literal value generic, negative numbers (e.g '-1') are parsed as negated literal
+fn literal_value(value: &Expression) -> Option<Literal> {
+    match &value.node {
+        ExpressionKind::Literal(literal) => Some(literal.clone()),
+        ExpressionKind::Unary(unary) if matches!(unary.operator.node, UnaryOperatorKind::Neg) => match literal_value(&unary.expression)? {
+            Literal::Int(value) => Some(Literal::Int(-value)),
+            Literal::Uint(value) => i64::try_from(value).ok().map(|value| Literal::Int(-value)),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+fn is_valid_value(value_type: &SoulType, literal: &Literal) -> bool {
+    let is_unsigned = matches!(value_type.base, TypeKind::Uint(_) | TypeKind::SystemUint | TypeKind::UntypedUint);
+
+    is_assignable(value_type, &literal.to_soul_type()) &&
+    !(is_unsigned && matches!(literal, Literal::Int(value) if *value < 0))
+}
+
+fn check_constraint(scope: &ScopeVisitor, impls: &TraitImpls, constraint: &TypeConstraint, ty: &SoulType, generic_name: &Ident, name: &str) -> Option<String> {
+
+    let is_valid = match constraint {
+        TypeConstraint::LiteralTypeEnum(body) => body.types.iter().any(|member| is_same_type(member, ty)),
+        TypeConstraint::Type(constraint_type) => match &constraint_type.base {
+            TypeKind::Trait(trait_name) => {
+
+                return match implements_trait(scope, impls, ty, trait_name) {
+                    true => None,
+                    false => Some(format!("'{}' does not implement trait '{}' required by generic '{}' of '{}'", type_name(ty), trait_name, generic_name, name)),
+                }
+            },
+            TypeKind::TypeEnum(enum_name, members) => {
+                matches!(&ty.base, TypeKind::TypeEnum(other, _) if other == enum_name) ||
+                members.iter().any(|member| is_same_type(member, ty))
+            },
+            // not found, already reported by type resolution
+            TypeKind::Unknown(_) | TypeKind::Generic(_) => true,
+            _ => is_assignable(constraint_type, ty),
+        },
+    };
+
+    match is_valid {
+        true => None,
+        false => Some(format!("generic '{}' of '{}' has to be {} but is '{}'", generic_name, name, constraint_to_string(constraint), type_name(ty))),
+    }
+}
+
+fn constraint_to_string(constraint: &TypeConstraint) -> String {
+    match constraint {
+        TypeConstraint::LiteralTypeEnum(body) => format!("one of typeof[{}]", body.types.iter().map(type_name).join(",")),
+        TypeConstraint::Type(SoulType{base: TypeKind::TypeEnum(name, _), ..}) => format!("one of '{}'", name),
+        TypeConstraint::Type(ty) => format!("'{}'", type_name(ty)),
+    }
+}
+
+fn is_same_type(member: &SoulType, ty: &SoulType) -> bool {
+    type_name(member) == type_name(ty)
+}
@@ -0,0 +1,155 @@
+use crate::steps::{sementic::test_helpers::{check_types, error_messages}, step_interfaces::{i_parser::abstract_syntax_tree::soul_type::{soul_type::TypeWrapper, type_kind::{TypeKind, TypeSize}}, i_sementic::generic_instances::GenericInstanceKind}};
+
+const SUM_GENERIC: &str = "
+sumGeneric<T: impl[u8, i8, int]>(T a, T b) T {
+    return a + b
+}
+";
+
+#[test]
+fn test_generic_function_instances() {
+    let source = format!("{}
+main() {{
+    sumGeneric<int>(1, 2)
+    sumGeneric<u8>(1, 2)
+    sumGeneric(3, 4)
+}}
+", SUM_GENERIC);
+
+    let (scope, faults) = check_types(&source);
+    assert!(error_messages(&faults).is_empty(), "{:?}", error_messages(&faults));
+
+    let names = scope.generic_instances.iter().map(|instance| instance.name.as_str()).collect::<Vec<_>>();
+    assert_eq!(names, vec!["sumGeneric<int>", "sumGeneric<u8>"]);
+
+    let instance = scope.generic_instances.get("sumGeneric<u8>").expect("instance should be recorded");
+    let function = match &instance.kind {
+        GenericInstanceKind::Function(function) => function,
+        other => panic!("instance should be a function but is {:?}", other),
+    };
+
+    assert!(function.signature.generics.is_empty());
+    assert!(function.signature.parameters.iter().all(|parameter| parameter.node.ty.base == TypeKind::Uint(TypeSize::Bit8)));
+    assert_eq!(function.signature.return_type.as_ref().map(|ty| &ty.base), Some(&TypeKind::Uint(TypeSize::Bit8)));
+}
+
+#[test]
+fn test_generic_constraint_violations() {
+    let source = format!("{}
+trait Debug {{
+    ToString() str
+}}
+
+use int impl Debug {{
+    ToString() str {{
+        return \"int\"
+    }}
+}}
+
+show<T: Debug>(T value) str {{
+    return value.ToString()
+}}
+
+main() {{
+    sumGeneric<str>(\"a\", \"b\")
+    sumGeneric<int>(1, \"b\")
+    show(1)
+    show(true)
+}}
+", SUM_GENERIC);
+
+    let (scope, faults) = check_types(&source);
+    let errors = error_messages(&faults);
+    assert_eq!(errors.len(), 3, "{:?}", errors);
+
+    assert!(errors.iter().any(|err| err.contains("at 21:") && err.contains("generic 'T' of 'sumGeneric' has to be one of typeof[u8,i8,int] but is 'str'")), "{:?}", errors);
+    assert!(errors.iter().any(|err| err.contains("at 22:") && err.contains("argument 'b' of function 'sumGeneric' should be 'int' but is 'str'")), "{:?}", errors);
+    assert!(errors.iter().any(|err| err.contains("at 24:") && err.contains("'bool' does not implement trait 'Debug' required by generic 'T' of 'show'")), "{:?}", errors);
+
+    assert!(!scope.generic_instances.contains("sumGeneric<str>"));
+    assert!(scope.generic_instances.contains("show<int>"));
+    assert!(!scope.generic_instances.contains("show<bool>"));
+}
+
+#[test]
+fn test_value_generics() {
+    let source = "
+sumStackArray<N impl uint>(int[N] arr) int {
+    return 1
+}
+
+main() {
+    sumStackArray([1, 2, 3])
+    sumStackArray<5>([1, 2, 3, 4, 5])
+    sumStackArray<4>([1, 2, 3, 4, 5])
+    sumStackArray<-1>([1])
+}
+";
+
+    let (scope, faults) = check_types(source);
+    let errors = error_messages(&faults);
+    assert_eq!(errors.len(), 2, "{:?}", errors);
+
+    assert!(errors.iter().any(|err| err.contains("at 9:") && err.contains("argument 'arr' of 'sumStackArray' has 5 elements but 'N' is 4")), "{:?}", errors);
+    assert!(errors.iter().any(|err| err.contains("at 10:") && err.contains("generic 'N' of 'sumStackArray' has to be a value of type 'uint' but is '-1'")), "{:?}", errors);
+
+    let names = scope.generic_instances.iter().map(|instance| instance.name.as_str()).collect::<Vec<_>>();
+    assert_eq!(names, vec!["sumStackArray<3>", "sumStackArray<5>"]);
+
+    match &scope.generic_instances.get("sumStackArray<3>").expect("instance should be recorded").kind {
+        GenericInstanceKind::Function(function) => assert_eq!(function.signature.parameters[0].node.ty.wrappers, vec![TypeWrapper::StackArray(3)]),
+        other => panic!("instance should be a function but is {:?}", other),
+    }
+}
+
+#[test]
+fn test_generic_struct_instances() {
+    let source = "
+struct Box<T: impl[int, str]> {
+    T value
+}
+
+main() {
+    Box<int> number = Box<int>{value: 1}
+    text := Box{value: \"hello\"}
+    Box<bool> flag = Box<bool>{value: true}
+}
+";
+
+    let (scope, faults) = check_types(source);
+    let errors = error_messages(&faults);
+    assert_eq!(errors.len(), 1, "{:?}", errors);
+    assert!(errors[0].contains("at 9:") && errors[0].contains("generic 'T' of 'Box' has to be one of typeof[int,str] but is 'bool'"), "{}", errors[0]);
+
+    let names = scope.generic_instances.iter().map(|instance| instance.name.as_str()).collect::<Vec<_>>();
+    assert_eq!(names, vec!["Box<int>", "Box<str>"]);
+
+    match &scope.generic_instances.get("Box<str>").expect("instance should be recorded").kind {
+        GenericInstanceKind::Struct(struct_) => assert_eq!(struct_.fields[0].node.ty.base, TypeKind::Str),
+        other => panic!("instance should be a struct but is {:?}", other),
+    }
+}
+
+#[test]
+fn test_generic_class_constructor() {
+    let source = "
+class GenClass<T> {
+    T field = ()
+
+    This() {
+        return This{}
+    }
+
+    This(T field) {
+        return GenClass{field: field}
+    }
+
+    getField(this@) T@ {
+        return this.field
+    }
+}
+";
+
+    let (_, faults) = check_types(source);
+    assert!(error_messages(&faults).is_empty(), "{:?}", error_messages(&faults));
+}
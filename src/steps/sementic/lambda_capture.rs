@@ -0,0 +1,431 @@
+use crate::{errors::soul_error::{new_soul_error, pass_soul_error, SoulErrorKind, SoulSpan}, steps::{sementic::{borrow_checker::{call_signatures, consumes_argument, is_auto_copy, is_consume_methode}, generics::{collect_trait_impls, TraitImpls}, ruleset::takes_mut_this, type_checking::find_callee_functions}, step_interfaces::{i_parser::{abstract_syntax_tree::{abstract_syntax_tree::AbstractSyntacTree, expression::{CaseDoKind, ElseKind, Expression, ExpressionGroup, ExpressionKind, If, ReturnKind, UnaryOperatorKind, UnwrapVariable, VariableName}, function::{Capture, CaptureKind, Lambda, LambdaBody, LambdaMode}, object::ClassChild, soul_type::{soul_type::SoulType, type_kind::TypeKind}, statement::{Block, Statement, StatementKind}}, scope_builder::{ScopeId, ScopeKind, Variable}}, i_sementic::{ast_visitor::{AstAnalyser, LambdaCaptureAnalyser}, scope_vistitor::ScopeVisitor}}}};
+
+/// lambda whose body is being analysed
+struct OpenLambda {
+    scope_id: ScopeId,
+    captures: Vec<Capture>,
+}
+
+#[derive(Default)]
+struct CaptureContext {
+    /// innermost lambda last
+    lambdas: Vec<OpenLambda>,
+    impls: TraitImpls,
+}
+
+impl AstAnalyser for LambdaCaptureAnalyser {
+    fn analyse_ast(&mut self, tree: &mut AbstractSyntacTree) {
+        let mut context = CaptureContext::default();
+        collect_trait_impls(&tree.root, &mut context.impls);
+        self.analyse_block(&mut tree.root, &mut context);
+    }
+}
+
+impl LambdaCaptureAnalyser {
+
+    fn analyse_block(&mut self, block: &mut Block, context: &mut CaptureContext) {
+        let parent_id = self.get_scope().current_id();
+        self.get_scope_mut().set_current(block.scope_id);
+
+        for statment in &mut block.statments {
+            self.analyse_statment(statment, context);
+        }
+
+        self.get_scope_mut()
+            .set_current(parent_id)
+            .expect("scope_id should be valid");
+    }
+
+    fn analyse_statment(&mut self, statment: &mut Statement, context: &mut CaptureContext) {
+        let span = statment.span;
+
+        let parent_id = self.get_scope().current_id();
+        if let Some(id) = statment.node.get_scope_id() && self.get_scope_mut().set_current(id).is_none() {
+            panic!("could not get scope_id({}) at line: {}:{}", id.0, span.line_number, span.line_offset)
+        }
+
+        match &mut statment.node {
+            StatementKind::Enum(_) |
+            StatementKind::Trait(_) |
+            StatementKind::Union(_) |
+            StatementKind::Struct(_) |
+            StatementKind::TypeEnum(_) |
+            StatementKind::CloseBlock => (),
+
+            StatementKind::Expression(expression) => self.analyse_expression(expression, CaptureKind::ConstRef, context),
+            StatementKind::Variable(variable_name) => self.analyse_variable(&variable_name.name.0, context),
+            StatementKind::Assignment(assignment) => {
+                self.analyse_expression(&mut assignment.value, CaptureKind::ConstRef, context);
+                self.analyse_expression(&mut assignment.variable, CaptureKind::MutRef, context);
+            },
+            StatementKind::Function(function) => self.analyse_block(&mut function.block, context),
+            StatementKind::UseBlock(use_block) => self.analyse_block(&mut use_block.block, context),
+            StatementKind::Class(class) => {
+
+                for child in &mut class.children {
+                    match child {
+                        ClassChild::Field(_) => (),
+                        ClassChild::Methode(methode) => self.analyse_block(&mut methode.node.block, context),
+                        ClassChild::ImplBlock(impl_block) => self.analyse_block(&mut impl_block.node.block, context),
+                    }
+                }
+            },
+        }
+
+        self.get_scope_mut()
+            .set_current(parent_id)
+            .expect("scope_id should be valid");
+    }
+
+    fn analyse_variable(&mut self, name: &str, context: &mut CaptureContext) {
+        let mut value = match self.get_variable(name) {
+            Some(Variable{initialize_value: Some(value), ..}) => value.clone(),
+            _ => return,
+        };
+
+        self.analyse_expression(&mut value, CaptureKind::ConstRef, context);
+
+        let variable = self.get_scope_mut()
+            .flat_lookup_mut(name)
+            .and_then(|kinds| kinds.iter_mut().find_map(|kind| match &mut kind.node {
+                ScopeKind::Variable(variable) => Some(variable),
+                _ => None,
+            }));
+
+        if let Some(variable) = variable {
+            // type of variable is inferred before the mode of the lambda is known
+            if let (ExpressionKind::Lambda(lambda), TypeKind::Lambda(signature)) = (&value.node, &mut variable.ty.base) {
+                signature.mode = lambda.signature.mode.clone();
+            }
+            variable.initialize_value = Some(value);
+        }
+    }
+
+    /// 'usage' is how the value of expression is used (e.g. 'MutRef' for the left side of an assignment)
+    fn analyse_expression(&mut self, expression: &mut Expression, usage: CaptureKind, context: &mut CaptureContext) {
+        let span = expression.span;
+
+        let parent_id = self.get_scope().current_id();
+        if let Some(id) = expression.node.get_scope_id() && self.get_scope_mut().set_current(id).is_none() {
+            panic!("could not get scope_id({}) at line: {}:{}", id.0, span.line_number, span.line_offset)
+        }
+
+        self.analyse_expression_kind(expression, usage, context);
+
+        self.get_scope_mut()
+            .set_current(parent_id)
+            .expect("scope_id should be valid");
+    }
+
+    fn analyse_expression_kind(&mut self, expression: &mut Expression, usage: CaptureKind, context: &mut CaptureContext) {
+        let span = expression.span;
+
+        match &mut expression.node {
+            ExpressionKind::Empty |
+            ExpressionKind::Default |
+            ExpressionKind::Literal(_) |
+            ExpressionKind::StaticField(_) |
+            ExpressionKind::ExternalExpression(_) => (),
+
+            ExpressionKind::Variable(variable_name) => self.capture(variable_name, usage, context),
+            ExpressionKind::Lambda(lambda) => {
+                for argument in &mut lambda.arguments.values {
+                    self.analyse_expression(argument, CaptureKind::ConstRef, context);
+                }
+
+                context.lambdas.push(OpenLambda{scope_id: lambda.scope_id, captures: vec![]});
+                match &mut lambda.body {
+                    LambdaBody::Block(block) => self.analyse_block(block, context),
+                    // value of body is returned
+                    LambdaBody::Expression(body) => self.analyse_expression(body, CaptureKind::Consume, context),
+                }
+
+                let captures = context.lambdas.pop().expect("lambda should be pushed").captures;
+                self.set_captures(lambda, captures, span);
+            },
+            ExpressionKind::FunctionCall(function_call) => {
+                let callee_type = match function_call.callee.as_ref().map(|callee| &callee.node) {
+                    Some(ExpressionKind::Variable(variable_name)) => self.get_variable_type(&variable_name.name.0),
+                    _ => None,
+                };
+                let signatures = call_signatures(self.get_scope(), function_call, callee_type.as_ref());
+
+                for (index, argument) in function_call.arguments.values.iter_mut().enumerate() {
+                    // argument of a consuming parameter is moved into the call
+                    let usage = match consumes_argument(&signatures, index) {
+                        true => CaptureKind::Consume,
+                        false => CaptureKind::ConstRef,
+                    };
+                    self.analyse_expression(argument, usage, context);
+                }
+
+                match &mut function_call.callee {
+                    Some(callee) => {
+                        let usage = self.callee_usage(callee, &function_call.name.0);
+                        self.analyse_expression(callee, usage, context);
+                    },
+                    // calling a captured lambda uses it as its mode requires
+                    None => if let Some(ty) = self.get_variable_type(&function_call.name.0) && let TypeKind::Lambda(signature) = &ty.base {
+                        let usage = match signature.mode {
+                            LambdaMode::Const => CaptureKind::ConstRef,
+                            LambdaMode::Mut => CaptureKind::MutRef,
+                            LambdaMode::Consume => CaptureKind::Consume,
+                        };
+
+                        let variable_name = VariableName{name: function_call.name.clone(), span};
+                        self.capture(&variable_name, usage, context);
+                    },
+                }
+            },
+            ExpressionKind::StructConstructor(struct_constructor) => for value in struct_constructor.arguments.values.values_mut() {
+                self.analyse_expression(value, CaptureKind::ConstRef, context);
+            },
+            // moving a field or element out of a captured variable is not supported so reading it only needs a reference
+            ExpressionKind::AccessField(access_field) => self.analyse_expression(&mut access_field.object, place_usage(usage), context),
+            ExpressionKind::Index(index) => {
+                self.analyse_expression(&mut index.index, CaptureKind::ConstRef, context);
+                self.analyse_expression(&mut index.collection, place_usage(usage), context);
+            },
+            ExpressionKind::StaticMethod(static_method) => for argument in &mut static_method.arguments.values {
+                self.analyse_expression(argument, CaptureKind::ConstRef, context);
+            },
+            ExpressionKind::UnwrapVariable(unwrap_variable) => {
+                if let UnwrapVariable::MultiVariable{initializer: Some(initializer), ..} = unwrap_variable {
+                    self.analyse_expression(initializer, CaptureKind::ConstRef, context);
+                }
+            },
+            ExpressionKind::Unary(unary) => match unary.operator.node {
+                UnaryOperatorKind::Increment{..} |
+                UnaryOperatorKind::Decrement{..} => self.analyse_expression(&mut unary.expression, CaptureKind::MutRef, context),
+                _ => self.analyse_expression(&mut unary.expression, CaptureKind::ConstRef, context),
+            },
+            ExpressionKind::Binary(binary) => {
+                self.analyse_expression(&mut binary.left, CaptureKind::ConstRef, context);
+                self.analyse_expression(&mut binary.right, CaptureKind::ConstRef, context);
+            },
+            ExpressionKind::If(if_decl) => self.analyse_if(if_decl, context),
+            ExpressionKind::For(for_decl) => {
+                self.analyse_expression(&mut for_decl.collection, CaptureKind::ConstRef, context);
+                self.analyse_block(&mut for_decl.block, context);
+            },
+            ExpressionKind::While(while_decl) => {
+                if let Some(condition) = &mut while_decl.condition {
+                    self.analyse_expression(condition, CaptureKind::ConstRef, context);
+                }
+                self.analyse_block(&mut while_decl.block, context);
+            },
+            ExpressionKind::Match(match_decl) => {
+                self.analyse_expression(&mut match_decl.condition, CaptureKind::ConstRef, context);
+
+                for case in &mut match_decl.cases {
+                    let parent_id = self.get_scope().current_id();
+                    self.get_scope_mut().set_current(case.scope_id);
+
+                    match &mut case.do_fn {
+                        CaseDoKind::Block(block) => self.analyse_block(&mut block.node, context),
+                        CaseDoKind::Expression(do_expression) => self.analyse_expression(do_expression, CaptureKind::ConstRef, context),
+                    }
+
+                    self.get_scope_mut()
+                        .set_current(parent_id)
+                        .expect("scope_id should be valid");
+                }
+            },
+            ExpressionKind::Ternary(ternary) => {
+                self.analyse_expression(&mut ternary.condition, CaptureKind::ConstRef, context);
+                self.analyse_expression(&mut ternary.if_branch, usage.clone(), context);
+                self.analyse_expression(&mut ternary.else_branch, usage, context);
+            },
+            ExpressionKind::MutRef(inner) => self.analyse_expression(inner, CaptureKind::MutRef, context),
+            // writing through a reference only reads the captured reference
+            ExpressionKind::Deref(inner) |
+            ExpressionKind::ConstRef(inner) |
+            ExpressionKind::Copy(inner) => self.analyse_expression(inner, CaptureKind::ConstRef, context),
+            ExpressionKind::Block(block) => self.analyse_block(block, context),
+            ExpressionKind::ReturnLike(return_like) => if let Some(value) = &mut return_like.value {
+                let usage = match return_like.kind {
+                    ReturnKind::Return => CaptureKind::Consume,
+                    _ => CaptureKind::ConstRef,
+                };
+                self.analyse_expression(value, usage, context);
+            },
+            ExpressionKind::ExpressionGroup(expression_group) => match expression_group {
+                ExpressionGroup::Tuple(tuple) => for value in &mut tuple.values {
+                    self.analyse_expression(value, CaptureKind::ConstRef, context);
+                },
+                ExpressionGroup::Array(array) => for value in &mut array.values {
+                    self.analyse_expression(value, CaptureKind::ConstRef, context);
+                },
+                ExpressionGroup::NamedTuple(named_tuple) => for value in named_tuple.values.values_mut() {
+                    self.analyse_expression(value, CaptureKind::ConstRef, context);
+                },
+                ExpressionGroup::ArrayFiller(array_filler) => {
+                    self.analyse_expression(&mut array_filler.amount, CaptureKind::ConstRef, context);
+                    self.analyse_expression(&mut array_filler.fill_expr, CaptureKind::ConstRef, context);
+                },
+            },
+        }
+    }
+
+    fn analyse_if(&mut self, if_decl: &mut If, context: &mut CaptureContext) {
+        self.analyse_expression(&mut if_decl.condition, CaptureKind::ConstRef, context);
+        self.analyse_block(&mut if_decl.block, context);
+
+        for branch in &mut if_decl.else_branchs {
+            match &mut branch.node {
+                ElseKind::Else(block) => self.analyse_block(&mut block.node, context),
+                ElseKind::ElseIf(else_if) => self.analyse_if(&mut else_if.node, context),
+            }
+        }
+    }
+
+    /// 'Consume' for a methode that takes 'this' by value, 'MutRef' for a methode that mutates 'this'
+    fn callee_usage(&self, callee: &Expression, name: &str) -> CaptureKind {
+
+        let ty = match &callee.node {
+            ExpressionKind::Variable(variable_name) => self.get_variable_type(&variable_name.name.0),
+            _ => None,
+        };
+
+        let methodes = match ty {
+            Some(ty) => find_callee_functions(self.get_scope(), name, &ty, true),
+            None => return CaptureKind::ConstRef,
+        };
+
+        if !methodes.is_empty() && methodes.iter().all(is_consume_methode) {
+            CaptureKind::Consume
+        }
+        else if methodes.iter().any(|methode| takes_mut_this(&methode)) {
+            CaptureKind::MutRef
+        }
+        else {
+            CaptureKind::ConstRef
+        }
+    }
+
+    /// adds variable to the captures of every open lambda that is declared inside of the scope of variable
+    fn capture(&mut self, variable_name: &VariableName, usage: CaptureKind, context: &mut CaptureContext) {
+        let name = &variable_name.name.0;
+
+        if context.lambdas.is_empty() {
+            return
+        }
+
+        let declared_in = match self.declaring_scope(name) {
+            Some(id) if id != ScopeVisitor::GLOBAL_SCOPE_INDEX => id,
+            _ => return,
+        };
+
+        // copy types are copied into the lambda instead of consumed
+        let kind = match usage {
+            CaptureKind::Consume if self.get_variable_type(name).is_none_or(|ty| is_auto_copy(self.get_scope(), &context.impls, &ty)) => CaptureKind::ConstRef,
+            _ => usage,
+        };
+
+        for lambda in &mut context.lambdas {
+
+            if is_inside(self.get_scope(), declared_in, lambda.scope_id) {
+                continue
+            }
+
+            match lambda.captures.iter_mut().find(|capture| &capture.variable.name.0 == name) {
+                Some(capture) if capture.kind < kind => {
+                    capture.kind = kind.clone();
+                    capture.variable.span = variable_name.span;
+                },
+                Some(_) => (),
+                None => lambda.captures.push(Capture{variable: variable_name.clone(), kind: kind.clone()}),
+            }
+        }
+    }
+
+    /// picks the weakest mode that can hold captures, reports captures that the declared mode can not hold
+    fn set_captures(&mut self, lambda: &mut Lambda, captures: Vec<Capture>, span: SoulSpan) {
+        let strongest = captures.iter().max_by(|a, b| a.kind.cmp(&b.kind));
+        let required = strongest.map(|capture| capture.kind.required_mode()).unwrap_or(LambdaMode::Const);
+
+        match &lambda.declared_mode {
+            Some(declared) if !declared.includes(&required) => {
+                let capture = strongest.expect("required mode is only stronger than ConstFn if there is a capture");
+
+                let error = new_soul_error(
+                    SoulErrorKind::InvalidInContext,
+                    Some(span),
+                    format!(
+                        "lambda is declared as '{}' but has to be '{}' because it captures '{}' as {}",
+                        declared.get_lambda_name(),
+                        required.get_lambda_name(),
+                        capture.variable.name,
+                        capture.kind.to_str(),
+                    ),
+                );
+                let error = pass_soul_error(SoulErrorKind::InvalidInContext, Some(capture.variable.span), format!("'{}' is captured here", capture.variable.name), error);
+                self.add_error(error);
+
+                lambda.signature.mode = declared.clone();
+            },
+            Some(declared) => lambda.signature.mode = declared.clone(),
+            None => lambda.signature.mode = required,
+        }
+
+        lambda.captures = captures;
+    }
+
+    /// scope (seen from the current scope) that declares variable name
+    fn declaring_scope(&self, name: &str) -> Option<ScopeId> {
+        let scopes = self.get_scope().get_scopes();
+        let mut current_index = Some(self.get_scope().current_id());
+
+        while let Some(index) = current_index {
+            let scope = &scopes[index.0];
+
+            let is_variable = scope.get(name)
+                .is_some_and(|kinds| kinds.iter().any(|kind| matches!(kind.node, ScopeKind::Variable(_))));
+
+            if is_variable {
+                return Some(index)
+            }
+
+            current_index = scope.parent_index;
+        }
+
+        None
+    }
+
+    fn get_variable(&self, name: &str) -> Option<&Variable> {
+
+        match &self.get_scope().lookup_kind(name, |kind| matches!(kind, ScopeKind::Variable(_)))?.node {
+            ScopeKind::Variable(variable) => Some(variable),
+            _ => None,
+        }
+    }
+
+    fn get_variable_type(&self, name: &str) -> Option<SoulType> {
+        self.get_variable(name)
+            .filter(|variable| !variable.ty.is_none_type())
+            .map(|variable| variable.ty.clone())
+    }
+}
+
+/// true if scope 'id' is 'parent' or one of its (grand)children
+fn is_inside(scope: &ScopeVisitor, id: ScopeId, parent: ScopeId) -> bool {
+    let mut current_index = Some(id);
+
+    while let Some(index) = current_index {
+        if index == parent {
+            return true
+        }
+
+        current_index = scope.get_scopes()[index.0].parent_index;
+    }
+
+    false
+}
+
+/// usage of the root of a field access or index
+fn place_usage(usage: CaptureKind) -> CaptureKind {
+    match usage {
+        CaptureKind::MutRef => CaptureKind::MutRef,
+        _ => CaptureKind::ConstRef,
+    }
+}
@@ -0,0 +1,229 @@
+use crate::steps::{sementic::test_helpers::{check_tree_borrows, error_messages, parse_source, variable_type}, step_interfaces::{i_parser::{abstract_syntax_tree::{abstract_syntax_tree::AbstractSyntacTree, expression::{ExpressionKind, Tuple}, function::{CaptureKind, Lambda, LambdaBody, LambdaMode, LambdaSignature}, soul_type::type_kind::TypeKind, spanned::Spanned, statement::{Block, StatementKind}}, scope_builder::ScopeKind}, i_sementic::{scope_vistitor::ScopeVisitor, soul_fault::SoulFault}}};
+
+const METHODES: &str = "
+int[] Clear(this&) {
+}
+
+int[] consumeToEl(mut this, int a) int[] {
+    this[0] = a
+    return this
+}
+";
+
+/// the parser can not make lambdas yet, so every block statement becomes a lambda with that block as body
+/// (as value of the variable declared or assigned right before the block, otherwise as expression statement)
+/// blocks of an if are searched too
+fn check_lambdas(source: &str, declared_mode: Option<LambdaMode>) -> (AbstractSyntacTree, ScopeVisitor, Vec<SoulFault>) {
+    let (mut tree, mut scope) = parse_source(source);
+
+    for statment in &mut tree.root.statments {
+        if let StatementKind::Function(function) = &mut statment.node {
+            into_lambdas(&mut function.block, &mut scope, &declared_mode);
+        }
+    }
+
+    check_tree_borrows(tree, scope)
+}
+
+fn into_lambdas(block: &mut Block, scope: &mut ScopeVisitor, declared_mode: &Option<LambdaMode>) {
+    let statments = std::mem::take(&mut block.statments);
+
+    for mut statment in statments {
+        let mut body = match &mut statment.node {
+            StatementKind::Expression(Spanned{node: ExpressionKind::Block(body), ..}) => body.clone(),
+            StatementKind::Expression(Spanned{node: ExpressionKind::If(if_decl), ..}) => {
+                into_lambdas(&mut if_decl.block, scope, declared_mode);
+                block.statments.push(statment);
+                continue
+            },
+            _ => {
+                block.statments.push(statment);
+                continue
+            },
+        };
+
+        into_lambdas(&mut body, scope, declared_mode);
+        let lambda = Spanned::new(ExpressionKind::Lambda(Lambda{
+            signature: LambdaSignature{params: vec![], return_type: None, mode: LambdaMode::Const, has_return: false},
+            arguments: Tuple{values: vec![]},
+            scope_id: body.scope_id,
+            body: LambdaBody::Block(body),
+            captures: vec![],
+            declared_mode: declared_mode.clone(),
+        }), statment.span);
+
+        if let Some(StatementKind::Assignment(assignment)) = block.statments.last_mut().map(|statment| &mut statment.node) {
+            assignment.value = lambda;
+            continue
+        }
+
+        let variable = match block.statments.last().map(|statment| &statment.node) {
+            Some(StatementKind::Variable(variable_name)) => scope.get_scopes_mut()[block.scope_id.0]
+                .get_mut(&variable_name.name.0)
+                .and_then(|kinds| kinds.iter_mut().find_map(|kind| match &mut kind.node {
+                    ScopeKind::Variable(variable) => Some(variable),
+                    _ => None,
+                })),
+            _ => None,
+        };
+
+        match variable {
+            Some(variable) => variable.initialize_value = Some(lambda),
+            None => {
+                statment.node = StatementKind::Expression(lambda);
+                block.statments.push(statment);
+            },
+        }
+    }
+}
+
+fn lambda_of<'a>(scope: &'a ScopeVisitor, name: &str) -> &'a Lambda {
+    scope.get_scopes().iter()
+        .filter_map(|scope| scope.get(name))
+        .flatten()
+        .find_map(|kind| match &kind.node {
+            ScopeKind::Variable(variable) => match &variable.initialize_value.as_ref()?.node {
+                ExpressionKind::Lambda(lambda) => Some(lambda),
+                _ => None,
+            },
+            _ => None,
+        })
+        .unwrap_or_else(|| panic!("variable '{}' should be a lambda", name))
+}
+
+fn captures(lambda: &Lambda) -> Vec<(String, CaptureKind)> {
+    lambda.captures.iter()
+        .map(|capture| (capture.variable.name.0.clone(), capture.kind.clone()))
+        .collect()
+}
+
+#[test]
+fn test_infer_captures() {
+    let source = format!("{}
+main() {{
+    int count = 1
+    str name = \"soul\"
+    int[] list = [1, 2]
+    int[] other = [3]
+
+    reader := 0
+    {{
+        Println(name)
+        Println(count)
+    }}
+    counter := 0
+    {{
+        local := count + 1
+        count = local
+        list.Clear()
+    }}
+    consumer := 0
+    {{
+        el := other.consumeToEl(1)
+        Println(name)
+        return count
+    }}
+}}
+", METHODES);
+
+    let (_, scope, faults) = check_lambdas(&source, None);
+    assert!(error_messages(&faults).is_empty(), "{:?}", error_messages(&faults));
+
+    let reader = lambda_of(&scope, "reader");
+    assert_eq!(captures(reader), vec![("name".into(), CaptureKind::ConstRef), ("count".into(), CaptureKind::ConstRef)]);
+    assert_eq!(reader.signature.mode, LambdaMode::Const);
+
+    let counter = lambda_of(&scope, "counter");
+    assert_eq!(captures(counter), vec![("count".into(), CaptureKind::MutRef), ("list".into(), CaptureKind::MutRef)]);
+    assert_eq!(counter.signature.mode, LambdaMode::Mut);
+    assert!(matches!(variable_type(&scope, "counter").base, TypeKind::Lambda(signature) if signature.mode == LambdaMode::Mut));
+
+    // 'count' is an int so returning it copies it
+    let consumer = lambda_of(&scope, "consumer");
+    assert_eq!(captures(consumer), vec![("other".into(), CaptureKind::Consume), ("name".into(), CaptureKind::ConstRef), ("count".into(), CaptureKind::ConstRef)]);
+    assert_eq!(consumer.signature.mode, LambdaMode::Consume);
+}
+
+#[test]
+fn test_nested_lambda_captures() {
+    let source = "
+main() {
+    int count = 1
+    outer := 0
+    {
+        int local = 2
+        inner := 0
+        {
+            count = local
+        }
+    }
+}
+";
+    let (_, scope, faults) = check_lambdas(source, None);
+    assert!(error_messages(&faults).is_empty(), "{:?}", error_messages(&faults));
+
+    assert_eq!(captures(lambda_of(&scope, "inner")), vec![("local".into(), CaptureKind::ConstRef), ("count".into(), CaptureKind::MutRef)]);
+    assert_eq!(captures(lambda_of(&scope, "outer")), vec![("count".into(), CaptureKind::MutRef)]);
+}
+
+#[test]
+fn test_declared_mode_conflict() {
+    let source = "
+main() {
+    int count = 1
+    counter := 0
+    {
+        count = 2
+    }
+}
+";
+    let (_, _, faults) = check_lambdas(source, Some(LambdaMode::Const));
+    let errors = error_messages(&faults);
+    assert_eq!(errors.len(), 1, "{:?}", errors);
+    assert!(errors[0].contains("lambda is declared as 'ConstFn' but has to be 'MutFn' because it captures 'count' as mutable reference"), "{}", errors[0]);
+    assert!(errors[0].contains("at 6:8| 'count' is captured here"), "{}", errors[0]);
+
+    let (_, scope, faults) = check_lambdas(source, Some(LambdaMode::Consume));
+    assert!(error_messages(&faults).is_empty(), "{:?}", error_messages(&faults));
+    assert_eq!(lambda_of(&scope, "counter").signature.mode, LambdaMode::Consume);
+}
+
+#[test]
+fn test_escaping_lambda() {
+    let source = "
+main() {
+    int count = 1
+    mut reader := 0
+    {
+        Println(count)
+    }
+    if true {
+        int local = 2
+        reader = 0
+        {
+            Println(local)
+        }
+    }
+    reader()
+}
+";
+    let (_, _, faults) = check_lambdas(source, None);
+    let errors = error_messages(&faults);
+    assert_eq!(errors.len(), 1, "{:?}", errors);
+    assert!(errors[0].contains("'reader' is used after the variable it references ('local') went out of scope"), "{}", errors[0]);
+
+    let source = format!("{}
+main() {{
+    int[] other = [3]
+    consumer := 0
+    {{
+        el := other.consumeToEl(1)
+    }}
+    Println(other)
+}}
+", METHODES);
+    let (_, _, faults) = check_lambdas(&source, None);
+    let errors = error_messages(&faults);
+    assert_eq!(errors.len(), 1, "{:?}", errors);
+    assert!(errors[0].contains("'other' is used after being consumed"), "{}", errors[0]);
+}
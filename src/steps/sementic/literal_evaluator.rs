@@ -0,0 +1,1328 @@
+use std::collections::{BTreeMap, HashMap, HashSet};
+use crate::{errors::soul_error::{new_soul_error, pass_soul_error, SoulError, SoulErrorKind, SoulSpan}, steps::{sementic::{overload_resolution::resolve_overload, type_checking::{find_functions, type_name}}, step_interfaces::{i_parser::{abstract_syntax_tree::{abstract_syntax_tree::AbstractSyntacTree, enum_like::{Enum, EnumVariantKind}, expression::{BinaryOperatorKind, CaseDoKind, ElseKind, Expression, ExpressionGroup, ExpressionKind, Ident, If, IfCaseKind, ReturnKind, UnaryOperatorKind, UnwrapVariable}, function::{Function, LambdaBody}, literal::{Double, Literal}, object::ClassChild, soul_type::{soul_type::{Modifier, SoulType, TypeWrapper}, type_kind::{TypeKind, TypeSize}}, statement::{Block, Statement, StatementKind}}, scope_builder::{ProgramMemmory, ScopeId, ScopeKind, Variable}}, i_sementic::{ast_visitor::{AstAnalyser, LiteralEvaluator}, scope_vistitor::ScopeVisitor}}}};
+
+const MAX_CALL_DEPTH: usize = 256;
+/// stack of the thread that evaluates calls, calls are evaluated recursively so 'MAX_CALL_DEPTH' calls have to fit (also in debug builds)
+const CALL_STACK_SIZE: usize = 64 * 1024 * 1024;
+/// max amount of loop iterations and calls of one evaluation
+const MAX_STEPS: usize = 1_000_000;
+
+/// why the evaluation of an expression stopped before it had a value
+enum Interrupt {
+    Error(SoulError),
+    Return(Option<Literal>),
+    Break,
+    Fall(Literal),
+}
+
+impl From<SoulError> for Interrupt {
+    fn from(error: SoulError) -> Self {
+        Interrupt::Error(error)
+    }
+}
+
+type Evaluation<T> = std::result::Result<T, Interrupt>;
+
+/// number operand of an arithmetic operator
+#[derive(Clone, Copy)]
+enum Number {
+    Int(i128),
+    Float(f64),
+}
+
+/// evaluates expressions at compile time, only 'Literal' variables and the locals of evaluated functions are known
+pub struct Evaluator<'a> {
+    scope: &'a ScopeVisitor,
+    current_scope: ScopeId,
+    /// locals of the function that is evaluated, innermost block last
+    locals: Vec<HashMap<String, Literal>>,
+    /// evaluated 'Literal' variables by (scope, name)
+    globals: HashMap<(usize, String), Literal>,
+    evaluating: HashSet<(usize, String)>,
+    call_depth: usize,
+    steps: usize,
+}
+
+#[derive(Default)]
+struct EvaluateContext {
+    /// body of 'Literal' function is evaluated per call so not while walking the tree
+    in_literal_function: bool,
+}
+
+impl AstAnalyser for LiteralEvaluator {
+
+    fn analyse_ast(&mut self, tree: &mut AbstractSyntacTree) {
+        let mut context = EvaluateContext::default();
+        self.analyse_block(&mut tree.root, &mut context);
+    }
+}
+
+impl LiteralEvaluator {
+
+    fn analyse_block(&mut self, block: &mut Block, context: &mut EvaluateContext) {
+        let parent_id = self.get_scope().current_id();
+        self.get_scope_mut().set_current(block.scope_id);
+
+        for statment in &mut block.statments {
+            self.analyse_statment(statment, context);
+        }
+
+        self.get_scope_mut()
+            .set_current(parent_id)
+            .expect("scope_id should be valid");
+    }
+
+    fn analyse_statment(&mut self, statment: &mut Statement, context: &mut EvaluateContext) {
+        let span = statment.span;
+
+        let parent_id = self.get_scope().current_id();
+        if let Some(id) = statment.node.get_scope_id() && self.get_scope_mut().set_current(id).is_none() {
+            panic!("could not get scope_id({}) at line: {}:{}", id.0, span.line_number, span.line_offset)
+        }
+
+        match &mut statment.node {
+            StatementKind::Enum(enum_) => self.analyse_enum(enum_),
+            StatementKind::Trait(_) |
+            StatementKind::Union(_) |
+            StatementKind::Struct(_) |
+            StatementKind::TypeEnum(_) |
+            StatementKind::CloseBlock => (),
+
+            StatementKind::Expression(expression) => self.analyse_expression(expression, context),
+            StatementKind::Variable(variable_name) => self.analyse_variable(&variable_name.name.0, context),
+            StatementKind::Assignment(assignment) => {
+                self.analyse_expression(&mut assignment.variable, context);
+                self.analyse_expression(&mut assignment.value, context);
+            },
+            StatementKind::Function(function) => self.analyse_function(function),
+            StatementKind::UseBlock(use_block) => self.analyse_block(&mut use_block.block, context),
+            StatementKind::Class(class) => {
+
+                for child in &mut class.children {
+                    match child {
+                        ClassChild::Field(_) => (),
+                        ClassChild::Methode(methode) => {
+                            let parent_id = self.get_scope().current_id();
+                            self.get_scope_mut().set_current(methode.node.block.scope_id);
+
+                            self.analyse_function(&mut methode.node);
+
+                            self.get_scope_mut()
+                                .set_current(parent_id)
+                                .expect("scope_id should be valid");
+                        },
+                        ClassChild::ImplBlock(impl_block) => self.analyse_block(&mut impl_block.node.block, context),
+                    }
+                }
+            },
+        }
+
+        self.get_scope_mut()
+            .set_current(parent_id)
+            .expect("scope_id should be valid");
+    }
+
+    fn analyse_function(&mut self, function: &mut Function) {
+        let mut context = EvaluateContext{in_literal_function: function.signature.ruleset == Modifier::Literal};
+        self.analyse_block(&mut function.block, &mut context);
+    }
+
+    /// evaluates the value of every variant, gives implicit values (e.g. 'Three' is 11 in 'enum Nums { Ten = 10, Three }') and checks for duplicate values
+    fn analyse_enum(&mut self, enum_: &mut Enum) {
+        let mut values: Vec<(Ident, Literal)> = vec![];
+
+        match &mut enum_.variants {
+            EnumVariantKind::Int(variants) => for variant in variants.iter() {
+                self.add_enum_value(&enum_.name, &variant.node.name, Literal::Int(variant.node.value), variant.span, &mut values);
+            },
+            EnumVariantKind::Expression(variants) => {
+                let is_integer = enum_.ty.as_ref().is_some_and(|ty| ty.wrappers.is_empty() && ty.base.is_integer());
+
+                let mut next_value = 0i128;
+                for variant in variants.iter_mut() {
+                    let variant_span = variant.value.span;
+
+                    let literal = match &variant.value.node {
+                        // type checker reports implicit value of none integer enum
+                        ExpressionKind::Default if !is_integer => continue,
+                        ExpressionKind::Default => match from_int(next_value, variant_span) {
+                            Ok(literal) => literal,
+                            Err(_) => {
+                                self.add_error(new_soul_error(SoulErrorKind::InvalidInContext, Some(variant_span), format!("implicit value of variant '{}' of enum '{}' is out of range", variant.name, enum_.name)));
+                                continue
+                            },
+                        },
+                        _ => match Evaluator::new(self.get_scope()).evaluate(&variant.value, self.get_scope().current_id()) {
+                            Ok(literal) => literal,
+                            Err(error) => {
+                                self.add_error(pass_soul_error(
+                                    SoulErrorKind::InvalidInContext,
+                                    Some(variant_span),
+                                    format!("value of variant '{}' of enum '{}' has to be known at compile time", variant.name, enum_.name),
+                                    error,
+                                ));
+                                continue
+                            },
+                        },
+                    };
+
+                    if let Some(ty) = enum_.ty.as_ref().filter(|_| is_integer) && let Err(error) = check_integer_range(ty, &literal, variant_span) {
+                        self.add_error(error);
+                        continue
+                    }
+
+                    if let Some(Number::Int(value)) = to_number(&literal) {
+                        next_value = value + 1;
+                    }
+
+                    self.add_enum_value(&enum_.name, &variant.name, literal.clone(), variant_span, &mut values);
+                    variant.value = intern_literal(&mut self.get_scope_mut().global_literals, literal, variant_span);
+                }
+            },
+        }
+
+        let scope_enum = self.get_scope_mut()
+            .lookup_mut(&enum_.name.0)
+            .and_then(|kinds| kinds.iter_mut().find_map(|kind| match &mut kind.node {
+                ScopeKind::Enum(scope_enum) => Some(scope_enum),
+                _ => None,
+            }));
+
+        if let Some(scope_enum) = scope_enum {
+            scope_enum.variants = enum_.variants.clone();
+        }
+    }
+
+    fn add_enum_value(&mut self, enum_name: &Ident, name: &Ident, value: Literal, span: SoulSpan, values: &mut Vec<(Ident, Literal)>) {
+
+        if let Some((duplicate, _)) = values.iter().find(|(_, other)| is_same_value(other, &value)) {
+            self.add_error(new_soul_error(
+                SoulErrorKind::InvalidInContext,
+                Some(span),
+                format!("variant '{}' of enum '{}' has the same value ({}) as variant '{}'", name, enum_name, value.value_to_string(), duplicate),
+            ));
+        }
+
+        values.push((name.clone(), value));
+    }
+
+    /// evaluates calls to 'Literal' functions in initializer and the initializer of 'Literal' variables
+    fn analyse_variable(&mut self, name: &str, context: &mut EvaluateContext) {
+        let (mut value, ty) = match self.get_variable(name) {
+            Some(Variable{initialize_value: Some(value), ty, ..}) => (value.clone(), ty.clone()),
+            _ => return,
+        };
+        let is_literal = ty.modifier == Modifier::Literal;
+
+        if !is_literal || context.in_literal_function {
+            self.analyse_expression(&mut value, context);
+        }
+        else {
+            let result = Evaluator::new(self.get_scope()).evaluate(&value, self.get_scope().current_id())
+                .and_then(|literal| check_integer_range(&ty, &literal, value.span).map(|_| literal));
+
+            match result {
+                Ok(literal) => value = intern_literal(&mut self.get_scope_mut().global_literals, literal, value.span),
+                Err(error) => self.add_error(pass_soul_error(
+                    SoulErrorKind::InvalidInContext,
+                    Some(value.span),
+                    format!("value of '{}' variable '{}' has to be known at compile time", Modifier::Literal.to_str(), name),
+                    error,
+                )),
+            }
+        }
+
+        let variable = self.get_scope_mut()
+            .flat_lookup_mut(name)
+            .and_then(|kinds| kinds.iter_mut().find_map(|kind| match &mut kind.node {
+                ScopeKind::Variable(variable) => Some(variable),
+                _ => None,
+            }));
+
+        if let Some(variable) = variable {
+            variable.initialize_value = Some(value);
+        }
+    }
+
+    fn analyse_expression(&mut self, expression: &mut Expression, context: &mut EvaluateContext) {
+        let span = expression.span;
+
+        let parent_id = self.get_scope().current_id();
+        if let Some(id) = expression.node.get_scope_id() && self.get_scope_mut().set_current(id).is_none() {
+            panic!("could not get scope_id({}) at line: {}:{}", id.0, span.line_number, span.line_offset)
+        }
+
+        self.analyse_expression_kind(expression, context);
+
+        self.get_scope_mut()
+            .set_current(parent_id)
+            .expect("scope_id should be valid");
+    }
+
+    fn analyse_expression_kind(&mut self, expression: &mut Expression, context: &mut EvaluateContext) {
+
+        match &mut expression.node {
+            ExpressionKind::Empty |
+            ExpressionKind::Default |
+            ExpressionKind::Literal(_) |
+            ExpressionKind::Variable(_) |
+            ExpressionKind::StaticField(_) |
+            ExpressionKind::ExternalExpression(_) => (),
+
+            ExpressionKind::Index(index) => {
+                self.analyse_expression(&mut index.collection, context);
+                self.analyse_expression(&mut index.index, context);
+            },
+            ExpressionKind::Lambda(lambda) => {
+                for argument in &mut lambda.arguments.values {
+                    self.analyse_expression(argument, context);
+                }
+
+                match &mut lambda.body {
+                    LambdaBody::Block(block) => self.analyse_block(block, context),
+                    LambdaBody::Expression(body) => self.analyse_expression(body, context),
+                }
+            },
+            ExpressionKind::FunctionCall(function_call) => {
+                if let Some(callee) = &mut function_call.callee {
+                    self.analyse_expression(callee, context);
+                }
+                for argument in &mut function_call.arguments.values {
+                    self.analyse_expression(argument, context);
+                }
+
+                let is_literal_call = function_call.callee.is_none() && !context.in_literal_function && {
+                    let candidates = find_functions(self.get_scope(), &function_call.name.0);
+                    let mut candidates = candidates.iter().filter(|candidate| candidate.parameters.len() == function_call.arguments.values.len()).peekable();
+                    candidates.peek().is_some() && candidates.all(|candidate| candidate.ruleset == Modifier::Literal)
+                };
+
+                if is_literal_call {
+                    self.replace_literal_call(expression);
+                }
+            },
+            ExpressionKind::StructConstructor(struct_constructor) => for value in struct_constructor.arguments.values.values_mut() {
+                self.analyse_expression(value, context);
+            },
+            ExpressionKind::AccessField(access_field) => self.analyse_expression(&mut access_field.object, context),
+            ExpressionKind::StaticMethod(static_method) => for argument in &mut static_method.arguments.values {
+                self.analyse_expression(argument, context);
+            },
+            ExpressionKind::UnwrapVariable(unwrap_variable) => {
+                if let UnwrapVariable::MultiVariable{initializer: Some(initializer), ..} = unwrap_variable {
+                    self.analyse_expression(initializer, context);
+                }
+            },
+            ExpressionKind::Unary(unary) => self.analyse_expression(&mut unary.expression, context),
+            ExpressionKind::Binary(binary) => {
+                self.analyse_expression(&mut binary.left, context);
+                self.analyse_expression(&mut binary.right, context);
+            },
+            ExpressionKind::If(if_decl) => self.analyse_if(if_decl, context),
+            ExpressionKind::For(for_decl) => {
+                self.analyse_expression(&mut for_decl.collection, context);
+                self.analyse_block(&mut for_decl.block, context);
+            },
+            ExpressionKind::While(while_decl) => {
+                if let Some(condition) = &mut while_decl.condition {
+                    self.analyse_expression(condition, context);
+                }
+                self.analyse_block(&mut while_decl.block, context);
+            },
+            ExpressionKind::Match(match_decl) => {
+                self.analyse_expression(&mut match_decl.condition, context);
+
+                for case in &mut match_decl.cases {
+                    let parent_id = self.get_scope().current_id();
+                    self.get_scope_mut().set_current(case.scope_id);
+
+                    match &mut case.do_fn {
+                        CaseDoKind::Block(block) => self.analyse_block(&mut block.node, context),
+                        CaseDoKind::Expression(do_expression) => self.analyse_expression(do_expression, context),
+                    }
+
+                    self.get_scope_mut()
+                        .set_current(parent_id)
+                        .expect("scope_id should be valid");
+                }
+            },
+            ExpressionKind::Ternary(ternary) => {
+                self.analyse_expression(&mut ternary.condition, context);
+                self.analyse_expression(&mut ternary.if_branch, context);
+                self.analyse_expression(&mut ternary.else_branch, context);
+            },
+            ExpressionKind::Deref(inner) |
+            ExpressionKind::MutRef(inner) |
+            ExpressionKind::ConstRef(inner) |
+            ExpressionKind::Copy(inner) => self.analyse_expression(inner, context),
+            ExpressionKind::Block(block) => self.analyse_block(block, context),
+            ExpressionKind::ReturnLike(return_like) => if let Some(value) = &mut return_like.value {
+                self.analyse_expression(value, context);
+            },
+            ExpressionKind::ExpressionGroup(expression_group) => match expression_group {
+                ExpressionGroup::Tuple(tuple) => for value in &mut tuple.values {
+                    self.analyse_expression(value, context);
+                },
+                ExpressionGroup::Array(array) => for value in &mut array.values {
+                    self.analyse_expression(value, context);
+                },
+                ExpressionGroup::NamedTuple(named_tuple) => for value in named_tuple.values.values_mut() {
+                    self.analyse_expression(value, context);
+                },
+                ExpressionGroup::ArrayFiller(array_filler) => {
+                    self.analyse_expression(&mut array_filler.amount, context);
+                    self.analyse_expression(&mut array_filler.fill_expr, context);
+                },
+            },
+        }
+    }
+
+    fn analyse_if(&mut self, if_decl: &mut If, context: &mut EvaluateContext) {
+        self.analyse_expression(&mut if_decl.condition, context);
+        self.analyse_block(&mut if_decl.block, context);
+
+        for branch in &mut if_decl.else_branchs {
+            match &mut branch.node {
+                ElseKind::Else(block) => self.analyse_block(&mut block.node, context),
+                ElseKind::ElseIf(else_if) => self.analyse_if(&mut else_if.node, context),
+            }
+        }
+    }
+
+    /// calls to 'Literal' functions are replaced by their result
+    fn replace_literal_call(&mut self, call: &mut Expression) {
+        let result = Evaluator::new(self.get_scope()).evaluate(call, self.get_scope().current_id());
+
+        match result {
+            Ok(literal) => *call = intern_literal(&mut self.get_scope_mut().global_literals, literal, call.span),
+            Err(error) => self.add_error(error),
+        }
+    }
+
+    fn get_variable(&self, name: &str) -> Option<&Variable> {
+        self.get_scope()
+            .flat_lookup(name)?
+            .iter()
+            .find_map(|kind| match &kind.node {
+                ScopeKind::Variable(variable) => Some(variable),
+                _ => None,
+            })
+    }
+}
+
+impl<'a> Evaluator<'a> {
+
+    pub fn new(scope: &'a ScopeVisitor) -> Self {
+        Self {
+            scope,
+            current_scope: scope.current_id(),
+            locals: vec![],
+            globals: HashMap::new(),
+            evaluating: HashSet::new(),
+            call_depth: 0,
+            steps: 0,
+        }
+    }
+
+    /// evaluator that also knows locals (e.g variables that retain their literal value)
+    pub fn with_locals(scope: &'a ScopeVisitor, locals: HashMap<String, Literal>) -> Self {
+        let mut this = Self::new(scope);
+        this.locals.push(locals);
+        this
+    }
+
+    /// value of expression in scope, error if expression can not be evaluated at compile time
+    pub fn evaluate(&mut self, expression: &Expression, scope_id: ScopeId) -> Result<Literal, SoulError> {
+        self.current_scope = scope_id;
+
+        match self.value(expression) {
+            Ok(literal) => Ok(literal),
+            Err(Interrupt::Error(error)) => Err(error),
+            Err(_) => Err(new_soul_error(SoulErrorKind::InvalidInContext, Some(expression.span), "'return', 'break' and 'fall' can not be evaluated outside of their function, loop or block")),
+        }
+    }
+
+    fn value(&mut self, expression: &Expression) -> Evaluation<Literal> {
+        match self.evaluate_expression(expression)? {
+            Some(literal) => Ok(literal),
+            None => Err(error(expression.span, "expression does not have a value")),
+        }
+    }
+
+    /// None if expression does not have a value (e.g 'while' loop)
+    fn evaluate_expression(&mut self, expression: &Expression) -> Evaluation<Option<Literal>> {
+        let parent_scope = self.current_scope;
+        if let Some(id) = expression.node.get_scope_id() {
+            self.current_scope = id;
+        }
+
+        let result = self.evaluate_expression_kind(expression);
+        self.current_scope = parent_scope;
+        result
+    }
+
+    fn evaluate_expression_kind(&mut self, expression: &Expression) -> Evaluation<Option<Literal>> {
+        let span = expression.span;
+
+        let literal = match &expression.node {
+            ExpressionKind::Empty => return Ok(None),
+            ExpressionKind::Literal(literal) => self.resolve_literal(literal, span)?,
+            ExpressionKind::Variable(variable_name) => self.get_variable(&variable_name.name.0, span)?,
+            ExpressionKind::Copy(inner) => self.value(inner)?,
+            ExpressionKind::Unary(unary) => match unary.operator.node {
+                UnaryOperatorKind::Neg => match to_number(&self.value(&unary.expression)?) {
+                    Some(Number::Int(value)) => from_int(-value, span)?,
+                    Some(Number::Float(value)) => Literal::Float(Double::new(-value)),
+                    None => return Err(error(span, "operator '-' can only be used on numbers")),
+                },
+                UnaryOperatorKind::Not => match self.value(&unary.expression)? {
+                    Literal::Bool(value) => Literal::Bool(!value),
+                    Literal::Int(value) => Literal::Int(!value),
+                    Literal::Uint(value) => Literal::Uint(!value),
+                    _ => return Err(error(span, "operator '!' can only be used on bool or integer")),
+                },
+                UnaryOperatorKind::Increment{before_var} => self.step_variable(&unary.expression, 1, before_var, span)?,
+                UnaryOperatorKind::Decrement{before_var} => self.step_variable(&unary.expression, -1, before_var, span)?,
+                UnaryOperatorKind::Invalid => return Err(error(span, "invalid operator")),
+            },
+            ExpressionKind::Binary(binary) => {
+                let operator = &binary.operator.node;
+                let left = self.value(&binary.left)?;
+
+                // short circuit
+                match (operator, &left) {
+                    (BinaryOperatorKind::LogAnd, Literal::Bool(false)) => return Ok(Some(Literal::Bool(false))),
+                    (BinaryOperatorKind::LogOr, Literal::Bool(true)) => return Ok(Some(Literal::Bool(true))),
+                    _ => (),
+                }
+
+                let right = self.value(&binary.right)?;
+                binary_operation(operator, left, right, span)?
+            },
+            ExpressionKind::Ternary(ternary) => match self.value(&ternary.condition)? {
+                Literal::Bool(true) => self.value(&ternary.if_branch)?,
+                Literal::Bool(false) => self.value(&ternary.else_branch)?,
+                _ => return Err(error(ternary.condition.span, "condition should be 'bool'")),
+            },
+            ExpressionKind::Index(index) => {
+                let collection = self.value(&index.collection)?;
+                let position = self.index_value(&index.index)?;
+                get_element(collection, position, span)?
+            },
+            ExpressionKind::AccessField(access_field) => match self.value(&access_field.object)? {
+                Literal::NamedTuple{values, ..} => match values.get(&access_field.field.name) {
+                    Some(value) => value.clone(),
+                    None => return Err(error(span, format!("field '{}' not found", access_field.field.name.0))),
+                },
+                _ => return Err(error(span, format!("field '{}' can not be evaluated at compile time", access_field.field.name.0))),
+            },
+            ExpressionKind::ExpressionGroup(expression_group) => match expression_group {
+                ExpressionGroup::Tuple(tuple) => Literal::new_tuple(self.values(&tuple.values)?),
+                ExpressionGroup::Array(array) => Literal::new_array(self.values(&array.values)?, &span)?,
+                ExpressionGroup::NamedTuple(named_tuple) => {
+                    let mut values = BTreeMap::new();
+                    for (name, value) in &named_tuple.values {
+                        values.insert(name.clone(), self.value(value)?);
+                    }
+                    Literal::new_named_tuple(values, named_tuple.insert_defaults)
+                },
+                ExpressionGroup::ArrayFiller(array_filler) => {
+                    let amount = self.index_value(&array_filler.amount)?;
+
+                    let mut values = Vec::with_capacity(amount);
+                    self.locals.push(HashMap::new());
+                    for i in 0..amount {
+                        self.add_step(span)?;
+
+                        if let Some(index) = &array_filler.index {
+                            self.declare(&index.name.0, Literal::Uint(i as u64));
+                        }
+                        values.push(self.value(&array_filler.fill_expr)?);
+                    }
+                    self.locals.pop();
+
+                    Literal::new_array(values, &span)?
+                },
+            },
+            ExpressionKind::Block(block) => return self.evaluate_value_block(block),
+            ExpressionKind::If(if_decl) => return catch_fall(self.evaluate_if(if_decl)),
+            ExpressionKind::Match(match_decl) => {
+                let condition = self.value(&match_decl.condition)?;
+
+                for case in &match_decl.cases {
+                    let parent_scope = self.current_scope;
+                    self.current_scope = case.scope_id;
+                    self.locals.push(HashMap::new());
+
+                    let result = match self.case_matches(&case.if_kind, &condition, span) {
+                        Ok(true) => Some(match &case.do_fn {
+                            CaseDoKind::Block(block) => self.evaluate_value_block(&block.node),
+                            CaseDoKind::Expression(do_expression) => catch_fall(self.evaluate_expression(do_expression)),
+                        }),
+                        Ok(false) => None,
+                        Err(interrupt) => Some(Err(interrupt)),
+                    };
+
+                    self.locals.pop();
+                    self.current_scope = parent_scope;
+
+                    if let Some(result) = result {
+                        return result
+                    }
+                }
+
+                return Err(error(span, format!("no case of match matches '{}'", condition.value_to_string())))
+            },
+            ExpressionKind::While(while_decl) => {
+                loop {
+                    self.add_step(span)?;
+
+                    if let Some(condition) = &while_decl.condition {
+                        match self.value(condition)? {
+                            Literal::Bool(true) => (),
+                            Literal::Bool(false) => break,
+                            _ => return Err(error(condition.span, "condition should be 'bool'")),
+                        }
+                    }
+
+                    match self.evaluate_block(&while_decl.block) {
+                        Err(Interrupt::Break) => break,
+                        result => { result?; },
+                    }
+                }
+                return Ok(None)
+            },
+            ExpressionKind::For(for_decl) => {
+                let elements = match self.value(&for_decl.collection)? {
+                    Literal::Array{values, ..} => values,
+                    Literal::Str(value) => value.chars().map(Literal::Char).collect(),
+                    literal => match to_number(&literal) {
+                        Some(Number::Int(amount)) if amount >= 0 => (0..amount as u64).map(Literal::Uint).collect(),
+                        _ => return Err(error(for_decl.collection.span, format!("can not iterate over '{}'", literal.value_to_string()))),
+                    },
+                };
+
+                for element in elements {
+                    self.add_step(span)?;
+
+                    self.locals.push(HashMap::new());
+                    if let Some(pattern) = &for_decl.element {
+                        self.declare_pattern(pattern, element)?;
+                    }
+                    let result = self.evaluate_block(&for_decl.block);
+                    self.locals.pop();
+
+                    match result {
+                        Err(Interrupt::Break) => break,
+                        result => result?,
+                    }
+                }
+                return Ok(None)
+            },
+            ExpressionKind::ReturnLike(return_like) => {
+                let value = match &return_like.value {
+                    Some(value) => self.evaluate_expression(value)?,
+                    None => None,
+                };
+
+                return Err(match (&return_like.kind, value) {
+                    (ReturnKind::Return, value) => Interrupt::Return(value),
+                    (ReturnKind::Break, _) => Interrupt::Break,
+                    (ReturnKind::Fall, Some(value)) => Interrupt::Fall(value),
+                    (ReturnKind::Fall, None) => return Err(error(span, "'fall' should have a value")),
+                })
+            },
+            ExpressionKind::FunctionCall(function_call) => {
+                if function_call.callee.is_some() {
+                    return Err(error(span, format!("methode '{}' can not be evaluated at compile time", function_call.name.0)))
+                }
+
+                let arguments = self.values(&function_call.arguments.values)?;
+                return self.call_function(&function_call.name.0, arguments, span)
+            },
+
+            ExpressionKind::Default |
+            ExpressionKind::Deref(_) |
+            ExpressionKind::MutRef(_) |
+            ExpressionKind::ConstRef(_) |
+            ExpressionKind::Lambda(_) |
+            ExpressionKind::StaticField(_) |
+            ExpressionKind::StaticMethod(_) |
+            ExpressionKind::UnwrapVariable(_) |
+            ExpressionKind::StructConstructor(_) |
+            ExpressionKind::ExternalExpression(_) => return Err(error(span, "expression can not be evaluated at compile time")),
+        };
+
+        Ok(Some(literal))
+    }
+
+    fn values(&mut self, expressions: &[Expression]) -> Evaluation<Vec<Literal>> {
+        expressions.iter()
+            .map(|expression| self.value(expression))
+            .collect()
+    }
+
+    fn index_value(&mut self, expression: &Expression) -> Evaluation<usize> {
+        let literal = self.value(expression)?;
+
+        match to_number(&literal) {
+            Some(Number::Int(value)) if value >= 0 => Ok(value as usize),
+            _ => Err(error(expression.span, format!("'{}' is not a valid index", literal.value_to_string()))),
+        }
+    }
+
+    fn evaluate_block(&mut self, block: &Block) -> Evaluation<()> {
+        let parent_scope = self.current_scope;
+        self.current_scope = block.scope_id;
+        self.locals.push(HashMap::new());
+
+        let mut result = Ok(());
+        for statment in &block.statments {
+            result = self.evaluate_statment(statment);
+            if result.is_err() {
+                break
+            }
+        }
+
+        self.locals.pop();
+        self.current_scope = parent_scope;
+        result
+    }
+
+    /// block-valued expression (e.g 'x := {fall 1}')
+    fn evaluate_value_block(&mut self, block: &Block) -> Evaluation<Option<Literal>> {
+        catch_fall(self.evaluate_block(block).map(|_| None))
+    }
+
+    fn evaluate_if(&mut self, if_decl: &If) -> Evaluation<Option<Literal>> {
+        let condition = match self.value(&if_decl.condition)? {
+            Literal::Bool(condition) => condition,
+            _ => return Err(error(if_decl.condition.span, "condition should be 'bool'")),
+        };
+
+        if condition {
+            self.evaluate_block(&if_decl.block)?;
+            return Ok(None)
+        }
+
+        for branch in &if_decl.else_branchs {
+            match &branch.node {
+                ElseKind::ElseIf(else_if) => {
+                    let is_true = matches!(self.value(&else_if.node.condition)?, Literal::Bool(true));
+                    if is_true {
+                        return self.evaluate_if(&else_if.node)
+                    }
+                },
+                ElseKind::Else(block) => {
+                    self.evaluate_block(&block.node)?;
+                    return Ok(None)
+                },
+            }
+        }
+
+        Ok(None)
+    }
+
+    fn evaluate_statment(&mut self, statment: &Statement) -> Evaluation<()> {
+        let span = statment.span;
+
+        match &statment.node {
+            StatementKind::Expression(expression) => {
+                self.evaluate_expression(expression)?;
+            },
+            StatementKind::Variable(variable_name) => {
+                let value = self.find_variable(&variable_name.name.0)
+                    .and_then(|(variable, _)| variable.initialize_value.as_ref());
+
+                if let Some(value) = value {
+                    let value = self.value(value)?;
+                    self.declare(&variable_name.name.0, value);
+                }
+            },
+            StatementKind::Assignment(assignment) => {
+                let value = self.value(&assignment.value)?;
+                self.assign(&assignment.variable, value, span)?;
+            },
+
+            StatementKind::Enum(_) |
+            StatementKind::Class(_) |
+            StatementKind::Trait(_) |
+            StatementKind::Union(_) |
+            StatementKind::Struct(_) |
+            StatementKind::TypeEnum(_) |
+            StatementKind::Function(_) |
+            StatementKind::UseBlock(_) |
+            StatementKind::CloseBlock => (),
+        }
+
+        Ok(())
+    }
+
+    fn case_matches(&mut self, if_kind: &IfCaseKind, condition: &Literal, span: SoulSpan) -> Evaluation<bool> {
+
+        match if_kind {
+            IfCaseKind::Expression(expression) => {
+                let case = self.value(expression)?;
+                Ok(matches!(binary_operation(&BinaryOperatorKind::Eq, condition.clone(), case, expression.span)?, Literal::Bool(true)))
+            },
+            IfCaseKind::Variant{name, params} if params.values.is_empty() => {
+                // 'true'/'false' are parsed as variable names
+                match (name.0.as_str(), condition) {
+                    ("true", Literal::Bool(value)) => Ok(*value),
+                    ("false", Literal::Bool(value)) => Ok(!*value),
+                    ("_", _) => Ok(true),
+                    (name, condition) => {
+                        self.declare(name, condition.clone());
+                        Ok(true)
+                    },
+                }
+            },
+            IfCaseKind::Variant{name, ..} |
+            IfCaseKind::NamedVariant{name, ..} => Err(error(span, format!("variant '{}' can not be matched at compile time", name.0))),
+        }
+    }
+
+    fn call_function(&mut self, name: &str, arguments: Vec<Literal>, span: SoulSpan) -> Evaluation<Option<Literal>> {
+
+        if self.call_depth > 0 {
+            return self.evaluate_call(name, arguments, span)
+        }
+
+        // the stack of the caller (e.g. a thread of the threadpool) is too small for deep recursion
+        std::thread::scope(|threads| {
+            std::thread::Builder::new()
+                .stack_size(CALL_STACK_SIZE)
+                .spawn_scoped(threads, || self.evaluate_call(name, arguments, span))
+                .map_err(|err| error(span, format!("could not start evaluation of '{}': {}", name, err)))?
+                .join()
+                .unwrap_or_else(|panic| std::panic::resume_unwind(panic))
+        })
+    }
+
+    fn evaluate_call(&mut self, name: &str, arguments: Vec<Literal>, span: SoulSpan) -> Evaluation<Option<Literal>> {
+        self.add_step(span)?;
+
+        if self.call_depth >= MAX_CALL_DEPTH {
+            return Err(error(span, format!("recursion of '{}' is deeper than {} calls", name, MAX_CALL_DEPTH)))
+        }
+
+        let function = self.select_function(name, &arguments, span)?;
+        if function.signature.ruleset == Modifier::Default {
+            return Err(error(span, format!(
+                "function '{}' is not '{}' or '{}' so can not be evaluated at compile time",
+                name,
+                Modifier::Const.to_str(),
+                Modifier::Literal.to_str(),
+            )))
+        }
+
+        let locals = std::mem::take(&mut self.locals);
+        let parent_scope = self.current_scope;
+        self.call_depth += 1;
+
+        let mut parameters = HashMap::new();
+        for (parameter, argument) in function.signature.parameters.iter().zip(arguments) {
+            parameters.insert(parameter.node.name.0.clone(), argument);
+        }
+        self.locals.push(parameters);
+
+        let result = self.evaluate_block(&function.block);
+
+        self.call_depth -= 1;
+        self.current_scope = parent_scope;
+        self.locals = locals;
+
+        let value = match result {
+            Ok(()) => None,
+            Err(Interrupt::Return(value)) => value,
+            Err(Interrupt::Error(error)) => return Err(Interrupt::Error(pass_soul_error(
+                error.get_last_kind(),
+                Some(span),
+                format!("while evaluating '{}' at compile time", name),
+                error,
+            ))),
+            Err(_) => return Err(error(span, format!("'break' or 'fall' outside of loop in '{}'", name))),
+        };
+
+        if value.is_none() && function.signature.return_type.is_some() {
+            return Err(error(span, format!("'{}' did not return a value", name)))
+        }
+        Ok(value)
+    }
+
+    /// function of name visible from the current scope that best matches the arguments
+    fn select_function(&self, name: &str, arguments: &[Literal], span: SoulSpan) -> Evaluation<&'a Function> {
+        let mut functions = vec![];
+
+        let scopes = self.scope.get_scopes();
+        let mut current_index = Some(self.current_scope);
+        while let Some(index) = current_index {
+
+            for kind in scopes[index.0].get(name).into_iter().flatten() {
+                if let ScopeKind::Functions(candidates) = &kind.node {
+                    functions.extend(candidates.iter().map(|function| &function.node).filter(|function| function.signature.parameters.len() == arguments.len()));
+                }
+            }
+
+            current_index = scopes[index.0].parent_index;
+        }
+
+        if functions.len() == 1 {
+            return Ok(functions[0])
+        }
+
+        let signatures = functions.iter().map(|function| function.signature.clone()).collect::<Vec<_>>();
+        let argument_types = arguments.iter().map(|argument| Some(argument.to_soul_type())).collect::<Vec<_>>();
+
+        match resolve_overload(&signatures, &argument_types) {
+            Ok(signature) => Ok(functions[signatures.iter().position(|other| std::ptr::eq(other, signature)).expect("signature should be in candidates")]),
+            Err(_) if functions.is_empty() => Err(error(span, format!("function '{}' is not known at compile time", name))),
+            Err(_) => Err(error(span, format!("could not select overload of '{}' for arguments at compile time", name))),
+        }
+    }
+
+    fn get_variable(&mut self, name: &str, span: SoulSpan) -> Evaluation<Literal> {
+
+        if let Some(value) = self.locals.iter().rev().find_map(|frame| frame.get(name)) {
+            return Ok(value.clone())
+        }
+
+        let (variable, scope_id) = match self.find_variable(name) {
+            Some(found) => found,
+            None => return Err(error(span, format!("'{}' is not known at compile time", name))),
+        };
+
+        if !is_compile_time_variable(variable) {
+            return Err(error(span, format!("'{}' is not known at compile time, only '{}' variables can be used", name, Modifier::Literal.to_str())))
+        }
+
+        let key = (scope_id.0, name.to_string());
+        if let Some(value) = self.globals.get(&key) {
+            return Ok(value.clone())
+        }
+
+        let value = match &variable.initialize_value {
+            Some(value) => value,
+            None => return Err(error(span, format!("'{}' does not have a value", name))),
+        };
+
+        if !self.evaluating.insert(key.clone()) {
+            return Err(error(span, format!("value of '{}' depends on itself", name)))
+        }
+
+        // initializer is evaluated where the variable is declared
+        let locals = std::mem::take(&mut self.locals);
+        let parent_scope = std::mem::replace(&mut self.current_scope, scope_id);
+        let result = self.value(value);
+        self.current_scope = parent_scope;
+        self.locals = locals;
+
+        self.evaluating.remove(&key);
+        let value = result?;
+        self.globals.insert(key, value.clone());
+        Ok(value)
+    }
+
+    /// variable symbol visible from the current scope and the scope it is declared in
+    fn find_variable(&self, name: &str) -> Option<(&'a Variable, ScopeId)> {
+        let scopes = self.scope.get_scopes();
+
+        let mut current_index = Some(self.current_scope);
+        while let Some(index) = current_index {
+
+            let variable = scopes[index.0].get(name)
+                .into_iter()
+                .flatten()
+                .find_map(|kind| match &kind.node {
+                    ScopeKind::Variable(variable) => Some(variable),
+                    _ => None,
+                });
+
+            if let Some(variable) = variable {
+                return Some((variable, index))
+            }
+
+            current_index = scopes[index.0].parent_index;
+        }
+
+        None
+    }
+
+    fn declare(&mut self, name: &str, value: Literal) {
+        if self.locals.is_empty() {
+            self.locals.push(HashMap::new());
+        }
+
+        self.locals.last_mut()
+            .expect("locals should have frame")
+            .insert(name.into(), value);
+    }
+
+    fn declare_pattern(&mut self, pattern: &Expression, value: Literal) -> Evaluation<()> {
+
+        match (&pattern.node, value) {
+            (ExpressionKind::Variable(variable_name), value) => self.declare(&variable_name.name.0, value),
+            (ExpressionKind::ExpressionGroup(ExpressionGroup::Tuple(tuple)), Literal::Tuple{values}) if tuple.values.len() == values.len() => {
+                for (element, value) in tuple.values.iter().zip(values) {
+                    self.declare_pattern(element, value)?;
+                }
+            },
+            (_, value) => return Err(error(pattern.span, format!("can not unpack '{}'", value.value_to_string()))),
+        }
+
+        Ok(())
+    }
+
+    /// only locals of the evaluated function can be assigned
+    fn assign(&mut self, place: &Expression, value: Literal, span: SoulSpan) -> Evaluation<()> {
+
+        match &place.node {
+            ExpressionKind::Variable(variable_name) => {
+                let name = &variable_name.name.0;
+
+                if let Some(local) = self.locals.iter_mut().rev().find_map(|frame| frame.get_mut(name)) {
+                    *local = value;
+                }
+                else if self.find_variable(name).is_some_and(|(variable, scope_id)| variable.ty.modifier != Modifier::Literal && self.is_local_scope(scope_id)) {
+                    self.declare(name, value);
+                }
+                else {
+                    return Err(error(span, format!("can not assign to '{}' at compile time", name)))
+                }
+            },
+            ExpressionKind::Index(index) => {
+                let position = self.index_value(&index.index)?;
+                let mut collection = self.value(&index.collection)?;
+
+                match &mut collection {
+                    Literal::Array{values, ..} |
+                    Literal::Tuple{values} if position < values.len() => values[position] = value,
+                    _ => return Err(error(span, format!("index {} is out of bounds", position))),
+                }
+                self.assign(&index.collection, collection, span)?;
+            },
+            ExpressionKind::AccessField(access_field) => {
+                let mut object = self.value(&access_field.object)?;
+
+                match &mut object {
+                    Literal::NamedTuple{values, ..} => {
+                        values.insert(access_field.field.name.clone(), value);
+                    },
+                    _ => return Err(error(span, format!("can not assign to field '{}' at compile time", access_field.field.name.0))),
+                }
+                self.assign(&access_field.object, object, span)?;
+            },
+            _ => return Err(error(span, "can not assign at compile time")),
+        }
+
+        Ok(())
+    }
+
+    /// variable declared in scope of function that is being evaluated (assigned before it was read)
+    fn is_local_scope(&self, scope_id: ScopeId) -> bool {
+        !self.locals.is_empty() && scope_id.0 != ScopeVisitor::GLOBAL_SCOPE_INDEX.0
+    }
+
+    /// 'i++', 'i--', '++i' and '--i'
+    fn step_variable(&mut self, place: &Expression, amount: i128, before_var: bool, span: SoulSpan) -> Evaluation<Literal> {
+        let old = self.value(place)?;
+
+        let new = match to_number(&old) {
+            Some(Number::Int(value)) => from_int(value + amount, span)?,
+            Some(Number::Float(value)) => Literal::Float(Double::new(value + amount as f64)),
+            None => return Err(error(span, "can only increment or decrement numbers")),
+        };
+
+        self.assign(place, new.clone(), span)?;
+        Ok(if before_var { new } else { old })
+    }
+
+    fn resolve_literal(&self, literal: &Literal, span: SoulSpan) -> Evaluation<Literal> {
+
+        match literal {
+            Literal::ProgramMemmory(name, _) => match self.scope.global_literals.get(name) {
+                Some(literal) => self.resolve_literal(literal, span),
+                None => Err(error(span, format!("'{}' not found in program memmory", name.0))),
+            },
+            Literal::Int(value) => from_int(*value as i128, span),
+            literal => Ok(literal.clone()),
+        }
+    }
+
+    fn add_step(&mut self, span: SoulSpan) -> Evaluation<()> {
+        self.steps += 1;
+
+        if self.steps > MAX_STEPS {
+            return Err(error(span, format!("compile time evaluation did not finish within {} steps", MAX_STEPS)))
+        }
+        Ok(())
+    }
+}
+
+/// complex literals are stored in program memmory
+pub fn intern_literal(memmory: &mut ProgramMemmory, literal: Literal, span: SoulSpan) -> Expression {
+    let literal = match literal {
+        Literal::Tuple{..} |
+        Literal::Array{..} |
+        Literal::NamedTuple{..} => {
+            let literal_type = literal.get_literal_type();
+            let id = memmory.insert(literal);
+            Literal::ProgramMemmory(ProgramMemmory::to_program_memory_name(&id), literal_type)
+        },
+        literal => literal,
+    };
+
+    Expression::new(ExpressionKind::Literal(literal), span)
+}
+
+/// 'Literal' variables and 'const' variables initialized with a literal
+pub fn is_compile_time_variable(variable: &Variable) -> bool {
+
+    match variable.ty.modifier {
+        Modifier::Literal => true,
+        Modifier::Const => matches!(variable.initialize_value.as_ref().map(|value| &value.node), Some(ExpressionKind::Literal(_))),
+        Modifier::Default => false,
+    }
+}
+
+fn catch_fall(result: Evaluation<Option<Literal>>) -> Evaluation<Option<Literal>> {
+    match result {
+        Err(Interrupt::Fall(value)) => Ok(Some(value)),
+        result => result,
+    }
+}
+
+fn error<S: Into<String>>(span: SoulSpan, msg: S) -> Interrupt {
+    Interrupt::Error(new_soul_error(SoulErrorKind::InvalidInContext, Some(span), msg))
+}
+
+/// 'Int(1)' and 'Uint(1)' are the same value
+fn is_same_value(left: &Literal, right: &Literal) -> bool {
+    match (to_number(left), to_number(right)) {
+        (Some(Number::Int(left)), Some(Number::Int(right))) => left == right,
+        _ => left == right,
+    }
+}
+
+fn to_number(literal: &Literal) -> Option<Number> {
+    match literal {
+        Literal::Int(value) => Some(Number::Int(*value as i128)),
+        Literal::Uint(value) => Some(Number::Int(*value as i128)),
+        Literal::Float(value) => Some(Number::Float(value.as_f64())),
+        _ => None,
+    }
+}
+
+/// positive integers are 'untypedUint' like in the parser
+fn from_int(value: i128, span: SoulSpan) -> Evaluation<Literal> {
+    if value >= 0 && let Ok(value) = u64::try_from(value) {
+        return Ok(Literal::Uint(value))
+    }
+
+    match i64::try_from(value) {
+        Ok(value) => Ok(Literal::Int(value)),
+        Err(_) => Err(error(span, format!("integer overflow, '{}' does not fit in 64 bits", value))),
+    }
+}
+
+/// error if integer literal does not fit in ty (e.g. '256' in 'u8'), checks every element of arrays
+fn check_integer_range(ty: &SoulType, literal: &Literal, span: SoulSpan) -> Result<(), SoulError> {
+
+    if let Literal::Array{values, ..} = literal && matches!(ty.wrappers.last(), Some(TypeWrapper::Array | TypeWrapper::StackArray(_))) {
+        let mut element_type = ty.clone();
+        element_type.wrappers.pop();
+        return values.iter().try_for_each(|value| check_integer_range(&element_type, value, span))
+    }
+
+    let value = match literal {
+        Literal::Int(value) => *value as i128,
+        Literal::Uint(value) => *value as i128,
+        _ => return Ok(()),
+    };
+
+    let (min, max) = match integer_range(&ty.base) {
+        Some(range) if ty.wrappers.is_empty() => range,
+        _ => return Ok(()),
+    };
+
+    if value < min || value > max {
+        return Err(new_soul_error(SoulErrorKind::InvalidInContext, Some(span), format!("integer overflow, '{}' does not fit in '{}'", value, type_name(ty))))
+    }
+
+    Ok(())
+}
+
+/// smallest and biggest value of integer type, untyped integers are 'int' and 'uint' like in the backend
+fn integer_range(ty: &TypeKind) -> Option<(i128, i128)> {
+    let bits = |size: &TypeSize| match size {
+        TypeSize::Bit8 => 8,
+        TypeSize::Bit16 => 16,
+        TypeSize::Bit32 => 32,
+        TypeSize::Bit64 => 64,
+    };
+
+    Some(match ty {
+        TypeKind::UntypedInt | TypeKind::SystemInt => (i64::MIN as i128, i64::MAX as i128),
+        TypeKind::Int(size) => (-(1i128 << (bits(size) - 1)), (1i128 << (bits(size) - 1)) - 1),
+        TypeKind::UntypedUint | TypeKind::SystemUint => (0, u64::MAX as i128),
+        TypeKind::Uint(size) => (0, (1i128 << bits(size)) - 1),
+        _ => return None,
+    })
+}
+
+fn get_element(collection: Literal, position: usize, span: SoulSpan) -> Evaluation<Literal> {
+    let length = match &collection {
+        Literal::Array{values, ..} |
+        Literal::Tuple{values} => values.len(),
+        Literal::Str(value) => value.chars().count(),
+        _ => return Err(error(span, format!("can not index '{}'", collection.value_to_string()))),
+    };
+
+    if position >= length {
+        return Err(error(span, format!("index {} is out of bounds for length {}", position, length)))
+    }
+
+    Ok(match collection {
+        Literal::Array{mut values, ..} |
+        Literal::Tuple{mut values} => values.swap_remove(position),
+        Literal::Str(value) => Literal::Char(value.chars().nth(position).expect("position should be in bounds")),
+        _ => unreachable!(),
+    })
+}
+
+fn binary_operation(operator: &BinaryOperatorKind, left: Literal, right: Literal, span: SoulSpan) -> Evaluation<Literal> {
+    let invalid = |left: &Literal, right: &Literal| error(span, format!(
+        "operator '{}' can not be used on '{}' and '{}' at compile time",
+        operator.to_str(),
+        left.value_to_string(),
+        right.value_to_string(),
+    ));
+
+    if let (Some(left_number), Some(right_number)) = (to_number(&left), to_number(&right)) {
+        return number_operation(operator, left_number, right_number, span)?.ok_or_else(|| invalid(&left, &right))
+    }
+
+    let literal = match (operator, &left, &right) {
+        (BinaryOperatorKind::Eq, left, right) => Literal::Bool(left == right),
+        (BinaryOperatorKind::NotEq, left, right) => Literal::Bool(left != right),
+
+        (BinaryOperatorKind::LogAnd | BinaryOperatorKind::BitAnd, Literal::Bool(left), Literal::Bool(right)) => Literal::Bool(*left && *right),
+        (BinaryOperatorKind::LogOr | BinaryOperatorKind::BitOr, Literal::Bool(left), Literal::Bool(right)) => Literal::Bool(*left || *right),
+        (BinaryOperatorKind::BitXor, Literal::Bool(left), Literal::Bool(right)) => Literal::Bool(left != right),
+
+        (BinaryOperatorKind::Add, Literal::Str(left), Literal::Str(right)) => Literal::Str(format!("{}{}", left, right)),
+
+        (BinaryOperatorKind::Lt, Literal::Char(left), Literal::Char(right)) => Literal::Bool(left < right),
+        (BinaryOperatorKind::Gt, Literal::Char(left), Literal::Char(right)) => Literal::Bool(left > right),
+        (BinaryOperatorKind::Le, Literal::Char(left), Literal::Char(right)) => Literal::Bool(left <= right),
+        (BinaryOperatorKind::Ge, Literal::Char(left), Literal::Char(right)) => Literal::Bool(left >= right),
+        (BinaryOperatorKind::Lt, Literal::Str(left), Literal::Str(right)) => Literal::Bool(left < right),
+        (BinaryOperatorKind::Gt, Literal::Str(left), Literal::Str(right)) => Literal::Bool(left > right),
+        (BinaryOperatorKind::Le, Literal::Str(left), Literal::Str(right)) => Literal::Bool(left <= right),
+        (BinaryOperatorKind::Ge, Literal::Str(left), Literal::Str(right)) => Literal::Bool(left >= right),
+
+        _ => return Err(invalid(&left, &right)),
+    };
+
+    Ok(literal)
+}
+
+/// None if operator can not be used on numbers
+fn number_operation(operator: &BinaryOperatorKind, left: Number, right: Number, span: SoulSpan) -> Evaluation<Option<Literal>> {
+
+    let (left, right) = match (left, right) {
+        (Number::Int(left), Number::Int(right)) => return int_operation(operator, left, right, span),
+        (Number::Int(left), Number::Float(right)) => (left as f64, right),
+        (Number::Float(left), Number::Int(right)) => (left, right as f64),
+        (Number::Float(left), Number::Float(right)) => (left, right),
+    };
+
+    let value = match operator {
+        BinaryOperatorKind::Add => left + right,
+        BinaryOperatorKind::Sub => left - right,
+        BinaryOperatorKind::Mul => left * right,
+        BinaryOperatorKind::Div if right == 0.0 => return Err(error(span, "division by zero")),
+        BinaryOperatorKind::Div => left / right,
+        BinaryOperatorKind::Mod if right == 0.0 => return Err(error(span, "modulo by zero")),
+        BinaryOperatorKind::Mod => left % right,
+        BinaryOperatorKind::Pow => left.powf(right),
+        BinaryOperatorKind::Root if right == 0.0 => return Err(error(span, "root of degree zero")),
+        BinaryOperatorKind::Root if left < 0.0 => return Err(error(span, "root of negative number")),
+        BinaryOperatorKind::Root => left.powf(1.0 / right),
+        BinaryOperatorKind::Log if left <= 0.0 || right <= 0.0 || right == 1.0 => return Err(error(span, format!("logarithm of '{}' with base '{}' is undefined", left, right))),
+        BinaryOperatorKind::Log => left.log(right),
+
+        BinaryOperatorKind::Eq => return Ok(Some(Literal::Bool(left == right))),
+        BinaryOperatorKind::NotEq => return Ok(Some(Literal::Bool(left != right))),
+        BinaryOperatorKind::Lt => return Ok(Some(Literal::Bool(left < right))),
+        BinaryOperatorKind::Gt => return Ok(Some(Literal::Bool(left > right))),
+        BinaryOperatorKind::Le => return Ok(Some(Literal::Bool(left <= right))),
+        BinaryOperatorKind::Ge => return Ok(Some(Literal::Bool(left >= right))),
+        _ => return Ok(None),
+    };
+
+    Ok(Some(Literal::Float(Double::new(value))))
+}
+
+fn int_operation(operator: &BinaryOperatorKind, left: i128, right: i128, span: SoulSpan) -> Evaluation<Option<Literal>> {
+    let overflow = || error(span, format!("integer overflow in '{} {} {}'", left, operator.to_str(), right));
+
+    let value = match operator {
+        BinaryOperatorKind::Add => left.checked_add(right).ok_or_else(overflow)?,
+        BinaryOperatorKind::Sub => left.checked_sub(right).ok_or_else(overflow)?,
+        BinaryOperatorKind::Mul => left.checked_mul(right).ok_or_else(overflow)?,
+        BinaryOperatorKind::Div if right == 0 => return Err(error(span, "division by zero")),
+        BinaryOperatorKind::Div => left / right,
+        BinaryOperatorKind::Mod if right == 0 => return Err(error(span, "modulo by zero")),
+        BinaryOperatorKind::Mod => left % right,
+        BinaryOperatorKind::Pow if right < 0 => return Err(error(span, format!("integer '{} {} {}' has a negative exponent, use a float (e.g. '{}.0 {} {}')", left, operator.to_str(), right, left, operator.to_str(), right))),
+        BinaryOperatorKind::Pow => u32::try_from(right).ok().and_then(|right| left.checked_pow(right)).ok_or_else(overflow)?,
+        BinaryOperatorKind::Root if right <= 0 => return Err(error(span, format!("root of degree '{}'", right))),
+        BinaryOperatorKind::Root if left < 0 && right % 2 == 0 => return Err(error(span, "even root of negative number")),
+        BinaryOperatorKind::Root => int_root(left, right),
+        BinaryOperatorKind::Log if left <= 0 || right <= 1 => return Err(error(span, format!("logarithm of '{}' with base '{}' is undefined", left, right))),
+        BinaryOperatorKind::Log => left.ilog(right) as i128,
+
+        BinaryOperatorKind::BitAnd => left & right,
+        BinaryOperatorKind::BitOr => left | right,
+        BinaryOperatorKind::BitXor => left ^ right,
+
+        BinaryOperatorKind::Range => {
+            if right - left > MAX_STEPS as i128 {
+                return Err(error(span, format!("range '{}..{}' is too big to evaluate at compile time", left, right)))
+            }
+
+            let values = (left..right).map(|value| from_int(value, span)).collect::<Evaluation<Vec<_>>>()?;
+            return Ok(Some(Literal::new_array(values, &span)?))
+        },
+
+        BinaryOperatorKind::Eq => return Ok(Some(Literal::Bool(left == right))),
+        BinaryOperatorKind::NotEq => return Ok(Some(Literal::Bool(left != right))),
+        BinaryOperatorKind::Lt => return Ok(Some(Literal::Bool(left < right))),
+        BinaryOperatorKind::Gt => return Ok(Some(Literal::Bool(left > right))),
+        BinaryOperatorKind::Le => return Ok(Some(Literal::Bool(left <= right))),
+        BinaryOperatorKind::Ge => return Ok(Some(Literal::Bool(left >= right))),
+        _ => return Ok(None),
+    };
+
+    from_int(value, span).map(Some)
+}
+
+/// integer root rounded towards zero (e.g '10 </ 3' is 2)
+fn int_root(value: i128, degree: i128) -> i128 {
+    let sign = value.signum();
+    let value = value.abs();
+
+    let mut root = (value as f64).powf(1.0 / degree as f64).round() as i128;
+    let pow = |root: i128| root.checked_pow(degree as u32).unwrap_or(i128::MAX);
+
+    while root > 0 && pow(root) > value {
+        root -= 1;
+    }
+    while pow(root + 1) <= value {
+        root += 1;
+    }
+
+    sign * root
+}
+
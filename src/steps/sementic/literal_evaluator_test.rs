@@ -0,0 +1,215 @@
+use crate::steps::{sementic::test_helpers::{check_literals, error_messages, global_symbol}, step_interfaces::{i_parser::{abstract_syntax_tree::{expression::{ExpressionKind, Ident}, literal::Literal}, scope_builder::ScopeKind}, i_sementic::scope_vistitor::ScopeVisitor}};
+
+fn literal_value(scope: &ScopeVisitor, name: &str) -> Literal {
+    let variable = match global_symbol(scope, name) {
+        ScopeKind::Variable(variable) => variable,
+        other => panic!("'{}' should be variable but is {:?}", name, other),
+    };
+
+    match &variable.initialize_value.as_ref().expect("variable should be initialized").node {
+        ExpressionKind::Literal(Literal::ProgramMemmory(name, _)) => scope.global_literals.get(name).expect("literal should be in program memmory").clone(),
+        ExpressionKind::Literal(literal) => literal.clone(),
+        other => panic!("'{}' should be evaluated to literal but is {:?}", name, other),
+    }
+}
+
+#[test]
+fn test_evaluate_literals() {
+    let source = "
+Literal factorial(int n) int {
+    mut result := 1
+    mut i := 1
+    while i <= n {
+        result = result * i
+        i++
+    }
+    return result
+}
+
+Literal fib(int n) int {
+    if n < 2 {
+        return n
+    }
+    return fib(n - 1) + fib(n - 2)
+}
+
+Literal sign(int n) int {
+    if n < 0 {
+        return -1
+    }
+    const result := match n {
+        0 => fall 0,
+        _ => fall 1,
+    }
+    return result
+}
+
+Literal int base = 2
+Literal power = base ** 10
+Literal root = 27 </ 3
+Literal log = 100 log 10
+Literal fact = factorial(5)
+Literal fibs = [fib(1), fib(5), fib(10)]
+Literal negative = sign(-4) * base
+Literal tuple = (power, true)
+";
+
+    let (scope, faults) = check_literals(source);
+    assert!(error_messages(&faults).is_empty(), "{:?}", error_messages(&faults));
+
+    assert_eq!(literal_value(&scope, "power"), Literal::Uint(1024));
+    assert_eq!(literal_value(&scope, "root"), Literal::Uint(3));
+    assert_eq!(literal_value(&scope, "log"), Literal::Uint(2));
+    assert_eq!(literal_value(&scope, "fact"), Literal::Uint(120));
+    assert_eq!(literal_value(&scope, "negative"), Literal::Int(-2));
+    assert_eq!(literal_value(&scope, "tuple"), Literal::new_tuple(vec![Literal::Uint(1024), Literal::Bool(true)]));
+
+    match literal_value(&scope, "fibs") {
+        Literal::Array{values, ..} => assert_eq!(values, vec![Literal::Uint(1), Literal::Uint(5), Literal::Uint(55)]),
+        other => panic!("'fibs' should be array but is {:?}", other),
+    }
+
+    assert!(scope.global_literals.get(&Ident::new("__soul_mem_does_not_exist")).is_none());
+}
+
+#[test]
+fn test_division_by_zero() {
+    let source = "
+Literal divide(int a, int b) int {
+    return a / b
+}
+
+Literal int zero = 0
+Literal int quotient = divide(4, zero)
+";
+
+    let (_, faults) = check_literals(source);
+    let errors = error_messages(&faults);
+    assert_eq!(errors.len(), 1, "{:?}", errors);
+    assert!(errors[0].contains("at 7:"), "{}", errors[0]);
+    assert!(errors[0].contains("while evaluating 'divide' at compile time"), "{}", errors[0]);
+    assert!(errors[0].contains("at 3:") && errors[0].contains("division by zero"), "{}", errors[0]);
+}
+
+#[test]
+fn test_not_known_at_compile_time() {
+    let source = "
+mut runtime := 3
+
+add(int a, int b) int {
+    return a + b
+}
+
+Literal int first = runtime + 1
+Literal int second = add(1, 2)
+";
+
+    let (_, faults) = check_literals(source);
+    let errors = error_messages(&faults);
+    assert_eq!(errors.len(), 2, "{:?}", errors);
+    assert!(errors[0].contains("at 8:") && errors[0].contains("'runtime' is not known at compile time"), "{}", errors[0]);
+    assert!(errors[1].contains("at 9:") && errors[1].contains("'add' is not 'const' or 'Literal'"), "{}", errors[1]);
+}
+
+#[test]
+fn test_enum_values() {
+    let source = "
+enum Nums {
+    One,
+    Two,
+    Ten = 10,
+    Three
+}
+
+enum Small impl u8 {
+    A = 1 + 2,
+    B,
+    C = 3
+}
+
+enum Paths impl str {
+    MainDisk = \"C:\",
+    OtherDisk = \"D:\"
+}
+";
+
+    let (scope, faults) = check_literals(source);
+    let errors = error_messages(&faults);
+    assert_eq!(errors.len(), 1, "{:?}", errors);
+    assert!(errors[0].contains("at 12:") && errors[0].contains("variant 'C' of enum 'Small' has the same value (3) as variant 'A'"), "{}", errors[0]);
+
+    let values = |name: &str| match global_symbol(&scope, name) {
+        ScopeKind::Enum(enum_) => enum_.values().into_iter()
+            .map(|(name, value)| (name.0.clone(), value.expect("variant should be evaluated")))
+            .collect::<Vec<_>>(),
+        other => panic!("'{}' should be enum but is {:?}", name, other),
+    };
+
+    assert_eq!(values("Nums"), vec![("One".into(), Literal::Int(0)), ("Two".into(), Literal::Int(1)), ("Ten".into(), Literal::Int(10)), ("Three".into(), Literal::Int(11))]);
+    assert_eq!(values("Small"), vec![("A".into(), Literal::Uint(3)), ("B".into(), Literal::Uint(4)), ("C".into(), Literal::Uint(3))]);
+    assert_eq!(values("Paths"), vec![("MainDisk".into(), Literal::Str("C:".into())), ("OtherDisk".into(), Literal::Str("D:".into()))]);
+}
+
+#[test]
+fn test_integer_overflow_of_type() {
+    let source = "
+Literal big = 9223372036854775807 + 1
+Literal u8 small = 200 + 56
+Literal i8[] bytes = [1, 200]
+Literal neg = 2 ** (0 - 1)
+Literal u8 fits = 200 + 55
+
+enum Small impl u8 {
+    A = 255,
+    B
+}
+";
+
+    let (scope, faults) = check_literals(source);
+    let errors = error_messages(&faults);
+    assert_eq!(errors.len(), 5, "{:?}", errors);
+    assert!(errors[0].contains("at 2:") && errors[0].contains("integer overflow, '9223372036854775808' does not fit in 'int'"), "{}", errors[0]);
+    assert!(errors[1].contains("at 3:") && errors[1].contains("integer overflow, '256' does not fit in 'u8'"), "{}", errors[1]);
+    assert!(errors[2].contains("at 4:") && errors[2].contains("integer overflow, '200' does not fit in 'i8'"), "{}", errors[2]);
+    assert!(errors[3].contains("at 5:") && errors[3].contains("integer '2 ** -1' has a negative exponent, use a float (e.g. '2.0 ** -1')"), "{}", errors[3]);
+    assert!(errors[4].contains("at 10:") && errors[4].contains("integer overflow, '256' does not fit in 'u8'"), "{}", errors[4]);
+
+    assert_eq!(literal_value(&scope, "fits"), Literal::Uint(255));
+}
+
+#[test]
+fn test_recursion_limit() {
+    let source = "
+Literal countDown(int n) int {
+    if n == 0 {
+        return 0
+    }
+    return countDown(n - 1)
+}
+
+Literal int deep = countDown(200)
+Literal int tooDeep = countDown(100000)
+";
+
+    let (scope, faults) = check_literals(source);
+    let errors = error_messages(&faults);
+    assert_eq!(errors.len(), 1, "{:?}", errors);
+    assert!(errors[0].contains("at 10:") && errors[0].contains("recursion of 'countDown' is deeper than 256 calls"), "{}", errors[0]);
+
+    assert_eq!(literal_value(&scope, "deep"), Literal::Uint(0));
+}
+
+#[test]
+fn test_duplicate_enum_value_span() {
+    let source = "
+enum Nums {
+    Ten = 10,
+    Three,
+    Eleven = 11
+}
+";
+    let (_, faults) = check_literals(source);
+    let errors = error_messages(&faults);
+    assert_eq!(errors.len(), 1, "{:?}", errors);
+    assert!(errors[0].starts_with("at 5:4| variant 'Eleven' of enum 'Nums' has the same value (11) as variant 'Three'"), "{}", errors[0]);
+}
@@ -0,0 +1,517 @@
+use std::collections::HashMap;
+use crate::{errors::soul_error::SoulSpan, steps::{sementic::{literal_evaluator::{intern_literal, is_compile_time_variable, Evaluator}, ruleset::takes_mut_this, type_checking::{find_callee_functions, find_functions}}, step_interfaces::{i_parser::{abstract_syntax_tree::{abstract_syntax_tree::AbstractSyntacTree, expression::{CaseDoKind, ElseKind, Expression, ExpressionGroup, ExpressionKind, If, UnaryOperatorKind, UnwrapVariable}, function::{Function, LambdaBody}, literal::Literal, object::ClassChild, soul_type::soul_type::Modifier, statement::{Block, Statement, StatementKind}}, scope_builder::{ScopeKind, Variable}}, i_sementic::ast_visitor::{AstAnalyser, LiteralRetentionAnalyser}}}};
+
+/// read of a variable and the literal value it retained at that point (None if only known at runtime)
+#[derive(Debug, Clone, PartialEq)]
+pub struct RetainedUse {
+    pub name: String,
+    pub span: SoulSpan,
+    pub value: Option<Literal>,
+}
+
+/// literal status of the variables declared per block, innermost block last
+type RetentionFrames = Vec<HashMap<String, Option<Literal>>>;
+
+#[derive(Default)]
+struct RetentionContext {
+    frames: RetentionFrames,
+    uses: Vec<RetainedUse>,
+    /// only collects which variables lose their literal status (used for loops and lambdas)
+    is_dry_run: bool,
+}
+
+impl AstAnalyser for LiteralRetentionAnalyser {
+
+    fn analyse_ast(&mut self, tree: &mut AbstractSyntacTree) {
+        self.analyse_retention(tree);
+    }
+}
+
+impl LiteralRetentionAnalyser {
+
+    /// tracks which variables retain their literal value and runs calls to 'const' functions with only literals at compile time
+    pub fn analyse_retention(&mut self, tree: &mut AbstractSyntacTree) -> Vec<RetainedUse> {
+        let mut context = RetentionContext::default();
+        self.analyse_block(&mut tree.root, &mut context);
+        context.uses
+    }
+
+    fn analyse_block(&mut self, block: &mut Block, context: &mut RetentionContext) {
+        let parent_id = self.get_scope().current_id();
+        self.get_scope_mut().set_current(block.scope_id);
+        context.frames.push(HashMap::new());
+
+        for statment in &mut block.statments {
+            self.analyse_statment(statment, context);
+        }
+
+        context.frames.pop();
+        self.get_scope_mut()
+            .set_current(parent_id)
+            .expect("scope_id should be valid");
+    }
+
+    fn analyse_statment(&mut self, statment: &mut Statement, context: &mut RetentionContext) {
+        let span = statment.span;
+
+        let parent_id = self.get_scope().current_id();
+        if let Some(id) = statment.node.get_scope_id() && self.get_scope_mut().set_current(id).is_none() {
+            panic!("could not get scope_id({}) at line: {}:{}", id.0, span.line_number, span.line_offset)
+        }
+
+        match &mut statment.node {
+            StatementKind::Enum(_) |
+            StatementKind::Trait(_) |
+            StatementKind::Union(_) |
+            StatementKind::Struct(_) |
+            StatementKind::TypeEnum(_) |
+            StatementKind::CloseBlock => (),
+
+            StatementKind::Expression(expression) => self.analyse_expression(expression, context),
+            StatementKind::Variable(variable_name) => self.analyse_variable(&variable_name.name.0, context),
+            StatementKind::Assignment(assignment) => {
+                self.analyse_expression(&mut assignment.value, context);
+                self.analyse_place(&mut assignment.variable, context);
+            },
+            StatementKind::Function(function) => self.analyse_function(function, context),
+            StatementKind::UseBlock(use_block) => self.analyse_block(&mut use_block.block, context),
+            StatementKind::Class(class) => {
+
+                for child in &mut class.children {
+                    match child {
+                        ClassChild::Field(_) => (),
+                        ClassChild::Methode(methode) => {
+                            let parent_id = self.get_scope().current_id();
+                            self.get_scope_mut().set_current(methode.node.block.scope_id);
+
+                            self.analyse_function(&mut methode.node, context);
+
+                            self.get_scope_mut()
+                                .set_current(parent_id)
+                                .expect("scope_id should be valid");
+                        },
+                        ClassChild::ImplBlock(impl_block) => self.analyse_block(&mut impl_block.node.block, context),
+                    }
+                }
+            },
+        }
+
+        self.get_scope_mut()
+            .set_current(parent_id)
+            .expect("scope_id should be valid");
+    }
+
+    /// function body can be called at any time so only starts with its parameters (as runtime values)
+    fn analyse_function(&mut self, function: &mut Function, context: &mut RetentionContext) {
+        let parameters = function.signature.parameters.iter()
+            .map(|parameter| (parameter.node.name.0.clone(), None))
+            .collect();
+
+        let frames = std::mem::replace(&mut context.frames, vec![parameters]);
+        self.analyse_block(&mut function.block, context);
+        context.frames = frames;
+    }
+
+    fn analyse_variable(&mut self, name: &str, context: &mut RetentionContext) {
+        let mut value = match self.get_variable(name) {
+            Some(Variable{initialize_value: Some(value), ..}) => value.clone(),
+            _ => {
+                declare(context, name, None);
+                return
+            },
+        };
+
+        self.analyse_expression(&mut value, context);
+
+        let literal = match &value.node {
+            _ if context.is_dry_run => None,
+            ExpressionKind::Literal(literal) => Some(literal.clone()),
+            _ => self.evaluate(&value, context),
+        };
+
+        if !context.is_dry_run {
+            let variable = self.get_scope_mut()
+                .flat_lookup_mut(name)
+                .and_then(|kinds| kinds.iter_mut().find_map(|kind| match &mut kind.node {
+                    ScopeKind::Variable(variable) => Some(variable),
+                    _ => None,
+                }));
+
+            if let Some(variable) = variable {
+                variable.initialize_value = Some(value);
+            }
+        }
+
+        declare(context, name, literal);
+    }
+
+    /// assigned variable loses its literal status
+    fn analyse_place(&mut self, place: &mut Expression, context: &mut RetentionContext) {
+
+        match &mut place.node {
+            ExpressionKind::Variable(variable_name) => lose_status(context, &variable_name.name.0),
+            ExpressionKind::Index(index) => {
+                self.analyse_expression(&mut index.index, context);
+                self.analyse_place(&mut index.collection, context);
+            },
+            ExpressionKind::AccessField(access_field) => self.analyse_place(&mut access_field.object, context),
+            ExpressionKind::Deref(inner) => self.analyse_expression(inner, context),
+            _ => self.analyse_expression(place, context),
+        }
+    }
+
+    fn analyse_expression(&mut self, expression: &mut Expression, context: &mut RetentionContext) {
+        let span = expression.span;
+
+        let parent_id = self.get_scope().current_id();
+        if let Some(id) = expression.node.get_scope_id() && self.get_scope_mut().set_current(id).is_none() {
+            panic!("could not get scope_id({}) at line: {}:{}", id.0, span.line_number, span.line_offset)
+        }
+
+        self.analyse_expression_kind(expression, context);
+
+        self.get_scope_mut()
+            .set_current(parent_id)
+            .expect("scope_id should be valid");
+    }
+
+    fn analyse_expression_kind(&mut self, expression: &mut Expression, context: &mut RetentionContext) {
+        let span = expression.span;
+
+        match &mut expression.node {
+            ExpressionKind::Empty |
+            ExpressionKind::Default |
+            ExpressionKind::Literal(_) |
+            ExpressionKind::StaticField(_) |
+            ExpressionKind::ExternalExpression(_) => (),
+
+            ExpressionKind::Variable(variable_name) => if !context.is_dry_run {
+                let name = variable_name.name.0.clone();
+                let value = self.retained_value(context, &name);
+                context.uses.push(RetainedUse{name, span, value});
+            },
+            ExpressionKind::Index(index) => {
+                self.analyse_expression(&mut index.collection, context);
+                self.analyse_expression(&mut index.index, context);
+            },
+            ExpressionKind::Lambda(lambda) => {
+                for argument in &mut lambda.arguments.values {
+                    self.analyse_expression(argument, context);
+                }
+
+                // lambda can be called at any time so everything it mutates loses literal status
+                let parameters: HashMap<_, _> = lambda.signature.params.iter()
+                    .map(|parameter| (parameter.node.name.0.clone(), None))
+                    .collect();
+
+                let mut frames = context.frames.clone();
+                frames.push(parameters.clone());
+                self.dry_run(&mut frames, |this, context| match &mut lambda.body {
+                    LambdaBody::Block(block) => this.analyse_block(block, context),
+                    LambdaBody::Expression(body) => this.analyse_expression(body, context),
+                });
+                frames.pop();
+                lose_changed(context, &frames);
+
+                let frames = std::mem::replace(&mut context.frames, vec![parameters]);
+                match &mut lambda.body {
+                    LambdaBody::Block(block) => self.analyse_block(block, context),
+                    LambdaBody::Expression(body) => self.analyse_expression(body, context),
+                }
+                context.frames = frames;
+            },
+            ExpressionKind::FunctionCall(function_call) => {
+                for argument in &mut function_call.arguments.values {
+                    self.analyse_expression(argument, context);
+                }
+
+                if let Some(callee) = &mut function_call.callee {
+                    self.analyse_expression(callee, context);
+
+                    if self.mutates_callee(callee, &function_call.name.0, function_call.arguments.values.len()) {
+                        self.analyse_place(callee, context);
+                    }
+                    return
+                }
+
+                if context.is_dry_run || !self.is_const_call(&function_call.name.0, function_call.arguments.values.len()) {
+                    return
+                }
+
+                let is_known = function_call.arguments.values.iter().all(|argument| match &argument.node {
+                    ExpressionKind::Literal(_) => true,
+                    ExpressionKind::Variable(variable_name) => self.retained_value(context, &variable_name.name.0).is_some(),
+                    _ => false,
+                });
+
+                if is_known && let Some(literal) = self.evaluate(expression, context) {
+                    *expression = intern_literal(&mut self.get_scope_mut().global_literals, literal, span);
+                }
+            },
+            ExpressionKind::StructConstructor(struct_constructor) => for value in struct_constructor.arguments.values.values_mut() {
+                self.analyse_expression(value, context);
+            },
+            ExpressionKind::AccessField(access_field) => self.analyse_expression(&mut access_field.object, context),
+            ExpressionKind::StaticMethod(static_method) => for argument in &mut static_method.arguments.values {
+                self.analyse_expression(argument, context);
+            },
+            ExpressionKind::UnwrapVariable(unwrap_variable) => {
+                if let UnwrapVariable::MultiVariable{initializer: Some(initializer), ..} = unwrap_variable {
+                    self.analyse_expression(initializer, context);
+                }
+            },
+            ExpressionKind::Unary(unary) => match unary.operator.node {
+                UnaryOperatorKind::Increment{..} |
+                UnaryOperatorKind::Decrement{..} => self.analyse_place(&mut unary.expression, context),
+                _ => self.analyse_expression(&mut unary.expression, context),
+            },
+            ExpressionKind::Binary(binary) => {
+                self.analyse_expression(&mut binary.left, context);
+                self.analyse_expression(&mut binary.right, context);
+            },
+            ExpressionKind::If(if_decl) => self.analyse_if(if_decl, context),
+            ExpressionKind::For(for_decl) => {
+                self.analyse_expression(&mut for_decl.collection, context);
+
+                let mut element = HashMap::new();
+                if let Some(pattern) = &for_decl.element {
+                    let mut names = vec![];
+                    push_pattern_names(pattern, &mut names);
+                    element.extend(names.into_iter().map(|name| (name, None)));
+                }
+
+                self.analyse_loop(context, |this, context| {
+                    context.frames.push(element.clone());
+                    this.analyse_block(&mut for_decl.block, context);
+                    context.frames.pop();
+                });
+            },
+            ExpressionKind::While(while_decl) => self.analyse_loop(context, |this, context| {
+                if let Some(condition) = &mut while_decl.condition {
+                    this.analyse_expression(condition, context);
+                }
+                this.analyse_block(&mut while_decl.block, context);
+            }),
+            ExpressionKind::Match(match_decl) => {
+                self.analyse_expression(&mut match_decl.condition, context);
+
+                let before = context.frames.clone();
+                let mut merged = before.clone();
+                for case in &mut match_decl.cases {
+                    let parent_id = self.get_scope().current_id();
+                    self.get_scope_mut().set_current(case.scope_id);
+                    context.frames = before.clone();
+
+                    match &mut case.do_fn {
+                        CaseDoKind::Block(block) => self.analyse_block(&mut block.node, context),
+                        CaseDoKind::Expression(do_expression) => self.analyse_expression(do_expression, context),
+                    }
+
+                    merge(&mut merged, &context.frames);
+                    self.get_scope_mut()
+                        .set_current(parent_id)
+                        .expect("scope_id should be valid");
+                }
+                context.frames = merged;
+            },
+            ExpressionKind::Ternary(ternary) => {
+                self.analyse_expression(&mut ternary.condition, context);
+
+                let before = context.frames.clone();
+                self.analyse_expression(&mut ternary.if_branch, context);
+                let if_frames = std::mem::replace(&mut context.frames, before);
+                self.analyse_expression(&mut ternary.else_branch, context);
+                merge(&mut context.frames, &if_frames);
+            },
+            ExpressionKind::MutRef(inner) => self.analyse_place(inner, context),
+            ExpressionKind::Deref(inner) |
+            ExpressionKind::ConstRef(inner) |
+            ExpressionKind::Copy(inner) => self.analyse_expression(inner, context),
+            ExpressionKind::Block(block) => self.analyse_block(block, context),
+            ExpressionKind::ReturnLike(return_like) => if let Some(value) = &mut return_like.value {
+                self.analyse_expression(value, context);
+            },
+            ExpressionKind::ExpressionGroup(expression_group) => match expression_group {
+                ExpressionGroup::Tuple(tuple) => for value in &mut tuple.values {
+                    self.analyse_expression(value, context);
+                },
+                ExpressionGroup::Array(array) => for value in &mut array.values {
+                    self.analyse_expression(value, context);
+                },
+                ExpressionGroup::NamedTuple(named_tuple) => for value in named_tuple.values.values_mut() {
+                    self.analyse_expression(value, context);
+                },
+                ExpressionGroup::ArrayFiller(array_filler) => {
+                    self.analyse_expression(&mut array_filler.amount, context);
+                    self.analyse_expression(&mut array_filler.fill_expr, context);
+                },
+            },
+        }
+    }
+
+    /// variable keeps its literal status after the if only if it has the same value in every branch
+    fn analyse_if(&mut self, if_decl: &mut If, context: &mut RetentionContext) {
+        self.analyse_expression(&mut if_decl.condition, context);
+        let skipped = context.frames.clone();
+
+        self.analyse_block(&mut if_decl.block, context);
+        let mut merged = std::mem::replace(&mut context.frames, skipped);
+
+        for branch in &mut if_decl.else_branchs {
+            match &mut branch.node {
+                ElseKind::Else(block) => self.analyse_block(&mut block.node, context),
+                ElseKind::ElseIf(else_if) => self.analyse_if(&mut else_if.node, context),
+            }
+        }
+
+        merge(&mut merged, &context.frames);
+        context.frames = merged;
+    }
+
+    /// variables that are changed in the body lose their literal status before the first iteration
+    fn analyse_loop<F>(&mut self, context: &mut RetentionContext, mut body: F)
+    where
+        F: FnMut(&mut Self, &mut RetentionContext),
+    {
+        let mut frames = context.frames.clone();
+        self.dry_run(&mut frames, &mut body);
+        lose_changed(context, &frames);
+
+        let before = context.frames.clone();
+        body(self, context);
+        merge(&mut context.frames, &before);
+    }
+
+    fn dry_run<F>(&mut self, frames: &mut RetentionFrames, body: F)
+    where
+        F: FnOnce(&mut Self, &mut RetentionContext),
+    {
+        let mut dry_context = RetentionContext{frames: std::mem::take(frames), uses: vec![], is_dry_run: true};
+        body(self, &mut dry_context);
+        *frames = dry_context.frames;
+    }
+
+    fn retained_value(&self, context: &RetentionContext, name: &str) -> Option<Literal> {
+
+        if let Some(value) = context.frames.iter().rev().find_map(|frame| frame.get(name)) {
+            return value.clone()
+        }
+
+        match self.get_variable(name) {
+            Some(variable) if is_compile_time_variable(variable) => match &variable.initialize_value.as_ref()?.node {
+                ExpressionKind::Literal(literal) => Some(literal.clone()),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    /// value of expression using the variables that retained their literal value
+    fn evaluate(&self, expression: &Expression, context: &RetentionContext) -> Option<Literal> {
+        let mut locals = HashMap::new();
+        for frame in &context.frames {
+            for (name, value) in frame {
+                match value {
+                    Some(value) => locals.insert(name.clone(), value.clone()),
+                    None => locals.remove(name),
+                };
+            }
+        }
+
+        Evaluator::with_locals(self.get_scope(), locals)
+            .evaluate(expression, self.get_scope().current_id())
+            .ok()
+    }
+
+    /// all candidates are 'const' or 'Literal' so the call can be run at compile time
+    fn is_const_call(&self, name: &str, argument_len: usize) -> bool {
+        let candidates = find_functions(self.get_scope(), name);
+        let mut candidates = candidates.iter().filter(|candidate| candidate.parameters.len() == argument_len).peekable();
+        candidates.peek().is_some() && candidates.all(|candidate| candidate.ruleset != Modifier::Default)
+    }
+
+    /// methode could take 'this&' (e.g 'array.push(1)')
+    fn mutates_callee(&self, callee: &Expression, name: &str, argument_len: usize) -> bool {
+        let callee_type = match &callee.node {
+            ExpressionKind::Variable(variable_name) => self.get_variable(&variable_name.name.0).map(|variable| variable.ty.clone()),
+            _ => None,
+        };
+
+        let candidates = match callee_type {
+            Some(ty) if !ty.is_none_type() => find_callee_functions(self.get_scope(), name, &ty, true),
+            _ => return true,
+        };
+
+        let candidates = candidates.iter().filter(|candidate| candidate.parameters.len() == argument_len).collect::<Vec<_>>();
+        candidates.is_empty() || candidates.iter().any(takes_mut_this)
+    }
+
+    fn get_variable(&self, name: &str) -> Option<&Variable> {
+
+        match &self.get_scope().lookup_kind(name, |kind| matches!(kind, ScopeKind::Variable(_)))?.node {
+            ScopeKind::Variable(variable) => Some(variable),
+            _ => None,
+        }
+    }
+}
+
+/// readable list of every variable read and whether it was still a literal (for '--showOutput')
+pub fn retention_to_string(uses: &[RetainedUse]) -> String {
+    uses.iter()
+        .map(|retained| match &retained.value {
+            Some(value) => format!("{}:{} {} = Literal {}", retained.span.line_number, retained.span.line_offset, retained.name, value.value_to_string()),
+            None => format!("{}:{} {} = runtime", retained.span.line_number, retained.span.line_offset, retained.name),
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn declare(context: &mut RetentionContext, name: &str, value: Option<Literal>) {
+    if context.frames.is_empty() {
+        context.frames.push(HashMap::new());
+    }
+
+    context.frames.last_mut()
+        .expect("frames should have frame")
+        .insert(name.into(), value);
+}
+
+fn lose_status(context: &mut RetentionContext, name: &str) {
+
+    match context.frames.iter_mut().rev().find_map(|frame| frame.get_mut(name)) {
+        Some(value) => *value = None,
+        // variable declared outside of the current function (e.g global)
+        None => if let Some(frame) = context.frames.first_mut() {
+            frame.insert(name.into(), None);
+        },
+    }
+}
+
+/// every variable that changed in frames loses its literal status in context
+fn lose_changed(context: &mut RetentionContext, frames: &RetentionFrames) {
+    merge(&mut context.frames, frames);
+}
+
+/// keeps literal status only if variable has the same value in both frames
+fn merge(frames: &mut RetentionFrames, other: &RetentionFrames) {
+
+    for (frame, other) in frames.iter_mut().zip(other) {
+        for (name, other_value) in other {
+            let value = frame.entry(name.clone()).or_insert(None);
+            if value != other_value {
+                *value = None;
+            }
+        }
+    }
+}
+
+fn push_pattern_names(pattern: &Expression, names: &mut Vec<String>) {
+
+    match &pattern.node {
+        ExpressionKind::Variable(variable_name) => names.push(variable_name.name.0.clone()),
+        ExpressionKind::ExpressionGroup(ExpressionGroup::Tuple(tuple)) => for element in &tuple.values {
+            push_pattern_names(element, names);
+        },
+        _ => (),
+    }
+}
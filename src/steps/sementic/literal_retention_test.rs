@@ -0,0 +1,70 @@
+use crate::steps::{sementic::{literal_retention::RetainedUse, test_helpers::{check_retention, error_messages, initializer}}, step_interfaces::i_parser::abstract_syntax_tree::{expression::ExpressionKind, literal::Literal}};
+
+fn uses_of<'a>(uses: &'a [RetainedUse], name: &str) -> Vec<&'a Option<Literal>> {
+    uses.iter()
+        .filter(|retained| retained.name == name)
+        .map(|retained| &retained.value)
+        .collect()
+}
+
+#[test]
+fn test_retained_literal_in_const_call() {
+    let source = "
+const triple(int n) int {
+    return n * 3
+}
+
+main() {
+    mut a := 1
+    const b := 5
+    const first := triple(a)
+    a = 20
+    const second := triple(a)
+    const third := triple(b)
+}
+";
+
+    let (scope, uses, faults) = check_retention(source);
+    assert!(error_messages(&faults).is_empty(), "{:?}", error_messages(&faults));
+
+    assert_eq!(initializer(&scope, "first").node, ExpressionKind::Literal(Literal::Uint(3)));
+    assert!(matches!(initializer(&scope, "second").node, ExpressionKind::FunctionCall(_)));
+    assert_eq!(initializer(&scope, "third").node, ExpressionKind::Literal(Literal::Uint(15)));
+
+    assert_eq!(uses_of(&uses, "a"), vec![&Some(Literal::Int(1)), &None]);
+    assert_eq!(uses_of(&uses, "b"), vec![&Some(Literal::Int(5))]);
+}
+
+#[test]
+fn test_retention_through_branches_and_loops() {
+    let source = "
+main() {
+    mut a := 1
+    mut b := 2
+    mut c := 3
+    mut i := 0
+    if a == 1 {
+        b = 4
+    }
+    else {
+        Println(a)
+    }
+    while i < 3 {
+        Println(a)
+        i++
+    }
+    mut ref := &c
+    Println(a)
+    Println(b)
+    Println(c)
+}
+";
+
+    let (_, uses, _) = check_retention(source);
+
+    let one = Some(Literal::Int(1));
+    assert_eq!(uses_of(&uses, "a"), vec![&one, &one, &one, &one]);
+    assert_eq!(uses_of(&uses, "b"), vec![&None]);
+    assert_eq!(uses_of(&uses, "c"), vec![&None]);
+    assert_eq!(uses_of(&uses, "i"), vec![&None]);
+}
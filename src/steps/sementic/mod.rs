@@ -1,2 +1,49 @@
 pub mod name_resolution;
-pub mod external_header;
\ No newline at end of file
+pub mod external_header;
+pub mod type_resolution;
+pub mod type_checking;
+pub mod generics;
+pub mod overload_resolution;
+pub mod exhaustiveness;
+pub mod control_flow;
+pub mod traits;
+pub mod ruleset;
+pub mod literal_evaluator;
+pub mod literal_retention;
+pub mod lambda_capture;
+pub mod borrow_checker;
+pub mod optimizer;
+pub mod reachability;
+
+#[cfg(test)]
+pub(crate) mod test_helpers;
+#[cfg(test)]
+mod external_header_test;
+#[cfg(test)]
+mod type_resolution_test;
+#[cfg(test)]
+mod type_checking_test;
+#[cfg(test)]
+mod generics_test;
+#[cfg(test)]
+mod overload_resolution_test;
+#[cfg(test)]
+mod exhaustiveness_test;
+#[cfg(test)]
+mod control_flow_test;
+#[cfg(test)]
+mod traits_test;
+#[cfg(test)]
+mod ruleset_test;
+#[cfg(test)]
+mod literal_evaluator_test;
+#[cfg(test)]
+mod literal_retention_test;
+#[cfg(test)]
+mod lambda_capture_test;
+#[cfg(test)]
+mod borrow_checker_test;
+#[cfg(test)]
+mod optimizer_test;
+#[cfg(test)]
+mod reachability_test;
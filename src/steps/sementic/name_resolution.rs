@@ -198,6 +198,9 @@ impl NameResolutionAnalyser {
             ExpressionKind::ConstRef(spanned) => {
                 self.try_analyse_expression(spanned)?;
             },
+            ExpressionKind::Copy(spanned) => {
+                self.try_analyse_expression(spanned)?;
+            },
             ExpressionKind::Block(block) => {
                 self.analyse_block(block);
             },
@@ -326,30 +329,39 @@ impl NameResolutionAnalyser {
     
     fn analyse_scopes(&mut self) {
         
-        let scopes = self.get_scope().get_scopes().clone();
-        for scope in scopes {
-            self.analyse_scope(scope);
+        let parent_id = self.get_scope().current_id();
+        for index in 0..self.get_scope().get_scopes().len() {
+            
+            let mut scope = self.get_scope().get_scopes()[index].clone();
+            self.get_scope_mut().set_current(scope.self_index);
+            
+            self.analyse_scope(&mut scope);
+            self.get_scope_mut().get_scopes_mut()[index] = scope;
         }
+
+        self.get_scope_mut()
+            .set_current(parent_id)
+            .expect("scope_id should be valid");
     }
 
-    fn analyse_scope(&mut self, scope: Scope) {
+    fn analyse_scope(&mut self, scope: &mut Scope) {
 
-        for (_, symbools) in scope.symbols {
+        for symbools in scope.symbols.values_mut() {
 
             for symbool in symbools {
 
-                match symbool.node {
-                    ScopeKind::Enum(enum_) => self.scope_check_enum(&enum_, symbool.span),
-                    ScopeKind::Union(union) => self.scope_check_union(&union, symbool.span),
-                    ScopeKind::Class(class) => self.scope_check_class(&class, symbool.span),
-                    ScopeKind::Trait(trait_) => self.scope_check_trait(&trait_, symbool.span),
-                    ScopeKind::Struct(struct_) => self.scope_check_struct(&struct_, symbool.span),
-                    ScopeKind::Type(soul_type) => self.scope_check_soul_type(&soul_type, symbool.span),
-                    ScopeKind::TypeEnum(type_enum) => self.scope_check_type_enum(&type_enum, symbool.span),
-                    ScopeKind::Functions(functions) => self.scope_check_functions(&functions, symbool.span),
-                    ScopeKind::Variable(mut variable) => self.scope_check_variable(&mut variable, symbool.span),
-                    ScopeKind::TypeDef{new_type, of_type:_} => self.scope_check_soul_type(&new_type, symbool.span),
-                    ScopeKind::UseTypeDef{new_type, of_type:_} => self.scope_check_soul_type(&new_type, symbool.span),
+                match &mut symbool.node {
+                    ScopeKind::Enum(enum_) => self.scope_check_enum(enum_, symbool.span),
+                    ScopeKind::Union(union) => self.scope_check_union(union, symbool.span),
+                    ScopeKind::Class(class) => self.scope_check_class(class, symbool.span),
+                    ScopeKind::Trait(trait_) => self.scope_check_trait(trait_, symbool.span),
+                    ScopeKind::Struct(struct_) => self.scope_check_struct(struct_, symbool.span),
+                    ScopeKind::Type(soul_type) => self.scope_check_soul_type(soul_type, symbool.span),
+                    ScopeKind::TypeEnum(type_enum) => self.scope_check_type_enum(type_enum, symbool.span),
+                    ScopeKind::Functions(functions) => self.scope_check_functions(functions, symbool.span),
+                    ScopeKind::Variable(variable) => self.scope_check_variable(variable, symbool.span),
+                    ScopeKind::TypeDef{new_type, of_type:_} => self.scope_check_soul_type(new_type, symbool.span),
+                    ScopeKind::UseTypeDef{new_type, of_type:_} => self.scope_check_soul_type(new_type, symbool.span),
                 }
             }
         }
@@ -385,7 +397,7 @@ impl NameResolutionAnalyser {
         self.could_be_name(&enum_.name.0, [NameType::CamelCase, NameType::PascalCase], span);
 
         match &enum_.variants {
-            EnumVariantKind::Int(enum_variants) => for name in enum_variants.iter().map(|el| &el.name.0) {
+            EnumVariantKind::Int(enum_variants) => for name in enum_variants.iter().map(|el| &el.node.name.0) {
                 self.should_be_name(name, NameType::PascalCase, span);
             },
 
@@ -0,0 +1,451 @@
+use crate::{errors::soul_error::SoulSpan, steps::{sementic::literal_evaluator::{intern_literal, is_compile_time_variable, Evaluator}, step_interfaces::{i_parser::{abstract_syntax_tree::{abstract_syntax_tree::AbstractSyntacTree, expression::{BinaryOperatorKind, CaseDoKind, ElseKind, Expression, ExpressionGroup, ExpressionKind, If, UnaryOperatorKind, UnwrapVariable}, function::LambdaBody, literal::{Double, Literal}, object::ClassChild, pretty_format::ToString, soul_type::soul_type::SoulType, spanned::Spanned, statement::{Block, Statement, StatementKind}}, scope_builder::{ScopeKind, Variable}}, i_sementic::ast_visitor::{AstAnalyser, Optimizer}}}};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum OptimizationKind {
+    /// 'Binary' or 'Unary' over literals replaced by its value (e.g '2 ** 3' becomes '8')
+    Fold,
+    /// read of an immutable variable replaced by its literal value
+    Propagate,
+    /// branch that can never run is removed (e.g 'while false {}')
+    DeadBranch,
+}
+
+/// expression that is replaced by the optimizer, 'before' and 'after' are only for '--showOutput'
+#[derive(Debug, Clone, PartialEq)]
+pub struct Optimization {
+    pub kind: OptimizationKind,
+    pub span: SoulSpan,
+    pub before: String,
+    pub after: String,
+}
+
+impl AstAnalyser for Optimizer {
+
+    fn analyse_ast(&mut self, tree: &mut AbstractSyntacTree) {
+        self.optimize(tree);
+    }
+}
+
+impl Optimizer {
+
+    /// folds constant expressions, propagates immutable literal variables and removes dead branches
+    pub fn optimize(&mut self, tree: &mut AbstractSyntacTree) -> Vec<Optimization> {
+        let mut optimizations = vec![];
+        self.analyse_block(&mut tree.root, &mut optimizations);
+        optimizations
+    }
+
+    fn analyse_block(&mut self, block: &mut Block, optimizations: &mut Vec<Optimization>) {
+        let parent_id = self.get_scope().current_id();
+        self.get_scope_mut().set_current(block.scope_id);
+
+        for statment in &mut block.statments {
+            self.analyse_statment(statment, optimizations);
+        }
+
+        // removed dead branches
+        block.statments.retain(|statment| !matches!(statment.node, StatementKind::Expression(Spanned{node: ExpressionKind::Empty, ..})));
+
+        self.get_scope_mut()
+            .set_current(parent_id)
+            .expect("scope_id should be valid");
+    }
+
+    fn analyse_statment(&mut self, statment: &mut Statement, optimizations: &mut Vec<Optimization>) {
+        let span = statment.span;
+
+        let parent_id = self.get_scope().current_id();
+        if let Some(id) = statment.node.get_scope_id() && self.get_scope_mut().set_current(id).is_none() {
+            panic!("could not get scope_id({}) at line: {}:{}", id.0, span.line_number, span.line_offset)
+        }
+
+        match &mut statment.node {
+            StatementKind::Enum(_) |
+            StatementKind::Trait(_) |
+            StatementKind::Union(_) |
+            StatementKind::Struct(_) |
+            StatementKind::TypeEnum(_) |
+            StatementKind::CloseBlock => (),
+
+            StatementKind::Expression(expression) => self.analyse_expression(expression, optimizations),
+            StatementKind::Variable(variable_name) => self.analyse_variable(&variable_name.name.0, optimizations),
+            StatementKind::Assignment(assignment) => {
+                self.analyse_place(&mut assignment.variable, optimizations);
+                self.analyse_expression(&mut assignment.value, optimizations);
+            },
+            StatementKind::Function(function) => self.analyse_block(&mut function.block, optimizations),
+            StatementKind::UseBlock(use_block) => self.analyse_block(&mut use_block.block, optimizations),
+            StatementKind::Class(class) => {
+
+                for child in &mut class.children {
+                    match child {
+                        ClassChild::Field(_) => (),
+                        ClassChild::Methode(methode) => self.analyse_block(&mut methode.node.block, optimizations),
+                        ClassChild::ImplBlock(impl_block) => self.analyse_block(&mut impl_block.node.block, optimizations),
+                    }
+                }
+            },
+        }
+
+        self.get_scope_mut()
+            .set_current(parent_id)
+            .expect("scope_id should be valid");
+    }
+
+    fn analyse_variable(&mut self, name: &str, optimizations: &mut Vec<Optimization>) {
+        let mut value = match self.get_variable(name) {
+            Some(Variable{initialize_value: Some(value), ..}) => value.clone(),
+            _ => return,
+        };
+
+        self.analyse_expression(&mut value, optimizations);
+
+        let variable = self.get_scope_mut()
+            .flat_lookup_mut(name)
+            .and_then(|kinds| kinds.iter_mut().find_map(|kind| match &mut kind.node {
+                ScopeKind::Variable(variable) => Some(variable),
+                _ => None,
+            }));
+
+        if let Some(variable) = variable {
+            variable.initialize_value = Some(value);
+        }
+    }
+
+    /// expression that is written to or referenced, only the expressions inside of it are optimized (e.g. the index in 'list[1 + 1]')
+    fn analyse_place(&mut self, place: &mut Expression, optimizations: &mut Vec<Optimization>) {
+
+        match &mut place.node {
+            ExpressionKind::Variable(_) => (),
+            ExpressionKind::AccessField(access_field) => self.analyse_place(&mut access_field.object, optimizations),
+            ExpressionKind::Index(index) => {
+                self.analyse_place(&mut index.collection, optimizations);
+                self.analyse_expression(&mut index.index, optimizations);
+            },
+            ExpressionKind::Deref(inner) => self.analyse_place(inner, optimizations),
+            _ => self.analyse_expression(place, optimizations),
+        }
+    }
+
+    fn analyse_expression(&mut self, expression: &mut Expression, optimizations: &mut Vec<Optimization>) {
+        let span = expression.span;
+
+        let parent_id = self.get_scope().current_id();
+        if let Some(id) = expression.node.get_scope_id() && self.get_scope_mut().set_current(id).is_none() {
+            panic!("could not get scope_id({}) at line: {}:{}", id.0, span.line_number, span.line_offset)
+        }
+
+        self.analyse_expression_kind(expression, optimizations);
+
+        self.get_scope_mut()
+            .set_current(parent_id)
+            .expect("scope_id should be valid");
+    }
+
+    fn analyse_expression_kind(&mut self, expression: &mut Expression, optimizations: &mut Vec<Optimization>) {
+
+        match &mut expression.node {
+            ExpressionKind::Empty |
+            ExpressionKind::Default |
+            ExpressionKind::Literal(_) |
+            ExpressionKind::StaticField(_) |
+            ExpressionKind::ExternalExpression(_) => (),
+
+            ExpressionKind::Variable(variable_name) => {
+                let name = variable_name.name.0.clone();
+                self.propagate(expression, &name, optimizations);
+            },
+            ExpressionKind::Index(index) => {
+                self.analyse_place(&mut index.collection, optimizations);
+                self.analyse_expression(&mut index.index, optimizations);
+            },
+            ExpressionKind::Lambda(lambda) => {
+                for argument in &mut lambda.arguments.values {
+                    self.analyse_expression(argument, optimizations);
+                }
+
+                match &mut lambda.body {
+                    LambdaBody::Block(block) => self.analyse_block(block, optimizations),
+                    LambdaBody::Expression(body) => self.analyse_expression(body, optimizations),
+                }
+            },
+            ExpressionKind::FunctionCall(function_call) => {
+                if let Some(callee) = &mut function_call.callee {
+                    self.analyse_place(callee, optimizations);
+                }
+                for argument in &mut function_call.arguments.values {
+                    self.analyse_expression(argument, optimizations);
+                }
+            },
+            ExpressionKind::StructConstructor(struct_constructor) => for value in struct_constructor.arguments.values.values_mut() {
+                self.analyse_expression(value, optimizations);
+            },
+            ExpressionKind::AccessField(access_field) => self.analyse_place(&mut access_field.object, optimizations),
+            ExpressionKind::StaticMethod(static_method) => for argument in &mut static_method.arguments.values {
+                self.analyse_expression(argument, optimizations);
+            },
+            ExpressionKind::UnwrapVariable(unwrap_variable) => {
+                if let UnwrapVariable::MultiVariable{initializer: Some(initializer), ..} = unwrap_variable {
+                    self.analyse_expression(initializer, optimizations);
+                }
+            },
+            ExpressionKind::Unary(unary) => {
+                if matches!(unary.operator.node, UnaryOperatorKind::Increment{..} | UnaryOperatorKind::Decrement{..}) {
+                    self.analyse_place(&mut unary.expression, optimizations);
+                    return
+                }
+
+                self.analyse_expression(&mut unary.expression, optimizations);
+                if is_literal(&unary.expression) {
+                    self.fold(expression, optimizations);
+                }
+            },
+            ExpressionKind::Binary(binary) => {
+                self.analyse_expression(&mut binary.left, optimizations);
+                self.analyse_expression(&mut binary.right, optimizations);
+
+                // a range is folded into an array with every element so it stays a range
+                if binary.operator.node != BinaryOperatorKind::Range && is_literal(&binary.left) && is_literal(&binary.right) {
+                    self.fold(expression, optimizations);
+                }
+            },
+            ExpressionKind::If(if_decl) => {
+                self.analyse_if(if_decl, optimizations);
+
+                if let Some(replacement) = self.remove_dead_if(if_decl, expression.span, optimizations) {
+                    expression.node = replacement;
+                }
+            },
+            ExpressionKind::For(for_decl) => {
+                self.analyse_expression(&mut for_decl.collection, optimizations);
+                self.analyse_block(&mut for_decl.block, optimizations);
+            },
+            ExpressionKind::While(while_decl) => {
+                if let Some(condition) = &mut while_decl.condition {
+                    self.analyse_expression(condition, optimizations);
+                }
+
+                if while_decl.condition.as_ref().is_some_and(|condition| is_bool(condition, false)) {
+                    record(optimizations, OptimizationKind::DeadBranch, expression.span, format!("while {}", Literal::Bool(false).to_string()), "<removed>".into());
+                    expression.node = ExpressionKind::Empty;
+                    return
+                }
+                self.analyse_block(&mut while_decl.block, optimizations);
+            },
+            ExpressionKind::Match(match_decl) => {
+                self.analyse_expression(&mut match_decl.condition, optimizations);
+
+                for case in &mut match_decl.cases {
+                    let parent_id = self.get_scope().current_id();
+                    self.get_scope_mut().set_current(case.scope_id);
+
+                    match &mut case.do_fn {
+                        CaseDoKind::Block(block) => self.analyse_block(&mut block.node, optimizations),
+                        CaseDoKind::Expression(do_expression) => self.analyse_expression(do_expression, optimizations),
+                    }
+
+                    self.get_scope_mut()
+                        .set_current(parent_id)
+                        .expect("scope_id should be valid");
+                }
+            },
+            ExpressionKind::Ternary(ternary) => {
+                self.analyse_expression(&mut ternary.condition, optimizations);
+                self.analyse_expression(&mut ternary.if_branch, optimizations);
+                self.analyse_expression(&mut ternary.else_branch, optimizations);
+
+                let branch = match &ternary.condition.node {
+                    ExpressionKind::Literal(Literal::Bool(true)) => &ternary.if_branch,
+                    ExpressionKind::Literal(Literal::Bool(false)) => &ternary.else_branch,
+                    _ => return,
+                };
+
+                let branch = branch.node.clone();
+                record(optimizations, OptimizationKind::DeadBranch, expression.span, expression.to_string(), branch.to_string());
+                expression.node = branch;
+            },
+            ExpressionKind::Deref(inner) |
+            ExpressionKind::Copy(inner) => self.analyse_expression(inner, optimizations),
+            ExpressionKind::MutRef(inner) |
+            ExpressionKind::ConstRef(inner) => self.analyse_place(inner, optimizations),
+            ExpressionKind::Block(block) => self.analyse_block(block, optimizations),
+            ExpressionKind::ReturnLike(return_like) => if let Some(value) = &mut return_like.value {
+                self.analyse_expression(value, optimizations);
+            },
+            ExpressionKind::ExpressionGroup(expression_group) => match expression_group {
+                ExpressionGroup::Tuple(tuple) => for value in &mut tuple.values {
+                    self.analyse_expression(value, optimizations);
+                },
+                ExpressionGroup::Array(array) => for value in &mut array.values {
+                    self.analyse_expression(value, optimizations);
+                },
+                ExpressionGroup::NamedTuple(named_tuple) => for value in named_tuple.values.values_mut() {
+                    self.analyse_expression(value, optimizations);
+                },
+                ExpressionGroup::ArrayFiller(array_filler) => {
+                    self.analyse_expression(&mut array_filler.amount, optimizations);
+                    self.analyse_expression(&mut array_filler.fill_expr, optimizations);
+                },
+            },
+        }
+    }
+
+    fn analyse_if(&mut self, if_decl: &mut If, optimizations: &mut Vec<Optimization>) {
+        self.analyse_expression(&mut if_decl.condition, optimizations);
+        self.analyse_block(&mut if_decl.block, optimizations);
+
+        for branch in &mut if_decl.else_branchs {
+            match &mut branch.node {
+                ElseKind::Else(block) => self.analyse_block(&mut block.node, optimizations),
+                ElseKind::ElseIf(else_if) => self.analyse_if(&mut else_if.node, optimizations),
+            }
+        }
+    }
+
+    /// removes branches with a literal condition, returns the expression that replaces the whole 'if' (None if the 'if' stays)
+    fn remove_dead_if(&mut self, if_decl: &mut If, span: SoulSpan, optimizations: &mut Vec<Optimization>) -> Option<ExpressionKind> {
+
+        if is_bool(&if_decl.condition, true) {
+            record(optimizations, OptimizationKind::DeadBranch, span, format!("if {}", if_decl.condition.to_string()), "<if block>".into());
+            return Some(ExpressionKind::Block(if_decl.block.clone()))
+        }
+
+        if is_bool(&if_decl.condition, false) {
+            let mut branches = std::mem::take(&mut if_decl.else_branchs).into_iter();
+
+            let replacement = match branches.next().map(|branch| branch.node) {
+                None => {
+                    record(optimizations, OptimizationKind::DeadBranch, span, format!("if {}", if_decl.condition.to_string()), "<removed>".into());
+                    return Some(ExpressionKind::Empty)
+                },
+                Some(ElseKind::Else(block)) => {
+                    record(optimizations, OptimizationKind::DeadBranch, span, format!("if {}", if_decl.condition.to_string()), "<else block>".into());
+                    return Some(ExpressionKind::Block(block.node))
+                },
+                Some(ElseKind::ElseIf(else_if)) => {
+                    record(optimizations, OptimizationKind::DeadBranch, span, format!("if {}", if_decl.condition.to_string()), format!("if {}", else_if.node.condition.to_string()));
+
+                    let mut next = else_if.node;
+                    next.else_branchs.extend(branches);
+                    next
+                },
+            };
+
+            *if_decl = replacement;
+            return Some(self.remove_dead_if(if_decl, span, optimizations).unwrap_or_else(|| ExpressionKind::If(if_decl.clone())))
+        }
+
+        let mut index = 0;
+        while index < if_decl.else_branchs.len() {
+            let branch = &if_decl.else_branchs[index];
+
+            let else_if = match &branch.node {
+                ElseKind::ElseIf(else_if) => else_if,
+                ElseKind::Else(_) => break,
+            };
+
+            if is_bool(&else_if.node.condition, false) {
+                record(optimizations, OptimizationKind::DeadBranch, branch.span, format!("else if {}", else_if.node.condition.to_string()), "<removed>".into());
+                if_decl.else_branchs.remove(index);
+                continue
+            }
+
+            if is_bool(&else_if.node.condition, true) {
+                record(optimizations, OptimizationKind::DeadBranch, branch.span, format!("else if {}", else_if.node.condition.to_string()), "<else block>".into());
+
+                let block = Spanned::new(else_if.node.block.clone(), branch.span);
+                if_decl.else_branchs.truncate(index);
+                if_decl.else_branchs.push(Spanned::new(ElseKind::Else(block.clone()), block.span));
+                break
+            }
+
+            index += 1;
+        }
+
+        None
+    }
+
+    /// replaces expression by its value if it can be evaluated (e.g. '2 </ 4' can not because it has no real value)
+    fn fold(&mut self, expression: &mut Expression, optimizations: &mut Vec<Optimization>) {
+        let literal = match Evaluator::new(self.get_scope()).evaluate(expression, self.get_scope().current_id()) {
+            Ok(literal) => literal,
+            Err(_) => return,
+        };
+
+        let folded = intern_literal(&mut self.get_scope_mut().global_literals, literal, expression.span);
+        record(optimizations, OptimizationKind::Fold, expression.span, expression.to_string(), folded.to_string());
+        *expression = folded;
+    }
+
+    /// replaces a read of an immutable variable that is initialized with a basic literal by that literal
+    fn propagate(&mut self, expression: &mut Expression, name: &str, optimizations: &mut Vec<Optimization>) {
+        let literal = match self.get_variable(name) {
+            Some(variable) if is_compile_time_variable(variable) => match &variable.initialize_value.as_ref().map(|value| &value.node) {
+                Some(ExpressionKind::Literal(literal)) => as_type(literal, &variable.ty),
+                _ => None,
+            },
+            _ => None,
+        };
+
+        if let Some(literal) = literal {
+            record(optimizations, OptimizationKind::Propagate, expression.span, name.into(), literal.to_string());
+            expression.node = ExpressionKind::Literal(literal);
+        }
+    }
+
+    fn get_variable(&self, name: &str) -> Option<&Variable> {
+
+        match &self.get_scope().lookup_kind(name, |kind| matches!(kind, ScopeKind::Variable(_)))?.node {
+            ScopeKind::Variable(variable) => Some(variable),
+            _ => None,
+        }
+    }
+}
+
+/// readable list of every optimization (for '--showOutput')
+pub fn optimizations_to_string(optimizations: &[Optimization]) -> String {
+    optimizations.iter()
+        .map(|optimization| format!(
+            "{}:{} {:?}\n\tbefore: {}\n\tafter:  {}",
+            optimization.span.line_number,
+            optimization.span.line_offset,
+            optimization.kind,
+            optimization.before,
+            optimization.after,
+        ))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn record(optimizations: &mut Vec<Optimization>, kind: OptimizationKind, span: SoulSpan, before: String, after: String) {
+    optimizations.push(Optimization{kind, span, before, after});
+}
+
+fn is_literal(expression: &Expression) -> bool {
+    matches!(expression.node, ExpressionKind::Literal(_))
+}
+
+fn is_bool(expression: &Expression, value: bool) -> bool {
+    matches!(expression.node, ExpressionKind::Literal(Literal::Bool(literal)) if literal == value)
+}
+
+/// basic literal as value of ty (e.g. '1' as 'f32' is '1.0'), None if the literal can not be propagated
+fn as_type(literal: &Literal, ty: &SoulType) -> Option<Literal> {
+
+    if !ty.wrappers.is_empty() {
+        return None
+    }
+
+    match literal {
+        Literal::Int(value) if ty.base.is_float() => Some(Literal::Float(Double::new(*value as f64))),
+        Literal::Uint(value) if ty.base.is_float() => Some(Literal::Float(Double::new(*value as f64))),
+        Literal::Float(_) if ty.base.is_integer() => None,
+        Literal::Int(_) |
+        Literal::Uint(_) |
+        Literal::Float(_) |
+        Literal::Bool(_) |
+        Literal::Char(_) |
+        Literal::Str(_) => Some(literal.clone()),
+        _ => None,
+    }
+}
@@ -0,0 +1,160 @@
+use crate::steps::{sementic::{optimizer::OptimizationKind, test_helpers::{error_messages, initializer, optimize_source}}, step_interfaces::{i_parser::abstract_syntax_tree::{abstract_syntax_tree::AbstractSyntacTree, expression::{ElseKind, ExpressionKind}, literal::{Double, Literal}, statement::{Block, Statement, StatementKind}}, i_sementic::scope_vistitor::ScopeVisitor}};
+
+fn literal_of<'a>(scope: &'a ScopeVisitor, name: &str) -> &'a Literal {
+    match &initializer(scope, name).node {
+        ExpressionKind::Literal(literal) => literal,
+        other => panic!("'{}' should be folded to a literal but is {:?}", name, other),
+    }
+}
+
+fn main_statments(tree: &AbstractSyntacTree) -> &Vec<Statement> {
+    tree.root.statments.iter()
+        .find_map(|statment| match &statment.node {
+            StatementKind::Function(function) if function.signature.name.0 == "main" => Some(&function.block.statments),
+            _ => None,
+        })
+        .expect("source should have main")
+}
+
+/// values assigned in block (e.g. [1] for '{ x = 1 }')
+fn assignments(block: &Block) -> Vec<i64> {
+    block.statments.iter()
+        .filter_map(|statment| match &statment.node {
+            StatementKind::Assignment(assignment) => match &assignment.value.node {
+                ExpressionKind::Literal(Literal::Int(value)) => Some(*value),
+                _ => None,
+            },
+            _ => None,
+        })
+        .collect()
+}
+
+#[test]
+fn test_fold_constants() {
+    let source = "
+main() {
+    mut a := 1
+    power := 2 ** 10
+    root := 27 </ 3
+    logarithm := 100 log 10
+    mixed := a + 2 * 3
+    flag := !(1 < 2)
+    negative := -(4 - 6)
+}
+";
+    let (_, scope, optimizations, faults) = optimize_source(source);
+    assert!(error_messages(&faults).is_empty(), "{:?}", error_messages(&faults));
+
+    assert_eq!(literal_of(&scope, "power"), &Literal::Uint(1024));
+    assert_eq!(literal_of(&scope, "root"), &Literal::Uint(3));
+    assert_eq!(literal_of(&scope, "logarithm"), &Literal::Uint(2));
+    assert_eq!(literal_of(&scope, "flag"), &Literal::Bool(false));
+    assert_eq!(literal_of(&scope, "negative"), &Literal::Uint(2));
+
+    // 'a' is mutable so only the right side is folded
+    match &initializer(&scope, "mixed").node {
+        ExpressionKind::Binary(binary) => {
+            assert!(matches!(binary.left.node, ExpressionKind::Variable(_)));
+            assert_eq!(binary.right.node, ExpressionKind::Literal(Literal::Uint(6)));
+        },
+        other => panic!("'mixed' should stay a binary but is {:?}", other),
+    }
+
+    assert!(optimizations.iter().all(|optimization| optimization.kind == OptimizationKind::Fold), "{:?}", optimizations);
+    assert!(optimizations.iter().any(|optimization| optimization.before.ends_with("2 ** Literal untypedInt 10") && optimization.after.ends_with("1024")), "{:?}", optimizations);
+}
+
+#[test]
+fn test_propagate_constants() {
+    let source = "
+main() {
+    count := 4
+    const f32 ratio = 2
+    mut total := count * 2
+    scaled := ratio / 4
+    mut other := 1
+    next := other + 1
+    total = count
+}
+";
+    let (tree, scope, optimizations, faults) = optimize_source(source);
+    assert!(error_messages(&faults).is_empty(), "{:?}", error_messages(&faults));
+
+    assert_eq!(literal_of(&scope, "total"), &Literal::Uint(8));
+    assert_eq!(literal_of(&scope, "scaled"), &Literal::Float(Double::new(0.5)));
+    assert!(matches!(initializer(&scope, "next").node, ExpressionKind::Binary(_)));
+
+    let assignment = main_statments(&tree).iter()
+        .find_map(|statment| match &statment.node {
+            StatementKind::Assignment(assignment) => Some(assignment),
+            _ => None,
+        })
+        .expect("main should have assignment");
+    assert!(matches!(assignment.variable.node, ExpressionKind::Variable(_)));
+    assert_eq!(assignment.value.node, ExpressionKind::Literal(Literal::Int(4)));
+
+    let propagated = optimizations.iter()
+        .filter(|optimization| optimization.kind == OptimizationKind::Propagate)
+        .map(|optimization| optimization.before.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(propagated, vec!["count", "ratio", "count"]);
+}
+
+#[test]
+fn test_remove_dead_branches() {
+    let source = "
+main() {
+    mut x := 0
+    if true {
+        x = 1
+    }
+    if false {
+        x = 2
+    } else if x == 0 {
+        x = 3
+    } else {
+        x = 4
+    }
+    while false {
+        x = 5
+    }
+    if x == 1 {
+        x = 6
+    } else if false {
+        x = 7
+    } else if true {
+        x = 8
+    } else {
+        x = 9
+    }
+}
+";
+    let (tree, _, optimizations, faults) = optimize_source(source);
+    assert!(error_messages(&faults).is_empty(), "{:?}", error_messages(&faults));
+
+    let expressions = main_statments(&tree).iter()
+        .filter_map(|statment| match &statment.node {
+            StatementKind::Expression(expression) => Some(&expression.node),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(expressions.len(), 3, "{:?}", expressions);
+
+    assert!(matches!(expressions[0], ExpressionKind::Block(block) if assignments(block) == vec![1]), "{:?}", expressions[0]);
+
+    match expressions[1] {
+        ExpressionKind::If(if_decl) => {
+            assert!(matches!(if_decl.condition.node, ExpressionKind::Binary(_)));
+            assert!(matches!(if_decl.else_branchs.as_slice(), [branch] if matches!(branch.node, ElseKind::Else(_))));
+        },
+        other => panic!("'else if' should become the 'if' but is {:?}", other),
+    }
+
+    match expressions[2] {
+        ExpressionKind::If(if_decl) => assert!(matches!(if_decl.else_branchs.as_slice(), [branch] if matches!(&branch.node, ElseKind::Else(block) if assignments(&block.node) == vec![8]))),
+        other => panic!("'if' should stay but is {:?}", other),
+    }
+
+    let dead = optimizations.iter().filter(|optimization| optimization.kind == OptimizationKind::DeadBranch).count();
+    assert_eq!(dead, 5, "{:?}", optimizations);
+}
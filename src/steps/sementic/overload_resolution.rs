@@ -0,0 +1,143 @@
+use crate::{errors::soul_error::SoulSpan, steps::{sementic::type_checking::{default_untyped, is_assignable, type_name}, step_interfaces::{i_parser::abstract_syntax_tree::{function::{Function, FunctionSignature}, pretty_format::ToString, soul_type::{soul_type::SoulType, type_kind::TypeKind}, spanned::Spanned}}}};
+
+/// cost of passing an argument that exactly matches the parameter
+const EXACT_COST: u32 = 0;
+/// cost of passing an untyped literal to the default type of the literal (e.g untypedInt -> int)
+const DEFAULT_LITERAL_COST: u32 = 1;
+/// cost of any other valid conversion (e.g untypedInt -> u8, mut ref -> const ref)
+const CONVERSION_COST: u32 = 2;
+/// cost of passing an argument to a generic (or otherwise unchecked) parameter
+const GENERIC_COST: u32 = 3;
+
+pub enum OverloadError<'a> {
+    NoMatch,
+    Ambiguous(Vec<&'a FunctionSignature>),
+}
+
+/// two overloads in the same scope that can not be told apart by a caller
+pub struct OverloadConflict {
+    pub name: String,
+    pub span: SoulSpan,
+    pub other_span: SoulSpan,
+    pub only_return_differs: bool,
+}
+
+/// selects the overload with the lowest total argument cost,
+/// unresolved arguments (see [`is_unresolved_argument`]) match every parameter
+pub fn resolve_overload<'a>(candidates: &'a [FunctionSignature], argument_types: &[Option<SoulType>]) -> Result<&'a FunctionSignature, OverloadError<'a>> {
+    let mut best_cost = u32::MAX;
+    let mut best = vec![];
+
+    for candidate in candidates {
+
+        let cost = match call_cost(candidate, argument_types) {
+            Some(cost) => cost,
+            None => continue,
+        };
+
+        if cost < best_cost {
+            best_cost = cost;
+            best.clear();
+        }
+
+        if cost == best_cost {
+            best.push(candidate);
+        }
+    }
+
+    match best.len() {
+        0 => Err(OverloadError::NoMatch),
+        1 => Ok(best[0]),
+        _ => Err(OverloadError::Ambiguous(best)),
+    }
+}
+
+/// finds every pair of functions that have the same callee and parameter types
+pub fn find_overload_conflicts(functions: &[Spanned<Function>]) -> Vec<OverloadConflict> {
+    let mut conflicts = vec![];
+
+    for (i, function) in functions.iter().enumerate() {
+
+        let other = functions[..i].iter()
+            .find(|other| is_same_overload(&other.node.signature, &function.node.signature));
+
+        if let Some(other) = other {
+            conflicts.push(OverloadConflict{
+                name: function.node.signature.name.0.clone(),
+                span: function.span,
+                other_span: other.span,
+                only_return_differs: return_type_name(&function.node.signature) != return_type_name(&other.node.signature),
+            });
+        }
+    }
+
+    conflicts
+}
+
+/// argument of which the type is not known (yet) in this pass
+pub fn is_unresolved_argument(argument: &Option<SoulType>) -> bool {
+    argument.as_ref().is_none_or(|ty| matches!(ty.base, TypeKind::Generic(_) | TypeKind::Unknown(_)))
+}
+
+pub fn candidates_to_string<'a, I>(candidates: I) -> String
+where
+    I: IntoIterator<Item = &'a FunctionSignature>
+{
+    candidates.into_iter()
+        .map(|signature| format!("\n\t- {}", signature.to_string()))
+        .collect()
+}
+
+fn call_cost(signature: &FunctionSignature, argument_types: &[Option<SoulType>]) -> Option<u32> {
+
+    if signature.parameters.len() != argument_types.len() {
+        return None
+    }
+
+    let mut total = 0;
+    for (parameter, argument) in signature.parameters.iter().zip(argument_types) {
+
+        if let Some(argument) = argument.as_ref().filter(|_| !is_unresolved_argument(argument)) {
+            total += argument_cost(&parameter.node.ty, argument)?;
+        }
+    }
+
+    Some(total)
+}
+
+fn argument_cost(parameter: &SoulType, argument: &SoulType) -> Option<u32> {
+
+    if !is_assignable(parameter, argument) {
+        return None
+    }
+
+    let cost = if matches!(parameter.base, TypeKind::Generic(_) | TypeKind::Unknown(_) | TypeKind::Trait(_)) {
+        GENERIC_COST
+    }
+    else if type_name(parameter) == type_name(argument) {
+        EXACT_COST
+    }
+    else if type_name(parameter) == type_name(&default_untyped(argument.clone())) {
+        DEFAULT_LITERAL_COST
+    }
+    else {
+        CONVERSION_COST
+    };
+
+    Some(cost)
+}
+
+fn is_same_overload(a: &FunctionSignature, b: &FunctionSignature) -> bool {
+    let callee_name = |signature: &FunctionSignature| signature.callee.as_ref().map(|callee| (
+        type_name(&callee.node.extention_type),
+        callee.node.this.as_ref().map(type_name),
+    ));
+
+    callee_name(a) == callee_name(b) &&
+    a.parameters.len() == b.parameters.len() &&
+    a.parameters.iter().zip(&b.parameters).all(|(a, b)| type_name(&a.node.ty) == type_name(&b.node.ty))
+}
+
+fn return_type_name(signature: &FunctionSignature) -> String {
+    type_name(signature.return_type.as_ref().unwrap_or(&SoulType::none()))
+}
@@ -0,0 +1,121 @@
+use crate::steps::{sementic::{type_checking::type_name, test_helpers::{check_types, error_messages, variable_type}}, step_interfaces::i_parser::abstract_syntax_tree::soul_type::type_kind::{TypeKind, TypeSize}};
+
+const OVERLOADS: &str = "
+func(int a) int {
+    return a
+}
+
+func(str a) str {
+    return a
+}
+
+func(mut int[] a) bool {
+    return true
+}
+
+func(f64 a) f64 {
+    return a
+}
+";
+
+#[test]
+fn test_select_overload_by_argument() {
+    let source = format!("{}
+main() {{
+    a := func(1)
+    b := func(\"foo\")
+    c := func([1, 2, 3])
+    d := func(1.0)
+}}
+", OVERLOADS);
+
+    let (scope, faults) = check_types(&source);
+    assert!(error_messages(&faults).is_empty(), "{:?}", error_messages(&faults));
+
+    assert_eq!(variable_type(&scope, "a").base, TypeKind::SystemInt);
+    assert_eq!(variable_type(&scope, "b").base, TypeKind::Str);
+    assert_eq!(variable_type(&scope, "c").base, TypeKind::Bool);
+    assert_eq!(variable_type(&scope, "d").base, TypeKind::Float(TypeSize::Bit64));
+
+    // backends get the selected overload of every call
+    let parameters = scope.resolved_calls.iter()
+        .filter(|(_, name, _)| *name == "func")
+        .map(|(span, _, signature)| (span.line_number, type_name(&signature.parameters[0].node.ty)))
+        .collect::<Vec<_>>();
+    assert_eq!(parameters, vec![(19, "int".to_string()), (20, "str".to_string()), (21, "int[]".to_string()), (22, "f64".to_string())]);
+}
+
+#[test]
+fn test_untyped_literal_prefers_default_type() {
+    let source = "
+pick(i8 a) i8 {
+    return a
+}
+
+pick(int a) int {
+    return a
+}
+
+main() {
+    value := pick(1)
+}
+";
+    let (scope, faults) = check_types(source);
+    assert!(error_messages(&faults).is_empty(), "{:?}", error_messages(&faults));
+    assert_eq!(variable_type(&scope, "value").base, TypeKind::SystemInt);
+}
+
+#[test]
+fn test_ambiguous_call() {
+    let source = "
+pick(i8 a, int b) {
+}
+
+pick(int a, i8 b) {
+}
+
+main() {
+    pick(1, 1)
+}
+";
+    let (_, faults) = check_types(source);
+    let errors = error_messages(&faults);
+
+    assert_eq!(errors.len(), 1, "{:?}", errors);
+    assert!(errors[0].contains("call to function 'pick' is ambiguous"), "{}", errors[0]);
+    assert!(errors[0].contains("pick(const i8 a, const int b)") && errors[0].contains("pick(const int a, const i8 b)"), "{}", errors[0]);
+}
+
+#[test]
+fn test_no_matching_overload() {
+    let source = format!("{}
+main() {{
+    func(true)
+}}
+", OVERLOADS);
+
+    let (_, faults) = check_types(&source);
+    let errors = error_messages(&faults);
+
+    assert_eq!(errors.len(), 1, "{:?}", errors);
+    assert!(errors[0].contains("no overload of function 'func' matches arguments (bool)"), "{}", errors[0]);
+    assert!(errors[0].contains("func(const str a) str"), "{}", errors[0]);
+}
+
+#[test]
+fn test_overload_by_return_type_is_error() {
+    let source = "
+func(int a) int {
+    return a
+}
+
+func(int b) str {
+    return \"\"
+}
+";
+    let (_, faults) = check_types(source);
+    let errors = error_messages(&faults);
+
+    assert_eq!(errors.len(), 1, "{:?}", errors);
+    assert!(errors[0].contains("overloads of function 'func' only differ by return type"), "{}", errors[0]);
+}
@@ -0,0 +1,505 @@
+use std::collections::{BTreeSet, HashSet};
+use crate::{errors::soul_error::{new_soul_error, SoulErrorKind, SoulSpan}, steps::step_interfaces::{i_parser::{abstract_syntax_tree::{abstract_syntax_tree::AbstractSyntacTree, expression::{CaseDoKind, ElseKind, Expression, ExpressionGroup, ExpressionKind, If, IfCaseKind, UnwrapVariable}, function::{Function, LambdaBody}, object::{ClassChild, Field}, soul_type::{soul_type::{SoulType, TypeGenericKind}, type_kind::{SoulPagePath, TypeKind, UnionKind}}, statement::{Block, StatementKind}}, header::starts_with_capital, scope_builder::ScopeKind}, i_sementic::{scope_vistitor::ScopeVisitor, soul_fault::SoulFault}}};
+
+pub const MAIN_FUNCTION: &str = "main";
+
+/// analysed page of the program
+pub struct ProgramPage<'a> {
+    pub path: SoulPagePath,
+    pub tree: &'a AbstractSyntacTree,
+    pub scope: &'a ScopeVisitor,
+}
+
+/// global free function that can not be reached from 'main'
+#[derive(Debug, Clone, PartialEq)]
+pub struct DeadFunction {
+    pub page: SoulPagePath,
+    pub name: String,
+    pub span: SoulSpan,
+    /// exported in the header of page (starts with a capital)
+    pub is_public: bool,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Reachability {
+    /// global free functions reachable from 'main' (overloads share the same entry)
+    pub reachable: BTreeSet<(SoulPagePath, String)>,
+    pub dead_functions: Vec<DeadFunction>,
+    /// pages that no reachable code uses, these do not have to be emitted
+    pub unused_pages: Vec<SoulPagePath>,
+}
+
+enum Work {
+    /// global free function of page
+    Function(SoulPagePath, String),
+    /// every methode with this name, the type of the callee is not known so all pages are searched
+    Methode(String),
+    /// page is used so its global variables are initialized
+    Page(SoulPagePath),
+}
+
+/// finds every global function and page reachable from 'main' of main_page,
+/// returns None if main_page has no 'main' (e.g. when building a library)
+pub fn analyse_reachability(pages: &[ProgramPage], main_page: &SoulPagePath) -> Option<Reachability> {
+    let main = pages.iter().find(|page| &page.path == main_page)?;
+    global_functions(main.tree, MAIN_FUNCTION).next()?;
+
+    let mut walker = Walker{
+        pages,
+        work: vec![Work::Function(main_page.clone(), MAIN_FUNCTION.into())],
+        reachable: BTreeSet::new(),
+        used_methodes: HashSet::new(),
+        used_pages: BTreeSet::new(),
+    };
+    walker.run();
+
+    let dead_functions = pages.iter()
+        .flat_map(|page| page.tree.root.statments.iter().filter_map(move |statment| match &statment.node {
+            StatementKind::Function(function) if function.signature.callee.is_none() => Some((page, &function.signature.name.0, statment.span)),
+            _ => None,
+        }))
+        .filter(|(page, name, _)| !walker.reachable.contains(&(page.path.clone(), name.to_string())))
+        .map(|(page, name, span)| DeadFunction{page: page.path.clone(), name: name.clone(), span, is_public: starts_with_capital(name)})
+        .collect();
+
+    let unused_pages = pages.iter()
+        .filter(|page| !walker.used_pages.contains(&page.path))
+        .map(|page| page.path.clone())
+        .collect();
+
+    Some(Reachability{reachable: walker.reachable, dead_functions, unused_pages})
+}
+
+/// warning for every private function of page that is never used
+pub fn unused_function_warnings(reachability: &Reachability, page: &SoulPagePath) -> Vec<SoulFault> {
+    reachability.dead_functions.iter()
+        .filter(|dead| &dead.page == page && !dead.is_public)
+        .map(|dead| SoulFault::new_warning(new_soul_error(SoulErrorKind::InvalidInContext, Some(dead.span), format!("function '{}' is never used", dead.name))))
+        .collect()
+}
+
+/// removes the global functions of page that can not be reached so they are not emitted
+pub fn remove_dead_functions(tree: &mut AbstractSyntacTree, reachability: &Reachability, page: &SoulPagePath) {
+    let dead = reachability.dead_functions.iter()
+        .filter(|dead| &dead.page == page)
+        .map(|dead| dead.name.as_str())
+        .collect::<HashSet<_>>();
+
+    tree.root.statments.retain(|statment| match &statment.node {
+        StatementKind::Function(function) => function.signature.callee.is_some() || !dead.contains(function.signature.name.0.as_str()),
+        _ => true,
+    });
+}
+
+/// readable list of dead public functions per page and unused pages (for '--showOutput')
+pub fn dead_symbols_to_string(reachability: &Reachability) -> String {
+    let mut pages = reachability.dead_functions.iter()
+        .filter(|dead| dead.is_public)
+        .map(|dead| &dead.page)
+        .collect::<Vec<_>>();
+    pages.sort();
+    pages.dedup();
+
+    let mut lines = vec![];
+    for page in pages {
+        lines.push(format!("page {}", page.0));
+
+        for dead in reachability.dead_functions.iter().filter(|dead| &dead.page == page && dead.is_public) {
+            lines.push(format!("\t{}:{} {}", dead.span.line_number, dead.span.line_offset, dead.name));
+        }
+    }
+
+    if !reachability.unused_pages.is_empty() {
+        lines.push("unused pages".into());
+        for page in &reachability.unused_pages {
+            lines.push(format!("\t{}", page.0));
+        }
+    }
+
+    lines.join("\n")
+}
+
+fn global_functions<'a>(tree: &'a AbstractSyntacTree, name: &'a str) -> impl Iterator<Item = &'a Function> {
+    tree.root.statments.iter().filter_map(move |statment| match &statment.node {
+        StatementKind::Function(function) if function.signature.callee.is_none() && function.signature.name.0 == name => Some(function),
+        _ => None,
+    })
+}
+
+/// extention methodes, methodes in 'use' blocks and class methodes with this name
+fn methodes<'a>(tree: &'a AbstractSyntacTree, name: &'a str) -> Vec<&'a Function> {
+    let mut methodes = vec![];
+    let is_methode = |function: &Function| function.signature.name.0 == name;
+
+    let use_block_methodes = |block: &'a Block, methodes: &mut Vec<&'a Function>| for statment in &block.statments {
+        if let StatementKind::Function(function) = &statment.node && is_methode(function) {
+            methodes.push(function);
+        }
+    };
+
+    for statment in &tree.root.statments {
+        match &statment.node {
+            StatementKind::Function(function) if function.signature.callee.is_some() && is_methode(function) => methodes.push(function),
+            StatementKind::UseBlock(use_block) => use_block_methodes(&use_block.block, &mut methodes),
+            StatementKind::Class(class) => for child in &class.children {
+                match child {
+                    ClassChild::Field(_) => (),
+                    ClassChild::Methode(methode) => if is_methode(&methode.node) {
+                        methodes.push(&methode.node);
+                    },
+                    ClassChild::ImplBlock(impl_block) => use_block_methodes(&impl_block.node.block, &mut methodes),
+                }
+            },
+            _ => (),
+        }
+    }
+
+    methodes
+}
+
+struct Walker<'a> {
+    pages: &'a [ProgramPage<'a>],
+    work: Vec<Work>,
+    reachable: BTreeSet<(SoulPagePath, String)>,
+    used_methodes: HashSet<String>,
+    used_pages: BTreeSet<SoulPagePath>,
+}
+
+impl<'a> Walker<'a> {
+
+    fn run(&mut self) {
+        while let Some(work) = self.work.pop() {
+
+            match work {
+                Work::Function(path, name) => {
+                    let page = match self.get_page(&path) {
+                        Some(page) => page,
+                        None => continue,
+                    };
+
+                    // not a function (e.g. a variable with the same name)
+                    if global_functions(page.tree, &name).next().is_none() || !self.reachable.insert((path.clone(), name.clone())) {
+                        continue
+                    }
+
+                    for function in global_functions(page.tree, &name) {
+                        self.walk_function(function, page);
+                    }
+                    self.work.push(Work::Page(path));
+                },
+                Work::Methode(name) => {
+                    if !self.used_methodes.insert(name.clone()) {
+                        continue
+                    }
+
+                    for page in self.pages {
+                        let methodes = methodes(page.tree, &name);
+                        if methodes.is_empty() {
+                            continue
+                        }
+
+                        for methode in methodes {
+                            self.walk_function(methode, page);
+                        }
+                        self.work.push(Work::Page(page.path.clone()));
+                    }
+                },
+                Work::Page(path) => {
+                    let page = match self.get_page(&path) {
+                        Some(page) => page,
+                        None => continue,
+                    };
+
+                    if !self.used_pages.insert(path) {
+                        continue
+                    }
+
+                    // global variables are initialized if page is used
+                    for statment in &page.tree.root.statments {
+                        match &statment.node {
+                            StatementKind::Variable(variable) => self.walk_variable(&variable.name.0, &page.tree.root, page),
+                            StatementKind::Struct(struct_decl) => for field in &struct_decl.fields {
+                                self.walk_field(&field.node, page);
+                            },
+                            StatementKind::Class(class) => for child in &class.children {
+                                if let ClassChild::Field(field) = child {
+                                    self.walk_field(&field.node, page);
+                                }
+                            },
+                            _ => (),
+                        }
+                    }
+                },
+            }
+        }
+    }
+
+    fn get_page(&self, path: &SoulPagePath) -> Option<&'a ProgramPage<'a>> {
+        self.pages.iter().find(|page| &page.path == path)
+    }
+
+    fn walk_function(&mut self, function: &Function, page: &ProgramPage) {
+        let signature = &function.signature;
+
+        if let Some(callee) = &signature.callee {
+            self.walk_type(&callee.node.extention_type);
+        }
+        for parameter in &signature.parameters {
+            self.walk_type(&parameter.node.ty);
+        }
+        if let Some(return_type) = &signature.return_type {
+            self.walk_type(return_type);
+        }
+
+        self.walk_block(&function.block, page);
+    }
+
+    fn walk_block(&mut self, block: &Block, page: &ProgramPage) {
+
+        for statment in &block.statments {
+
+            match &statment.node {
+                StatementKind::Enum(_) |
+                StatementKind::Trait(_) |
+                StatementKind::Union(_) |
+                StatementKind::Struct(_) |
+                StatementKind::TypeEnum(_) |
+                StatementKind::CloseBlock => (),
+
+                StatementKind::Expression(expression) => self.walk_expression(expression, page),
+                StatementKind::Variable(variable) => self.walk_variable(&variable.name.0, block, page),
+                StatementKind::Assignment(assignment) => {
+                    self.walk_expression(&assignment.variable, page);
+                    self.walk_expression(&assignment.value, page);
+                },
+                StatementKind::Function(function) => self.walk_function(function, page),
+                StatementKind::UseBlock(use_block) => self.walk_block(&use_block.block, page),
+                StatementKind::Class(class) => for child in &class.children {
+                    match child {
+                        ClassChild::Field(field) => self.walk_field(&field.node, page),
+                        ClassChild::Methode(methode) => self.walk_function(&methode.node, page),
+                        ClassChild::ImplBlock(impl_block) => self.walk_block(&impl_block.node.block, page),
+                    }
+                },
+            }
+        }
+    }
+
+    fn walk_field(&mut self, field: &Field, page: &ProgramPage) {
+        self.walk_type(&field.ty);
+        if let Some(default_value) = &field.default_value {
+            self.walk_expression(default_value, page);
+        }
+    }
+
+    fn walk_variable(&mut self, name: &str, block: &Block, page: &ProgramPage) {
+        let kinds = match page.scope.get_scopes()[block.scope_id.0].get(name) {
+            Some(kinds) => kinds,
+            None => return,
+        };
+
+        for kind in kinds {
+            if let ScopeKind::Variable(variable) = &kind.node {
+                self.walk_type(&variable.ty);
+                if let Some(value) = &variable.initialize_value {
+                    self.walk_expression(value, page);
+                }
+            }
+        }
+    }
+
+    fn walk_expression(&mut self, expression: &Expression, page: &ProgramPage) {
+
+        match &expression.node {
+            ExpressionKind::Empty |
+            ExpressionKind::Default |
+            ExpressionKind::Literal(_) => (),
+
+            ExpressionKind::Variable(variable) => {
+                // function used as value
+                self.work.push(Work::Function(page.path.clone(), variable.name.0.clone()));
+            },
+            ExpressionKind::ExternalExpression(external) => {
+                self.work.push(Work::Page(external.path.clone()));
+
+                match &external.expr.node {
+                    ExpressionKind::FunctionCall(function_call) if function_call.callee.is_none() => {
+                        self.work.push(Work::Function(external.path.clone(), function_call.name.0.clone()));
+                        self.walk_generics(&function_call.generics);
+                        for argument in &function_call.arguments.values {
+                            self.walk_expression(argument, page);
+                        }
+                    },
+                    ExpressionKind::Variable(variable) => self.work.push(Work::Function(external.path.clone(), variable.name.0.clone())),
+                    _ => self.walk_expression(&external.expr, page),
+                }
+            },
+            ExpressionKind::FunctionCall(function_call) => {
+                match &function_call.callee {
+                    Some(callee) => {
+                        self.work.push(Work::Methode(function_call.name.0.clone()));
+                        self.walk_expression(callee, page);
+                    },
+                    None => self.work.push(Work::Function(page.path.clone(), function_call.name.0.clone())),
+                }
+
+                self.walk_generics(&function_call.generics);
+                for argument in &function_call.arguments.values {
+                    self.walk_expression(argument, page);
+                }
+            },
+            ExpressionKind::StaticMethod(static_method) => {
+                self.work.push(Work::Methode(static_method.name.0.clone()));
+                self.walk_type(&static_method.callee.node);
+                self.walk_generics(&static_method.generics);
+                for argument in &static_method.arguments.values {
+                    self.walk_expression(argument, page);
+                }
+            },
+            ExpressionKind::StructConstructor(struct_constructor) => {
+                self.walk_type(&struct_constructor.calle);
+                for value in struct_constructor.arguments.values.values() {
+                    self.walk_expression(value, page);
+                }
+            },
+            ExpressionKind::StaticField(static_field) => self.walk_type(&static_field.object),
+            ExpressionKind::AccessField(access_field) => self.walk_expression(&access_field.object, page),
+            ExpressionKind::Index(index) => {
+                self.walk_expression(&index.collection, page);
+                self.walk_expression(&index.index, page);
+            },
+            ExpressionKind::Lambda(lambda) => {
+                for parameter in &lambda.signature.params {
+                    self.walk_type(&parameter.node.ty);
+                }
+                for argument in &lambda.arguments.values {
+                    self.walk_expression(argument, page);
+                }
+
+                match &lambda.body {
+                    LambdaBody::Block(block) => self.walk_block(block, page),
+                    LambdaBody::Expression(body) => self.walk_expression(body, page),
+                }
+            },
+            ExpressionKind::UnwrapVariable(unwrap_variable) => {
+                if let UnwrapVariable::MultiVariable{ty, initializer, ..} = unwrap_variable {
+                    self.walk_type(ty);
+                    if let Some(initializer) = initializer {
+                        self.walk_expression(initializer, page);
+                    }
+                }
+            },
+            ExpressionKind::Unary(unary) => self.walk_expression(&unary.expression, page),
+            ExpressionKind::Binary(binary) => {
+                self.walk_expression(&binary.left, page);
+                self.walk_expression(&binary.right, page);
+            },
+            ExpressionKind::If(if_decl) => self.walk_if(if_decl, page),
+            ExpressionKind::For(for_decl) => {
+                self.walk_expression(&for_decl.collection, page);
+                self.walk_block(&for_decl.block, page);
+            },
+            ExpressionKind::While(while_decl) => {
+                if let Some(condition) = &while_decl.condition {
+                    self.walk_expression(condition, page);
+                }
+                self.walk_block(&while_decl.block, page);
+            },
+            ExpressionKind::Match(match_decl) => {
+                self.walk_expression(&match_decl.condition, page);
+
+                for case in &match_decl.cases {
+                    if let IfCaseKind::Expression(pattern) = &case.if_kind {
+                        self.walk_expression(pattern, page);
+                    }
+
+                    match &case.do_fn {
+                        CaseDoKind::Block(block) => self.walk_block(&block.node, page),
+                        CaseDoKind::Expression(do_expression) => self.walk_expression(do_expression, page),
+                    }
+                }
+            },
+            ExpressionKind::Ternary(ternary) => {
+                self.walk_expression(&ternary.condition, page);
+                self.walk_expression(&ternary.if_branch, page);
+                self.walk_expression(&ternary.else_branch, page);
+            },
+            ExpressionKind::Deref(inner) |
+            ExpressionKind::MutRef(inner) |
+            ExpressionKind::ConstRef(inner) |
+            ExpressionKind::Copy(inner) => self.walk_expression(inner, page),
+            ExpressionKind::Block(block) => self.walk_block(block, page),
+            ExpressionKind::ReturnLike(return_like) => if let Some(value) = &return_like.value {
+                self.walk_expression(value, page);
+            },
+            ExpressionKind::ExpressionGroup(expression_group) => match expression_group {
+                ExpressionGroup::Tuple(tuple) => for value in &tuple.values {
+                    self.walk_expression(value, page);
+                },
+                ExpressionGroup::Array(array) => {
+                    for ty in array.collection_type.iter().chain(array.element_type.iter()) {
+                        self.walk_type(ty);
+                    }
+                    for value in &array.values {
+                        self.walk_expression(value, page);
+                    }
+                },
+                ExpressionGroup::NamedTuple(named_tuple) => for value in named_tuple.values.values() {
+                    self.walk_expression(value, page);
+                },
+                ExpressionGroup::ArrayFiller(array_filler) => {
+                    for ty in array_filler.collection_type.iter().chain(array_filler.element_type.iter()) {
+                        self.walk_type(ty);
+                    }
+                    self.walk_expression(&array_filler.amount, page);
+                    self.walk_expression(&array_filler.fill_expr, page);
+                },
+            },
+        }
+    }
+
+    fn walk_if(&mut self, if_decl: &If, page: &ProgramPage) {
+        self.walk_expression(&if_decl.condition, page);
+        self.walk_block(&if_decl.block, page);
+
+        for branch in &if_decl.else_branchs {
+            match &branch.node {
+                ElseKind::Else(block) => self.walk_block(&block.node, page),
+                ElseKind::ElseIf(else_if) => self.walk_if(&else_if.node, page),
+            }
+        }
+    }
+
+    /// types of other pages make that page used
+    fn walk_type(&mut self, ty: &SoulType) {
+
+        match &ty.base {
+            TypeKind::ExternalType(external) => self.work.push(Work::Page(external.node.path.clone())),
+            TypeKind::ExternalPath(external) => self.work.push(Work::Page(external.node.path.clone())),
+            TypeKind::UnionVariant(union_type) => if let UnionKind::External(external) = &union_type.union {
+                self.work.push(Work::Page(external.path.clone()));
+            },
+            TypeKind::Tuple(types) |
+            TypeKind::TypeEnum(_, types) => for ty in types {
+                self.walk_type(ty);
+            },
+            TypeKind::NamedTuple(types) => for ty in types.values() {
+                self.walk_type(ty);
+            },
+            TypeKind::Function(signature) => for parameter in &signature.parameters {
+                self.walk_type(&parameter.node.ty);
+            },
+            _ => (),
+        }
+
+        self.walk_generics(&ty.generics);
+    }
+
+    fn walk_generics(&mut self, generics: &[TypeGenericKind]) {
+        for generic in generics {
+            if let TypeGenericKind::Type(ty) = generic {
+                self.walk_type(ty);
+            }
+        }
+    }
+}
@@ -0,0 +1,145 @@
+use std::collections::HashMap;
+use crate::steps::{sementic::{reachability::{analyse_reachability, dead_symbols_to_string, remove_dead_functions, unused_function_warnings, ProgramPage, Reachability}, test_helpers::{check_tree_borrows, error_messages, parse_header, parse_source, warning_messages}}, step_interfaces::{i_parser::{abstract_syntax_tree::{abstract_syntax_tree::AbstractSyntacTree, soul_type::type_kind::SoulPagePath, statement::StatementKind}, header::ExternalHeaders}, i_sementic::scope_vistitor::ScopeVisitor}};
+
+const MAIN_PAGE: &str = "testProject.main";
+const TEST_PAGE: &str = "testProject.std.test";
+const UNUSED_PAGE: &str = "testProject.std.unused";
+
+const MAIN_SOURCE: &str = "
+use this.std.test.[Sum]
+
+Unused() {
+}
+
+helper() int {
+    return this::std::test::Sum(1, 2)
+}
+
+orphan(int a) int {
+    return a
+}
+
+double(int a) int {
+    return a * 2
+}
+
+main() {
+    sum := helper()
+    if sum > 2 {
+        other := double(sum)
+    }
+}
+";
+
+const TEST_SOURCE: &str = "
+Sum(int a, int b) int {
+    return add(a, b)
+}
+
+add(int a, int b) int {
+    return a + b
+}
+
+Product(int a, int b) int {
+    return a * b
+}
+
+unusedHelper() {
+}
+";
+
+const UNUSED_SOURCE: &str = "
+Divide(int a, int b) int {
+    return a / b
+}
+";
+
+fn analyse_pages() -> Vec<(SoulPagePath, AbstractSyntacTree, ScopeVisitor)> {
+    let sources = [(MAIN_PAGE, MAIN_SOURCE), (TEST_PAGE, TEST_SOURCE), (UNUSED_PAGE, UNUSED_SOURCE)];
+
+    let headers = sources.iter()
+        .map(|(page, source)| (SoulPagePath(page.to_string()), parse_header(source)))
+        .collect::<HashMap<_, _>>();
+
+    sources.iter()
+        .map(|(page, source)| {
+            let (tree, mut scope) = parse_source(source);
+            scope.external_headers = ExternalHeaders::from_headers(headers.clone());
+
+            let (tree, scope, faults) = check_tree_borrows(tree, scope);
+            assert!(error_messages(&faults).is_empty(), "{}: {:?}", page, error_messages(&faults));
+            (SoulPagePath(page.to_string()), tree, scope)
+        })
+        .collect()
+}
+
+fn reachability_of(pages: &[(SoulPagePath, AbstractSyntacTree, ScopeVisitor)]) -> Option<Reachability> {
+    let program = pages.iter()
+        .map(|(path, tree, scope)| ProgramPage{path: path.clone(), tree, scope})
+        .collect::<Vec<_>>();
+
+    analyse_reachability(&program, &SoulPagePath(MAIN_PAGE.into()))
+}
+
+fn dead_names(reachability: &Reachability, page: &str) -> Vec<String> {
+    let mut names = reachability.dead_functions.iter()
+        .filter(|dead| dead.page.0 == page)
+        .map(|dead| dead.name.clone())
+        .collect::<Vec<_>>();
+    names.sort();
+    names
+}
+
+fn function_names(tree: &AbstractSyntacTree) -> Vec<&str> {
+    tree.root.statments.iter()
+        .filter_map(|statment| match &statment.node {
+            StatementKind::Function(function) => Some(function.signature.name.0.as_str()),
+            _ => None,
+        })
+        .collect()
+}
+
+#[test]
+fn test_reachable_from_main() {
+    let pages = analyse_pages();
+    let reachability = reachability_of(&pages).expect("main page should have main");
+
+    assert_eq!(dead_names(&reachability, MAIN_PAGE), vec!["Unused", "orphan"]);
+    assert_eq!(dead_names(&reachability, TEST_PAGE), vec!["Product", "unusedHelper"]);
+    assert_eq!(dead_names(&reachability, UNUSED_PAGE), vec!["Divide"]);
+    assert_eq!(reachability.unused_pages, vec![SoulPagePath(UNUSED_PAGE.into())]);
+
+    // called through other page and in nested block
+    assert!(reachability.reachable.contains(&(SoulPagePath(TEST_PAGE.into()), "add".into())));
+    assert!(reachability.reachable.contains(&(SoulPagePath(MAIN_PAGE.into()), "double".into())));
+}
+
+#[test]
+fn test_warn_and_remove_dead_functions() {
+    let mut pages = analyse_pages();
+    let reachability = reachability_of(&pages).expect("main page should have main");
+
+    let warnings = warning_messages(&unused_function_warnings(&reachability, &SoulPagePath(MAIN_PAGE.into())));
+    assert_eq!(warnings.len(), 1, "{:?}", warnings);
+    assert!(warnings[0].contains("at 11:") && warnings[0].contains("function 'orphan' is never used"), "{:?}", warnings);
+
+    for (path, tree, _) in &mut pages {
+        remove_dead_functions(tree, &reachability, path);
+    }
+    assert_eq!(function_names(&pages[0].1), vec!["helper", "double", "main"]);
+    assert_eq!(function_names(&pages[1].1), vec!["Sum", "add"]);
+    assert!(function_names(&pages[2].1).is_empty());
+
+    let report = dead_symbols_to_string(&reachability);
+    assert_eq!(report, format!("page {}\n\t4:1 Unused\npage {}\n\t10:1 Product\npage {}\n\t2:1 Divide\nunused pages\n\t{}", MAIN_PAGE, TEST_PAGE, UNUSED_PAGE, UNUSED_PAGE));
+}
+
+#[test]
+fn test_no_main() {
+    let pages = analyse_pages();
+    let program = pages.iter()
+        .map(|(path, tree, scope)| ProgramPage{path: path.clone(), tree, scope})
+        .collect::<Vec<_>>();
+
+    assert!(analyse_reachability(&program, &SoulPagePath(TEST_PAGE.into())).is_none());
+}
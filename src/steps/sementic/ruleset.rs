@@ -0,0 +1,473 @@
+use crate::{errors::soul_error::{new_soul_error, pass_soul_error, SoulErrorKind, SoulSpan}, steps::{sementic::{type_checking::{find_callee_functions, find_functions, STRICT_BORROW, STRICT_BORROW_MUT, STRICT_UNSTRICT}, type_resolution::STRICT_TYPE}, step_interfaces::{i_parser::{abstract_syntax_tree::{abstract_syntax_tree::AbstractSyntacTree, pretty_format::ToString, expression::{CaseDoKind, ElseKind, Expression, ExpressionGroup, ExpressionKind, If, UnaryOperatorKind}, function::{FunctionCall, FunctionSignature, LambdaBody}, object::ClassChild, soul_type::soul_type::{Modifier, SoulType, TypeWrapper}, statement::{Block, Statement, StatementKind}}, scope_builder::ScopeKind}, i_sementic::ast_visitor::{AstAnalyser, RulesetAnalyser}}}};
+
+/// builtin functions and methodes without side effects, these can be called from 'const' rulesets
+const CONST_BUILTINS: &[&str] = &[STRICT_TYPE, STRICT_BORROW, STRICT_BORROW_MUT, STRICT_UNSTRICT];
+
+/// 'const' or 'Literal' function or block that the current code is in
+struct Ruleset {
+    modifier: Modifier,
+    /// e.g "'const' function 'foo'"
+    owner: String,
+    span: SoulSpan,
+    /// variables declared inside of the ruleset, only these can be mutated
+    locals: Vec<String>,
+}
+
+#[derive(Default)]
+struct RulesetContext {
+    ruleset: Option<Ruleset>,
+}
+
+impl AstAnalyser for RulesetAnalyser {
+
+    fn analyse_ast(&mut self, tree: &mut AbstractSyntacTree) {
+        let mut context = RulesetContext::default();
+        self.analyse_block(&tree.root, tree.root.statments.first().map(|statment| statment.span).unwrap_or_default(), &mut context);
+    }
+}
+
+impl RulesetAnalyser {
+
+    /// span is the span of the statement or expression that declares the block
+    fn analyse_block(&mut self, block: &Block, span: SoulSpan, context: &mut RulesetContext) {
+        let parent_id = self.get_scope().current_id();
+        self.get_scope_mut().set_current(block.scope_id);
+
+        // a block can make the ruleset stricter but never looser
+        let outer = if strictness(&block.ruleset) > context.ruleset.as_ref().map(|ruleset| strictness(&ruleset.modifier)).unwrap_or(0) {
+            let owner = format!("'{}' block", block.ruleset.to_str());
+            context.ruleset.replace(Ruleset{modifier: block.ruleset.clone(), owner, span, locals: vec![]})
+        }
+        else {
+            None
+        };
+
+        let locals_len = context.ruleset.as_ref().map(|ruleset| ruleset.locals.len());
+        for statment in &block.statments {
+            self.analyse_statment(statment, context);
+        }
+
+        if outer.is_some() {
+            context.ruleset = outer;
+        }
+        else if let Some(ruleset) = &mut context.ruleset && let Some(locals_len) = locals_len {
+            ruleset.locals.truncate(locals_len);
+        }
+
+        self.get_scope_mut()
+            .set_current(parent_id)
+            .expect("scope_id should be valid");
+    }
+
+    fn analyse_statment(&mut self, statment: &Statement, context: &mut RulesetContext) {
+        let span = statment.span;
+
+        let parent_id = self.get_scope().current_id();
+        if let Some(id) = statment.node.get_scope_id() && self.get_scope_mut().set_current(id).is_none() {
+            panic!("could not get scope_id({}) at line: {}:{}", id.0, span.line_number, span.line_offset)
+        }
+
+        match &statment.node {
+            StatementKind::Enum(_) |
+            StatementKind::Trait(_) |
+            StatementKind::Union(_) |
+            StatementKind::Struct(_) |
+            StatementKind::TypeEnum(_) |
+            StatementKind::CloseBlock => (),
+
+            StatementKind::Expression(expression) => self.analyse_expression(expression, context),
+            StatementKind::Variable(variable_name) => {
+                let value = self.get_scope()
+                    .flat_lookup(&variable_name.name.0)
+                    .and_then(|kinds| kinds.iter().find_map(|kind| match &kind.node {
+                        ScopeKind::Variable(variable) => variable.initialize_value.clone(),
+                        _ => None,
+                    }));
+
+                if let Some(value) = value {
+                    self.analyse_expression(&value, context);
+                }
+
+                if let Some(ruleset) = &mut context.ruleset {
+                    ruleset.locals.push(variable_name.name.0.clone());
+                }
+            },
+            StatementKind::Assignment(assignment) => {
+                self.check_mutation(&assignment.variable, span, context);
+                self.analyse_expression(&assignment.variable, context);
+                self.analyse_expression(&assignment.value, context);
+            },
+            StatementKind::Function(function) => self.analyse_function(&function.signature, &function.block, span),
+            StatementKind::UseBlock(use_block) => self.analyse_block(&use_block.block, span, context),
+            StatementKind::Class(class) => {
+
+                for child in &class.children {
+                    match child {
+                        ClassChild::Field(_) => (),
+                        ClassChild::Methode(methode) => {
+                            let parent_id = self.get_scope().current_id();
+                            self.get_scope_mut().set_current(methode.node.block.scope_id);
+
+                            self.analyse_function(&methode.node.signature, &methode.node.block, methode.span);
+
+                            self.get_scope_mut()
+                                .set_current(parent_id)
+                                .expect("scope_id should be valid");
+                        },
+                        ClassChild::ImplBlock(impl_block) => self.analyse_block(&impl_block.node.block, impl_block.span, context),
+                    }
+                }
+            },
+        }
+
+        self.get_scope_mut()
+            .set_current(parent_id)
+            .expect("scope_id should be valid");
+    }
+
+    /// functions do not inherit the ruleset of the function they are declared in
+    fn analyse_function(&mut self, signature: &FunctionSignature, block: &Block, span: SoulSpan) {
+        let mut context = RulesetContext::default();
+
+        if signature.ruleset != Modifier::Default {
+            // parameters are copies (or references that can not be mutated through, see check_mutation)
+            let mut locals = signature.parameters.iter()
+                .map(|parameter| parameter.node.name.0.clone())
+                .collect::<Vec<_>>();
+
+            if signature.callee.as_ref().and_then(|callee| callee.node.this.as_ref()).is_some_and(|this| !is_reference(this)) {
+                locals.push("this".into());
+            }
+
+            let owner = format!("'{}' function '{}'", signature.ruleset.to_str(), signature.name.0);
+            context.ruleset = Some(Ruleset{modifier: signature.ruleset.clone(), owner, span, locals});
+        }
+
+        self.analyse_block(block, span, &mut context);
+    }
+
+    fn analyse_expression(&mut self, expression: &Expression, context: &mut RulesetContext) {
+        let span = expression.span;
+
+        let parent_id = self.get_scope().current_id();
+        if let Some(id) = expression.node.get_scope_id() && self.get_scope_mut().set_current(id).is_none() {
+            panic!("could not get scope_id({}) at line: {}:{}", id.0, span.line_number, span.line_offset)
+        }
+
+        self.analyse_expression_kind(expression, context);
+
+        self.get_scope_mut()
+            .set_current(parent_id)
+            .expect("scope_id should be valid");
+    }
+
+    fn analyse_expression_kind(&mut self, expression: &Expression, context: &mut RulesetContext) {
+        let span = expression.span;
+
+        match &expression.node {
+            ExpressionKind::Empty |
+            ExpressionKind::Default |
+            ExpressionKind::Literal(_) |
+            ExpressionKind::Variable(_) |
+            ExpressionKind::StaticField(_) |
+            ExpressionKind::UnwrapVariable(_) => (),
+
+            ExpressionKind::ExternalExpression(_) => self.check_literal(span, "uses an external expression", context),
+            ExpressionKind::MutRef(inner) => {
+                if context.ruleset.is_some() {
+                    self.add_ruleset_error(span, "can not borrow mutably".into(), context);
+                }
+                self.analyse_expression(inner, context);
+            },
+            ExpressionKind::ConstRef(inner) => {
+                self.check_literal(span, "uses a reference", context);
+                self.analyse_expression(inner, context);
+            },
+            ExpressionKind::Deref(inner) => {
+                self.check_literal(span, "dereferences a reference", context);
+                self.analyse_expression(inner, context);
+            },
+            ExpressionKind::Copy(inner) => self.analyse_expression(inner, context),
+            ExpressionKind::AccessField(access_field) => self.analyse_expression(&access_field.object, context),
+            ExpressionKind::Index(index) => {
+                self.analyse_expression(&index.collection, context);
+                self.analyse_expression(&index.index, context);
+            },
+            ExpressionKind::FunctionCall(function_call) => {
+                if let Some(callee) = &function_call.callee {
+                    self.analyse_expression(callee, context);
+                }
+                for argument in &function_call.arguments.values {
+                    self.analyse_expression(argument, context);
+                }
+
+                self.check_function_call(function_call, span, context);
+            },
+            ExpressionKind::StaticMethod(static_method) => {
+                for argument in &static_method.arguments.values {
+                    self.analyse_expression(argument, context);
+                }
+
+                if context.ruleset.is_some() {
+                    let candidates = find_callee_functions(self.get_scope(), &static_method.name.0, &static_method.callee.node, false);
+                    self.check_callable(&static_method.name.0, &candidates, static_method.arguments.values.len(), span, context);
+                }
+            },
+            ExpressionKind::StructConstructor(struct_constructor) => for value in struct_constructor.arguments.values.values() {
+                self.analyse_expression(value, context);
+            },
+            ExpressionKind::Unary(unary) => {
+                if matches!(unary.operator.node, UnaryOperatorKind::Increment{..} | UnaryOperatorKind::Decrement{..}) {
+                    self.check_mutation(&unary.expression, span, context);
+                }
+                self.analyse_expression(&unary.expression, context);
+            },
+            ExpressionKind::Binary(binary) => {
+                self.analyse_expression(&binary.left, context);
+                self.analyse_expression(&binary.right, context);
+            },
+            ExpressionKind::Ternary(ternary) => {
+                self.analyse_expression(&ternary.condition, context);
+                self.analyse_expression(&ternary.if_branch, context);
+                self.analyse_expression(&ternary.else_branch, context);
+            },
+            ExpressionKind::Lambda(lambda) => {
+                let locals_len = context.ruleset.as_mut().map(|ruleset| {
+                    let len = ruleset.locals.len();
+                    ruleset.locals.extend(lambda.signature.params.iter().map(|parameter| parameter.node.name.0.clone()));
+                    len
+                });
+
+                match &lambda.body {
+                    LambdaBody::Block(block) => self.analyse_block(block, span, context),
+                    LambdaBody::Expression(body) => self.analyse_expression(body, context),
+                }
+
+                if let Some(ruleset) = &mut context.ruleset && let Some(locals_len) = locals_len {
+                    ruleset.locals.truncate(locals_len);
+                }
+            },
+            ExpressionKind::If(if_decl) => self.analyse_if(if_decl, span, context),
+            ExpressionKind::Match(match_decl) => {
+                self.analyse_expression(&match_decl.condition, context);
+
+                for case in &match_decl.cases {
+                    let parent_id = self.get_scope().current_id();
+                    self.get_scope_mut().set_current(case.scope_id);
+
+                    match &case.do_fn {
+                        CaseDoKind::Block(block) => self.analyse_block(&block.node, block.span, context),
+                        CaseDoKind::Expression(do_expression) => self.analyse_expression(do_expression, context),
+                    }
+
+                    self.get_scope_mut()
+                        .set_current(parent_id)
+                        .expect("scope_id should be valid");
+                }
+            },
+            ExpressionKind::For(for_decl) => {
+                self.analyse_expression(&for_decl.collection, context);
+
+                if let Some(ruleset) = &mut context.ruleset && let Some(element) = &for_decl.element {
+                    push_pattern_names(element, &mut ruleset.locals);
+                }
+                self.analyse_block(&for_decl.block, span, context);
+            },
+            ExpressionKind::While(while_decl) => {
+                if let Some(condition) = &while_decl.condition {
+                    self.analyse_expression(condition, context);
+                }
+                self.analyse_block(&while_decl.block, span, context);
+            },
+            ExpressionKind::Block(block) => self.analyse_block(block, span, context),
+            ExpressionKind::ReturnLike(return_like) => if let Some(value) = &return_like.value {
+                self.analyse_expression(value, context);
+            },
+            ExpressionKind::ExpressionGroup(expression_group) => match expression_group {
+                ExpressionGroup::Tuple(tuple) => for value in &tuple.values {
+                    self.analyse_expression(value, context);
+                },
+                ExpressionGroup::Array(array) => for value in &array.values {
+                    self.analyse_expression(value, context);
+                },
+                ExpressionGroup::NamedTuple(named_tuple) => for value in named_tuple.values.values() {
+                    self.analyse_expression(value, context);
+                },
+                ExpressionGroup::ArrayFiller(array_filler) => {
+                    self.analyse_expression(&array_filler.amount, context);
+                    self.analyse_expression(&array_filler.fill_expr, context);
+                },
+            },
+        }
+    }
+
+    fn analyse_if(&mut self, if_decl: &If, span: SoulSpan, context: &mut RulesetContext) {
+        self.analyse_expression(&if_decl.condition, context);
+        self.analyse_block(&if_decl.block, span, context);
+
+        for branch in &if_decl.else_branchs {
+            match &branch.node {
+                ElseKind::Else(block) => self.analyse_block(&block.node, block.span, context),
+                ElseKind::ElseIf(else_if) => self.analyse_if(&else_if.node, else_if.span, context),
+            }
+        }
+    }
+
+    /// only variables declared inside of the ruleset can be mutated (not through a reference)
+    fn check_mutation(&mut self, place: &Expression, span: SoulSpan, context: &mut RulesetContext) {
+        let ruleset = match &context.ruleset {
+            Some(ruleset) => ruleset,
+            None => return,
+        };
+
+        let is_local = match local_root(place) {
+            Some(name) => ruleset.locals.iter().any(|local| local == name),
+            None => false,
+        };
+
+        if !is_local {
+            let msg = format!("can not mutate '{}', only variables declared inside of it can be mutated", place.node.to_string());
+            self.add_ruleset_error(span, msg, context);
+        }
+    }
+
+    fn check_function_call(&mut self, function_call: &FunctionCall, span: SoulSpan, context: &mut RulesetContext) {
+        if context.ruleset.is_none() {
+            return
+        }
+
+        let name = &function_call.name.0;
+        let argument_len = function_call.arguments.values.len();
+
+        let callee = match &function_call.callee {
+            Some(callee) => callee,
+            None => {
+                let candidates = find_functions(self.get_scope(), name);
+                let is_ctor = candidates.is_empty() && self.get_scope().lookup_kind(name, ScopeKind::is_type).is_some();
+
+                if !is_ctor {
+                    self.check_callable(name, &candidates, argument_len, span, context);
+                }
+                return
+            },
+        };
+
+        let callee_type = match &callee.node {
+            ExpressionKind::Variable(variable_name) => self.get_variable_type(&variable_name.name.0),
+            _ => None,
+        };
+
+        let candidates = match callee_type {
+            Some(ty) => find_callee_functions(self.get_scope(), name, &ty, true),
+            None => return,
+        };
+
+        // e.g 'global.push(1)' mutates global
+        let candidates = with_argument_len(&candidates, argument_len);
+        if !candidates.is_empty() && candidates.iter().all(takes_mut_this) {
+            self.check_mutation(callee, span, context);
+        }
+
+        self.check_callable(name, &candidates.into_iter().cloned().collect::<Vec<_>>(), argument_len, span, context);
+    }
+
+    /// reports error if none of the candidates can be called from the current ruleset
+    fn check_callable(&mut self, name: &str, candidates: &[FunctionSignature], argument_len: usize, span: SoulSpan, context: &mut RulesetContext) {
+        let modifier = match &context.ruleset {
+            Some(ruleset) => ruleset.modifier.clone(),
+            None => return,
+        };
+
+        let candidates = with_argument_len(candidates, argument_len);
+        if candidates.is_empty() {
+
+            match modifier {
+                Modifier::Literal => self.add_ruleset_error(span, format!("can not call '{}', it is not known at compile time", name), context),
+                _ if CONST_BUILTINS.contains(&name) => (),
+                _ => self.add_ruleset_error(span, format!("can not call '{}', it is not a known '{}' function", name, modifier.to_str()), context),
+            }
+            return
+        }
+
+        if candidates.iter().any(|candidate| strictness(&candidate.ruleset) >= strictness(&modifier)) {
+            return
+        }
+
+        let msg = match modifier {
+            Modifier::Literal => format!("can only call '{0}' functions but '{1}' is not '{0}'", modifier.to_str(), name),
+            _ => format!("can not call non-{} function '{}'", modifier.to_str(), name),
+        };
+        self.add_ruleset_error(span, msg, context);
+    }
+
+    /// Literal ruleset has to be evaluated at compile time so can not use runtime only features
+    fn check_literal(&mut self, span: SoulSpan, what: &str, context: &mut RulesetContext) {
+        if context.ruleset.as_ref().is_some_and(|ruleset| ruleset.modifier == Modifier::Literal) {
+            self.add_ruleset_error(span, format!("{}, which can not be evaluated at compile time", what), context);
+        }
+    }
+
+    fn add_ruleset_error(&mut self, span: SoulSpan, msg: String, context: &RulesetContext) {
+        let ruleset = context.ruleset.as_ref().expect("ruleset should be active");
+
+        let error = new_soul_error(SoulErrorKind::InvalidInContext, Some(span), format!("{} {}", ruleset.owner, msg));
+        let error = pass_soul_error(SoulErrorKind::InvalidInContext, Some(ruleset.span), format!("{} is declared here", ruleset.owner), error);
+        self.add_error(error);
+    }
+
+    fn get_variable_type(&self, name: &str) -> Option<SoulType> {
+
+        match &self.get_scope().lookup_kind(name, |kind| matches!(kind, ScopeKind::Variable(_)))?.node {
+            ScopeKind::Variable(variable) if !variable.ty.is_none_type() => Some(variable.ty.clone()),
+            _ => None,
+        }
+    }
+}
+
+/// Default < const < Literal
+fn strictness(modifier: &Modifier) -> u8 {
+    match modifier {
+        Modifier::Default => 0,
+        Modifier::Const => 1,
+        Modifier::Literal => 2,
+    }
+}
+
+fn with_argument_len(candidates: &[FunctionSignature], argument_len: usize) -> Vec<&FunctionSignature> {
+    candidates.iter()
+        .filter(|candidate| candidate.parameters.len() == argument_len)
+        .collect()
+}
+
+/// variable at the root of a place expression, None if place is written through a reference (e.g '*ref = 1')
+fn local_root(place: &Expression) -> Option<&str> {
+
+    match &place.node {
+        ExpressionKind::Variable(variable_name) => Some(&variable_name.name.0),
+        ExpressionKind::AccessField(access_field) => local_root(&access_field.object),
+        ExpressionKind::Index(index) => local_root(&index.collection),
+        _ => None,
+    }
+}
+
+fn push_pattern_names(pattern: &Expression, names: &mut Vec<String>) {
+
+    match &pattern.node {
+        ExpressionKind::Variable(variable_name) => names.push(variable_name.name.0.clone()),
+        ExpressionKind::ExpressionGroup(ExpressionGroup::Tuple(tuple)) => for element in &tuple.values {
+            push_pattern_names(element, names);
+        },
+        _ => (),
+    }
+}
+
+fn is_reference(ty: &SoulType) -> bool {
+    matches!(ty.wrappers.last(), Some(TypeWrapper::MutRef(_) | TypeWrapper::ConstRef(_)))
+}
+
+/// methode that mutates 'this' (e.g 'push(this&, int value)')
+pub fn takes_mut_this(methode: &&FunctionSignature) -> bool {
+    methode.callee.as_ref()
+        .and_then(|callee| callee.node.this.as_ref())
+        .is_some_and(|this| matches!(this.wrappers.last(), Some(TypeWrapper::MutRef(_))))
+}
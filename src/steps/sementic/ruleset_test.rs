@@ -0,0 +1,104 @@
+use crate::steps::sementic::test_helpers::{check_rulesets, error_messages};
+
+const FUNCTIONS: &str = "
+mut global := 1
+
+add(int a, int b) int {
+    return a + b
+}
+
+const constAdd(int a, int b) int {
+    return a + b
+}
+
+Literal square(int a) int {
+    return a * a
+}
+";
+
+#[test]
+fn test_valid_rulesets() {
+    let source = format!("{}
+const sum(int n) int {{
+    mut total := 0
+    for i in 0..n {{
+        total += constAdd(i, square(i))
+    }}
+    return total
+}}
+
+Literal factorial(int n) int {{
+    mut result := 1
+    for i in 1..n {{
+        result *= i
+    }}
+    return square(result)
+}}
+
+main() {{
+    global = add(sum(2), factorial(3))
+}}
+", FUNCTIONS);
+
+    let faults = check_rulesets(&source);
+    assert!(error_messages(&faults).is_empty(), "{:?}", error_messages(&faults));
+}
+
+#[test]
+fn test_const_ruleset() {
+    let source = format!("{}
+const foo(int a) int {{
+    mut sum := a
+    global = 2
+    int& ref = &sum
+    return add(sum, 1)
+}}
+", FUNCTIONS);
+
+    let faults = check_rulesets(&source);
+    let errors = error_messages(&faults);
+
+    assert_eq!(errors.len(), 3, "{:?}", errors);
+    assert!(errors[0].contains("at 16:1| 'const' function 'foo' is declared here"), "{}", errors[0]);
+    assert!(errors[0].contains("at 18:4| 'const' function 'foo' can not mutate 'global', only variables declared inside of it can be mutated"), "{}", errors[0]);
+    assert!(errors[1].contains("'const' function 'foo' can not borrow mutably"), "{}", errors[1]);
+    assert!(errors[2].contains("'const' function 'foo' can not call non-const function 'add'"), "{}", errors[2]);
+}
+
+#[test]
+fn test_literal_ruleset() {
+    let source = format!("{}
+Literal bar(int a) int {{
+    b := constAdd(a, 1)
+    int@ ref = @a
+    Println(a)
+    return square(b)
+}}
+", FUNCTIONS);
+
+    let faults = check_rulesets(&source);
+    let errors = error_messages(&faults);
+
+    assert_eq!(errors.len(), 3, "{:?}", errors);
+    assert!(errors[0].contains("'Literal' function 'bar' can only call 'Literal' functions but 'constAdd' is not 'Literal'"), "{}", errors[0]);
+    assert!(errors[1].contains("'Literal' function 'bar' uses a reference, which can not be evaluated at compile time"), "{}", errors[1]);
+    assert!(errors[2].contains("'Literal' function 'bar' can not call 'Println', it is not known at compile time"), "{}", errors[2]);
+}
+
+#[test]
+fn test_const_unknown_callee() {
+    let source = format!("{}
+const pure(int a) int {{
+    Println(\"side effect\")
+    int strict = Strict(a).UnStrict()
+    return strict
+}}
+", FUNCTIONS);
+
+    let faults = check_rulesets(&source);
+    let errors = error_messages(&faults);
+
+    // builtins without side effects (e.g. 'Strict') are allowed
+    assert_eq!(errors.len(), 1, "{:?}", errors);
+    assert!(errors[0].contains("at 17:4| 'const' function 'pure' can not call 'Println', it is not a known 'const' function"), "{}", errors[0]);
+}
@@ -0,0 +1,212 @@
+use std::{collections::HashMap, io::{BufReader, Cursor}};
+use crate::steps::{sementic::{literal_retention::RetainedUse, optimizer::Optimization}, parser::parser::parse_ast, source_reader::source_reader::read_source_file, step_interfaces::{i_parser::{abstract_syntax_tree::{abstract_syntax_tree::AbstractSyntacTree, expression::Expression, soul_type::soul_type::SoulType}, header::{ExternalHeaders, Header}, parser_response::ParserResponse, scope_builder::{ScopeKind, Variable}}, i_sementic::{ast_visitor::{AstAnalyser, BorrowChecker, ControlFlowAnalyser, ExternalHeaderAnalyser, LambdaCaptureAnalyser, LiteralEvaluator, LiteralRetentionAnalyser, NameResolutionAnalyser, Optimizer, RulesetAnalyser, TraitAnalyser, TypeCheckingAnalyser, TypeResolutionAnalyser}, scope_vistitor::ScopeVisitor, soul_fault::{SoulFault, SoulFaultKind}}}, tokenizer::tokenizer::tokenize};
+
+pub const TEST_PROJECT: &str = "testProject";
+
+fn parse_response(source: &str) -> ParserResponse {
+    let reader = BufReader::new(Cursor::new(source.as_bytes()));
+    let source_file = read_source_file(reader, "    ").expect("source should be readable");
+    let tokens = tokenize(source_file).expect("source should tokenize");
+    
+    parse_ast(tokens, TEST_PROJECT.into())
+        .unwrap_or_else(|err| panic!("source should parse: {}", err.to_err_message().join("\n")))
+}
+
+pub fn parse_source(source: &str) -> (AbstractSyntacTree, ScopeVisitor) {
+    let ParserResponse{tree, scopes} = parse_response(source);
+    (tree, ScopeVisitor::new(scopes, ExternalHeaders::from_headers(HashMap::new())))
+}
+
+/// header of source as if it was another page in the project
+pub fn parse_header(source: &str) -> Header {
+    Header::from_scope_builder(&parse_response(source).scopes)
+}
+
+pub fn error_messages(faults: &[SoulFault]) -> Vec<String> {
+    faults.iter()
+        .filter(|fault| matches!(fault.kind, SoulFaultKind::Error))
+        .map(|fault| fault.msg.to_err_message().join("\n"))
+        .collect()
+}
+
+pub fn global_symbol<'a>(scope: &'a ScopeVisitor, name: &str) -> &'a ScopeKind {
+    &scope.get_scopes()[ScopeVisitor::GLOBAL_SCOPE_INDEX.0]
+        .get(name)
+        .unwrap_or_else(|| panic!("'{}' should be in global scope", name))[0]
+        .node
+}
+
+/// last analyser that analyse_until runs, in the order the compiler runs them
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Stage {
+    TypeChecking,
+    ControlFlow,
+    Traits,
+    Rulesets,
+    LiteralEvaluation,
+    LiteralRetention,
+    BorrowChecking,
+    Optimization,
+}
+
+/// tree and scope after analyse_until, retained_uses and optimizations are empty if their stage did not run
+pub struct Analysis {
+    pub tree: AbstractSyntacTree,
+    pub scope: ScopeVisitor,
+    pub faults: Vec<SoulFault>,
+    pub retained_uses: Vec<RetainedUse>,
+    pub optimizations: Vec<Optimization>,
+}
+
+/// runs every analyser up to and including stage
+pub fn analyse_until(mut tree: AbstractSyntacTree, scope: ScopeVisitor, stage: Stage) -> Analysis {
+    let mut retained_uses = vec![];
+    let mut optimizations = vec![];
+
+    macro_rules! stop_at {
+        ($stage:expr, $analyser:expr) => {
+            if stage == $stage {
+                let (scope, faults, _) = $analyser.consume_to_tuple();
+                return Analysis{tree, scope, faults, retained_uses, optimizations}
+            }
+        };
+    }
+
+    let mut analyser = NameResolutionAnalyser::new(scope, true);
+    analyser.analyse_ast(&mut tree);
+    let mut analyser = ExternalHeaderAnalyser::new(analyser, true);
+    analyser.analyse_ast(&mut tree);
+    let mut analyser = TypeResolutionAnalyser::new(analyser, true);
+    analyser.analyse_ast(&mut tree);
+    let mut analyser = TypeCheckingAnalyser::new(analyser, true);
+    analyser.analyse_ast(&mut tree);
+    stop_at!(Stage::TypeChecking, analyser);
+
+    let mut analyser = ControlFlowAnalyser::new(analyser, true);
+    analyser.analyse_ast(&mut tree);
+    stop_at!(Stage::ControlFlow, analyser);
+
+    let mut analyser = TraitAnalyser::new(analyser, true);
+    analyser.analyse_ast(&mut tree);
+    stop_at!(Stage::Traits, analyser);
+
+    let mut analyser = RulesetAnalyser::new(analyser, true);
+    analyser.analyse_ast(&mut tree);
+    stop_at!(Stage::Rulesets, analyser);
+
+    let mut analyser = LiteralEvaluator::new(analyser, true);
+    analyser.analyse_ast(&mut tree);
+    stop_at!(Stage::LiteralEvaluation, analyser);
+
+    let mut analyser = LiteralRetentionAnalyser::new(analyser, true);
+    retained_uses = analyser.analyse_retention(&mut tree);
+    stop_at!(Stage::LiteralRetention, analyser);
+
+    let mut analyser = LambdaCaptureAnalyser::new(analyser, true);
+    analyser.analyse_ast(&mut tree);
+    let mut analyser = BorrowChecker::new(analyser, true);
+    analyser.analyse_ast(&mut tree);
+    stop_at!(Stage::BorrowChecking, analyser);
+
+    let mut analyser = Optimizer::new(analyser, true);
+    optimizations = analyser.optimize(&mut tree);
+
+    let (scope, faults, _) = analyser.consume_to_tuple();
+    Analysis{tree, scope, faults, retained_uses, optimizations}
+}
+
+/// parses source and runs every analyser up to and including stage
+pub fn analyse_source(source: &str, stage: Stage) -> Analysis {
+    let (tree, scope) = parse_source(source);
+    analyse_until(tree, scope, stage)
+}
+
+pub fn check_types(source: &str) -> (ScopeVisitor, Vec<SoulFault>) {
+    check_types_with_headers(source, ExternalHeaders::from_headers(HashMap::new()))
+}
+
+pub fn check_types_with_headers(source: &str, headers: ExternalHeaders) -> (ScopeVisitor, Vec<SoulFault>) {
+    let (tree, mut scope) = parse_source(source);
+    scope.external_headers = headers;
+
+    let Analysis{scope, faults, ..} = analyse_until(tree, scope, Stage::TypeChecking);
+    (scope, faults)
+}
+
+pub fn check_control_flow(source: &str) -> Vec<SoulFault> {
+    analyse_source(source, Stage::ControlFlow).faults
+}
+
+pub fn check_traits(source: &str) -> Vec<SoulFault> {
+    analyse_source(source, Stage::Traits).faults
+}
+
+pub fn check_rulesets(source: &str) -> Vec<SoulFault> {
+    analyse_source(source, Stage::Rulesets).faults
+}
+
+pub fn check_literals(source: &str) -> (ScopeVisitor, Vec<SoulFault>) {
+    let Analysis{scope, faults, ..} = analyse_source(source, Stage::LiteralEvaluation);
+    (scope, faults)
+}
+
+pub fn check_retention(source: &str) -> (ScopeVisitor, Vec<RetainedUse>, Vec<SoulFault>) {
+    let Analysis{scope, retained_uses, faults, ..} = analyse_source(source, Stage::LiteralRetention);
+    (scope, retained_uses, faults)
+}
+
+pub fn check_borrows(source: &str) -> Vec<SoulFault> {
+    analyse_source(source, Stage::BorrowChecking).faults
+}
+
+/// like check_borrows but for a tree that is changed after parsing (e.g. to add nodes the parser can not make yet)
+pub fn check_tree_borrows(tree: AbstractSyntacTree, scope: ScopeVisitor) -> (AbstractSyntacTree, ScopeVisitor, Vec<SoulFault>) {
+    let Analysis{tree, scope, faults, ..} = analyse_until(tree, scope, Stage::BorrowChecking);
+    (tree, scope, faults)
+}
+
+/// runs every analyser and the optimizer, returns the optimized tree and the optimizations that were done
+pub fn optimize_source(source: &str) -> (AbstractSyntacTree, ScopeVisitor, Vec<Optimization>, Vec<SoulFault>) {
+    let Analysis{tree, scope, optimizations, faults, ..} = analyse_source(source, Stage::Optimization);
+    (tree, scope, optimizations, faults)
+}
+
+pub fn warning_messages(faults: &[SoulFault]) -> Vec<String> {
+    faults.iter()
+        .filter(|fault| matches!(fault.kind, SoulFaultKind::Warning))
+        .map(|fault| fault.msg.to_err_message().join("\n"))
+        .collect()
+}
+
+fn variable<'a>(scope: &'a ScopeVisitor, name: &str) -> Option<&'a Variable> {
+    scope.get_scopes().iter()
+        .filter_map(|scope| scope.get(name))
+        .flatten()
+        .find_map(|kind| match &kind.node {
+            ScopeKind::Variable(variable) => Some(variable),
+            _ => None,
+        })
+}
+
+pub fn variable_type(scope: &ScopeVisitor, name: &str) -> SoulType {
+    variable(scope, name)
+        .map(|variable| variable.ty.clone())
+        .unwrap_or_else(|| panic!("variable '{}' should be in scope", name))
+}
+
+pub fn initializer<'a>(scope: &'a ScopeVisitor, name: &str) -> &'a Expression {
+    variable(scope, name)
+        .and_then(|variable| variable.initialize_value.as_ref())
+        .unwrap_or_else(|| panic!("variable '{}' should be initialized", name))
+}
+
+/// runs analysers of source up to stage and checks that there is exactly one error that contains every expected part
+pub fn assert_single_error(source: &str, stage: Stage, expected: &[&str]) {
+    let faults = analyse_source(source, stage).faults;
+    let errors = error_messages(&faults);
+
+    assert_eq!(errors.len(), 1, "{:?}", errors);
+    for expected in expected {
+        assert!(errors[0].contains(expected), "expected '{}' in: {}", expected, errors[0]);
+    }
+}
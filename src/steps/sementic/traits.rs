@@ -0,0 +1,270 @@
+use std::collections::BTreeMap;
+use crate::{errors::soul_error::{new_soul_error, pass_soul_error, SoulErrorKind, SoulSpan}, steps::{sementic::{borrow_checker::is_auto_copy, generics::{collect_trait_impls, TraitImpls}, type_checking::substitute_generics, type_resolution::{type_with_generics, AUTO_COPY_TRAIT, THIS_TYPE}}, step_interfaces::{i_parser::{abstract_syntax_tree::{abstract_syntax_tree::AbstractSyntacTree, expression::Ident, pretty_format::ToString, function::FunctionSignature, generic::GenericKind, object::{Class, ClassChild, Field}, soul_type::{soul_type::{SoulType, TypeGenericKind}, type_kind::TypeKind}, statement::{Block, Statement, StatementKind, UseBlock}}, scope_builder::ScopeKind}, i_sementic::ast_visitor::{AstAnalyser, TraitAnalyser}}}};
+
+impl AstAnalyser for TraitAnalyser {
+
+    fn analyse_ast(&mut self, tree: &mut AbstractSyntacTree) {
+        let mut impls = vec![];
+        collect_trait_impls(&tree.root, &mut impls);
+        self.analyse_block(&tree.root, &impls);
+    }
+}
+
+/// methode an implementor has to provide, with 'This' and the generics of the trait already substituted
+struct RequiredMethode {
+    trait_name: String,
+    signature: FunctionSignature,
+    span: SoulSpan,
+}
+
+impl TraitAnalyser {
+
+    fn analyse_statment(&mut self, statment: &Statement, impls: &TraitImpls) {
+
+        let parent_id = self.get_scope().current_id();
+        if let Some(id) = statment.node.get_scope_id() && self.get_scope_mut().set_current(id).is_none() {
+            panic!("could not get scope_id({}) at line: {}:{}", id.0, statment.span.line_number, statment.span.line_offset)
+        }
+
+        match &statment.node {
+            StatementKind::Class(class) => self.check_class(class, statment.span, impls),
+            StatementKind::UseBlock(use_block) => {
+
+                if let Some(impl_trait) = &use_block.impl_trait {
+                    let provided = block_methodes(&use_block.block);
+                    self.check_implementation(&use_block.ty, impl_trait, &provided, statment.span, impls);
+                }
+                self.analyse_block(&use_block.block, impls);
+            },
+            StatementKind::Function(function) => self.analyse_block(&function.block, impls),
+            _ => (),
+        }
+
+        self.get_scope_mut()
+            .set_current(parent_id)
+            .expect("scope_id should be valid");
+    }
+
+    fn check_class(&mut self, class: &Class, span: SoulSpan, impls: &TraitImpls) {
+        let this_type = type_with_generics(TypeKind::Class(class.name.clone()), &class.generics);
+
+        let mut provided = vec![];
+        for child in &class.children {
+
+            match child {
+                ClassChild::Field(_) => (),
+                ClassChild::Methode(methode) => provided.push((&methode.node.signature, methode.span)),
+                ClassChild::ImplBlock(impl_block) => provided.extend(block_methodes(&impl_block.node.block)),
+            }
+        }
+
+        for implement in &class.implements {
+            self.check_implementation(&this_type, implement, &provided, span, impls);
+        }
+
+        for child in &class.children {
+
+            if let ClassChild::ImplBlock(impl_block) = child && let UseBlock{impl_trait: Some(impl_trait), ..} = &impl_block.node {
+                self.check_implementation(&this_type, impl_trait, &provided, impl_block.span, impls);
+            }
+        }
+    }
+
+    /// checks that 'provided' has every methode of 'trait_type' and its supertraits
+    fn check_implementation(&mut self, this_type: &SoulType, trait_type: &SoulType, provided: &[(&FunctionSignature, SoulSpan)], span: SoulSpan, impls: &TraitImpls) {
+
+        if matches!(&trait_type.base, TypeKind::Trait(name) if name.0 == AUTO_COPY_TRAIT) {
+            self.check_auto_copy(this_type, span, impls);
+            return
+        }
+
+        let mut required = vec![];
+        let mut visited = vec![];
+        self.collect_required(this_type, trait_type, span, &mut visited, &mut required);
+
+        for methode in required {
+            let candidates = provided.iter()
+                .filter(|(signature, _)| signature.name == methode.signature.name)
+                .collect::<Vec<_>>();
+
+            if candidates.iter().any(|(signature, _)| is_same_signature(signature, &methode.signature)) {
+                continue
+            }
+
+            match candidates.first() {
+                None => self.add_error(new_soul_error(
+                    SoulErrorKind::NotFoundInScope,
+                    Some(span),
+                    format!("'{}' does not implement methode '{}' of trait '{}'", this_type.to_string(), methode.signature.name, methode.trait_name),
+                )),
+                Some((signature, methode_span)) => {
+                    let trait_err = new_soul_error(SoulErrorKind::WrongType, Some(methode.span), format!("methode '{}' is declared in trait '{}' here", methode.signature.name, methode.trait_name));
+                    self.add_error(pass_soul_error(
+                        SoulErrorKind::WrongType,
+                        Some(*methode_span),
+                        format!(
+                            "methode '{}' of '{}' does not match trait '{}', expected '{}' but found '{}'",
+                            methode.signature.name,
+                            this_type.to_string(),
+                            methode.trait_name,
+                            signature_to_string(&methode.signature),
+                            signature_to_string(signature),
+                        ),
+                        trait_err,
+                    ));
+                },
+            }
+        }
+    }
+
+    fn collect_required(&mut self, this_type: &SoulType, trait_type: &SoulType, span: SoulSpan, visited: &mut Vec<Ident>, required: &mut Vec<RequiredMethode>) {
+
+        let name = match &trait_type.base {
+            TypeKind::Trait(name) => name,
+            // not found, already reported by type resolution
+            TypeKind::Unknown(_) => return,
+            _ => {
+                self.add_error(new_soul_error(SoulErrorKind::WrongType, Some(span), format!("'{}' is not a trait", trait_type.to_string())));
+                return
+            },
+        };
+
+        if visited.contains(name) {
+            return
+        }
+        visited.push(name.clone());
+
+        let trait_ = match self.get_scope().lookup_kind(&name.0, |kind| matches!(kind, ScopeKind::Trait(_))) {
+            Some(symbol) => match &symbol.node {
+                ScopeKind::Trait(trait_) => trait_.clone(),
+                _ => unreachable!("lookup_kind only returns traits"),
+            },
+            None => return,
+        };
+
+        let mut bindings = BTreeMap::new();
+        bindings.insert(Ident::new(THIS_TYPE), this_type.clone());
+
+        let mut generic_args = trait_type.generics.iter()
+            .filter_map(|generic| match generic {
+                TypeGenericKind::Type(ty) => Some(ty),
+                _ => None,
+            });
+
+        for generic in &trait_.signature.generics {
+
+            let default = match &generic.kind {
+                GenericKind::Type{default, ..} => default,
+                GenericKind::Lifetime => continue,
+            };
+
+            let binding = match (generic_args.next(), default) {
+                (Some(ty), _) => ty.clone(),
+                (None, Some(default)) => {
+                    let mut default = default.clone();
+                    substitute_generics(&mut default, &bindings);
+                    default
+                },
+                (None, None) => {
+                    self.add_error(new_soul_error(SoulErrorKind::WrongType, Some(span), format!("trait '{}' needs a type for generic '{}'", name, generic.name)));
+                    continue
+                },
+            };
+
+            bindings.insert(generic.name.clone(), binding);
+        }
+
+        for methode in &trait_.methodes {
+            let mut signature = methode.node.clone();
+
+            for parameter in &mut signature.parameters {
+                substitute_generics(&mut parameter.node.ty, &bindings);
+            }
+            if let Some(return_type) = &mut signature.return_type {
+                substitute_generics(return_type, &bindings);
+            }
+
+            required.push(RequiredMethode{trait_name: name.0.clone(), signature, span: methode.span});
+        }
+
+        for super_trait in &trait_.signature.implements {
+            let mut super_trait = super_trait.clone();
+            substitute_generics(&mut super_trait, &bindings);
+            self.collect_required(this_type, &super_trait, span, visited, required);
+        }
+    }
+
+    /// every field of an 'AutoCopy' type has to be 'AutoCopy' too
+    fn check_auto_copy(&mut self, this_type: &SoulType, span: SoulSpan, impls: &TraitImpls) {
+
+        let fields = match &this_type.base {
+            TypeKind::Struct(name) => match self.get_scope().lookup_kind(&name.0, |kind| matches!(kind, ScopeKind::Struct(_))).map(|symbol| &symbol.node) {
+                Some(ScopeKind::Struct(struct_)) => struct_.fields.iter().map(|field| field.node.clone()).collect::<Vec<Field>>(),
+                _ => return,
+            },
+            TypeKind::Class(name) => match self.get_scope().lookup_kind(&name.0, |kind| matches!(kind, ScopeKind::Class(_))).map(|symbol| &symbol.node) {
+                Some(ScopeKind::Class(class)) => class.children.iter()
+                    .filter_map(|child| match child {
+                        ClassChild::Field(field) => Some(field.node.clone()),
+                        _ => None,
+                    })
+                    .collect(),
+                _ => return,
+            },
+            _ => return,
+        };
+
+        let not_copy = fields.into_iter()
+            .filter(|field| !is_auto_copy(self.get_scope(), impls, &field.ty))
+            .collect::<Vec<_>>();
+
+        for field in not_copy {
+            self.add_error(new_soul_error(
+                SoulErrorKind::WrongType,
+                Some(span),
+                format!("'{}' can not implement '{}' because field '{}' of type '{}' is not '{}'", this_type.to_string(), AUTO_COPY_TRAIT, field.name, field.ty.to_string(), AUTO_COPY_TRAIT),
+            ));
+        }
+    }
+
+    fn analyse_block(&mut self, block: &Block, impls: &TraitImpls) {
+
+        for statment in &block.statments {
+            self.analyse_statment(statment, impls);
+        }
+    }
+}
+
+fn block_methodes(block: &Block) -> Vec<(&FunctionSignature, SoulSpan)> {
+    block.statments.iter()
+        .filter_map(|statment| match &statment.node {
+            StatementKind::Function(function) => Some((&function.signature, statment.span)),
+            _ => None,
+        })
+        .collect()
+}
+
+/// same parameter types, return type and 'this' (e.g 'this@' vs 'this&'), names of parameters can differ
+fn is_same_signature(signature: &FunctionSignature, required: &FunctionSignature) -> bool {
+    let this_of = |signature: &FunctionSignature| signature.callee.as_ref()
+        .and_then(|callee| callee.node.this.as_ref())
+        .map(|this| this.wrappers.clone());
+
+    signature.parameters.len() == required.parameters.len() &&
+    signature.parameters.iter().zip(&required.parameters).all(|(param, required)| param.node.ty == required.node.ty) &&
+    signature.return_type.clone().unwrap_or(SoulType::none()) == required.return_type.clone().unwrap_or(SoulType::none()) &&
+    this_of(signature) == this_of(required)
+}
+
+fn signature_to_string(signature: &FunctionSignature) -> String {
+    let this = signature.callee.as_ref()
+        .and_then(|callee| callee.node.this.as_ref())
+        .map(|this| format!("this{}", this.wrappers.to_string()));
+
+    let parameters = this.into_iter()
+        .chain(signature.parameters.iter().map(|param| format!("{} {}", param.node.ty.to_string(), param.node.name)))
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    format!("{}({}) {}", signature.name, parameters, signature.return_type.clone().unwrap_or(SoulType::none()).to_string())
+}
@@ -0,0 +1,103 @@
+use crate::steps::sementic::test_helpers::{check_traits, error_messages};
+
+#[test]
+fn test_implemented_traits() {
+    let source = "
+trait Debug {
+    ToString() str
+}
+
+trait Number {
+    Zero() This
+}
+
+trait Add<T, R = T> impl Debug + Number {
+    Add(T other) R
+}
+
+class Num impl Add<Num> {
+    int value
+
+    Add(Num other) Num {
+        return other
+    }
+
+    Zero() Num {
+        return Num(0)
+    }
+
+    impl Debug {
+        ToString() str {
+            return \"num\"
+        }
+    }
+}
+
+use int impl Debug {
+    ToString() str {
+        return \"int\"
+    }
+}
+";
+
+    let faults = check_traits(source);
+    assert!(error_messages(&faults).is_empty(), "{:?}", error_messages(&faults));
+}
+
+#[test]
+fn test_missing_and_mismatched_methodes() {
+    let source = "
+trait Debug {
+    ToString() str
+}
+
+trait Add<T, R = T> impl Debug {
+    Add(T other) R
+}
+
+class Num impl Add<Num> {
+    int value
+
+    Add(int other) Num {
+        return Num(other)
+    }
+}
+
+use int impl Debug {
+    ToString() int {
+        return 1
+    }
+}
+";
+
+    let faults = check_traits(source);
+    let errors = error_messages(&faults);
+    assert_eq!(errors.len(), 3, "{:?}", errors);
+
+    assert!(errors.iter().any(|err| err.contains("at 13:") && err.contains("methode 'Add' of 'Num' does not match trait 'Add', expected 'Add(const Num other) Num' but found 'Add(const int other) Num'") && err.contains("at 7:")), "{:?}", errors);
+    assert!(errors.iter().any(|err| err.contains("at 10:") && err.contains("'Num' does not implement methode 'ToString' of trait 'Debug'")), "{:?}", errors);
+    assert!(errors.iter().any(|err| err.contains("at 19:") && err.contains("methode 'ToString' of 'int' does not match trait 'Debug', expected 'ToString() str' but found 'ToString() int'")), "{:?}", errors);
+}
+
+#[test]
+fn test_auto_copy_fields() {
+    let source = "
+struct Point {
+    int x
+    int y
+}
+
+struct Path {
+    Point start
+    int[] steps
+}
+
+use Point impl AutoCopy {}
+use Path impl AutoCopy {}
+";
+
+    let faults = check_traits(source);
+    let errors = error_messages(&faults);
+    assert_eq!(errors.len(), 1, "{:?}", errors);
+    assert!(errors[0].contains("at 13:") && errors[0].contains("'Path' can not implement 'AutoCopy' because field 'steps' of type 'int[]' is not 'AutoCopy'"), "{}", errors[0]);
+}
@@ -0,0 +1,1992 @@
+use std::collections::{BTreeMap, HashMap, HashSet};
+use crate::{errors::soul_error::{new_soul_error, SoulErrorKind, SoulSpan}, steps::{sementic::{exhaustiveness::{check_cases, subject_name, CaseFault, MatchReport, MatchSubject}, generics::{bind_defaults, bind_explicit, check_constraints, collect_trait_impls, declaration_generics, implemented_traits, infer_from_arguments, instance_name, instantiate, is_concrete, is_concrete_binding, substitute, substitute_signature, TraitImpls}, overload_resolution::{candidates_to_string, find_overload_conflicts, is_unresolved_argument, resolve_overload, OverloadError}, type_resolution::{MUT_TYPE, STRICT_TYPE, THIS_TYPE}}, step_interfaces::{i_parser::{abstract_syntax_tree::{abstract_syntax_tree::AbstractSyntacTree, enum_like::{Enum, EnumVariantKind, Union, UnionVariantKind}, expression::{BinaryOperatorKind, CaseDoKind, CaseSwitch, ElseKind, Expression, ExpressionGroup, ExpressionKind, Ident, If, IfCaseKind, ReturnKind, UnaryOperatorKind, VariableName}, function::{FunctionSignature, LambdaBody, Parameter}, generic::{GenericKind, GenericParameter}, literal::Literal, object::{Class, ClassChild, Field, Visibility}, soul_type::{soul_type::{Modifier, SoulType, TypeGenericKind, TypeWrapper}, type_kind::{TypeKind, TypeSize}}, spanned::Spanned, statement::{Block, Statement, StatementKind}}, scope_builder::ScopeKind}, i_sementic::{ast_visitor::{AstAnalyser, TypeCheckingAnalyser}, generic_instances::{GenericBindings, GenericInstance, GenericInstanceKind}, scope_vistitor::ScopeVisitor}}}};
+
+const RANGE_TYPE: &str = "Range";
+/// static methode of every enum that returns all its variants
+pub const ENUM_VALUES: &str = "Values";
+/// 'strict.BorrowMut()' returns 'Mut<T>'
+pub const STRICT_BORROW_MUT: &str = "BorrowMut";
+/// 'strict.Borrow()' returns 'T@'
+pub const STRICT_BORROW: &str = "Borrow";
+/// 'strict.UnStrict()' returns 'T'
+pub const STRICT_UNSTRICT: &str = "UnStrict";
+/// functions of the runtime that every page can call without declaring them (and 'std::..' functions like 'std::fmt::Arg' of format strings)
+const RUNTIME_FUNCTIONS: &[&str] = &["Print", "Println"];
+
+/// expected type of 'return' in the function or lambda that is currently checked
+enum ReturnType {
+    Checked(SoulType),
+    Unchecked,
+}
+
+#[derive(Default)]
+struct CheckContext {
+    return_types: Vec<ReturnType>,
+    trait_impls: TraitImpls,
+    /// constraint violations that are already reported per line (e.g 'Box<bool> b = Box<bool>{..}' checks 'Box<bool>' twice)
+    reported: HashSet<(String, usize)>,
+    /// objects the current code is a methode or use block of (e.g. can access private fields of)
+    objects: Vec<Ident>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum FieldUse {
+    Get,
+    Set,
+}
+
+impl AstAnalyser for TypeCheckingAnalyser {
+
+    fn analyse_ast(&mut self, tree: &mut AbstractSyntacTree) {
+        self.check_overload_sets();
+
+        let mut context = CheckContext::default();
+        collect_trait_impls(&tree.root, &mut context.trait_impls);
+        self.analyse_block(&mut tree.root, &mut context);
+    }
+}
+
+impl TypeCheckingAnalyser {
+
+    fn analyse_statment(&mut self, statment: &mut Statement, context: &mut CheckContext) {
+        let span = statment.span;
+
+        let parent_id = self.get_scope().current_id();
+        if let Some(id) = statment.node.get_scope_id() && self.get_scope_mut().set_current(id).is_none() {
+            panic!("could not get scope_id({}) at line: {}:{}", id.0, span.line_number, span.line_offset)
+        }
+
+        match &mut statment.node {
+            StatementKind::Trait(_) => (),
+            StatementKind::Union(_) => (),
+            StatementKind::TypeEnum(_) => (),
+            StatementKind::CloseBlock => (),
+
+            StatementKind::Expression(expression) => {
+                self.check_expression(expression, context);
+            },
+            StatementKind::Variable(variable_name) => self.check_variable(variable_name, span, context),
+            StatementKind::Assignment(assignment) => {
+
+                if let ExpressionKind::Variable(variable_name) = &assignment.variable.node && self.is_uninferred_variable(&variable_name.name.0) {
+                    let name = variable_name.name.0.clone();
+                    let value_type = self.check_expression(&mut assignment.value, context);
+                    self.infer_variable_type(&name, value_type, span);
+                }
+                else {
+                    let variable_type = match &mut assignment.variable.node {
+                        ExpressionKind::AccessField(access_field) => self.check_expression(&mut access_field.object, context)
+                            .and_then(|object_type| self.get_field_type(&object_type, &access_field.field, FieldUse::Set, span, context)),
+                        _ => self.check_expression(&mut assignment.variable, context),
+                    };
+                    let value_type = self.check_expression(&mut assignment.value, context);
+
+                    if let (Some(variable_type), Some(value_type)) = (variable_type, value_type) && !self.is_assignable_in_tree(&variable_type, &value_type, span, context) {
+                        self.add_wrong_type(span, format!("can not assign '{}' to '{}'", type_name(&value_type), type_name(&variable_type)));
+                    }
+                }
+            },
+            StatementKind::Function(function) => {
+                self.check_function_block(&function.signature, &mut function.block, span, context);
+            },
+            StatementKind::UseBlock(use_block) => {
+                let object = object_name(&use_block.ty);
+                if let Some(object) = &object {
+                    context.objects.push(object.clone());
+                }
+
+                self.analyse_block(&mut use_block.block, context);
+
+                if object.is_some() {
+                    context.objects.pop();
+                }
+            },
+            StatementKind::Class(class) => self.check_class(class, context),
+            StatementKind::Struct(struct_) => for field in &mut struct_.fields {
+                self.check_field(&mut field.node, field.span, context);
+            },
+            StatementKind::Enum(enum_) => self.check_enum(enum_, context),
+        }
+
+        self.get_scope_mut()
+            .set_current(parent_id)
+            .expect("scope_id should be valid");
+    }
+
+    fn check_class(&mut self, class: &mut Class, context: &mut CheckContext) {
+        context.objects.push(class.name.clone());
+
+        for child in &mut class.children {
+
+            match child {
+                ClassChild::Field(field) => self.check_field(&mut field.node, field.span, context),
+                ClassChild::Methode(methode) => {
+                    let parent_id = self.get_scope().current_id();
+                    self.get_scope_mut().set_current(methode.node.block.scope_id);
+
+                    self.check_function_block(&methode.node.signature, &mut methode.node.block, methode.span, context);
+
+                    self.get_scope_mut()
+                        .set_current(parent_id)
+                        .expect("scope_id should be valid");
+                },
+                ClassChild::ImplBlock(impl_block) => self.analyse_block(&mut impl_block.node.block, context),
+            }
+        }
+
+        context.objects.pop();
+    }
+
+    fn check_enum(&mut self, enum_: &mut Enum, context: &mut CheckContext) {
+
+        let (ty, variants) = match (&enum_.ty, &mut enum_.variants) {
+            (Some(ty), EnumVariantKind::Expression(variants)) => (ty, variants),
+            _ => return,
+        };
+
+        for variant in variants {
+            
+            // implicit value (e.g 'Two' in 'enum Nums impl u8 { One = 1, Two }') is set by literal evaluator
+            if let ExpressionKind::Default = variant.value.node {
+
+                if !ty.wrappers.is_empty() || !ty.base.is_integer() {
+                    self.add_wrong_type(variant.value.span, format!("variant '{}' of enum '{}' needs a value, only variants of integer enums get an implicit value", variant.name, enum_.name));
+                }
+                continue
+            }
+
+            if let Some(value_type) = self.check_expression(&mut variant.value, context) && !is_assignable(ty, &value_type) {
+                self.add_wrong_type(variant.value.span, format!("value of variant '{}' of enum '{}' should be '{}' but is '{}'", variant.name, enum_.name, type_name(ty), type_name(&value_type)));
+            }
+        }
+    }
+
+    fn check_field(&mut self, field: &mut Field, span: SoulSpan, context: &mut CheckContext) {
+        self.check_type_instance(&field.ty, span, context);
+
+        let default_value = match &mut field.default_value {
+            Some(value) => value,
+            None => return,
+        };
+
+        if let Some(value_type) = self.check_expression(default_value, context) && !self.is_assignable_in_tree(&field.ty, &value_type, span, context) {
+            self.add_wrong_type(span, format!("default value of field '{}' is '{}' but field is '{}'", field.name.0, type_name(&value_type), type_name(&field.ty)));
+        }
+    }
+
+    fn check_function_block(&mut self, signature: &FunctionSignature, block: &mut Block, span: SoulSpan, context: &mut CheckContext) {
+        for parameter in &signature.parameters {
+            self.check_type_instance(&parameter.node.ty, parameter.span, context);
+        }
+
+        let return_type = signature.return_type.clone().unwrap_or(SoulType::none());
+        self.check_type_instance(&return_type, span, context);
+
+        context.return_types.push(ReturnType::Checked(return_type));
+        self.analyse_block(block, context);
+        context.return_types.pop();
+    }
+
+    /// infers type of variable if needed and checks initialize value against type of variable
+    fn check_variable(&mut self, variable_name: &VariableName, span: SoulSpan, context: &mut CheckContext) {
+        let name = &variable_name.name.0;
+
+        let variable = self.get_scope()
+            .flat_lookup(name)
+            .and_then(|kinds| kinds.iter().find_map(|kind| match &kind.node {
+                ScopeKind::Variable(variable) => Some(variable.clone()),
+                _ => None,
+            }));
+
+        let (mut ty, mut value) = match variable {
+            Some(variable) => match variable.initialize_value {
+                Some(value) => (variable.ty, value),
+                None => return,
+            },
+            None => return,
+        };
+
+        let value_type = self.check_expression(&mut value, context);
+        self.set_variable_value(name, value);
+
+        if ty.is_none_type() {
+
+            match value_type {
+                Some(value_type) if value_type.is_none_type() => {
+                    self.add_wrong_type(span, format!("can not infer type of variable '{}' from expression without value", name));
+                    return
+                },
+                Some(value_type) => {
+                    let modifier = ty.modifier;
+                    ty = default_untyped(value_type);
+                    ty.modifier = modifier;
+                },
+                None => return,
+            }
+        }
+        else {
+            self.check_type_instance(&ty, span, context);
+
+            if let Some(value_type) = value_type && !self.is_assignable_in_tree(&ty, &value_type, span, context) {
+                self.add_wrong_type(span, format!("can not assign '{}' to variable '{}' of type '{}'", type_name(&value_type), name, type_name(&ty)));
+            }
+        }
+
+        self.set_variable_type(name, ty);
+    }
+
+    /// variable declared without type and value (e.g 'mut a'), type is inferred from first assignment
+    fn is_uninferred_variable(&self, name: &str) -> bool {
+        match self.get_scope().lookup_kind(name, |kind| matches!(kind, ScopeKind::Variable(_))) {
+            Some(Spanned{node: ScopeKind::Variable(variable), ..}) => variable.ty.is_none_type() && variable.initialize_value.is_none(),
+            _ => false,
+        }
+    }
+
+    fn infer_variable_type(&mut self, name: &str, value_type: Option<SoulType>, span: SoulSpan) {
+        
+        let value_type = match value_type {
+            Some(value_type) if value_type.is_none_type() => {
+                self.add_wrong_type(span, format!("can not infer type of variable '{}' from expression without value", name));
+                return
+            },
+            Some(value_type) => value_type,
+            None => return,
+        };
+
+        let kinds = match self.get_scope_mut().lookup_mut(name) {
+            Some(kinds) => kinds,
+            None => return,
+        };
+
+        for kind in kinds {
+
+            if let ScopeKind::Variable(variable) = &mut kind.node {
+                let modifier = variable.ty.modifier.clone();
+                variable.ty = default_untyped(value_type);
+                variable.ty.modifier = modifier;
+                return
+            }
+        }
+    }
+
+    fn set_variable_type(&mut self, name: &str, ty: SoulType) {
+
+        let kinds = match self.get_scope_mut().flat_lookup_mut(name) {
+            Some(kinds) => kinds,
+            None => return,
+        };
+
+        for kind in kinds {
+
+            if let ScopeKind::Variable(variable) = &mut kind.node {
+                variable.ty = ty;
+                return
+            }
+        }
+    }
+
+    /// keeps what checking changed in initialize value (e.g. inferred generics of constructors)
+    fn set_variable_value(&mut self, name: &str, value: Expression) {
+
+        let kinds = match self.get_scope_mut().flat_lookup_mut(name) {
+            Some(kinds) => kinds,
+            None => return,
+        };
+
+        for kind in kinds {
+
+            if let ScopeKind::Variable(variable) = &mut kind.node {
+                variable.initialize_value = Some(value);
+                return
+            }
+        }
+    }
+
+    /// checks expression and returns its type, None if type could not be infered
+    fn check_expression(&mut self, expression: &mut Expression, context: &mut CheckContext) -> Option<SoulType> {
+        let span = expression.span;
+
+        let parent_id = self.get_scope().current_id();
+        if let Some(id) = expression.node.get_scope_id() && self.get_scope_mut().set_current(id).is_none() {
+            panic!("could not get scope_id({}) at line: {}:{}", id.0, span.line_number, span.line_offset)
+        }
+
+        let ty = self.infer_expression(expression, context);
+
+        self.get_scope_mut()
+            .set_current(parent_id)
+            .expect("scope_id should be valid");
+
+        ty
+    }
+
+    fn infer_expression(&mut self, expression: &mut Expression, context: &mut CheckContext) -> Option<SoulType> {
+        let span = expression.span;
+
+        match &mut expression.node {
+            ExpressionKind::Empty => Some(SoulType::none()),
+            ExpressionKind::Default => None,
+            ExpressionKind::UnwrapVariable(_) => None,
+            ExpressionKind::ExternalExpression(_) => None,
+
+            ExpressionKind::Literal(literal) => Some(literal.to_soul_type()),
+            ExpressionKind::Variable(variable_name) => self.get_variable_type(&variable_name.name.0),
+            ExpressionKind::Index(index) => {
+                let collection_type = self.check_expression(&mut index.collection, context);
+                let index_type = self.check_expression(&mut index.index, context);
+
+                if let Some(index_type) = &index_type && (!index_type.wrappers.is_empty() || !index_type.base.is_integer()) {
+                    self.add_wrong_type(index.index.span, format!("index should be an integer but is '{}'", type_name(index_type)));
+                }
+
+                let mut ty = collection_type?;
+                match ty.wrappers.last() {
+                    Some(TypeWrapper::Array) |
+                    Some(TypeWrapper::StackArray(_)) |
+                    Some(TypeWrapper::StackArrayGeneric(_)) => {
+                        ty.wrappers.pop();
+                        Some(ty)
+                    },
+                    None if ty.base == TypeKind::Str => Some(SoulType::from_type_kind(TypeKind::Char(TypeSize::Bit8))),
+                    _ => {
+                        self.add_wrong_type(span, format!("can not index type '{}'", type_name(&ty)));
+                        None
+                    }
+                }
+            },
+            ExpressionKind::Lambda(lambda) => {
+                let return_type = match &lambda.signature.return_type {
+                    Some(ty) => ReturnType::Checked(ty.as_ref().clone()),
+                    None => ReturnType::Unchecked,
+                };
+
+                for argument in &mut lambda.arguments.values {
+                    self.check_expression(argument, context);
+                }
+
+                context.return_types.push(return_type);
+                match &mut lambda.body {
+                    LambdaBody::Block(block) => self.analyse_block(block, context),
+                    LambdaBody::Expression(body) => {
+                        self.check_expression(body, context);
+                    },
+                }
+                context.return_types.pop();
+
+                Some(SoulType::from_type_kind(TypeKind::Lambda(lambda.signature.clone())))
+            },
+            ExpressionKind::FunctionCall(function_call) => {
+                let callee_type = function_call.callee.as_mut().map(|callee| self.check_expression(callee, context));
+
+                let mut argument_types = Vec::with_capacity(function_call.arguments.values.len());
+                for argument in &mut function_call.arguments.values {
+                    argument_types.push(self.check_expression(argument, context));
+                }
+
+                let candidates = match callee_type {
+                    Some(Some(callee_type)) if is_builtin_type(&callee_type, STRICT_TYPE) => return strict_methode_type(&function_call.name.0, &callee_type),
+                    Some(Some(callee_type)) if is_type_enum(&callee_type) => self.get_type_enum_methodes(&function_call.name.0, &callee_type, span, context),
+                    Some(Some(callee_type)) => self.get_methodes(&function_call.name.0, &callee_type),
+                    Some(None) => return self.get_type_name_call_type(function_call.callee.as_deref(), &function_call.name.0, &argument_types, span),
+                    None => match self.get_function_candidates(&function_call.name.0) {
+                        candidates if candidates.is_empty() && function_call.name.0 == STRICT_TYPE => return argument_types.first()?.clone().map(|ty| builtin_generic_type(STRICT_TYPE, default_untyped(ty.with_mod(Modifier::Default)))),
+                        candidates if candidates.is_empty() => return self.check_ctor_call(&function_call.name.0, &argument_types, span, context),
+                        candidates => candidates,
+                    },
+                };
+
+                let signature = self.select_signature(&function_call.name.0, &candidates, &argument_types, span, context)?;
+                if !signature.generics.is_empty() {
+                    let array_lengths = self.array_lengths(&function_call.arguments.values);
+                    self.check_generic_call(&signature, &function_call.generics, &argument_types, &array_lengths, span, context);
+                }
+
+                Some(return_type_of(&signature, &function_call.generics, &argument_types))
+            },
+            ExpressionKind::StructConstructor(struct_constructor) => {
+
+                let mut field_types = BTreeMap::new();
+                for (name, value) in struct_constructor.arguments.values.iter_mut() {
+                    field_types.insert(name.clone(), self.check_expression(value, context));
+                }
+
+                let ty = struct_constructor.calle.clone().with_mod(Modifier::Default);
+                let ty = self.check_constructor_generics(ty, &field_types, span, context);
+                // keep inferred generics so the backend constructs the same type as the variable (e.g 'Box<int>{..}' for 'Box{value: 1}')
+                struct_constructor.calle.generics = ty.generics.clone();
+
+                self.check_constructor_fields(&ty, &field_types, struct_constructor.arguments.insert_defaults, span);
+                Some(ty)
+            },
+            ExpressionKind::AccessField(access_field) => {
+                let object_type = self.check_expression(&mut access_field.object, context)?;
+                self.get_field_type(&object_type, &access_field.field, FieldUse::Get, span, context)
+            },
+            ExpressionKind::StaticField(static_field) => self.get_static_field_type(&static_field.object, &static_field.field, span),
+            ExpressionKind::StaticMethod(static_method) => {
+                let mut argument_types = Vec::with_capacity(static_method.arguments.values.len());
+                for argument in &mut static_method.arguments.values {
+                    argument_types.push(self.check_expression(argument, context));
+                }
+
+                let candidates = self.get_static_methodes(&static_method.name.0, &static_method.callee.node);
+                let signature = self.select_signature(&static_method.name.0, &candidates, &argument_types, span, context)?;
+                if !signature.generics.is_empty() {
+                    let array_lengths = self.array_lengths(&static_method.arguments.values);
+                    self.check_generic_call(&signature, &static_method.generics, &argument_types, &array_lengths, span, context);
+                }
+
+                Some(return_type_of(&signature, &static_method.generics, &argument_types))
+            },
+            ExpressionKind::Unary(unary) => {
+                let ty = self.check_expression(&mut unary.expression, context)?;
+
+                let is_valid = match unary.operator.node {
+                    UnaryOperatorKind::Invalid => return None,
+                    UnaryOperatorKind::Not => ty.wrappers.is_empty() && (ty.base == TypeKind::Bool || ty.base.is_integer()),
+                    UnaryOperatorKind::Neg |
+                    UnaryOperatorKind::Increment{..} |
+                    UnaryOperatorKind::Decrement{..} => ty.wrappers.is_empty() && ty.base.is_numeric(),
+                };
+
+                if !is_valid {
+                    self.add_wrong_type(span, format!("operator '{}' is not allowed on type '{}'", unary.operator.node.to_str(), type_name(&ty)));
+                    return None
+                }
+
+                Some(ty)
+            },
+            ExpressionKind::Binary(binary) => {
+                let left = self.check_expression(&mut binary.left, context);
+                let right = self.check_expression(&mut binary.right, context);
+
+                let operator = &binary.operator.node;
+                match operator {
+                    BinaryOperatorKind::TypeOf => return Some(SoulType::from_type_kind(TypeKind::Bool)),
+                    BinaryOperatorKind::Invalid => return None,
+                    _ => (),
+                }
+
+                let (left, right) = (left?, right?);
+                match binary_result_type(operator, &left, &right) {
+                    Some(ty) => Some(ty),
+                    None => {
+                        self.add_wrong_type(span, format!("operator '{}' is not allowed between '{}' and '{}'", operator.to_str(), type_name(&left), type_name(&right)));
+                        None
+                    },
+                }
+            },
+            // if, loops, match and blocks can give a value with 'fall' or 'break' so their type is left unknown
+            ExpressionKind::If(if_decl) => {
+                self.check_if(if_decl, context);
+                None
+            },
+            ExpressionKind::For(for_decl) => {
+                let collection_type = self.check_expression(&mut for_decl.collection, context);
+
+                if let (Some(element), Some(collection_type)) = (&for_decl.element, collection_type) {
+
+                    match element_type_of(&collection_type) {
+                        Some(element_type) => self.set_pattern_type(element, default_untyped(element_type)),
+                        None => self.add_wrong_type(for_decl.collection.span, format!("can not iterate over type '{}'", type_name(&collection_type))),
+                    }
+                }
+
+                self.analyse_block(&mut for_decl.block, context);
+                None
+            },
+            ExpressionKind::While(while_decl) => {
+
+                if let Some(condition) = &mut while_decl.condition {
+                    self.check_condition(condition, context);
+                }
+
+                self.analyse_block(&mut while_decl.block, context);
+                None
+            },
+            ExpressionKind::Match(match_decl) => {
+                let condition_type = self.check_expression(&mut match_decl.condition, context);
+
+                for case in &mut match_decl.cases {
+                    let parent_id = self.get_scope().current_id();
+                    self.get_scope_mut().set_current(case.scope_id);
+
+                    if let IfCaseKind::Expression(case_expression) = &mut case.if_kind {
+                        let case_type = self.check_expression(case_expression, context);
+
+                        if let (Some(condition_type), Some(case_type)) = (&condition_type, case_type) && !is_assignable(condition_type, &case_type) && !is_assignable(&case_type, condition_type) {
+                            self.add_wrong_type(case_expression.span, format!("case of type '{}' can not match on '{}'", type_name(&case_type), type_name(condition_type)));
+                        }
+                    }
+
+                    match &mut case.do_fn {
+                        CaseDoKind::Block(block) => self.analyse_block(&mut block.node, context),
+                        CaseDoKind::Expression(do_expression) => {
+                            self.check_expression(do_expression, context);
+                        },
+                    }
+
+                    self.get_scope_mut()
+                        .set_current(parent_id)
+                        .expect("scope_id should be valid");
+                }
+
+                if let Some(condition_type) = &condition_type {
+                    self.check_exhaustiveness(&match_decl.cases, condition_type, span);
+                }
+
+                None
+            },
+            ExpressionKind::Ternary(ternary) => {
+                self.check_condition(&mut ternary.condition, context);
+                let if_type = self.check_expression(&mut ternary.if_branch, context);
+                let else_type = self.check_expression(&mut ternary.else_branch, context);
+
+                let (if_type, else_type) = (if_type?, else_type?);
+                if is_assignable(&if_type, &else_type) {
+                    Some(if_type)
+                }
+                else if is_assignable(&else_type, &if_type) {
+                    Some(else_type)
+                }
+                else {
+                    self.add_wrong_type(span, format!("branches of ternary have different types '{}' and '{}'", type_name(&if_type), type_name(&else_type)));
+                    None
+                }
+            },
+            ExpressionKind::Deref(inner) => {
+                let mut ty = self.check_expression(inner, context)?;
+
+                match ty.wrappers.last() {
+                    Some(TypeWrapper::ConstRef(_)) |
+                    Some(TypeWrapper::MutRef(_)) |
+                    Some(TypeWrapper::Pointer) |
+                    Some(TypeWrapper::ConstPointer) => {
+                        ty.wrappers.pop();
+                        Some(ty)
+                    },
+                    None if is_builtin_type(&ty, MUT_TYPE) => builtin_inner_type(&ty),
+                    _ => {
+                        self.add_wrong_type(span, format!("can not dereference non reference type '{}'", type_name(&ty)));
+                        None
+                    },
+                }
+            },
+            ExpressionKind::MutRef(inner) => {
+                let mut ty = self.check_expression(inner, context)?;
+                ty.wrappers.push(TypeWrapper::MutRef(None));
+                Some(ty)
+            },
+            ExpressionKind::ConstRef(inner) => {
+                let mut ty = self.check_expression(inner, context)?;
+                ty.wrappers.push(TypeWrapper::ConstRef(None));
+                Some(ty)
+            },
+            ExpressionKind::Copy(inner) => self.check_expression(inner, context),
+            ExpressionKind::Block(block) => {
+                self.analyse_block(block, context);
+                None
+            },
+            ExpressionKind::ReturnLike(return_like) => {
+                let value_type = return_like.value.as_mut().map(|value| self.check_expression(value, context));
+
+                if return_like.kind == ReturnKind::Return {
+                    self.check_return(value_type, span, context);
+                }
+
+                Some(SoulType::none())
+            },
+            ExpressionKind::ExpressionGroup(expression_group) => self.infer_expression_group(expression_group, span, context),
+        }
+    }
+
+    fn infer_expression_group(&mut self, expression_group: &mut ExpressionGroup, span: SoulSpan, context: &mut CheckContext) -> Option<SoulType> {
+
+        match expression_group {
+            ExpressionGroup::Tuple(tuple) => {
+                let mut types = Vec::with_capacity(tuple.values.len());
+                for value in &mut tuple.values {
+                    types.push(self.check_expression(value, context));
+                }
+
+                let types = types.into_iter().collect::<Option<Vec<_>>>()?;
+                Some(SoulType::from_type_kind(TypeKind::Tuple(types)))
+            },
+            ExpressionGroup::NamedTuple(named_tuple) => {
+                let mut types = HashMap::with_capacity(named_tuple.values.len());
+                for (name, value) in &mut named_tuple.values {
+                    types.insert(name.clone(), self.check_expression(value, context));
+                }
+
+                let types = types.into_iter()
+                    .map(|(name, ty)| ty.map(|ty| (name, ty)))
+                    .collect::<Option<HashMap<_, _>>>()?;
+
+                Some(SoulType::from_type_kind(TypeKind::NamedTuple(types)))
+            },
+            ExpressionGroup::Array(array) => {
+                let mut element_type = array.element_type.clone();
+
+                for value in &mut array.values {
+                    let value_span = value.span;
+                    let value_type = self.check_expression(value, context);
+
+                    element_type = match (element_type, value_type) {
+                        (None, value_type) => value_type,
+                        (Some(element_type), None) => Some(element_type),
+                        (Some(element_type), Some(value_type)) => {
+
+                            if is_assignable(&element_type, &value_type) {
+                                Some(element_type)
+                            }
+                            else if array.element_type.is_none() && is_assignable(&value_type, &element_type) {
+                                Some(value_type)
+                            }
+                            else {
+                                self.add_wrong_type(value_span, format!("array element of type '{}' does not match array type '{}'", type_name(&value_type), type_name(&element_type)));
+                                Some(element_type)
+                            }
+                        },
+                    };
+                }
+
+                // collection type (e.g 'List[1, 2]') calls the array ctor of that type
+                if let Some(collection_type) = &array.collection_type {
+                    return Some(collection_type.clone().with_mod(Modifier::Default))
+                }
+
+                let mut ty = element_type?;
+                ty.wrappers.push(TypeWrapper::Array);
+                Some(ty)
+            },
+            ExpressionGroup::ArrayFiller(array_filler) => {
+                let amount_type = self.check_expression(&mut array_filler.amount, context);
+                if let Some(amount_type) = amount_type && !amount_type.base.is_integer() && element_type_of(&amount_type).is_none() {
+                    self.add_wrong_type(span, format!("array filler amount should be an integer but is '{}'", type_name(&amount_type)));
+                }
+
+                if let Some(index) = &array_filler.index {
+                    self.set_variable_type(&index.name.0, SoulType::from_type_kind(TypeKind::SystemUint));
+                }
+
+                let fill_type = self.check_expression(&mut array_filler.fill_expr, context);
+                let mut ty = match &array_filler.element_type {
+                    Some(element_type) => {
+
+                        if let Some(fill_type) = &fill_type && !is_assignable(element_type, fill_type) {
+                            self.add_wrong_type(array_filler.fill_expr.span, format!("array filler of type '{}' does not match array type '{}'", type_name(fill_type), type_name(element_type)));
+                        }
+
+                        element_type.clone()
+                    },
+                    None => fill_type?,
+                };
+
+                if let Some(collection_type) = &array_filler.collection_type {
+                    return Some(collection_type.clone().with_mod(Modifier::Default))
+                }
+
+                ty.wrappers.push(TypeWrapper::Array);
+                Some(ty)
+            },
+        }
+    }
+
+    fn check_if(&mut self, if_decl: &mut If, context: &mut CheckContext) {
+        self.check_condition(&mut if_decl.condition, context);
+        self.analyse_block(&mut if_decl.block, context);
+
+        for branch in &mut if_decl.else_branchs {
+
+            match &mut branch.node {
+                ElseKind::Else(block) => self.analyse_block(&mut block.node, context),
+                ElseKind::ElseIf(else_if) => self.check_if(&mut else_if.node, context),
+            }
+        }
+    }
+
+    fn check_condition(&mut self, condition: &mut Expression, context: &mut CheckContext) {
+
+        if let Some(ty) = self.check_expression(condition, context) && (!ty.wrappers.is_empty() || ty.base != TypeKind::Bool) {
+            self.add_wrong_type(condition.span, format!("condition should be 'bool' but is '{}'", type_name(&ty)));
+        }
+    }
+
+    fn check_return(&mut self, value_type: Option<Option<SoulType>>, span: SoulSpan, context: &mut CheckContext) {
+
+        let return_type = match context.return_types.last() {
+            Some(ReturnType::Checked(return_type)) => return_type.clone(),
+            Some(ReturnType::Unchecked) => return,
+            None => {
+                self.add_error(new_soul_error(SoulErrorKind::InvalidInContext, Some(span), "'return' can only be used in function"));
+                return
+            },
+        };
+
+        match value_type {
+            None if !return_type.is_none_type() => {
+                self.add_wrong_type(span, format!("return should have value of type '{}'", type_name(&return_type)));
+            },
+            Some(Some(value_type)) if return_type.is_none_type() && !value_type.is_none_type() => {
+                self.add_wrong_type(span, format!("function has no return type but returns '{}'", type_name(&value_type)));
+            },
+            Some(Some(value_type)) if !return_type.is_none_type() && !self.is_assignable_in_tree(&return_type, &value_type, span, context) => {
+                self.add_wrong_type(span, format!("return type is '{}' but returns '{}'", type_name(&return_type), type_name(&value_type)));
+            },
+            _ => (),
+        }
+    }
+
+    /// sets type of for loop element (variable or tuple of variables) in current scope
+    fn set_pattern_type(&mut self, pattern: &Expression, ty: SoulType) {
+
+        match &pattern.node {
+            ExpressionKind::Variable(variable_name) => self.set_variable_type(&variable_name.name.0, ty),
+            ExpressionKind::ExpressionGroup(ExpressionGroup::Tuple(tuple)) => {
+
+                if let TypeKind::Tuple(types) = ty.base {
+                    for (element, ty) in tuple.values.iter().zip(types) {
+                        self.set_pattern_type(element, ty);
+                    }
+                }
+            },
+            _ => (),
+        }
+    }
+
+    fn get_variable_type(&self, name: &str) -> Option<SoulType> {
+
+        let ty = match self.get_scope().lookup_kind(name, |kind| matches!(kind, ScopeKind::Variable(_))) {
+            Some(symbol) => match &symbol.node {
+                ScopeKind::Variable(variable) => variable.ty.clone(),
+                _ => return None,
+            },
+            None => return None,
+        };
+
+        if ty.is_none_type() {
+            None
+        }
+        else {
+            Some(ty)
+        }
+    }
+
+    fn get_field_type(&mut self, object_type: &SoulType, field: &VariableName, field_use: FieldUse, span: SoulSpan, context: &CheckContext) -> Option<SoulType> {
+
+        if object_type.wrappers.iter().any(|wrapper| !is_reference_wrapper(wrapper)) {
+            return None
+        }
+
+        let name = &field.name.0;
+        let fields = match &object_type.base {
+            TypeKind::NamedTuple(types) => return types.get(&field.name).cloned(),
+            TypeKind::Struct(struct_name) => match self.get_scope().lookup_kind(&struct_name.0, |kind| matches!(kind, ScopeKind::Struct(_))).map(|symbol| &symbol.node) {
+                Some(ScopeKind::Struct(struct_)) => struct_.fields.iter().map(|field| field.node.clone()).collect::<Vec<_>>(),
+                _ => return None,
+            },
+            TypeKind::Class(class_name) => match self.get_scope().lookup_kind(&class_name.0, |kind| matches!(kind, ScopeKind::Class(_))).map(|symbol| &symbol.node) {
+                Some(ScopeKind::Class(class)) => class.children.iter()
+                    .filter_map(|child| match child {
+                        ClassChild::Field(field) => Some(field.node.clone()),
+                        _ => None,
+                    })
+                    .collect::<Vec<_>>(),
+                _ => return None,
+            },
+            _ => return None,
+        };
+
+        match fields.into_iter().find(|field| &field.name.0 == name) {
+            Some(field) => {
+                self.check_field_visibility(object_type, &field, field_use, span, context);
+                Some(field.ty)
+            },
+            None => {
+                self.add_error(new_soul_error(
+                    SoulErrorKind::NotFoundInScope,
+                    Some(span),
+                    format!("field '{}' not found in type '{}'", name, type_name(object_type)),
+                ));
+                None
+            },
+        }
+    }
+
+    fn check_field_visibility(&mut self, object_type: &SoulType, field: &Field, field_use: FieldUse, span: SoulSpan, context: &CheckContext) {
+        
+        let object = match object_name(object_type) {
+            Some(object) => object,
+            None => return,
+        };
+
+        // methodes and use blocks of object have private access
+        if context.objects.contains(&object) {
+            return
+        }
+
+        let (visibility, action) = match field_use {
+            FieldUse::Get => (field.vis.get_visibility(), "read"),
+            FieldUse::Set => (field.vis.set_visibility(), "set"),
+        };
+
+        if visibility == Visibility::Private {
+            self.add_error(new_soul_error(
+                SoulErrorKind::InvalidInContext,
+                Some(span),
+                format!("field '{}' of type '{}' can only be {} inside of '{}'", field.name.0, type_name(object_type), action, object.0),
+            ));
+        }
+    }
+
+    fn get_static_field_type(&mut self, object_type: &SoulType, field: &VariableName, span: SoulSpan) -> Option<SoulType> {
+        let name = &field.name.0;
+
+        let has_variant = match &object_type.base {
+            TypeKind::Enum(enum_name) => match self.get_scope().lookup_kind(&enum_name.0, |kind| matches!(kind, ScopeKind::Enum(_))).map(|symbol| &symbol.node) {
+                Some(ScopeKind::Enum(enum_)) => match &enum_.variants {
+                    EnumVariantKind::Int(variants) => variants.iter().any(|variant| &variant.node.name.0 == name),
+                    EnumVariantKind::Expression(variants) => variants.iter().any(|variant| &variant.name.0 == name),
+                },
+                _ => return None,
+            },
+            TypeKind::Union(union_name) => match self.get_scope().lookup_kind(&union_name.0, |kind| matches!(kind, ScopeKind::Union(_))).map(|symbol| &symbol.node) {
+                Some(ScopeKind::Union(union)) => union.variants.iter().any(|variant| &variant.node.name.0 == name && is_empty_variant(&variant.node.field)),
+                _ => return None,
+            },
+            _ => return None,
+        };
+
+        if has_variant {
+            Some(object_type.clone().with_mod(Modifier::Default))
+        }
+        else {
+            self.add_error(new_soul_error(
+                SoulErrorKind::NotFoundInScope,
+                Some(span),
+                format!("variant '{}' not found in type '{}'", name, type_name(object_type)),
+            ));
+            None
+        }
+    }
+
+    /// all functions with name visible from current scope (closest scope first)
+    fn get_function_candidates(&self, name: &str) -> Vec<FunctionSignature> {
+        find_functions(self.get_scope(), name)
+    }
+
+    /// call on a callee without type, reports the call if the callee is the name of a type that has no such function (e.g. 'Shape.Circle(2)')
+    fn get_type_name_call_type(&mut self, callee: Option<&Expression>, name: &str, argument_types: &[Option<SoulType>], span: SoulSpan) -> Option<SoulType> {
+
+        if let Some(ty) = self.get_enum_values_type(callee, name, argument_types) {
+            return Some(ty)
+        }
+
+        let type_name = match callee.map(|callee| &callee.node) {
+            Some(ExpressionKind::Variable(variable)) => &variable.name.0,
+            _ => return None,
+        };
+
+        let is_variable = self.get_scope().lookup_kind(type_name, |kind| matches!(kind, ScopeKind::Variable(_))).is_some();
+        if !is_variable && self.get_scope().lookup_kind(type_name, ScopeKind::is_type).is_some() {
+            self.add_error(new_soul_error(SoulErrorKind::NotFoundInScope, Some(span), format!("function '{}' not found in '{}'", name, type_name)));
+        }
+        None
+    }
+
+    /// 'Nums.Values()' returns all variants of enum 'Nums' (as 'Nums[]')
+    fn get_enum_values_type(&self, callee: Option<&Expression>, name: &str, argument_types: &[Option<SoulType>]) -> Option<SoulType> {
+        
+        let enum_name = match callee.map(|callee| &callee.node) {
+            Some(ExpressionKind::Variable(variable)) if name == ENUM_VALUES && argument_types.is_empty() => &variable.name,
+            _ => return None,
+        };
+
+        self.get_scope().lookup_kind(&enum_name.0, |kind| matches!(kind, ScopeKind::Enum(_)))?;
+        Some(SoulType::from_type_kind(TypeKind::Enum(enum_name.clone())).with_wrappers(vec![TypeWrapper::Array]))
+    }
+
+    /// methodes of type enum are the methodes of the type enum itself and of the traits all its members implement
+    fn get_type_enum_methodes(&mut self, name: &str, callee_type: &SoulType, span: SoulSpan, context: &CheckContext) -> Vec<FunctionSignature> {
+        
+        let methodes = self.get_methodes(name, callee_type);
+        if !methodes.is_empty() {
+            return methodes
+        }
+
+        let traits = implemented_traits(self.get_scope(), &context.trait_impls, callee_type);
+        let mut methodes = vec![];
+        for trait_name in &traits {
+
+            let trait_ = match self.get_scope().lookup_kind(&trait_name.0, |kind| matches!(kind, ScopeKind::Trait(_))).map(|symbol| &symbol.node) {
+                Some(ScopeKind::Trait(trait_)) => trait_,
+                _ => continue,
+            };
+
+            for methode in trait_.methodes.iter().filter(|methode| methode.node.name.0 == name) {
+                let mut signature = methode.node.clone();
+
+                // 'This' of trait is the type enum
+                for parameter in &mut signature.parameters {
+                    replace_this_type(&mut parameter.node.ty, trait_name, callee_type);
+                }
+                if let Some(return_type) = &mut signature.return_type {
+                    replace_this_type(return_type, trait_name, callee_type);
+                }
+
+                methodes.push(signature);
+            }
+        }
+
+        if methodes.is_empty() {
+            let traits = traits.iter().map(|name| name.0.as_str()).collect::<Vec<_>>().join(", ");
+
+            self.add_error(new_soul_error(
+                SoulErrorKind::NotFoundInScope,
+                Some(span),
+                format!("methode '{}' is not part of a trait that every type of '{}' implements (common traits: [{}])", name, type_name(callee_type), traits),
+            ));
+        }
+
+        methodes
+    }
+
+    /// all methodes of name that take callee_type as 'this' (class methodes, use blocks and extention functions)
+    fn get_methodes(&self, name: &str, callee_type: &SoulType) -> Vec<FunctionSignature> {
+        find_callee_functions(self.get_scope(), name, callee_type, true)
+    }
+
+    /// ctor call (e.g 'Object(1)'), calling a type without arguments is the default ctor
+    fn check_ctor_call(&mut self, type_name: &str, argument_types: &[Option<SoulType>], span: SoulSpan, context: &mut CheckContext) -> Option<SoulType> {
+        let mut ctors = vec![];
+
+        for scope in self.get_scope().get_scopes() {
+            for ctor_name in ["This", "this"] {
+
+                let functions = scope.get(ctor_name)
+                    .into_iter()
+                    .flatten()
+                    .filter_map(|kind| match &kind.node {
+                        ScopeKind::Functions(functions) => Some(functions),
+                        _ => None,
+                    })
+                    .flatten();
+
+                for function in functions {
+
+                    if let Some(callee) = &function.node.signature.callee && callee.node.extention_type.base.try_get_name() == Some(type_name) {
+                        ctors.push(function.node.signature.clone());
+                    }
+                }
+            }
+        }
+
+        if ctors.is_empty() {
+            return self.check_call_without_ctor(type_name, argument_types, span, context)
+        }
+
+        let ty = ctors.first()?
+            .callee.as_ref()?
+            .node.extention_type
+            .clone()
+            .with_mod(Modifier::Default);
+
+        if !argument_types.is_empty() {
+            self.select_signature(type_name, &ctors, argument_types, span, context)?;
+        }
+
+        Some(ty)
+    }
+
+    /// call of name that has no functions or constructors, is the implicit default constructor of a class, a union variant (e.g 'Some(1)'),
+    /// a lambda variable or a runtime function (e.g 'Println')
+    fn check_call_without_ctor(&mut self, name: &str, argument_types: &[Option<SoulType>], span: SoulSpan, context: &mut CheckContext) -> Option<SoulType> {
+
+        if let Some(union) = self.find_union_of_variant(name) {
+            return self.check_variant_call(union, name, argument_types, span, context)
+        }
+
+        if self.get_scope().lookup_kind(name, |kind| matches!(kind, ScopeKind::Class(_))).is_some() {
+            return Some(SoulType::from_type_kind(TypeKind::Class(Ident::new(name))))
+        }
+
+        let is_variable = self.get_scope().lookup_kind(name, |kind| matches!(kind, ScopeKind::Variable(_))).is_some();
+        if !is_variable && !is_runtime_function(name) {
+            self.add_error(new_soul_error(SoulErrorKind::NotFoundInScope, Some(span), format!("function '{}' not found", name)));
+        }
+
+        None
+    }
+
+    /// union that has variant visible from current scope (closest scope first)
+    fn find_union_of_variant(&self, variant: &str) -> Option<Union> {
+        let scopes = self.get_scope().get_scopes();
+
+        let mut current_index = Some(self.get_scope().current_id());
+        while let Some(index) = current_index {
+
+            let union = scopes[index.0].symbols.values()
+                .flatten()
+                .find_map(|kind| match &kind.node {
+                    ScopeKind::Union(union) if union.variants.iter().any(|el| el.node.name.0 == variant) => Some(union),
+                    _ => None,
+                });
+            if let Some(union) = union {
+                return Some(union.clone())
+            }
+
+            current_index = scopes[index.0].parent_index;
+        }
+
+        None
+    }
+
+    /// 'Some(1)' gives the union of the variant with the generics infered from the fields (e.g 'Option<int>')
+    fn check_variant_call(&mut self, union: Union, variant: &str, argument_types: &[Option<SoulType>], span: SoulSpan, context: &mut CheckContext) -> Option<SoulType> {
+        let mut ty = SoulType::from_type_kind(TypeKind::Union(union.name.clone()));
+
+        let field_types = match union.variants.iter().find(|el| el.node.name.0 == variant).map(|el| &el.node.field) {
+            Some(UnionVariantKind::Tuple(types)) => types.clone(),
+            _ => return Some(ty),
+        };
+
+        if field_types.len() != argument_types.len() {
+            self.add_wrong_type(span, format!(
+                "variant '{}' of '{}' has {} field{} but is given {}",
+                variant,
+                union.name,
+                field_types.len(),
+                if field_types.len() == 1 { "" } else { "s" },
+                argument_types.len(),
+            ));
+            return None
+        }
+
+        let parameters = field_types.iter()
+            .enumerate()
+            .map(|(index, ty)| Spanned::new(Parameter{name: Ident::new(format!("_{}", index)), ty: ty.clone()}, span))
+            .collect::<Vec<_>>();
+
+        let mut bindings = GenericBindings::new();
+        for fault in infer_from_arguments(&union.name.0, &union.generics, &parameters, argument_types, &[], span, &mut bindings) {
+            self.add_wrong_type(span, fault);
+        }
+
+        let missing = bind_defaults(&union.generics, &mut bindings);
+        for (mut field_type, argument_type) in field_types.into_iter().zip(argument_types) {
+            substitute(&mut field_type, &bindings);
+
+            if let Some(argument_type) = argument_type && !self.is_assignable_in_tree(&field_type, argument_type, span, context) {
+                self.add_wrong_type(span, format!("can not give '{}' to field of variant '{}' of type '{}'", type_name(argument_type), variant, type_name(&field_type)));
+            }
+        }
+
+        if !missing.is_empty() {
+            return Some(ty)
+        }
+
+        ty.generics = union.generics.iter()
+            .filter_map(|generic| bindings.get(&generic.name).cloned())
+            .collect();
+
+        if self.check_generic_constraints(&union.name.0, &union.generics, &bindings, span, context) {
+            self.add_generic_instance(GenericInstanceKind::Union(union), bindings, span);
+        }
+
+        Some(ty)
+    }
+
+    fn get_static_methodes(&self, name: &str, callee_type: &SoulType) -> Vec<FunctionSignature> {
+        find_callee_functions(self.get_scope(), name, callee_type, false)
+    }
+
+    /// selects the overload that best matches the arguments, reports error if no or multiple overloads match
+    fn select_signature(&mut self, name: &str, candidates: &[FunctionSignature], argument_types: &[Option<SoulType>], span: SoulSpan, context: &CheckContext) -> Option<FunctionSignature> {
+
+        if candidates.is_empty() {
+            return None
+        }
+
+        let error = match resolve_overload(candidates, argument_types) {
+            Ok(signature) => {
+                self.get_scope_mut().resolved_calls.insert(span, name, signature.clone());
+                // overloads are resolved without trait impls and struct fields so check them for selected overload
+                self.report_argument_mismatch(signature, argument_types, span, context);
+                return Some(signature.clone())
+            },
+            Err(error) => error,
+        };
+
+        match error {
+            OverloadError::NoMatch if candidates.len() == 1 => self.report_argument_mismatch(&candidates[0], argument_types, span, context),
+            OverloadError::NoMatch => self.add_error(new_soul_error(
+                SoulErrorKind::ArgError,
+                Some(span),
+                format!(
+                    "no overload of function '{}' matches arguments ({}), candidates are:{}",
+                    name,
+                    argument_types.iter().map(|ty| ty.as_ref().map(type_name).unwrap_or("_".into())).collect::<Vec<_>>().join(", "),
+                    candidates_to_string(candidates),
+                ),
+            )),
+            // can only be resolved once the type of the argument is known
+            OverloadError::Ambiguous(_) if argument_types.iter().any(is_unresolved_argument) => (),
+            OverloadError::Ambiguous(matches) => self.add_error(new_soul_error(
+                SoulErrorKind::ArgError,
+                Some(span),
+                format!("call to function '{}' is ambiguous, candidates are:{}", name, candidates_to_string(matches)),
+            )),
+        }
+
+        None
+    }
+
+    /// reports overloads in the same scope that have the same parameters
+    fn check_overload_sets(&mut self) {
+        let mut conflicts = vec![];
+
+        for scope in self.get_scope().get_scopes() {
+            for kinds in scope.symbols.values() {
+                for kind in kinds {
+
+                    if let ScopeKind::Functions(functions) = &kind.node {
+                        conflicts.extend(find_overload_conflicts(functions));
+                    }
+                }
+            }
+        }
+
+        for conflict in conflicts {
+
+            let msg = if conflict.only_return_differs {
+                format!("overloads of function '{}' only differ by return type (other overload at {}:{}), overloading by return type is not allowed", conflict.name, conflict.other_span.line_number, conflict.other_span.line_offset)
+            }
+            else {
+                format!("function '{}' is already defined with the same parameters at {}:{}", conflict.name, conflict.other_span.line_number, conflict.other_span.line_offset)
+            };
+
+            self.add_error(new_soul_error(SoulErrorKind::InvalidInContext, Some(conflict.span), msg));
+        }
+    }
+
+    /// is_assignable that also checks what needs the whole tree, that value implements the trait of target
+    /// and that a named tuple has the fields of target struct or class (wrong fields are reported here)
+    fn is_assignable_in_tree(&mut self, target: &SoulType, value: &SoulType, span: SoulSpan, context: &CheckContext) -> bool {
+
+        if !is_assignable(target, value) {
+            return false
+        }
+
+        match (&target.base, &value.base) {
+            (TypeKind::Trait(_), value_base) if is_unchecked_type(value_base) => true,
+            (TypeKind::Trait(trait_name), value_base) => {
+                value_base == &target.base ||
+                implemented_traits(self.get_scope(), &context.trait_impls, &SoulType::from_type_kind(value_base.clone())).contains(trait_name)
+            },
+            (TypeKind::Struct(_) | TypeKind::Class(_), TypeKind::NamedTuple(fields)) => {
+                let field_types = fields.iter()
+                    .map(|(name, ty)| (name.clone(), Some(ty.clone())))
+                    .collect();
+
+                self.check_constructor_fields(target, &field_types, false, span);
+                true
+            },
+            _ => true,
+        }
+    }
+
+    fn report_argument_mismatch(&mut self, signature: &FunctionSignature, argument_types: &[Option<SoulType>], span: SoulSpan, context: &CheckContext) {
+        let name = &signature.name.0;
+
+        if signature.parameters.len() != argument_types.len() {
+            self.add_error(new_soul_error(
+                SoulErrorKind::ArgError,
+                Some(span),
+                format!("function '{}' expects {} arguments but got {}", name, signature.parameters.len(), argument_types.len()),
+            ));
+            return
+        }
+
+        for (parameter, argument_type) in signature.parameters.iter().zip(argument_types) {
+
+            if let Some(argument_type) = argument_type && !self.is_assignable_in_tree(&parameter.node.ty, argument_type, span, context) {
+                self.add_wrong_type(span, format!(
+                    "argument '{}' of function '{}' should be '{}' but is '{}'",
+                    parameter.node.name.0,
+                    name,
+                    type_name(&parameter.node.ty),
+                    type_name(argument_type),
+                ));
+            }
+        }
+    }
+
+    fn analyse_block(&mut self, block: &mut Block, context: &mut CheckContext) {
+        let parent_id = self.get_scope().current_id();
+        self.get_scope_mut().set_current(block.scope_id);
+
+        for statment in &mut block.statments {
+            self.analyse_statment(statment, context);
+        }
+
+        self.get_scope_mut()
+            .set_current(parent_id)
+            .expect("scope_id should be valid");
+    }
+
+    /// binds the generics of a call to a generic function, checks them against their constraints and records the monomorphized function
+    fn check_generic_call(&mut self, signature: &FunctionSignature, generics: &[TypeGenericKind], argument_types: &[Option<SoulType>], array_lengths: &[Option<usize>], span: SoulSpan, context: &mut CheckContext) {
+        let name = &signature.name.0;
+
+        let mut bindings = match bind_explicit(name, &signature.generics, generics) {
+            Ok(bindings) => bindings,
+            Err(msg) => {
+                self.add_error(new_soul_error(SoulErrorKind::ArgError, Some(span), msg));
+                return
+            },
+        };
+
+        let faults = infer_from_arguments(name, &signature.generics, &signature.parameters, argument_types, array_lengths, span, &mut bindings);
+        if !faults.is_empty() {
+            for msg in faults {
+                self.add_wrong_type(span, msg);
+            }
+            return
+        }
+
+        let missing = bind_defaults(&signature.generics, &mut bindings);
+        if !missing.is_empty() {
+
+            // can only be infered once the type of every argument is known
+            if argument_types.iter().all(|ty| ty.as_ref().is_some_and(is_concrete)) {
+                for generic in missing {
+                    self.add_wrong_type(span, format!("could not infer generic '{}' of '{}', give it explicitly (e.g '{}<..>(..)')", generic, name, name));
+                }
+            }
+            return
+        }
+
+        if !self.check_generic_constraints(name, &signature.generics, &bindings, span, context) {
+            return
+        }
+
+        let mut instance = signature.clone();
+        substitute_signature(&mut instance, &bindings);
+        self.report_argument_mismatch(&instance, argument_types, span, context);
+
+        let function = self.get_scope()
+            .get_scopes()
+            .iter()
+            .filter_map(|scope| scope.get(name))
+            .flatten()
+            .filter_map(|kind| match &kind.node {
+                ScopeKind::Functions(functions) => Some(functions),
+                _ => None,
+            })
+            .flatten()
+            .find(|function| &function.node.signature == signature)
+            .map(|function| function.node.clone());
+
+        if let Some(function) = function {
+            self.add_generic_instance(GenericInstanceKind::Function(Box::new(function)), bindings, span);
+        }
+    }
+
+    /// infers the generics of a generic struct from its field values (e.g 'Box{value: 1}' is 'Box<int>') and checks the instance
+    fn check_constructor_generics(&mut self, mut ty: SoulType, field_types: &BTreeMap<Ident, Option<SoulType>>, span: SoulSpan, context: &mut CheckContext) -> SoulType {
+
+        let struct_ = match self.get_generic_declaration(&ty.base) {
+            Some(GenericInstanceKind::Struct(struct_)) if ty.generics.is_empty() && !struct_.generics.is_empty() => struct_,
+            _ => {
+                self.check_type_instance(&ty, span, context);
+                return ty
+            },
+        };
+
+        let (parameters, argument_types): (Vec<_>, Vec<_>) = struct_.fields.iter()
+            .filter_map(|field| field_types.get(&field.node.name).map(|ty| (Spanned::new(Parameter{name: field.node.name.clone(), ty: field.node.ty.clone()}, field.span), ty.clone())))
+            .unzip();
+
+        let mut bindings = GenericBindings::new();
+        infer_from_arguments(&struct_.name.0, &struct_.generics, &parameters, &argument_types, &[], span, &mut bindings);
+
+        let missing = bind_defaults(&struct_.generics, &mut bindings);
+        if !missing.is_empty() {
+
+            if argument_types.iter().all(|ty| ty.as_ref().is_some_and(is_concrete)) {
+                for generic in missing {
+                    self.add_wrong_type(span, format!("could not infer generic '{}' of '{}', give it explicitly (e.g '{}<..>{{..}}')", generic, struct_.name, struct_.name));
+                }
+            }
+            return ty
+        }
+
+        ty.generics = struct_.generics.iter()
+            .filter_map(|generic| bindings.get(&generic.name).cloned())
+            .collect();
+
+        if self.check_generic_constraints(&struct_.name.0, &struct_.generics, &bindings, span, context) {
+            self.add_generic_instance(GenericInstanceKind::Struct(struct_), bindings, span);
+        }
+
+        ty
+    }
+
+    /// checks that every field in constructor exists and has the right type, and that all fields without default value are given (unless '..' is used)
+    fn check_constructor_fields(&mut self, ty: &SoulType, field_types: &BTreeMap<Ident, Option<SoulType>>, insert_defaults: bool, span: SoulSpan) {
+        
+        let (name, generics, fields) = match self.get_generic_declaration(&ty.base) {
+            Some(GenericInstanceKind::Struct(struct_)) => (struct_.name, struct_.generics, struct_.fields.into_iter().map(|field| field.node).collect::<Vec<_>>()),
+            Some(GenericInstanceKind::Class(class)) => {
+                let fields = class.children.into_iter()
+                    .filter_map(|child| match child {
+                        ClassChild::Field(field) => Some(field.node),
+                        _ => None,
+                    })
+                    .collect();
+
+                (class.name, class.generics, fields)
+            },
+            _ => return,
+        };
+
+        let bindings = bind_explicit(&name.0, &generics, &ty.generics).unwrap_or_default();
+
+        for (field_name, value_type) in field_types {
+
+            let field = match fields.iter().find(|field| &field.name == field_name) {
+                Some(field) => field,
+                None => {
+                    self.add_error(new_soul_error(
+                        SoulErrorKind::NotFoundInScope,
+                        Some(span),
+                        format!("field '{}' not found in type '{}'", field_name, name),
+                    ));
+                    continue
+                },
+            };
+
+            let mut field_type = field.ty.clone();
+            substitute(&mut field_type, &bindings);
+
+            if let Some(value_type) = value_type && is_concrete(&field_type) && !is_assignable(&field_type, value_type) {
+                self.add_wrong_type(span, format!("field '{}' of '{}' should be '{}' but is '{}'", field_name, name, type_name(&field_type), type_name(value_type)));
+            }
+        }
+
+        if insert_defaults {
+            return
+        }
+
+        for field in &fields {
+            
+            if field.default_value.is_none() && !field_types.contains_key(&field.name) {
+                self.add_error(new_soul_error(
+                    SoulErrorKind::InvalidInContext,
+                    Some(span),
+                    format!("field '{}' of '{}' is not given, add it or use '..' to give all missing fields their default value", field.name, name),
+                ));
+            }
+        }
+    }
+
+    /// checks the generics of struct, class and union types (e.g 'Box<int>') and records their monomorphized copy
+    fn check_type_instance(&mut self, ty: &SoulType, span: SoulSpan, context: &mut CheckContext) {
+
+        for generic in &ty.generics {
+            if let TypeGenericKind::Type(generic) = generic {
+                self.check_type_instance(generic, span, context);
+            }
+        }
+
+        if let TypeKind::Tuple(types) = &ty.base {
+            for ty in types {
+                self.check_type_instance(ty, span, context);
+            }
+        }
+
+        let declaration = match self.get_generic_declaration(&ty.base) {
+            Some(declaration) => declaration,
+            None => return,
+        };
+
+        let (name, generics) = declaration_generics(&declaration);
+        if generics.is_empty() && ty.generics.is_empty() {
+            return
+        }
+
+        // inside its own methodes and constructors a class is used with its own generics (e.g 'This{}' in 'class GenClass<T>')
+        if ty.generics.is_empty() && context.objects.contains(name) {
+            return
+        }
+
+        let mut bindings = match bind_explicit(&name.0, generics, &ty.generics) {
+            Ok(bindings) => bindings,
+            Err(msg) => {
+                self.add_wrong_type(span, msg);
+                return
+            },
+        };
+
+        let missing = bind_defaults(generics, &mut bindings);
+        if !missing.is_empty() {
+            for generic in missing {
+                self.add_wrong_type(span, format!("'{}' needs a type for generic '{}'", name, generic));
+            }
+            return
+        }
+
+        if self.check_generic_constraints(&name.0, generics, &bindings, span, context) {
+            self.add_generic_instance(declaration, bindings, span);
+        }
+    }
+
+    /// reports every binding that violates a constraint of its generic, returns true if all constraints hold
+    fn check_generic_constraints(&mut self, name: &str, generics: &[GenericParameter], bindings: &GenericBindings, span: SoulSpan, context: &mut CheckContext) -> bool {
+        let faults = check_constraints(self.get_scope(), &context.trait_impls, name, generics, bindings);
+        let is_valid = faults.is_empty();
+
+        for msg in faults {
+            if context.reported.insert((msg.clone(), span.line_number)) {
+                self.add_wrong_type(span, msg);
+            }
+        }
+
+        is_valid
+    }
+
+    /// records monomorphized copy of declaration, instances that still depend on other generics are not recorded
+    fn add_generic_instance(&mut self, declaration: GenericInstanceKind, bindings: GenericBindings, span: SoulSpan) {
+
+        if !bindings.values().all(is_concrete_binding) {
+            return
+        }
+
+        let (name, generics) = declaration_generics(&declaration);
+        let name = instance_name(&name.0, generics, &bindings);
+        if self.get_scope().generic_instances.contains(&name) {
+            return
+        }
+
+        let kind = instantiate(&declaration, &bindings);
+        self.get_scope_mut().generic_instances.insert(GenericInstance{name, bindings, kind, span});
+    }
+
+    /// declaration of struct, class or union type
+    fn get_generic_declaration(&self, ty: &TypeKind) -> Option<GenericInstanceKind> {
+        let name = match ty {
+            TypeKind::Struct(name) |
+            TypeKind::Class(name) |
+            TypeKind::Union(name) => name,
+            _ => return None,
+        };
+
+        let symbol = self.get_scope().lookup_kind(&name.0, |kind| matches!(kind, ScopeKind::Struct(_) | ScopeKind::Class(_) | ScopeKind::Union(_)))?;
+        match &symbol.node {
+            ScopeKind::Struct(struct_) => Some(GenericInstanceKind::Struct(struct_.clone())),
+            ScopeKind::Class(class) => Some(GenericInstanceKind::Class(class.clone())),
+            ScopeKind::Union(union) => Some(GenericInstanceKind::Union(union.clone())),
+            _ => None,
+        }
+    }
+
+    /// amount of elements of every array literal argument (e.g '[1, 2, 3]' has 3)
+    fn array_lengths(&self, arguments: &[Expression]) -> Vec<Option<usize>> {
+        arguments.iter()
+            .map(|argument| match &argument.node {
+                ExpressionKind::Literal(Literal::ProgramMemmory(name, _)) => match self.get_scope().global_literals.get(name) {
+                    Some(Literal::Array{values, ..}) => Some(values.len()),
+                    _ => None,
+                },
+                ExpressionKind::Literal(Literal::Array{values, ..}) => Some(values.len()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// match on 'bool', 'enum' or 'union' has to match every value
+    fn check_exhaustiveness(&mut self, cases: &[CaseSwitch], condition_type: &SoulType, span: SoulSpan) {
+        if !condition_type.wrappers.is_empty() {
+            return
+        }
+
+        let subject = match &condition_type.base {
+            TypeKind::Bool => MatchSubject::Bool,
+            TypeKind::Enum(enum_name) => match self.get_scope().lookup_kind(&enum_name.0, |kind| matches!(kind, ScopeKind::Enum(_))).map(|symbol| &symbol.node) {
+                Some(ScopeKind::Enum(enum_)) => MatchSubject::Enum{
+                    name: enum_name.0.clone(),
+                    variants: match &enum_.variants {
+                        EnumVariantKind::Int(variants) => variants.iter().map(|variant| variant.node.name.0.clone()).collect(),
+                        EnumVariantKind::Expression(variants) => variants.iter().map(|variant| variant.name.0.clone()).collect(),
+                    },
+                },
+                _ => return,
+            },
+            TypeKind::Union(union_name) => match self.get_scope().lookup_kind(&union_name.0, |kind| matches!(kind, ScopeKind::Union(_))).map(|symbol| &symbol.node) {
+                Some(ScopeKind::Union(union)) => MatchSubject::Union{
+                    name: union_name.0.clone(),
+                    variants: union.variants.iter().map(|variant| (variant.node.name.0.clone(), variant.node.field.clone())).collect(),
+                },
+                _ => return,
+            },
+            _ => return,
+        };
+
+        let MatchReport{missing, faults} = check_cases(&subject, cases);
+        for fault in faults {
+            match fault {
+                CaseFault::Unreachable{span, msg} => self.add_warning(new_soul_error(SoulErrorKind::InvalidInContext, Some(span), msg)),
+                CaseFault::WrongVariant{span, msg} => self.add_wrong_type(span, msg),
+            }
+        }
+
+        if !missing.is_empty() {
+            self.add_error(new_soul_error(
+                SoulErrorKind::InvalidInContext,
+                Some(span),
+                format!("match on '{}' is not exhaustive, missing '{}'", subject_name(&subject), missing.join("', '")),
+            ));
+        }
+    }
+
+    fn add_wrong_type(&mut self, span: SoulSpan, msg: String) {
+        self.add_error(new_soul_error(SoulErrorKind::WrongType, Some(span), msg));
+    }
+}
+
+/// checks if value of type 'value' can be stored in type 'target'
+pub fn is_assignable(target: &SoulType, value: &SoulType) -> bool {
+
+    if is_unchecked_type(&target.base) || is_unchecked_type(&value.base) {
+        return true
+    }
+
+    // '()' is the default constructor of every type
+    if value.wrappers.is_empty() && matches!(&value.base, TypeKind::Tuple(types) if types.is_empty()) {
+        return true
+    }
+
+    if target.wrappers.is_empty() && let TypeKind::TypeEnum(_, types) = &target.base {
+
+        return match &value.base {
+            TypeKind::TypeEnum(name, _) if target.base.try_get_name() == Some(name.0.as_str()) => true,
+            _ => types.iter().any(|member| is_assignable(member, value)),
+        }
+    }
+
+    if target.wrappers.len() != value.wrappers.len() ||
+       !target.wrappers.iter().zip(&value.wrappers).all(|(target, value)| is_wrapper_assignable(target, value))
+    {
+        return false
+    }
+
+    if !target.generics.is_empty() && !value.generics.is_empty() {
+
+        let generics_match = target.generics.len() == value.generics.len() && target.generics.iter().zip(&value.generics).all(|pair| match pair {
+            (TypeGenericKind::Type(target), TypeGenericKind::Type(value)) => is_assignable(target, value),
+            _ => true,
+        });
+
+        if !generics_match {
+            return false
+        }
+    }
+
+    is_base_assignable(&target.base, &value.base)
+}
+
+fn is_base_assignable(target: &TypeKind, value: &TypeKind) -> bool {
+
+    match (target, value) {
+        // needs the trait impls of the tree (see TypeCheckingAnalyser::is_assignable_in_tree)
+        (TypeKind::Trait(_), _) => true,
+
+        (target, TypeKind::UntypedInt) |
+        (target, TypeKind::UntypedUint) => target.is_numeric(),
+        (target, TypeKind::UntypedFloat) => target.is_float(),
+        (TypeKind::UntypedInt, value) |
+        (TypeKind::UntypedUint, value) |
+        (TypeKind::UntypedFloat, value) => value.is_numeric(),
+
+        (TypeKind::Tuple(targets), TypeKind::Tuple(values)) => {
+            targets.len() == values.len() && targets.iter().zip(values).all(|(target, value)| is_assignable(target, value))
+        },
+        (TypeKind::NamedTuple(targets), TypeKind::NamedTuple(values)) => {
+            targets.len() == values.len() && targets.iter().all(|(name, target)| values.get(name).is_some_and(|value| is_assignable(target, value)))
+        },
+        // needs the fields of the declaration (see TypeCheckingAnalyser::is_assignable_in_tree)
+        (TypeKind::Struct(_), TypeKind::NamedTuple(_)) |
+        (TypeKind::Class(_), TypeKind::NamedTuple(_)) => true,
+        (TypeKind::Union(target), TypeKind::UnionVariant(variant)) => variant.to_union_name_string() == target.0,
+
+        (target, value) => target == value,
+    }
+}
+
+fn is_wrapper_assignable(target: &TypeWrapper, value: &TypeWrapper) -> bool {
+
+    match (target, value) {
+        (TypeWrapper::Array | TypeWrapper::StackArray(_) | TypeWrapper::StackArrayGeneric(_), TypeWrapper::Array) |
+        (TypeWrapper::Array | TypeWrapper::StackArrayGeneric(_), TypeWrapper::StackArray(_) | TypeWrapper::StackArrayGeneric(_)) => true,
+        (TypeWrapper::StackArray(target), TypeWrapper::StackArray(value)) => target == value,
+        (TypeWrapper::StackArray(_), TypeWrapper::StackArrayGeneric(_)) => true,
+
+        (TypeWrapper::ConstRef(_), TypeWrapper::ConstRef(_) | TypeWrapper::MutRef(_)) => true,
+        (TypeWrapper::MutRef(_), TypeWrapper::MutRef(_)) => true,
+        (TypeWrapper::ConstPointer, TypeWrapper::ConstPointer | TypeWrapper::Pointer) => true,
+        (TypeWrapper::Pointer, TypeWrapper::Pointer) => true,
+        _ => false,
+    }
+}
+
+/// types that can not be checked in this pass (checked by other analysers)
+fn is_unchecked_type(ty: &TypeKind) -> bool {
+    matches!(
+        ty,
+        TypeKind::Unknown(_) | TypeKind::Generic(_) | TypeKind::Custom(_) |
+        TypeKind::ExternalType(_) | TypeKind::ExternalPath(_) | TypeKind::LifeTime(_)
+    )
+}
+
+fn is_reference_wrapper(wrapper: &TypeWrapper) -> bool {
+    matches!(wrapper, TypeWrapper::ConstRef(_) | TypeWrapper::MutRef(_) | TypeWrapper::Pointer | TypeWrapper::ConstPointer)
+}
+
+fn is_empty_variant(field: &UnionVariantKind) -> bool {
+    match field {
+        UnionVariantKind::Tuple(types) => types.is_empty(),
+        UnionVariantKind::NamedTuple(types) => types.is_empty(),
+    }
+}
+
+/// all functions with name visible from current scope (closest scope first)
+fn is_runtime_function(name: &str) -> bool {
+    RUNTIME_FUNCTIONS.contains(&name) || name.starts_with("std::")
+}
+
+pub fn find_functions(scope: &ScopeVisitor, name: &str) -> Vec<FunctionSignature> {
+    let scopes = scope.get_scopes();
+
+    let mut candidates = vec![];
+    let mut current_index = Some(scope.current_id());
+    while let Some(index) = current_index {
+
+        if let Some(kinds) = scopes[index.0].get(name) {
+
+            for kind in kinds {
+                if let ScopeKind::Functions(functions) = &kind.node {
+                    candidates.extend(functions.iter().map(|function| function.node.signature.clone()));
+                }
+            }
+        }
+
+        current_index = scopes[index.0].parent_index;
+    }
+
+    candidates
+}
+
+/// all functions of name with callee of callee_type, has_this is true for methodes and false for static methodes
+pub fn find_callee_functions(scope: &ScopeVisitor, name: &str, callee_type: &SoulType, has_this: bool) -> Vec<FunctionSignature> {
+    let mut candidates = vec![];
+
+    for scope in scope.get_scopes() {
+
+        let kinds = match scope.get(name) {
+            Some(kinds) => kinds,
+            None => continue,
+        };
+
+        for kind in kinds {
+
+            let functions = match &kind.node {
+                ScopeKind::Functions(functions) => functions,
+                _ => continue,
+            };
+
+            for function in functions {
+                let callee = match &function.node.signature.callee {
+                    Some(callee) => &callee.node,
+                    None => continue,
+                };
+
+                if callee.this.is_some() == has_this && is_callee_type(&callee.extention_type, callee_type) {
+                    candidates.push(function.node.signature.clone());
+                }
+            }
+        }
+    }
+
+    candidates
+}
+
+/// true if value of ty can be the callee of extention_type (e.g. 'int[]' for 'int[] First(this@)' but not 'int'),
+/// references to the callee are also allowed and unchecked bases (e.g. generic 'T' in 'T[]') can hold the other wrappers of ty
+fn is_callee_type(extention_type: &SoulType, ty: &SoulType) -> bool {
+    let reference_count = ty.wrappers.iter().rev().take_while(|wrapper| is_reference_wrapper(wrapper)).count();
+    let wrappers = &ty.wrappers[..ty.wrappers.len() - reference_count];
+
+    let is_same_wrappers = |wrappers: &[TypeWrapper]| {
+        wrappers.len() == extention_type.wrappers.len() &&
+        extention_type.wrappers.iter().zip(wrappers).all(|(target, value)| is_wrapper_assignable(target, value))
+    };
+
+    if is_unchecked_type(&extention_type.base) {
+        return wrappers.len() >= extention_type.wrappers.len() && is_same_wrappers(&wrappers[wrappers.len() - extention_type.wrappers.len()..])
+    }
+
+    extention_type.base == ty.base && is_same_wrappers(wrappers)
+}
+
+/// return type of call, generics of return type are replaced by explicit generics or infered from arguments
+fn return_type_of(signature: &FunctionSignature, generics: &[TypeGenericKind], argument_types: &[Option<SoulType>]) -> SoulType {
+    let mut return_type = match &signature.return_type {
+        Some(ty) => ty.clone(),
+        None => return SoulType::none(),
+    };
+
+    let mut bindings = BTreeMap::new();
+    let generic_names = signature.generics.iter()
+        .filter(|generic| matches!(generic.kind, GenericKind::Type{..}))
+        .map(|generic| &generic.name);
+
+    for (name, generic) in generic_names.zip(generics) {
+        if let TypeGenericKind::Type(ty) = generic {
+            bindings.insert(name.clone(), ty.clone());
+        }
+    }
+
+    for (parameter, argument) in signature.parameters.iter().zip(argument_types) {
+
+        if let (TypeKind::Generic(name), Some(argument)) = (&parameter.node.ty.base, argument) && parameter.node.ty.wrappers.is_empty() {
+            bindings.entry(name.clone()).or_insert_with(|| default_untyped(argument.clone()));
+        }
+    }
+
+    substitute_generics(&mut return_type, &bindings);
+    return_type
+}
+
+pub fn substitute_generics(ty: &mut SoulType, bindings: &BTreeMap<Ident, SoulType>) {
+
+    if let TypeKind::Generic(name) = &ty.base && let Some(binding) = bindings.get(name) {
+        let mut wrappers = binding.wrappers.clone();
+        wrappers.append(&mut ty.wrappers);
+
+        ty.base = binding.base.clone();
+        ty.generics = binding.generics.clone();
+        ty.wrappers = wrappers;
+    }
+
+    for generic in &mut ty.generics {
+        if let TypeGenericKind::Type(generic) = generic {
+            substitute_generics(generic, bindings);
+        }
+    }
+
+    if let TypeKind::Tuple(types) = &mut ty.base {
+        for ty in types {
+            substitute_generics(ty, bindings);
+        }
+    }
+}
+
+fn is_type_enum(ty: &SoulType) -> bool {
+    ty.wrappers.is_empty() && matches!(ty.base, TypeKind::TypeEnum(..))
+}
+
+/// replaces 'This' and the trait in methode of trait by the type that implements it
+fn replace_this_type(ty: &mut SoulType, trait_name: &Ident, implementor: &SoulType) {
+
+    let is_this = match &ty.base {
+        TypeKind::Trait(name) => name == trait_name,
+        TypeKind::Generic(name) => name.0 == THIS_TYPE,
+        _ => false,
+    };
+
+    if is_this {
+        let mut wrappers = implementor.wrappers.clone();
+        wrappers.append(&mut ty.wrappers);
+
+        ty.base = implementor.base.clone();
+        ty.wrappers = wrappers;
+    }
+}
+
+/// result type of binary operator, None if operator is not allowed for these types
+fn binary_result_type(operator: &BinaryOperatorKind, left: &SoulType, right: &SoulType) -> Option<SoulType> {
+    let is_primitive = left.wrappers.is_empty() && right.wrappers.is_empty();
+    let bool_type = SoulType::from_type_kind(TypeKind::Bool);
+
+    match operator {
+        BinaryOperatorKind::Add if is_primitive && left.base == TypeKind::Str && right.base == TypeKind::Str => Some(left.clone()),
+        BinaryOperatorKind::Add if matches!(left.wrappers.last(), Some(TypeWrapper::Array)) && is_assignable(left, right) => Some(left.clone()),
+
+        BinaryOperatorKind::Add |
+        BinaryOperatorKind::Sub |
+        BinaryOperatorKind::Mul |
+        BinaryOperatorKind::Div |
+        BinaryOperatorKind::Mod |
+        BinaryOperatorKind::Pow |
+        BinaryOperatorKind::Root |
+        BinaryOperatorKind::Log => if is_primitive { unify_numeric(left, right, |ty| ty.is_numeric()) } else { None },
+
+        BinaryOperatorKind::BitAnd |
+        BinaryOperatorKind::BitOr |
+        BinaryOperatorKind::BitXor => {
+            if is_primitive && left.base == TypeKind::Bool && right.base == TypeKind::Bool {
+                Some(bool_type)
+            }
+            else if is_primitive {
+                unify_numeric(left, right, |ty| ty.is_integer())
+            }
+            else {
+                None
+            }
+        },
+
+        BinaryOperatorKind::LogAnd |
+        BinaryOperatorKind::LogOr => (is_primitive && left.base == TypeKind::Bool && right.base == TypeKind::Bool).then_some(bool_type),
+
+        BinaryOperatorKind::Eq |
+        BinaryOperatorKind::NotEq => (is_assignable(left, right) || is_assignable(right, left)).then_some(bool_type),
+
+        BinaryOperatorKind::Lt |
+        BinaryOperatorKind::Gt |
+        BinaryOperatorKind::Le |
+        BinaryOperatorKind::Ge => {
+            let is_ordered = is_primitive && (
+                unify_numeric(left, right, |ty| ty.is_numeric()).is_some() ||
+                (left.base == right.base && matches!(left.base, TypeKind::Char(_) | TypeKind::Str))
+            );
+            is_ordered.then_some(bool_type)
+        },
+
+        BinaryOperatorKind::Range => {
+            let element = if is_primitive { unify_numeric(left, right, |ty| ty.is_integer())? } else { return None };
+
+            let mut range = SoulType::from_type_kind(TypeKind::Custom(Ident::new(RANGE_TYPE)));
+            range.generics.push(TypeGenericKind::Type(element));
+            Some(range)
+        },
+
+        BinaryOperatorKind::TypeOf => Some(bool_type),
+        BinaryOperatorKind::Invalid => None,
+    }
+}
+
+/// common numeric type of left and right (e.g untypedInt and i32 -> i32), None if types do not match
+fn unify_numeric<F>(left: &SoulType, right: &SoulType, is_allowed: F) -> Option<SoulType>
+where
+    F: Fn(&TypeKind) -> bool
+{
+    if is_unchecked_type(&left.base) {
+        return Some(right.clone())
+    }
+    if is_unchecked_type(&right.base) {
+        return Some(left.clone())
+    }
+
+    if !is_allowed(&left.base) || !is_allowed(&right.base) {
+        return None
+    }
+
+    let base = match (&left.base, &right.base) {
+        (left, right) if left == right => left.clone(),
+        (TypeKind::UntypedFloat, right) if right.is_untyped() => TypeKind::UntypedFloat,
+        (left, TypeKind::UntypedFloat) if left.is_untyped() => TypeKind::UntypedFloat,
+        (TypeKind::UntypedInt, TypeKind::UntypedUint) |
+        (TypeKind::UntypedUint, TypeKind::UntypedInt) => TypeKind::UntypedInt,
+        (left, right) if left.is_untyped() && is_base_assignable(right, left) => right.clone(),
+        (left, right) if right.is_untyped() && is_base_assignable(left, right) => left.clone(),
+        _ => return None,
+    };
+
+    let modifier = if left.modifier == right.modifier { left.modifier.clone() } else { Modifier::Default };
+    Some(SoulType::from_type_kind(base).with_mod(modifier))
+}
+
+/// built-in generic type (e.g 'Strict<T>' or 'Mut<T>') without wrappers
+pub fn is_builtin_type(ty: &SoulType, name: &str) -> bool {
+    ty.wrappers.is_empty() && matches!(&ty.base, TypeKind::Custom(ident) if ident.0 == name)
+}
+
+fn builtin_generic_type(name: &str, inner: SoulType) -> SoulType {
+    let mut ty = SoulType::from_type_kind(TypeKind::Custom(Ident::new(name)));
+    ty.generics.push(TypeGenericKind::Type(inner));
+    ty
+}
+
+/// 'T' of 'Strict<T>' or 'Mut<T>'
+fn builtin_inner_type(ty: &SoulType) -> Option<SoulType> {
+    match ty.generics.first() {
+        Some(TypeGenericKind::Type(inner)) => Some(inner.clone()),
+        _ => None,
+    }
+}
+
+fn strict_methode_type(name: &str, strict: &SoulType) -> Option<SoulType> {
+    let mut inner = builtin_inner_type(strict)?;
+
+    match name {
+        STRICT_BORROW_MUT => Some(builtin_generic_type(MUT_TYPE, inner)),
+        STRICT_BORROW => {
+            inner.wrappers.push(TypeWrapper::ConstRef(None));
+            Some(inner)
+        },
+        STRICT_UNSTRICT => Some(inner),
+        _ => None,
+    }
+}
+
+/// type of element when iterating over collection type
+fn element_type_of(collection: &SoulType) -> Option<SoulType> {
+
+    match collection.wrappers.last() {
+        Some(TypeWrapper::Array) |
+        Some(TypeWrapper::StackArray(_)) |
+        Some(TypeWrapper::StackArrayGeneric(_)) => {
+            let mut element = collection.clone();
+            element.wrappers.pop();
+            Some(element)
+        },
+        Some(_) => None,
+        None => match &collection.base {
+            TypeKind::Custom(name) if name.0 == RANGE_TYPE => match collection.generics.first() {
+                Some(TypeGenericKind::Type(element)) => Some(element.clone()),
+                _ => None,
+            },
+            TypeKind::Str => Some(SoulType::from_type_kind(TypeKind::Char(TypeSize::Bit8))),
+            base if base.is_integer() => Some(collection.clone()),
+            _ => None,
+        },
+    }
+}
+
+/// replaces untyped types with their default type (e.g untypedInt -> int)
+pub fn default_untyped(mut ty: SoulType) -> SoulType {
+    ty.base = match ty.base {
+        TypeKind::Tuple(types) => TypeKind::Tuple(types.into_iter().map(default_untyped).collect()),
+        TypeKind::NamedTuple(types) => TypeKind::NamedTuple(types.into_iter().map(|(name, ty)| (name, default_untyped(ty))).collect()),
+        base => base.untyped_to_typed(),
+    };
+
+    ty
+}
+
+/// name of struct or class of type
+fn object_name(ty: &SoulType) -> Option<Ident> {
+    match &ty.base {
+        TypeKind::Struct(name) |
+        TypeKind::Class(name) => Some(name.clone()),
+        _ => None,
+    }
+}
+
+/// type as shown in error messages (without modifier)
+pub fn type_name(ty: &SoulType) -> String {
+    ty.clone()
+        .with_mod(Modifier::Default)
+        .to_string()
+}
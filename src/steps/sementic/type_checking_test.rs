@@ -0,0 +1,359 @@
+use crate::steps::{sementic::test_helpers::{assert_single_error, check_types, error_messages, variable_type, Stage}, step_interfaces::i_parser::abstract_syntax_tree::{expression::Ident, soul_type::{soul_type::TypeWrapper, type_kind::{TypeKind, TypeSize}}}};
+
+#[test]
+fn test_infer_untyped_literals() {
+    let source = "
+main() {
+    mut number = 1
+    float := 1.0
+    text := \"hello\"
+    sum := number + 2
+    isBig := sum > 10
+    array := [1, 2, 3]
+}
+";
+    let (scope, faults) = check_types(source);
+    assert!(error_messages(&faults).is_empty(), "{:?}", error_messages(&faults));
+
+    assert_eq!(variable_type(&scope, "number").base, TypeKind::SystemInt);
+    assert!(variable_type(&scope, "number").modifier.is_mutable());
+    assert_eq!(variable_type(&scope, "float").base, TypeKind::Float(TypeSize::Bit32));
+    assert_eq!(variable_type(&scope, "text").base, TypeKind::Str);
+    assert_eq!(variable_type(&scope, "sum").base, TypeKind::SystemInt);
+    assert_eq!(variable_type(&scope, "isBig").base, TypeKind::Bool);
+
+    let array = variable_type(&scope, "array");
+    assert_eq!(array.base, TypeKind::SystemInt);
+    assert_eq!(array.wrappers.len(), 1);
+}
+
+#[test]
+fn test_wrong_variable_type() {
+    assert_single_error("main() {\n\tint a = \"hello\"\n}\n", Stage::TypeChecking, &["can not assign 'str' to variable 'a' of type 'int'"]);
+    assert_single_error("main() {\n\tu8 a = 1.0\n}\n", Stage::TypeChecking, &["can not assign 'untypedFloat' to variable 'a' of type 'u8'"]);
+}
+
+#[test]
+fn test_wrong_binary_operands() {
+    assert_single_error("main() {\n\ta := 1 + \"hello\"\n}\n", Stage::TypeChecking, &["operator '+' is not allowed between"]);
+    assert_single_error("main() {\n\tif 1 {\n\t}\n}\n", Stage::TypeChecking, &["condition should be 'bool'"]);
+}
+
+#[test]
+fn test_wrong_argument_type() {
+    let source = "
+sum(int a, int b) int {
+    return a + b
+}
+
+main() {
+    sum(1, true)
+}
+";
+    assert_single_error(source, Stage::TypeChecking, &["argument 'b' of function 'sum' should be 'int' but is 'bool'"]);
+}
+
+#[test]
+fn test_wrong_return_type() {
+    assert_single_error("foo() int {\n\treturn \"hello\"\n}\n", Stage::TypeChecking, &["return type is 'int' but returns 'str'"]);
+    assert_single_error("foo() {\n\treturn 1\n}\n", Stage::TypeChecking, &["function has no return type"]);
+}
+
+#[test]
+fn test_generic_return_type_from_argument() {
+    let source = "
+first<T>(T[] values, T fallback) T {
+    return fallback
+}
+
+main() {
+    value := first([1, 2], 3)
+    int other = first<int>([1], 2)
+}
+";
+    let (scope, faults) = check_types(source);
+    assert!(error_messages(&faults).is_empty(), "{:?}", error_messages(&faults));
+    assert_eq!(variable_type(&scope, "value").base, TypeKind::SystemInt);
+}
+
+#[test]
+fn test_infer_type_from_assignment() {
+    let source = "
+main() {
+    mut number
+    number = 1
+
+    const text
+    if true {
+        text = \"hello\"
+    }
+    else {
+        text = 2
+    }
+}
+";
+    let (scope, faults) = check_types(source);
+    let errors = error_messages(&faults);
+
+    assert_eq!(errors.len(), 1, "{:?}", errors);
+    assert!(errors[0].contains("at 11:8") && errors[0].contains("can not assign 'untypedInt' to 'str'"), "{}", errors[0]);
+
+    assert_eq!(variable_type(&scope, "number").base, TypeKind::SystemInt);
+    assert!(variable_type(&scope, "number").modifier.is_mutable());
+    assert_eq!(variable_type(&scope, "text").base, TypeKind::Str);
+    assert!(!variable_type(&scope, "text").modifier.is_mutable());
+}
+
+#[test]
+fn test_field_visibility() {
+    let source = "
+class Data {
+    int field
+    int pubGet {Get;} = 0
+    int private {set; get;} = 0
+
+    Reset(this&) {
+        this.pubGet = 0
+        this.private = this.private
+    }
+}
+
+use Data {
+    Private(this@) int {
+        return this.private
+    }
+}
+
+main() {
+    mut data := Data{field: 1}
+    data.field = 2
+    a := data.pubGet
+    data.pubGet = 1
+    b := data.private
+    data.private = 1
+}
+";
+    let (_, faults) = check_types(source);
+    let errors = error_messages(&faults);
+
+    assert_eq!(errors.len(), 3, "{:?}", errors);
+    assert!(errors.iter().any(|err| err.contains("at 23:") && err.contains("field 'pubGet' of type 'Data' can only be set inside of 'Data'")), "{:?}", errors);
+    assert!(errors.iter().any(|err| err.contains("at 24:") && err.contains("field 'private' of type 'Data' can only be read inside of 'Data'")), "{:?}", errors);
+    assert!(errors.iter().any(|err| err.contains("at 25:") && err.contains("field 'private' of type 'Data' can only be set inside of 'Data'")), "{:?}", errors);
+}
+
+#[test]
+fn test_struct_constructor_fields() {
+    let source = "
+struct Object {
+    int field
+    int default = 0
+}
+
+Object This(int field) {
+    return This{field, ..}
+}
+
+main() {
+    a := Object{field: 1}
+    b := Object{default: 1}
+    c := Object{default: 1, ..}
+    d := Object{field: \"hi\", other: 2}
+}
+";
+    let (_, faults) = check_types(source);
+    let errors = error_messages(&faults);
+
+    assert_eq!(errors.len(), 3, "{:?}", errors);
+    assert!(errors.iter().any(|err| err.contains("at 13:") && err.contains("field 'field' of 'Object' is not given, add it or use '..' to give all missing fields their default value")), "{:?}", errors);
+    assert!(errors.iter().any(|err| err.contains("at 15:") && err.contains("field 'field' of 'Object' should be 'int' but is 'str'")), "{:?}", errors);
+    assert!(errors.iter().any(|err| err.contains("at 15:") && err.contains("field 'other' not found in type 'Object'")), "{:?}", errors);
+}
+
+#[test]
+fn test_typed_enum_values() {
+    let source = "
+enum KeyValue impl (str, int[3]) {
+    Foo = (\"foo\", [1, 2, 43]),
+    Bar = 1
+}
+
+enum Paths impl str {
+    MainDisk = \"C:\",
+    OtherDisk
+}
+
+enum Nums {
+    One,
+    Two
+}
+
+main() {
+    nums := Nums.Values()
+}
+";
+    let (scope, faults) = check_types(source);
+    let errors = error_messages(&faults);
+
+    assert_eq!(errors.len(), 2, "{:?}", errors);
+    assert!(errors.iter().any(|err| err.contains("at 4:") && err.contains("value of variant 'Bar' of enum 'KeyValue' should be '(str, int[3])' but is 'untypedInt'")), "{:?}", errors);
+    assert!(errors.iter().any(|err| err.contains("at 9:") && err.contains("variant 'OtherDisk' of enum 'Paths' needs a value, only variants of integer enums get an implicit value")), "{:?}", errors);
+
+    let nums = variable_type(&scope, "nums");
+    assert_eq!(nums.base, TypeKind::Enum("Nums".into()));
+    assert_eq!(nums.wrappers, vec![TypeWrapper::Array]);
+}
+
+#[test]
+fn test_type_enum_common_traits() {
+    let source = "
+trait Debug {
+    ToString(this) str
+}
+
+trait Display impl Debug {
+    Show(this) str
+}
+
+use int impl Display {
+    ToString(this) str {
+        return \"int\"
+    }
+    Show(this) str {
+        return \"int\"
+    }
+}
+
+use f32 impl Debug {
+    ToString(this) str {
+        return \"f32\"
+    }
+}
+
+type Number impl[int, f32]
+
+main() {
+    Number number = 1
+    Number flag = true
+    text := number.ToString()
+    shown := number.Show()
+}
+";
+    let (scope, faults) = check_types(source);
+    let errors = error_messages(&faults);
+
+    assert_eq!(errors.len(), 2, "{:?}", errors);
+    assert!(errors.iter().any(|err| err.contains("at 29:") && err.contains("can not assign 'bool' to variable 'flag' of type 'Number'")), "{:?}", errors);
+    assert!(errors.iter().any(|err| err.contains("at 31:") && err.contains("methode 'Show' is not part of a trait that every type of 'Number' implements (common traits: [Debug])")), "{:?}", errors);
+
+    assert_eq!(variable_type(&scope, "text").base, TypeKind::Str);
+}
+
+#[test]
+fn test_methode_callee_wrappers() {
+    let source = "
+int[] Size(this@) int {
+    return 2
+}
+
+int Size(this) str {
+    return \"int\"
+}
+
+main() {
+    array := [1, 2]
+    count := array.Size()
+
+    number := 1
+    text := number.Size()
+}
+";
+    let (scope, faults) = check_types(source);
+    assert!(error_messages(&faults).is_empty(), "{:?}", error_messages(&faults));
+
+    // 'int[] Size(this@)' is not a methode of 'int' only of 'int[]'
+    assert_eq!(variable_type(&scope, "count").base, TypeKind::SystemInt);
+    assert_eq!(variable_type(&scope, "text").base, TypeKind::Str);
+}
+
+#[test]
+fn test_assign_to_trait_and_struct() {
+    let source = "
+trait Debug {
+    ToString() str
+}
+
+struct Object {
+    int field
+}
+
+use Object impl Debug {
+    ToString() str {
+        return \"object\"
+    }
+}
+
+show(Debug value) {
+}
+
+main() {
+    Debug a = Object{field: 1}
+    Debug b = 1
+    show(Object{field: 1})
+    show(true)
+
+    Object c = {field: 1}
+    Object d = {field: \"hi\", other: 2}
+}
+";
+    let (_, faults) = check_types(source);
+    let errors = error_messages(&faults);
+
+    assert_eq!(errors.len(), 4, "{:?}", errors);
+    assert!(errors.iter().any(|err| err.contains("at 21:") && err.contains("can not assign 'untypedInt' to variable 'b' of type 'Debug'")), "{:?}", errors);
+    assert!(errors.iter().any(|err| err.contains("at 23:") && err.contains("argument 'value' of function 'show' should be 'Debug' but is 'bool'")), "{:?}", errors);
+    assert!(errors.iter().any(|err| err.contains("at 26:") && err.contains("field 'field' of 'Object' should be 'int' but is 'str'")), "{:?}", errors);
+    assert!(errors.iter().any(|err| err.contains("at 26:") && err.contains("field 'other' not found in type 'Object'")), "{:?}", errors);
+}
+
+#[test]
+fn test_variant_and_default_constructor_calls() {
+    let source = "
+union Option<T> {
+    Some(T),
+    None,
+}
+
+union Shape {
+    Circle(int),
+    Empty,
+}
+
+class Counter {
+    int count {Get; set;} = 0
+}
+
+main() {
+    value := Some(1)
+    shape := Circle(2)
+    c := Counter()
+    int x = value
+    c.count = 5
+    undefinedFn(1)
+    Shape.Circle(2)
+    Println(1)
+}
+";
+
+    let (scope, faults) = check_types(source);
+    assert_eq!(variable_type(&scope, "value").to_string(), "const Option<int>");
+    assert_eq!(variable_type(&scope, "shape").base, TypeKind::Union(Ident::new("Shape")));
+    assert_eq!(variable_type(&scope, "c").base, TypeKind::Class(Ident::new("Counter")));
+
+    let errors = error_messages(&faults);
+    assert_eq!(errors.len(), 4, "{:?}", errors);
+    assert!(errors[0].contains("at 20:") && errors[0].contains("can not assign 'Option<int>' to variable 'x' of type 'int'"), "{}", errors[0]);
+    assert!(errors[1].contains("at 21:") && errors[1].contains("field 'count' of type 'Counter' can only be set inside of 'Counter'"), "{}", errors[1]);
+    assert!(errors[2].contains("at 22:") && errors[2].contains("function 'undefinedFn' not found"), "{}", errors[2]);
+    assert!(errors[3].contains("at 23:") && errors[3].contains("function 'Circle' not found in 'Shape'"), "{}", errors[3]);
+}
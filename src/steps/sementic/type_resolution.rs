@@ -0,0 +1,727 @@
+use std::collections::HashSet;
+use crate::{errors::soul_error::{new_soul_error, SoulErrorKind, SoulSpan}, steps::{sementic::external_header::{find_external_symbol, resolve_page_path}, step_interfaces::{i_parser::{abstract_syntax_tree::{abstract_syntax_tree::AbstractSyntacTree, enum_like::{Enum, EnumVariantKind, TypeEnum, Union, UnionVariantKind}, expression::{CaseDoKind, ElseKind, Expression, ExpressionGroup, ExpressionKind, Ident, If, IfCaseKind, UnwrapVariable}, function::{Function, FunctionCallee, FunctionSignature, LambdaBody, LambdaSignature}, generic::{GenericKind, GenericParameter, TypeConstraint}, object::{Class, ClassChild, Field, Struct, Trait}, soul_type::{soul_type::{Modifier, SoulType, TypeGenericKind, TypeWrapper}, type_kind::{ExternalType, TypeKind, UnionKind, UnionType}}, spanned::Spanned, statement::{Block, Statement, StatementKind, UseBlock}}, scope_builder::{ScopeId, ScopeKind}}, i_sementic::ast_visitor::{AstAnalyser, TypeResolutionAnalyser}}}};
+
+pub const THIS_TYPE: &str = "This";
+/// seperates pages and names in a path (e.g 'this::std::test::Sum')
+pub const PATH_SEPERATOR: &str = "::";
+const MAX_TYPEDEF_DEPTH: usize = 32;
+/// built-in wrapper that enforces aliasing rules on its value (e.g 'Strict<int> bar = Strict(foo)')
+pub const STRICT_TYPE: &str = "Strict";
+/// exclusive mutable borrow of a 'Strict' value (e.g 'Mut<int> ref = bar.BorrowMut()')
+pub const MUT_TYPE: &str = "Mut";
+/// built-in marker trait, values of an implementor are copied instead of consumed (e.g 'use Point impl AutoCopy {}')
+pub const AUTO_COPY_TRAIT: &str = "AutoCopy";
+
+/// generics and 'This' in scope at the current point of the tree
+#[derive(Default)]
+struct TypeContext {
+    generics: Vec<String>,
+    this_type: Option<SoulType>,
+    resolved_scopes: HashSet<usize>,
+    reported: HashSet<(String, SoulSpan)>,
+}
+
+impl AstAnalyser for TypeResolutionAnalyser {
+
+    fn analyse_ast(&mut self, tree: &mut AbstractSyntacTree) {
+        let mut context = TypeContext::default();
+        self.analyse_block(&mut tree.root, &mut context);
+    }
+}
+
+impl TypeResolutionAnalyser {
+
+    fn analyse_statment(&mut self, statment: &mut Statement, context: &mut TypeContext) {
+        let span = statment.span;
+
+        match &mut statment.node {
+            StatementKind::CloseBlock => (),
+            StatementKind::Variable(_) => (),
+
+            StatementKind::Expression(expression) => self.analyse_expression(expression, context),
+            StatementKind::Assignment(assignment) => {
+                self.analyse_expression(&mut assignment.variable, context);
+                self.analyse_expression(&mut assignment.value, context);
+            },
+            StatementKind::Function(function) => self.resolve_function(function, span, context, true),
+            StatementKind::UseBlock(use_block) => self.resolve_use_block(use_block, span, context),
+            StatementKind::Class(class) => self.resolve_class(class, span, context, true),
+            StatementKind::Struct(struct_) => self.resolve_struct(struct_, span, context),
+            StatementKind::Trait(trait_) => self.resolve_trait(trait_, span, context),
+            StatementKind::Enum(enum_) => self.resolve_enum(enum_, span, context),
+            StatementKind::Union(union) => self.resolve_union(union, span, context),
+            StatementKind::TypeEnum(type_enum) => self.resolve_type_enum(type_enum, span, context),
+        }
+    }
+
+    fn analyse_expression(&mut self, expression: &mut Expression, context: &mut TypeContext) {
+        let span = expression.span;
+        let parent_id = self.enter_scope(expression.node.get_scope_id(), span, context);
+
+        match &mut expression.node {
+            ExpressionKind::Empty => (),
+            ExpressionKind::Default => (),
+            ExpressionKind::Literal(_) => (),
+            ExpressionKind::Variable(_) => (),
+            ExpressionKind::ExternalExpression(_) => (),
+
+            ExpressionKind::Index(index) => {
+                self.analyse_expression(&mut index.collection, context);
+                self.analyse_expression(&mut index.index, context);
+            },
+            ExpressionKind::Lambda(lambda) => {
+                self.resolve_lambda_signature(&mut lambda.signature, span, context);
+                for argument in &mut lambda.arguments.values {
+                    self.analyse_expression(argument, context);
+                }
+
+                match &mut lambda.body {
+                    LambdaBody::Block(block) => self.analyse_block(block, context),
+                    LambdaBody::Expression(expression) => self.analyse_expression(expression, context),
+                }
+            },
+            ExpressionKind::FunctionCall(function_call) => {
+                self.resolve_type_generics(&mut function_call.generics, span, context);
+
+                if let Some(callee) = &mut function_call.callee {
+                    self.analyse_expression(callee, context);
+                }
+
+                for argument in &mut function_call.arguments.values {
+                    self.analyse_expression(argument, context);
+                }
+            },
+            ExpressionKind::StructConstructor(struct_constructor) => {
+                self.resolve_type(&mut struct_constructor.calle, span, context);
+
+                for expression in struct_constructor.arguments.values.values_mut() {
+                    self.analyse_expression(expression, context);
+                }
+            },
+            ExpressionKind::AccessField(access_field) => {
+                self.analyse_expression(&mut access_field.object, context);
+            },
+            ExpressionKind::StaticField(static_field) => {
+                self.resolve_type(&mut static_field.object, span, context);
+            },
+            ExpressionKind::StaticMethod(static_method) => {
+                let callee_span = static_method.callee.span;
+                self.resolve_type(&mut static_method.callee.node, callee_span, context);
+                self.resolve_type_generics(&mut static_method.generics, span, context);
+
+                for argument in &mut static_method.arguments.values {
+                    self.analyse_expression(argument, context);
+                }
+            },
+            ExpressionKind::UnwrapVariable(unwrap_variable) => {
+
+                if let UnwrapVariable::MultiVariable{vars:_, ty, initializer} = unwrap_variable {
+                    self.resolve_type(ty, span, context);
+
+                    if let Some(initializer) = initializer {
+                        self.analyse_expression(initializer, context);
+                    }
+                }
+            },
+            ExpressionKind::Unary(unary) => self.analyse_expression(&mut unary.expression, context),
+            ExpressionKind::Binary(binary) => {
+                self.analyse_expression(&mut binary.left, context);
+                self.analyse_expression(&mut binary.right, context);
+            },
+            ExpressionKind::If(if_decl) => self.analyse_if(if_decl, context),
+            ExpressionKind::For(for_decl) => {
+                if let Some(element) = &mut for_decl.element {
+                    self.analyse_expression(element, context);
+                }
+                self.analyse_expression(&mut for_decl.collection, context);
+                self.analyse_block(&mut for_decl.block, context);
+            },
+            ExpressionKind::While(while_decl) => {
+                if let Some(condition) = &mut while_decl.condition {
+                    self.analyse_expression(condition, context);
+                }
+                self.analyse_block(&mut while_decl.block, context);
+            },
+            ExpressionKind::Match(match_decl) => {
+                self.analyse_expression(&mut match_decl.condition, context);
+
+                for case in &mut match_decl.cases {
+                    let case_parent_id = self.enter_scope(Some(case.scope_id), span, context);
+
+                    match &mut case.if_kind {
+                        IfCaseKind::Expression(expression) => self.analyse_expression(expression, context),
+                        IfCaseKind::Variant{name:_, params} => for param in &mut params.values {
+                            self.analyse_expression(param, context);
+                        },
+                        IfCaseKind::NamedVariant{name:_, params} => for param in params.values.values_mut() {
+                            self.analyse_expression(param, context);
+                        },
+                    }
+
+                    match &mut case.do_fn {
+                        CaseDoKind::Block(block) => self.analyse_block(&mut block.node, context),
+                        CaseDoKind::Expression(expression) => self.analyse_expression(expression, context),
+                    }
+
+                    self.exit_scope(case_parent_id);
+                }
+            },
+            ExpressionKind::Ternary(ternary) => {
+                self.analyse_expression(&mut ternary.condition, context);
+                self.analyse_expression(&mut ternary.if_branch, context);
+                self.analyse_expression(&mut ternary.else_branch, context);
+            },
+            ExpressionKind::Deref(inner) => self.analyse_expression(inner, context),
+            ExpressionKind::MutRef(inner) => self.analyse_expression(inner, context),
+            ExpressionKind::ConstRef(inner) => self.analyse_expression(inner, context),
+            ExpressionKind::Copy(inner) => self.analyse_expression(inner, context),
+            ExpressionKind::Block(block) => self.analyse_block(block, context),
+            ExpressionKind::ReturnLike(return_like) => {
+                if let Some(value) = &mut return_like.value {
+                    self.analyse_expression(value, context);
+                }
+            },
+            ExpressionKind::ExpressionGroup(expression_group) => {
+
+                match expression_group {
+                    ExpressionGroup::Tuple(tuple) => for value in &mut tuple.values {
+                        self.analyse_expression(value, context);
+                    },
+                    ExpressionGroup::NamedTuple(named_tuple) => for value in named_tuple.values.values_mut() {
+                        self.analyse_expression(value, context);
+                    },
+                    ExpressionGroup::Array(array) => {
+                        self.resolve_optional_type(&mut array.collection_type, span, context);
+                        self.resolve_optional_type(&mut array.element_type, span, context);
+
+                        for value in &mut array.values {
+                            self.analyse_expression(value, context);
+                        }
+                    },
+                    ExpressionGroup::ArrayFiller(array_filler) => {
+                        self.resolve_optional_type(&mut array_filler.collection_type, span, context);
+                        self.resolve_optional_type(&mut array_filler.element_type, span, context);
+                        self.analyse_expression(&mut array_filler.amount, context);
+                        self.analyse_expression(&mut array_filler.fill_expr, context);
+                    },
+                }
+            },
+        }
+
+        self.exit_scope(parent_id);
+    }
+
+    fn analyse_if(&mut self, if_decl: &mut If, context: &mut TypeContext) {
+        self.analyse_expression(&mut if_decl.condition, context);
+        self.analyse_block(&mut if_decl.block, context);
+
+        for branch in &mut if_decl.else_branchs {
+
+            match &mut branch.node {
+                ElseKind::Else(block) => self.analyse_block(&mut block.node, context),
+                ElseKind::ElseIf(else_if) => self.analyse_if(&mut else_if.node, context),
+            }
+        }
+    }
+
+    fn analyse_block(&mut self, block: &mut Block, context: &mut TypeContext) {
+        let span = block.statments.first().map(|statment| statment.span).unwrap_or_default();
+        let parent_id = self.enter_scope(Some(block.scope_id), span, context);
+
+        for statment in &mut block.statments {
+            self.analyse_statment(statment, context);
+        }
+
+        self.exit_scope(parent_id);
+    }
+
+    /// sets current scope and resolves its symbols the first time the scope is entered
+    fn enter_scope(&mut self, scope_id: Option<ScopeId>, span: SoulSpan, context: &mut TypeContext) -> ScopeId {
+        let parent_id = self.get_scope().current_id();
+
+        if let Some(id) = scope_id {
+
+            if self.get_scope_mut().set_current(id).is_none() {
+                panic!("could not get scope_id({}) at line: {}:{}", id.0, span.line_number, span.line_offset)
+            }
+
+            self.resolve_scope(id, context);
+        }
+
+        parent_id
+    }
+
+    fn exit_scope(&mut self, parent_id: ScopeId) {
+        self.get_scope_mut()
+            .set_current(parent_id)
+            .expect("scope_id should be valid");
+    }
+
+    fn resolve_scope(&mut self, id: ScopeId, context: &mut TypeContext) {
+        if !context.resolved_scopes.insert(id.0) {
+            return
+        }
+
+        let mut symbols = self.get_scope().current_scope().symbols.clone();
+        let names = symbols.keys()
+            .cloned()
+            .collect::<std::collections::BTreeSet<_>>();
+
+        for name in names {
+            let kinds = symbols.get_mut(&name).expect("name should be in symbols");
+
+            for kind in kinds {
+                self.resolve_scope_kind(&mut kind.node, kind.span, context);
+            }
+        }
+
+        self.get_scope_mut().get_scopes_mut()[id.0].symbols = symbols;
+    }
+
+    fn resolve_scope_kind(&mut self, kind: &mut ScopeKind, span: SoulSpan, context: &mut TypeContext) {
+
+        match kind {
+            ScopeKind::Type(_) => (),
+
+            ScopeKind::Variable(variable) => {
+                self.resolve_type(&mut variable.ty, span, context);
+
+                if let Some(value) = &mut variable.initialize_value {
+                    self.analyse_expression(value, context);
+                }
+            },
+            ScopeKind::Functions(functions) => for function in functions {
+                self.resolve_function(&mut function.node, function.span, context, false);
+            },
+            ScopeKind::Class(class) => self.resolve_class(class, span, context, false),
+            ScopeKind::Trait(trait_) => self.resolve_trait(trait_, span, context),
+            ScopeKind::Struct(struct_) => self.resolve_struct(struct_, span, context),
+            ScopeKind::Enum(enum_) => self.resolve_enum(enum_, span, context),
+            ScopeKind::Union(union) => self.resolve_union(union, span, context),
+            ScopeKind::TypeEnum(type_enum) => self.resolve_type_enum(type_enum, span, context),
+            ScopeKind::TypeDef{new_type:_, of_type} => self.resolve_type(of_type, span, context),
+            ScopeKind::UseTypeDef{new_type:_, of_type} => self.resolve_type(of_type, span, context),
+        }
+    }
+
+    /// resolves function signature, if 'with_body' also resolve the statments of the function block
+    fn resolve_function(&mut self, function: &mut Function, span: SoulSpan, context: &mut TypeContext, with_body: bool) {
+        let generics_len = context.generics.len();
+        push_generic_names(&function.signature.generics, context);
+
+        let mut old_this = None;
+        if let Some(callee) = &mut function.signature.callee {
+            self.resolve_type(&mut callee.node.extention_type, callee.span, context);
+
+            if context.this_type.is_none() {
+                old_this = Some(context.this_type.replace(callee.node.extention_type.clone()));
+            }
+        }
+
+        self.resolve_signature_types(&mut function.signature, span, context);
+
+        if with_body {
+            self.analyse_block(&mut function.block, context);
+        }
+        else {
+            let parent_id = self.enter_scope(Some(function.block.scope_id), span, context);
+            self.exit_scope(parent_id);
+        }
+
+        if let Some(this) = old_this {
+            context.this_type = this;
+        }
+        context.generics.truncate(generics_len);
+    }
+
+    /// resolves all types of signature (assumes the generics of signature are already in context)
+    fn resolve_signature_types(&mut self, signature: &mut FunctionSignature, span: SoulSpan, context: &mut TypeContext) {
+        self.resolve_generic_parameters(&mut signature.generics, span, context);
+
+        if let Some(Spanned{node: FunctionCallee{extention_type:_, this: Some(this)}, span}) = &mut signature.callee {
+            self.resolve_type(this, *span, context);
+        }
+
+        for parameter in &mut signature.parameters {
+            self.resolve_type(&mut parameter.node.ty, parameter.span, context);
+        }
+
+        self.resolve_optional_type(&mut signature.return_type, span, context);
+    }
+
+    fn resolve_lambda_signature(&mut self, signature: &mut LambdaSignature, span: SoulSpan, context: &mut TypeContext) {
+        for parameter in &mut signature.params {
+            self.resolve_type(&mut parameter.node.ty, parameter.span, context);
+        }
+
+        if let Some(return_type) = &mut signature.return_type {
+            self.resolve_type(return_type, span, context);
+        }
+    }
+
+    fn resolve_use_block(&mut self, use_block: &mut UseBlock, span: SoulSpan, context: &mut TypeContext) {
+        self.resolve_type(&mut use_block.ty, span, context);
+
+        let old_this = context.this_type.replace(use_block.ty.clone());
+        if let Some(impl_trait) = &mut use_block.impl_trait {
+            self.resolve_type(impl_trait, span, context);
+        }
+
+        self.analyse_block(&mut use_block.block, context);
+        context.this_type = old_this;
+    }
+
+    fn resolve_class(&mut self, class: &mut Class, span: SoulSpan, context: &mut TypeContext, with_body: bool) {
+        let generics_len = context.generics.len();
+        push_generic_names(&class.generics, context);
+
+        let this = type_with_generics(TypeKind::Class(class.name.clone()), &class.generics);
+        let old_this = context.this_type.replace(this);
+
+        let parent_id = self.enter_scope(Some(class.scope_id), span, context);
+
+        self.resolve_generic_parameters(&mut class.generics, span, context);
+        for implement in &mut class.implements {
+            self.resolve_type(implement, span, context);
+        }
+
+        for child in &mut class.children {
+
+            match child {
+                ClassChild::Field(field) => self.resolve_field(&mut field.node, field.span, context),
+                ClassChild::Methode(methode) => self.resolve_function(&mut methode.node, methode.span, context, with_body),
+                ClassChild::ImplBlock(impl_block) => {
+
+                    if with_body {
+                        self.resolve_use_block(&mut impl_block.node, impl_block.span, context);
+                    }
+                    else if let Some(impl_trait) = &mut impl_block.node.impl_trait {
+                        self.resolve_type(impl_trait, impl_block.span, context);
+                    }
+                },
+            }
+        }
+
+        self.exit_scope(parent_id);
+        context.this_type = old_this;
+        context.generics.truncate(generics_len);
+    }
+
+    fn resolve_struct(&mut self, struct_: &mut Struct, span: SoulSpan, context: &mut TypeContext) {
+        let generics_len = context.generics.len();
+        push_generic_names(&struct_.generics, context);
+
+        let this = type_with_generics(TypeKind::Struct(struct_.name.clone()), &struct_.generics);
+        let old_this = context.this_type.replace(this);
+
+        let parent_id = self.enter_scope(Some(struct_.scope_id), span, context);
+
+        self.resolve_generic_parameters(&mut struct_.generics, span, context);
+        for field in &mut struct_.fields {
+            self.resolve_field(&mut field.node, field.span, context);
+        }
+
+        self.exit_scope(parent_id);
+        context.this_type = old_this;
+        context.generics.truncate(generics_len);
+    }
+
+    fn resolve_trait(&mut self, trait_: &mut Trait, span: SoulSpan, context: &mut TypeContext) {
+        let generics_len = context.generics.len();
+        push_generic_names(&trait_.signature.generics, context);
+
+        let this = SoulType::from_type_kind(TypeKind::Generic(Ident::new(THIS_TYPE)));
+        let old_this = context.this_type.replace(this);
+
+        let parent_id = self.enter_scope(Some(trait_.scope_id), span, context);
+
+        self.resolve_generic_parameters(&mut trait_.signature.generics, span, context);
+        for implement in &mut trait_.signature.implements {
+            self.resolve_type(implement, span, context);
+        }
+
+        for methode in &mut trait_.methodes {
+            let methode_generics_len = context.generics.len();
+            push_generic_names(&methode.node.generics, context);
+
+            if let Some(callee) = &mut methode.node.callee {
+                self.resolve_type(&mut callee.node.extention_type, callee.span, context);
+            }
+            self.resolve_signature_types(&mut methode.node, methode.span, context);
+
+            context.generics.truncate(methode_generics_len);
+        }
+
+        self.exit_scope(parent_id);
+        context.this_type = old_this;
+        context.generics.truncate(generics_len);
+    }
+
+    fn resolve_enum(&mut self, enum_: &mut Enum, span: SoulSpan, context: &mut TypeContext) {
+        let parent_id = self.enter_scope(Some(enum_.scope_id), span, context);
+
+        if let Some(ty) = &mut enum_.ty {
+            self.resolve_type(ty, span, context);
+        }
+
+        if let EnumVariantKind::Expression(variants) = &mut enum_.variants {
+
+            for variant in variants {
+                self.analyse_expression(&mut variant.value, context);
+            }
+        }
+
+        self.exit_scope(parent_id);
+    }
+
+    fn resolve_union(&mut self, union: &mut Union, span: SoulSpan, context: &mut TypeContext) {
+        let generics_len = context.generics.len();
+        push_generic_names(&union.generics, context);
+
+        let parent_id = self.enter_scope(Some(union.scope_id), span, context);
+
+        self.resolve_generic_parameters(&mut union.generics, span, context);
+        for variant in &mut union.variants {
+
+            match &mut variant.node.field {
+                UnionVariantKind::Tuple(types) => for ty in types {
+                    self.resolve_type(ty, variant.span, context);
+                },
+                UnionVariantKind::NamedTuple(types) => for ty in types.values_mut() {
+                    self.resolve_type(ty, variant.span, context);
+                },
+            }
+        }
+
+        self.exit_scope(parent_id);
+        context.generics.truncate(generics_len);
+    }
+
+    fn resolve_type_enum(&mut self, type_enum: &mut TypeEnum, span: SoulSpan, context: &mut TypeContext) {
+        for ty in &mut type_enum.body.types {
+            self.resolve_type(ty, span, context);
+        }
+    }
+
+    fn resolve_field(&mut self, field: &mut Field, span: SoulSpan, context: &mut TypeContext) {
+        self.resolve_type(&mut field.ty, span, context);
+
+        if let Some(default_value) = &mut field.default_value {
+            self.analyse_expression(default_value, context);
+        }
+    }
+
+    fn resolve_generic_parameters(&mut self, generics: &mut Vec<GenericParameter>, span: SoulSpan, context: &mut TypeContext) {
+
+        for generic in generics {
+
+            for constraint in &mut generic.constraint {
+
+                match constraint {
+                    TypeConstraint::Type(ty) => self.resolve_type(ty, span, context),
+                    TypeConstraint::LiteralTypeEnum(body) => for ty in &mut body.types {
+                        self.resolve_type(ty, span, context);
+                    },
+                }
+            }
+
+            if let GenericKind::Type{impl_type, default} = &mut generic.kind {
+                self.resolve_optional_type(impl_type, span, context);
+                self.resolve_optional_type(default, span, context);
+            }
+        }
+    }
+
+    fn resolve_type_generics(&mut self, generics: &mut Vec<TypeGenericKind>, span: SoulSpan, context: &mut TypeContext) {
+
+        for generic in generics {
+
+            match generic {
+                TypeGenericKind::Type(ty) => self.resolve_type(ty, span, context),
+                TypeGenericKind::Expression(expression) => self.analyse_expression(expression, context),
+                TypeGenericKind::Lifetime(_) => (),
+            }
+        }
+    }
+
+    fn resolve_optional_type(&mut self, ty: &mut Option<SoulType>, span: SoulSpan, context: &mut TypeContext) {
+        if let Some(ty) = ty {
+            self.resolve_type(ty, span, context);
+        }
+    }
+
+    fn resolve_type(&mut self, ty: &mut SoulType, span: SoulSpan, context: &mut TypeContext) {
+        self.resolve_type_depth(ty, span, context, 0);
+    }
+
+    fn resolve_type_depth(&mut self, ty: &mut SoulType, span: SoulSpan, context: &mut TypeContext, depth: usize) {
+
+        self.resolve_type_generics(&mut ty.generics, span, context);
+        for wrapper in &mut ty.wrappers {
+
+            if let TypeWrapper::StackArrayGeneric(array_size) = wrapper {
+                self.resolve_type_depth(array_size, span, context, depth);
+            }
+        }
+
+        match &mut ty.base {
+            TypeKind::Unknown(name) => {
+                let name = name.0.clone();
+
+                if let Some(resolved) = self.resolve_name(&name, span, context, depth) {
+                    let SoulType{modifier, base, mut wrappers, generics} = resolved;
+
+                    ty.base = base;
+                    if ty.modifier == Modifier::Default {
+                        ty.modifier = modifier;
+                    }
+
+                    if ty.generics.is_empty() {
+                        ty.generics = generics;
+                    }
+
+                    wrappers.append(&mut ty.wrappers);
+                    ty.wrappers = wrappers;
+                }
+            },
+            TypeKind::Tuple(types) => for ty in types {
+                self.resolve_type_depth(ty, span, context, depth);
+            },
+            TypeKind::NamedTuple(types) => for ty in types.values_mut() {
+                self.resolve_type_depth(ty, span, context, depth);
+            },
+            TypeKind::TypeEnum(_, types) => for ty in types {
+                self.resolve_type_depth(ty, span, context, depth);
+            },
+            TypeKind::Function(signature) => {
+                let generics_len = context.generics.len();
+                push_generic_names(&signature.generics, context);
+
+                self.resolve_signature_types(signature, span, context);
+                context.generics.truncate(generics_len);
+            },
+            TypeKind::Lambda(signature) => self.resolve_lambda_signature(signature, span, context),
+            _ => (),
+        }
+    }
+
+    fn resolve_name(&mut self, name: &str, span: SoulSpan, context: &mut TypeContext, depth: usize) -> Option<SoulType> {
+
+        if depth > MAX_TYPEDEF_DEPTH {
+            self.add_type_error(name, span, context, format!("type: '{}' is defined recursively", name));
+            return None
+        }
+
+        if name == THIS_TYPE {
+
+            if context.this_type.is_none() {
+                self.add_type_error(name, span, context, format!("'{}' can only be used inside a type or an extention methode", THIS_TYPE));
+            }
+            return context.this_type.clone()
+        }
+
+        if context.generics.iter().any(|generic| generic == name) {
+            return Some(SoulType::from_type_kind(TypeKind::Generic(Ident::new(name))))
+        }
+
+        if let Some(primitive) = TypeKind::from_internal_name(name) {
+            return Some(SoulType::from_type_kind(primitive))
+        }
+
+        if name.contains(PATH_SEPERATOR) {
+            return Some(self.resolve_path_name(name, span, context))
+        }
+
+        if name == STRICT_TYPE || name == MUT_TYPE {
+            return Some(SoulType::from_type_kind(TypeKind::Custom(Ident::new(name))))
+        }
+
+        if name == AUTO_COPY_TRAIT {
+            return Some(SoulType::from_type_kind(TypeKind::Trait(Ident::new(name))))
+        }
+
+        let symbol = match self.get_scope().lookup_kind(name, ScopeKind::is_type) {
+            Some(symbol) => &symbol.node,
+            None => {
+                self.add_type_error(name, span, context, format!("type: '{}' not found in scope", name));
+                return None
+            },
+        };
+
+        let mut of_type = match symbol {
+            ScopeKind::Struct(struct_) => return Some(SoulType::from_type_kind(TypeKind::Struct(struct_.name.clone()))),
+            ScopeKind::Class(class) => return Some(SoulType::from_type_kind(TypeKind::Class(class.name.clone()))),
+            ScopeKind::Trait(trait_) => return Some(SoulType::from_type_kind(TypeKind::Trait(trait_.signature.name.clone()))),
+            ScopeKind::Enum(enum_) => return Some(SoulType::from_type_kind(TypeKind::Enum(enum_.name.clone()))),
+            ScopeKind::Union(union) => return Some(SoulType::from_type_kind(TypeKind::Union(union.name.clone()))),
+            ScopeKind::Type(ty) => return Some(ty.clone()),
+
+            ScopeKind::TypeEnum(type_enum) => SoulType::from_type_kind(TypeKind::TypeEnum(type_enum.name.clone(), type_enum.body.types.clone())),
+            ScopeKind::TypeDef{new_type:_, of_type} => of_type.clone(),
+            ScopeKind::UseTypeDef{new_type:_, of_type} => of_type.clone(),
+
+            ScopeKind::Variable(_) |
+            ScopeKind::Functions(_) => unreachable!("lookup_kind only returns types"),
+        };
+
+        self.resolve_type_depth(&mut of_type, span, context, depth + 1);
+        Some(of_type)
+    }
+
+    /// resolves 'path::to::Type' to ExternalType and 'Union::Variant' to UnionVariant
+    fn resolve_path_name(&mut self, name: &str, span: SoulSpan, context: &mut TypeContext) -> SoulType {
+        let mut segments = name.split(PATH_SEPERATOR).collect::<Vec<_>>();
+        let type_name = Ident::new(segments.pop().unwrap_or(""));
+
+        if segments.len() == 1 {
+            let union = self.get_scope().lookup_kind(segments[0], |kind| matches!(kind, ScopeKind::Union(_) | ScopeKind::Type(_)));
+
+            match union {
+                Some(Spanned{node: ScopeKind::Union(union), span:_}) => {
+                    let union_type = UnionType{union: UnionKind::Union(union.name.clone()), variant: type_name};
+                    return SoulType::from_type_kind(TypeKind::UnionVariant(union_type))
+                },
+                Some(Spanned{node: ScopeKind::Type(SoulType{base: TypeKind::ExternalType(external), ..}), span:_}) => {
+                    let union_type = UnionType{union: UnionKind::External(external.node.clone()), variant: type_name};
+                    return SoulType::from_type_kind(TypeKind::UnionVariant(union_type))
+                },
+                _ => (),
+            }
+        }
+
+        let path = resolve_page_path(self.get_scope(), &segments);
+        if let Err(err) = find_external_symbol(&self.get_scope().external_headers, &path, &type_name.0, span) && context.reported.insert((name.to_string(), span)) {
+            self.add_error(err);
+        }
+
+        let external_type = ExternalType{path, name: type_name};
+        SoulType::from_type_kind(TypeKind::ExternalType(Spanned::new(external_type, span)))
+    }
+
+    fn add_type_error(&mut self, name: &str, span: SoulSpan, context: &mut TypeContext, msg: String) {
+        if context.reported.insert((name.to_string(), span)) {
+            self.add_error(new_soul_error(SoulErrorKind::NotFoundInScope, Some(span), msg));
+        }
+    }
+}
+
+fn push_generic_names(generics: &[GenericParameter], context: &mut TypeContext) {
+
+    for generic in generics {
+
+        if let GenericKind::Type{..} = generic.kind {
+            context.generics.push(generic.name.0.clone());
+        }
+    }
+}
+
+pub fn type_with_generics(base: TypeKind, generics: &[GenericParameter]) -> SoulType {
+    let mut ty = SoulType::from_type_kind(base);
+
+    ty.generics = generics.iter()
+        .filter(|generic| matches!(generic.kind, GenericKind::Type{..}))
+        .map(|generic| TypeGenericKind::Type(SoulType::from_type_kind(TypeKind::Generic(generic.name.clone()))))
+        .collect();
+
+    ty
+}
@@ -0,0 +1,106 @@
+use crate::steps::{sementic::test_helpers::{error_messages, global_symbol, parse_source}, step_interfaces::{i_parser::{abstract_syntax_tree::soul_type::{soul_type::SoulType, type_kind::{TypeKind, TypeSize}}, scope_builder::ScopeKind}, i_sementic::{ast_visitor::{AstAnalyser, ExternalHeaderAnalyser, NameResolutionAnalyser, TypeResolutionAnalyser}, scope_vistitor::ScopeVisitor, soul_fault::SoulFault}}};
+
+fn resolve_types(source: &str) -> (ScopeVisitor, Vec<SoulFault>) {
+    let (mut tree, scope) = parse_source(source);
+
+    let mut analyser = NameResolutionAnalyser::new(scope, true);
+    analyser.analyse_ast(&mut tree);
+    let mut analyser = ExternalHeaderAnalyser::new(analyser, true);
+    analyser.analyse_ast(&mut tree);
+    let mut analyser = TypeResolutionAnalyser::new(analyser, true);
+    analyser.analyse_ast(&mut tree);
+
+    let (scope, faults, _) = analyser.consume_to_tuple();
+    (scope, faults)
+}
+
+fn function_types(scope: &ScopeVisitor, name: &str) -> (Vec<SoulType>, Option<SoulType>) {
+    let signature = match global_symbol(scope, name) {
+        ScopeKind::Functions(functions) => &functions[0].node.signature,
+        other => panic!("'{}' should be function, found: {:?}", name, other),
+    };
+
+    (
+        signature.parameters.iter().map(|param| param.node.ty.clone()).collect(), 
+        signature.return_type.clone(),
+    )
+}
+
+#[test]
+fn test_resolve_primitives() {
+    let (scope, faults) = resolve_types("foo(int a, f32 b, char c, u8[] d) bool {\n\treturn true\n}\n");
+    assert!(error_messages(&faults).is_empty(), "{:?}", error_messages(&faults));
+
+    let (params, return_type) = function_types(&scope, "foo");
+    assert_eq!(params[0].base, TypeKind::SystemInt);
+    assert_eq!(params[1].base, TypeKind::Float(TypeSize::Bit32));
+    assert_eq!(params[2].base, TypeKind::Char(TypeSize::Bit8));
+    assert_eq!(params[3].base, TypeKind::Uint(TypeSize::Bit8));
+    assert_eq!(params[3].wrappers.len(), 1);
+    assert_eq!(return_type.unwrap().base, TypeKind::Bool);
+}
+
+#[test]
+fn test_resolve_user_types() {
+    let source = "
+struct Point {
+    int x
+}
+
+class Shape {
+    int sides
+}
+
+enum Color {
+    Red,
+    Blue,
+}
+
+type Number impl [int, f32]
+type Integer impl int
+
+paint(Point p, Shape s, Color c, Number n, Integer i) {
+}
+";
+    let (scope, faults) = resolve_types(source);
+    assert!(error_messages(&faults).is_empty(), "{:?}", error_messages(&faults));
+
+    let (params, _) = function_types(&scope, "paint");
+    assert_eq!(params[0].base, TypeKind::Struct("Point".into()));
+    assert_eq!(params[1].base, TypeKind::Class("Shape".into()));
+    assert_eq!(params[2].base, TypeKind::Enum("Color".into()));
+    assert!(matches!(&params[3].base, TypeKind::TypeEnum(name, types) if name.0 == "Number" && types[0].base == TypeKind::SystemInt));
+    assert_eq!(params[4].base, TypeKind::SystemInt);
+}
+
+#[test]
+fn test_resolve_generics_and_this() {
+    let source = "
+struct Point {
+    int x
+}
+
+Point This(int x) {
+    return This{x}
+}
+
+first<T>(T[] values) T {
+    return values[0]
+}
+";
+    let (scope, faults) = resolve_types(source);
+    assert!(error_messages(&faults).is_empty(), "{:?}", error_messages(&faults));
+
+    let (params, return_type) = function_types(&scope, "first");
+    assert_eq!(params[0].base, TypeKind::Generic("T".into()));
+    assert_eq!(return_type.unwrap().base, TypeKind::Generic("T".into()));
+}
+
+#[test]
+fn test_unknown_type_is_error() {
+    let (_, faults) = resolve_types("foo(Missing a) {\n}\n");
+    let errors = error_messages(&faults);
+
+    assert_eq!(errors.len(), 1, "{:?}", errors);
+    assert!(errors[0].contains("type: 'Missing' not found in scope"), "{}", errors[0]);
+}
@@ -0,0 +1,19 @@
+//! # Soul Language – Code Generator
+//!
+//! This module defines the **code generation step** in the Soul language compiler pipeline.
+//! It turns every analysed page (see [`SementicResponse`](crate::steps::step_interfaces::i_sementic::sementic_response::SementicResponse))
+//! into a C++ header and source file.
+//!
+//! ## Responsibilities
+//! - Every page becomes a namespace named after its [`SoulPagePath`] (e.g. `project.std.math` becomes `project::std::math`).
+//! - The header holds types, function declarations, generic functions, globals and `ProgramMemmory` constants.
+//! - The source holds the function definitions and the C++ `main` of the main page.
+
+use crate::steps::step_interfaces::i_parser::abstract_syntax_tree::soul_type::type_kind::SoulPagePath;
+
+#[derive(Debug, Clone)]
+pub struct CodeGeneratorResponse {
+    pub path: SoulPagePath,
+    pub header: String,
+    pub source: String,
+}
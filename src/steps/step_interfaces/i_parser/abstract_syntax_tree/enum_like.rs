@@ -3,16 +3,35 @@ use std::{collections::HashMap};
 use bincode::{Decode, Encode};
 use itertools::Itertools;
 use serde::{Deserialize, Serialize};
-use crate::steps::step_interfaces::i_parser::{abstract_syntax_tree::{expression::{Expression, Ident}, generic::GenericParameter, soul_type::soul_type::SoulType, spanned::Spanned}, scope_builder::ScopeId};
+use crate::steps::step_interfaces::i_parser::{abstract_syntax_tree::{expression::{Expression, ExpressionKind, Ident}, generic::GenericParameter, literal::Literal, soul_type::soul_type::SoulType, spanned::Spanned}, scope_builder::ScopeId};
 
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Encode, Decode)]
 pub struct Enum {
     pub name: Ident,
+    /// type of values (e.g 'str' in 'enum Paths impl str'), None for int enum
+    pub ty: Option<SoulType>,
     pub variants: EnumVariantKind,
     pub scope_id: ScopeId,
 }
 
+impl Enum {
+    /// name and value of every variant in order of declaration, value of expression variant is None if it is not a literal (evaluated by LiteralEvaluator)
+    pub fn values(&self) -> Vec<(&Ident, Option<Literal>)> {
+        match &self.variants {
+            EnumVariantKind::Int(variants) => variants.iter()
+                .map(|variant| (&variant.node.name, Some(Literal::Int(variant.node.value))))
+                .collect(),
+            EnumVariantKind::Expression(variants) => variants.iter()
+                .map(|variant| match &variant.value.node {
+                    ExpressionKind::Literal(literal) => (&variant.name, Some(literal.clone())),
+                    _ => (&variant.name, None),
+                })
+                .collect(),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Encode, Decode)]
 pub struct Union {
     pub name: Ident,
@@ -55,7 +74,7 @@ impl UnionVariantKind {
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Encode, Decode)]
 pub enum EnumVariantKind {
-    Int(Vec<EnumVariant<i64>>),
+    Int(Vec<Spanned<EnumVariant<i64>>>),
     Expression(Vec<EnumVariant<Expression>>)
 }
 
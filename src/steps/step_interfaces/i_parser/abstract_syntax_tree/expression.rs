@@ -67,6 +67,8 @@ pub enum ExpressionKind {
     MutRef(BoxExpression),
     /// A constant reference, e.g., `@x`.
     ConstRef(BoxExpression),
+    /// An explicit copy, the value is copied instead of consumed e.g., `copy x`.
+    Copy(BoxExpression),
 
     /// A block of statements, returning the last expression `{/*stuff*/}`.
     Block(Block),
@@ -396,6 +398,7 @@ impl ExpressionKind {
             ExpressionKind::Ternary(_) => "Ternary",
             ExpressionKind::Literal(_) => "Literal",
             ExpressionKind::ConstRef(_) => "ConstRef",
+            ExpressionKind::Copy(_) => "Copy",
             ExpressionKind::Variable(_) => "Variable",
             ExpressionKind::StructConstructor(_) => "Constructor",
             ExpressionKind::AccessField(_) => "AccessField",
@@ -429,10 +432,10 @@ impl BinaryOperatorKind {
         match name {
             val if val == SOUL_NAMES.get_name(NamesOperator::Equals) => Self::Eq,
             val if val == SOUL_NAMES.get_name(NamesOperator::NotEquals) => Self::NotEq,
-            val if val == SOUL_NAMES.get_name(NamesOperator::IsSmaller) => Self::Le,
-            val if val == SOUL_NAMES.get_name(NamesOperator::IsSmallerEquals) => Self::Lt,
-            val if val == SOUL_NAMES.get_name(NamesOperator::IsBigger) => Self::Ge,
-            val if val == SOUL_NAMES.get_name(NamesOperator::IsBiggerEquals) => Self::Gt,
+            val if val == SOUL_NAMES.get_name(NamesOperator::IsSmaller) => Self::Lt,
+            val if val == SOUL_NAMES.get_name(NamesOperator::IsSmallerEquals) => Self::Le,
+            val if val == SOUL_NAMES.get_name(NamesOperator::IsBigger) => Self::Gt,
+            val if val == SOUL_NAMES.get_name(NamesOperator::IsBiggerEquals) => Self::Ge,
             val if val == SOUL_NAMES.get_name(NamesOperator::Addition) => Self::Add,
             val if val == SOUL_NAMES.get_name(NamesOperator::Subtract) => Self::Sub,
             val if val == SOUL_NAMES.get_name(NamesOperator::Multiple) => Self::Mul,
@@ -442,7 +445,7 @@ impl BinaryOperatorKind {
             val if val == SOUL_NAMES.get_name(NamesOperator::Root) => Self::Root,
             val if val == SOUL_NAMES.get_name(NamesOperator::Logarithm) => Self::Log,
             val if val == SOUL_NAMES.get_name(NamesOperator::BitWiseOr) => Self::BitOr,
-            val if val == SOUL_NAMES.get_name(NamesOperator::BitWiseAnd) => Self::BitOr,
+            val if val == SOUL_NAMES.get_name(NamesOperator::BitWiseAnd) => Self::BitAnd,
             val if val == SOUL_NAMES.get_name(NamesOperator::BitWiseXor) => Self::BitXor,
             val if val == SOUL_NAMES.get_name(NamesOperator::LogicalOr) => Self::LogOr,
             val if val == SOUL_NAMES.get_name(NamesOperator::LogicalAnd) => Self::LogAnd,
@@ -456,10 +459,10 @@ impl BinaryOperatorKind {
         match self {
             Self::Eq      => SOUL_NAMES.get_name(NamesOperator::Equals),
             Self::NotEq   => SOUL_NAMES.get_name(NamesOperator::NotEquals),
-            Self::Le      => SOUL_NAMES.get_name(NamesOperator::IsSmaller),
-            Self::Lt      => SOUL_NAMES.get_name(NamesOperator::IsSmallerEquals),
-            Self::Ge      => SOUL_NAMES.get_name(NamesOperator::IsBigger),
-            Self::Gt      => SOUL_NAMES.get_name(NamesOperator::IsBiggerEquals),
+            Self::Lt      => SOUL_NAMES.get_name(NamesOperator::IsSmaller),
+            Self::Le      => SOUL_NAMES.get_name(NamesOperator::IsSmallerEquals),
+            Self::Gt      => SOUL_NAMES.get_name(NamesOperator::IsBigger),
+            Self::Ge      => SOUL_NAMES.get_name(NamesOperator::IsBiggerEquals),
             Self::Add     => SOUL_NAMES.get_name(NamesOperator::Addition),
             Self::Sub     => SOUL_NAMES.get_name(NamesOperator::Subtract),
             Self::Mul     => SOUL_NAMES.get_name(NamesOperator::Multiple),
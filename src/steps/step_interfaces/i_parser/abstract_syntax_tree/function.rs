@@ -15,7 +15,10 @@ pub struct Lambda {
     pub signature: LambdaSignature,
     pub arguments: Tuple,
     pub body: LambdaBody,
-    pub capture: Capture,
+    /// variables of the enclosing scopes that are used in body (filled by LambdaCaptureAnalyser)
+    pub captures: Vec<Capture>,
+    /// mode written in source, 'signature.mode' is the mode that is used
+    pub declared_mode: Option<LambdaMode>,
     pub scope_id: ScopeId,
 }
 
@@ -94,7 +97,8 @@ pub struct Capture {
     pub kind: CaptureKind,
 }
 
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Encode, Decode)]
+/// ordered from weakest to strongest capture
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize, Encode, Decode)]
 pub enum CaptureKind {
     ConstRef,
     MutRef,
@@ -126,6 +130,38 @@ impl LambdaMode {
             LambdaMode::Consume => "OnceFn",
         } 
     }
+
+    /// true if a lambda of this mode can hold the captures of a lambda of mode 'other' (ConstFn < MutFn < OnceFn)
+    pub fn includes(&self, other: &LambdaMode) -> bool {
+        self.strength() >= other.strength()
+    }
+
+    fn strength(&self) -> u8 {
+        match self {
+            LambdaMode::Const => 0,
+            LambdaMode::Mut => 1,
+            LambdaMode::Consume => 2,
+        }
+    }
+}
+
+impl CaptureKind {
+    /// weakest mode of a lambda that captures a variable like this
+    pub fn required_mode(&self) -> LambdaMode {
+        match self {
+            CaptureKind::ConstRef => LambdaMode::Const,
+            CaptureKind::MutRef => LambdaMode::Mut,
+            CaptureKind::Consume => LambdaMode::Consume,
+        }
+    }
+
+    pub fn to_str(&self) -> &'static str {
+        match self {
+            CaptureKind::ConstRef => "const reference",
+            CaptureKind::MutRef => "mutable reference",
+            CaptureKind::Consume => "value",
+        }
+    }
 }
 
 
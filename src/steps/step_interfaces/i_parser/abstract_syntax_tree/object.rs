@@ -52,11 +52,25 @@ pub struct Field {
 pub struct FieldAccess {
     /// None = use default (e.g. pub)
     pub get: Option<Visibility>, 
-    // None = disallow set
+    // None = disallow set (outside of object)
     pub set: Option<Visibility>, 
 }
 
 impl FieldAccess {
+    /// visibility of reading field
+    pub fn get_visibility(&self) -> Visibility {
+        self.get.clone().unwrap_or(Visibility::Public)
+    }
+
+    /// visibility of setting field, field without get/set (e.g. 'int field') can be set by everyone
+    pub fn set_visibility(&self) -> Visibility {
+        match (&self.get, &self.set) {
+            (None, None) => Visibility::Public,
+            (_, Some(vis)) => vis.clone(),
+            (_, None) => Visibility::Private,
+        }
+    }
+
     pub fn new_public() -> Self {
         Self{ get: Some(Visibility::Public), set: Some(Visibility::Public)}
     }
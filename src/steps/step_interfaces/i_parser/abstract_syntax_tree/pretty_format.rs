@@ -1,4 +1,5 @@
 use itertools::Itertools;
+use crate::soul_names::{NamesOtherKeyWords, SOUL_NAMES};
 use crate::steps::step_interfaces::i_parser::{abstract_syntax_tree::{abstract_syntax_tree::AbstractSyntacTree, enum_like::{Enum, EnumVariantKind, TypeEnum, Union, UnionVariant, UnionVariantKind}, expression::{AccessField, Binary, CaseDoKind, ElseKind, Expression, ExpressionGroup, ExpressionKind, ExternalExpression, For, If, IfCaseKind, Index, Match, NamedTuple, StaticField, Ternary, Tuple, Unary, UnwrapVariable, While}, function::{Function, FunctionCall, FunctionSignature, Lambda, LambdaBody, Parameter, StaticMethod, StructConstructor}, generic::GenericParameter, literal::Literal, object::{Class, ClassChild, Field, FieldAccess, Struct, Trait, Visibility}, soul_type::soul_type::{SoulType, TypeGenericKind, TypeWrapper}, spanned::Spanned, statement::{Block, StatementKind, UseBlock}}, scope_builder::{ScopeBuilder, ScopeKind}};
 
 pub trait PrettyFormat {
@@ -147,9 +148,10 @@ impl PrettyString for Enum {
     fn to_pretty(&self, tab: usize, is_last: bool) -> String {
         let prefix = tree_prefix(tab, is_last);
         format!(
-            "{}Enum >> {}\n{}",
+            "{}Enum >> {}{}\n{}",
             prefix,
             self.name,
+            self.ty.as_ref().map(|ty| format!(" impl {}", ty.to_string())).unwrap_or_default(),
             self.variants.to_pretty(tab+1, is_last)
         )
     }
@@ -159,7 +161,7 @@ impl PrettyString for EnumVariantKind {
     fn to_pretty(&self, tab: usize, is_last: bool) -> String {
         let prefix = tree_prefix(tab, is_last);
         match self {
-            EnumVariantKind::Int(enum_variants) => enum_variants.iter().map(|el| format!("{}{} = {}", prefix, el.name, el.value)).join("\n"),
+            EnumVariantKind::Int(enum_variants) => enum_variants.iter().map(|el| format!("{}{} = {}", prefix, el.node.name, el.node.value)).join("\n"),
             EnumVariantKind::Expression(enum_variants) => enum_variants.iter().map(|el| format!("{}{} = {}", prefix, el.name, el.value.to_string())).join("\n"),
         }
     }
@@ -379,7 +381,7 @@ impl PrettyString for ExpressionKind {
             ExpressionKind::Literal(literal) => literal.to_string(),
 
             ExpressionKind::Index(Index{collection, index}) => format!("{}[{}]", collection.to_pretty(tab + 1, is_last), index.to_pretty(tab + 1, is_last)),
-            ExpressionKind::Lambda(Lambda{signature, arguments, body, capture:_, scope_id:_}) => format!("{}({}) => {}", signature.mode.get_lambda_name(), arguments.to_string(), body.to_pretty(tab + 1, is_last)),
+            ExpressionKind::Lambda(Lambda{signature, arguments, body, captures:_, declared_mode:_, scope_id:_}) => format!("{}({}) => {}", signature.mode.get_lambda_name(), arguments.to_string(), body.to_pretty(tab + 1, is_last)),
             ExpressionKind::StructConstructor(StructConstructor{calle, arguments}) => format!("{}(|ctor|{})", calle.to_string(), arguments.to_string()),
             ExpressionKind::FunctionCall(FunctionCall{name, callee, generics, arguments}) => format!("{}{}{}({})", callee.as_ref().map(|el| format!("{}.",el.to_string())).unwrap_or(String::new()), name, generic_to_string(generics), arguments.to_string()),
 
@@ -403,6 +405,7 @@ impl PrettyString for ExpressionKind {
             ExpressionKind::Ternary(Ternary{condition, if_branch, else_branch}) => format!("{} ? {} : {}", condition.to_pretty(tab, is_last), if_branch.to_pretty(tab, is_last), else_branch.to_pretty(tab, is_last)),
 
             ExpressionKind::Deref(spanned) => format!("*{}", spanned.to_pretty(tab, is_last)),
+            ExpressionKind::Copy(spanned) => format!("{} {}", SOUL_NAMES.get_name(NamesOtherKeyWords::CopyData), spanned.to_pretty(tab, is_last)),
             ExpressionKind::MutRef(spanned) => format!("&{}", spanned.to_pretty(tab, is_last)),
             ExpressionKind::ConstRef(spanned) => format!("@{}", spanned.to_pretty(tab, is_last)),
 
@@ -471,7 +474,7 @@ impl ToString for ExpressionKind {
             ExpressionKind::Literal(literal) => literal.to_string(),
             
             ExpressionKind::Index(Index{collection, index}) => format!("{}[{}]", collection.to_string(), index.to_string()),
-            ExpressionKind::Lambda(Lambda{signature, arguments, body:_, capture:_, scope_id:_}) => format!("{}({})", signature.mode.get_lambda_name(), arguments.to_string()),
+            ExpressionKind::Lambda(Lambda{signature, arguments, body:_, captures:_, declared_mode:_, scope_id:_}) => format!("{}({})", signature.mode.get_lambda_name(), arguments.to_string()),
             ExpressionKind::StructConstructor(StructConstructor{calle, arguments}) => format!("{}(|ctor|{})", calle.to_string(), arguments.to_string()),
             ExpressionKind::FunctionCall(FunctionCall{name, callee, generics, arguments}) => format!("{}{}{}({})", callee.as_ref().map(|el| format!("{}.",el.to_string())).unwrap_or(String::new()), name, generic_to_string(generics), arguments.to_string()),
             
@@ -502,6 +505,7 @@ impl ToString for ExpressionKind {
             ExpressionKind::Ternary(Ternary{condition, if_branch, else_branch}) => format!("{} ? {} : {}", condition.to_string(), if_branch.to_string(), else_branch.to_string()),
             
             ExpressionKind::Deref(spanned) => format!("*{}", spanned.to_string()),
+            ExpressionKind::Copy(spanned) => format!("{} {}", SOUL_NAMES.get_name(NamesOtherKeyWords::CopyData), spanned.to_string()),
             ExpressionKind::MutRef(spanned) => format!("&{}", spanned.to_string()),
             ExpressionKind::ConstRef(spanned) => format!("@{}", spanned.to_string()),
             
@@ -199,6 +199,60 @@ impl TypeKind {
         }
     }
     
+    /// get the primitive type of an internal type name (e.g "i32" -> Int(Bit32)), returns None if name is not a primitive
+    pub fn from_internal_name(name: &str) -> Option<Self> {
+        const PRIMITIVES: &[(NamesInternalType, TypeKind)] = &[
+            (NamesInternalType::Character, TypeKind::Char(TypeSize::Bit8)),
+            (NamesInternalType::Character16, TypeKind::Char(TypeSize::Bit16)),
+            (NamesInternalType::Character32, TypeKind::Char(TypeSize::Bit32)),
+            (NamesInternalType::Character64, TypeKind::Char(TypeSize::Bit64)),
+            (NamesInternalType::Boolean, TypeKind::Bool),
+            (NamesInternalType::String, TypeKind::Str),
+            (NamesInternalType::None, TypeKind::None),
+            (NamesInternalType::UntypedInt, TypeKind::UntypedInt),
+            (NamesInternalType::Int, TypeKind::SystemInt),
+            (NamesInternalType::Int8, TypeKind::Int(TypeSize::Bit8)),
+            (NamesInternalType::Int16, TypeKind::Int(TypeSize::Bit16)),
+            (NamesInternalType::Int32, TypeKind::Int(TypeSize::Bit32)),
+            (NamesInternalType::Int64, TypeKind::Int(TypeSize::Bit64)),
+            (NamesInternalType::UntypedUint, TypeKind::UntypedUint),
+            (NamesInternalType::Uint, TypeKind::SystemUint),
+            (NamesInternalType::Uint8, TypeKind::Uint(TypeSize::Bit8)),
+            (NamesInternalType::Uint16, TypeKind::Uint(TypeSize::Bit16)),
+            (NamesInternalType::Uint32, TypeKind::Uint(TypeSize::Bit32)),
+            (NamesInternalType::Uint64, TypeKind::Uint(TypeSize::Bit64)),
+            (NamesInternalType::UntypedFloat, TypeKind::UntypedFloat),
+            (NamesInternalType::Float8, TypeKind::Float(TypeSize::Bit8)),
+            (NamesInternalType::Float16, TypeKind::Float(TypeSize::Bit16)),
+            (NamesInternalType::Float32, TypeKind::Float(TypeSize::Bit32)),
+            (NamesInternalType::Float64, TypeKind::Float(TypeSize::Bit64)),
+        ];
+
+        PRIMITIVES.iter()
+            .find(|(internal, _)| SOUL_NAMES.get_name(*internal) == name)
+            .map(|(_, kind)| kind.clone())
+    }
+
+    pub fn is_untyped(&self) -> bool {
+        matches!(self, TypeKind::UntypedInt | TypeKind::UntypedUint | TypeKind::UntypedFloat)
+    }
+
+    pub fn is_integer(&self) -> bool {
+        matches!(
+            self, 
+            TypeKind::UntypedInt | TypeKind::SystemInt | TypeKind::Int(_) |
+            TypeKind::UntypedUint | TypeKind::SystemUint | TypeKind::Uint(_)
+        )
+    }
+
+    pub fn is_float(&self) -> bool {
+        matches!(self, TypeKind::UntypedFloat | TypeKind::Float(_))
+    }
+
+    pub fn is_numeric(&self) -> bool {
+        self.is_integer() || self.is_float()
+    }
+
     pub fn try_get_name(&self) -> Option<&str> {
 
         Some(match self {
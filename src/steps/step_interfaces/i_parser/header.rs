@@ -35,6 +35,20 @@ impl Header {
 
 impl ExternalHeaders {
 
+    pub fn from_headers(headers: HashMap<SoulPagePath, Header>) -> Self {
+        Self(headers)
+    }
+
+    pub fn get(&self, path: &SoulPagePath) -> Option<&Header> {
+        self.0.get(path)
+    }
+
+    /// true if path is a page or a folder that contains pages (e.g 'project.std' for 'project.std.test')
+    pub fn contains_path(&self, path: &SoulPagePath) -> bool {
+        let folder = format!("{}.", path.0);
+        self.0.keys().any(|page| page == path || page.0.starts_with(&folder))
+    }
+
     pub fn new(run_options: &RunOptions) -> Result<Self, String> {
         let pages = run_options.get_file_paths()
             .map_err(|err| err.to_err_message().join(" "))?;
@@ -52,7 +66,7 @@ impl ExternalHeaders {
     }
 }
 
-fn starts_with_capital(text: &str) -> bool {
+pub fn starts_with_capital(text: &str) -> bool {
     text.chars()
         .next()
         .map(|c| c.is_uppercase())
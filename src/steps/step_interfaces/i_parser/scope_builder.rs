@@ -7,7 +7,7 @@ use crate::{errors::soul_error::{new_soul_error, SoulError, SoulErrorKind, SoulS
 
 type Scope = InnerScope<Vec<Spanned<ScopeKind>>>;
 
-#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, Encode, Decode)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Encode, Decode)]
 pub struct ScopeId(pub usize);
 
 #[derive(Debug, Clone, Serialize, Deserialize, Encode, Decode)]
@@ -45,6 +45,13 @@ pub enum ScopeKind {
     UseTypeDef{new_type: SoulType, of_type: SoulType},
 }
 
+impl ScopeKind {
+    /// true if symbol can be used as a type (e.g struct, typedef), false for variables and functions
+    pub fn is_type(&self) -> bool {
+        !matches!(self, ScopeKind::Variable(_) | ScopeKind::Functions(_))
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Encode, Decode)]
 pub struct Variable {
     pub name: VariableName,
@@ -76,6 +83,13 @@ impl ProgramMemmory {
         return id;
     }
 
+    /// literal that is stored under name (see to_program_memory_name)
+    pub fn get(&self, name: &Ident) -> Option<&Literal> {
+        self.store.iter()
+            .find(|(_, id)| Self::to_program_memory_name(id) == *name)
+            .map(|(literal, _)| literal)
+    }
+
     pub fn to_program_memory_name(this: &ProgramMemmoryId) -> Ident {
         Ident(format!("__soul_mem_{}", this.0))
     }
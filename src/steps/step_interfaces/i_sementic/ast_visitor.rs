@@ -42,6 +42,30 @@ pub struct ControlFlowAnalyser {
     has_error: bool,
 }
 
+pub struct RulesetAnalyser {
+    scope: ScopeVisitor,
+    faults: Vec<SoulFault>,
+    has_error: bool,
+}
+
+pub struct LiteralEvaluator {
+    scope: ScopeVisitor,
+    faults: Vec<SoulFault>,
+    has_error: bool,
+}
+
+pub struct LiteralRetentionAnalyser {
+    scope: ScopeVisitor,
+    faults: Vec<SoulFault>,
+    has_error: bool,
+}
+
+pub struct LambdaCaptureAnalyser {
+    scope: ScopeVisitor,
+    faults: Vec<SoulFault>,
+    has_error: bool,
+}
+
 pub struct BorrowChecker {
     scope: ScopeVisitor,
     faults: Vec<SoulFault>,
@@ -89,14 +113,42 @@ impl ControlFlowAnalyser {
 }
 
 impl TraitAnalyser {
+    pub fn new(analyser: ControlFlowAnalyser, should_reset: bool) -> Self {
+        let (scope, faults, has_error) = analyser.consume_to_tuple();
+        Self::inner_new(scope, faults, has_error, should_reset)   
+    }
+}
+
+impl RulesetAnalyser {
     pub fn new(analyser: TraitAnalyser, should_reset: bool) -> Self {
         let (scope, faults, has_error) = analyser.consume_to_tuple();
         Self::inner_new(scope, faults, has_error, should_reset)   
     }
 }
 
+impl LiteralEvaluator {
+    pub fn new(analyser: RulesetAnalyser, should_reset: bool) -> Self {
+        let (scope, faults, has_error) = analyser.consume_to_tuple();
+        Self::inner_new(scope, faults, has_error, should_reset)   
+    }
+}
+
+impl LiteralRetentionAnalyser {
+    pub fn new(analyser: LiteralEvaluator, should_reset: bool) -> Self {
+        let (scope, faults, has_error) = analyser.consume_to_tuple();
+        Self::inner_new(scope, faults, has_error, should_reset)   
+    }
+}
+
+impl LambdaCaptureAnalyser {
+    pub fn new(analyser: LiteralRetentionAnalyser, should_reset: bool) -> Self {
+        let (scope, faults, has_error) = analyser.consume_to_tuple();
+        Self::inner_new(scope, faults, has_error, should_reset)   
+    }
+}
+
 impl BorrowChecker {
-    pub fn new(analyser: TraitAnalyser, should_reset: bool) -> Self {
+    pub fn new(analyser: LambdaCaptureAnalyser, should_reset: bool) -> Self {
         let (scope, faults, has_error) = analyser.consume_to_tuple();
         Self::inner_new(scope, faults, has_error, should_reset)   
     }
@@ -156,6 +208,10 @@ impl_default_methods!(
     TypeCheckingAnalyser,
     ControlFlowAnalyser,
     TraitAnalyser,
+    RulesetAnalyser,
+    LiteralEvaluator,
+    LiteralRetentionAnalyser,
+    LambdaCaptureAnalyser,
     BorrowChecker,
     Optimizer
 );
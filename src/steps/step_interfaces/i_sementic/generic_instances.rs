@@ -0,0 +1,63 @@
+use std::collections::BTreeMap;
+use crate::{errors::soul_error::SoulSpan, steps::step_interfaces::i_parser::abstract_syntax_tree::{enum_like::Union, expression::Ident, function::Function, object::{Class, Struct}, soul_type::soul_type::TypeGenericKind}};
+
+/// concrete type (or value for 'N impl uint') of every generic of an instance
+pub type GenericBindings = BTreeMap<Ident, TypeGenericKind>;
+
+/// monomorphized copy of a generic declaration, all generic types in the declaration are replaced by their binding
+///
+/// types of local variables live in the scope of the original declaration so a backend
+/// still has to apply `bindings` when it emits the block of a function or methode
+#[derive(Debug, Clone)]
+pub enum GenericInstanceKind {
+    Function(Box<Function>),
+    Struct(Struct),
+    Class(Class),
+    Union(Union),
+}
+
+#[derive(Debug, Clone)]
+pub struct GenericInstance {
+    /// name of instance (e.g 'sumGeneric<int>')
+    pub name: String,
+    pub bindings: GenericBindings,
+    pub kind: GenericInstanceKind,
+    /// first use that created the instance
+    pub span: SoulSpan,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct GenericInstances(BTreeMap<String, GenericInstance>);
+
+impl GenericInstances {
+
+    pub fn new() -> Self {
+        Self(BTreeMap::new())
+    }
+
+    pub fn contains(&self, name: &str) -> bool {
+        self.0.contains_key(name)
+    }
+
+    /// only the first instance of name is stored
+    pub fn insert(&mut self, instance: GenericInstance) {
+        self.0.entry(instance.name.clone()).or_insert(instance);
+    }
+
+    pub fn get(&self, name: &str) -> Option<&GenericInstance> {
+        self.0.get(name)
+    }
+
+    /// all instances ordered by name
+    pub fn iter(&self) -> impl Iterator<Item = &GenericInstance> {
+        self.0.values()
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
@@ -2,3 +2,5 @@ pub mod sementic_response;
 pub mod scope_vistitor;
 pub mod ast_visitor;
 pub mod soul_fault;
+pub mod generic_instances;
+pub mod resolved_calls;
@@ -0,0 +1,36 @@
+use std::collections::BTreeMap;
+use crate::{errors::soul_error::SoulSpan, steps::step_interfaces::i_parser::abstract_syntax_tree::function::FunctionSignature};
+
+/// overload that the sementic analyser selected for every call (by span and name of the call),
+/// so a backend does not have to resolve overloads again
+#[derive(Debug, Clone, Default)]
+pub struct ResolvedCalls(BTreeMap<(SoulSpan, String), FunctionSignature>);
+
+impl ResolvedCalls {
+
+    pub fn new() -> Self {
+        Self(BTreeMap::new())
+    }
+
+    /// only the first resolution of a call is stored
+    pub fn insert(&mut self, span: SoulSpan, name: &str, signature: FunctionSignature) {
+        self.0.entry((span, name.to_string())).or_insert(signature);
+    }
+
+    pub fn get(&self, span: SoulSpan, name: &str) -> Option<&FunctionSignature> {
+        self.0.get(&(span, name.to_string()))
+    }
+
+    /// all calls ordered by span
+    pub fn iter(&self) -> impl Iterator<Item = (SoulSpan, &str, &FunctionSignature)> {
+        self.0.iter().map(|((span, name), signature)| (*span, name.as_str(), signature))
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
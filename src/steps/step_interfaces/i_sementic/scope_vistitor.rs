@@ -1,4 +1,4 @@
-use crate::steps::step_interfaces::i_parser::{abstract_syntax_tree::{spanned::Spanned}, header::{ExternalHeaders}, scope_builder::{InnerScope, ProgramMemmory, ScopeBuilder, ScopeId, ScopeKind}};
+use crate::steps::step_interfaces::{i_sementic::{generic_instances::GenericInstances, resolved_calls::ResolvedCalls}, i_parser::{abstract_syntax_tree::{spanned::Spanned}, header::{ExternalHeaders}, scope_builder::{InnerScope, ProgramMemmory, ScopeBuilder, ScopeId, ScopeKind}}};
 
 pub type Scope = InnerScope<Vec<Spanned<ScopeKind>>>;
 
@@ -8,6 +8,10 @@ pub struct ScopeVisitor {
     pub global_literals: ProgramMemmory,
     pub project_name: String,
     pub external_headers: ExternalHeaders,
+    /// monomorphized copies of generic declarations (see TypeCheckingAnalyser)
+    pub generic_instances: GenericInstances,
+    /// overload of every call (see TypeCheckingAnalyser)
+    pub resolved_calls: ResolvedCalls,
 }
 
 impl ScopeVisitor {
@@ -22,6 +26,8 @@ impl ScopeVisitor {
             project_name,
             global_literals,
             external_headers,
+            generic_instances: GenericInstances::new(),
+            resolved_calls: ResolvedCalls::new(),
         }
     }
 
@@ -77,6 +83,29 @@ impl ScopeVisitor {
         None
     }
 
+    /// like lookup but skips scopes where name is not of a kind that satisfies predicate (e.g. variable shadowing a type)
+    pub fn lookup_kind<F>(&self, name: &str, predicate: F) -> Option<&Spanned<ScopeKind>> 
+    where 
+        F: Fn(&ScopeKind) -> bool
+    {
+        let mut current_index = Some(self.current);
+
+        while let Some(index) = current_index {
+            let scope = &self.scopes[index.0];
+
+            let kind = scope.get(name)
+                .and_then(|kinds| kinds.iter().find(|kind| predicate(&kind.node)));
+            
+            if kind.is_some() {
+                return kind;
+            }
+
+            current_index = scope.parent_index;
+        }
+
+        None
+    }
+
     pub fn lookup_mut(&mut self, name: &str) -> Option<&mut Vec<Spanned<ScopeKind>>> {
         let mut current_index = Some(self.current);
 
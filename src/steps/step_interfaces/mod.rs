@@ -44,13 +44,13 @@
 //!      - Reports semantic and contextual errors.
 //!      - Prepares the AST for the final code generation phase.
 //!
-//! 5. ### `i_code_generator` *(Not yet implemented)*
+//! 5. ### [`i_code_generator`]
 //!    - **Purpose:** Transforms the final analyzed AST into C++ source code.
-//!    - **Planned Output:** C++ code that can be compiled into a binary using a standard C++ compiler.
-//!    - **Future Responsibilities:**
+//!    - **Output:** [`CodeGeneratorResponse`](crate::steps::step_interfaces::i_code_generator::CodeGeneratorResponse)
+//!    - **Responsibilities:**
 //!      - Generate equivalent C++ structures, functions, and logic from Soul AST nodes.
-//!      - Apply optimizations based on semantic information.
-//!      - Handle imports, module generation, and symbol table translation.
+//!      - Emit a header and source file per page under the output folder.
+//!      - Map pages to namespaces and imports to includes.
 //!
 //! ---
 //!
@@ -66,6 +66,7 @@ pub mod i_source_reader;
 pub mod i_tokenizer;
 pub mod i_sementic;
 pub mod i_parser;
+pub mod i_code_generator;
 
 
 